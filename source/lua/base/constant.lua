@@ -56,6 +56,96 @@ RIGID_BODY_KIND = {
     KINEMATIC_VELOCITY_BASED = 3.0,
 }
 
+---@enum format_kind
+FORMAT_KIND = {
+    JSON    = 0,
+    YAML    = 1,
+    TOML    = 2,
+    XML     = 3,
+    INI     = 4,
+    MSGPACK = 5,
+    CBOR    = 6,
+}
+
+---@enum compress_kind
+COMPRESS_KIND = {
+    DEFLATE = 0,
+    ZSTD    = 1,
+    LZ4     = 2,
+}
+
+---@enum encode_kind
+ENCODE_KIND = {
+    BASE64     = 0,
+    BASE64_URL = 1,
+    HEX        = 2,
+}
+
+---@enum hash_kind
+HASH_KIND = {
+    CRC32  = 0,
+    MD5    = 1,
+    SHA1   = 2,
+    SHA256 = 3,
+    XXHASH = 4,
+    BLAKE3 = 5,
+}
+
+---@enum noise_kind
+NOISE_KIND = {
+    PERLIN  = 0,
+    SIMPLEX = 1,
+    WORLEY  = 2,
+    VALUE   = 3,
+}
+
+---@enum fractal_kind
+FRACTAL_KIND = {
+    NONE   = 0,
+    FBM    = 1,
+    RIDGED = 2,
+    BILLOW = 3,
+}
+
+---@enum diagonal_rule
+DIAGONAL_RULE = {
+    NEVER        = 0,
+    NO_OBSTACLE  = 1,
+    ONE_OBSTACLE = 2,
+    ALWAYS       = 3,
+}
+
+---@enum font_mode
+FONT_MODE = {
+    DEFAULT = 0,
+    BITMAP  = 1,
+    SDF     = 2,
+}
+
+---@enum text_align
+TEXT_ALIGN = {
+    LEFT    = 0,
+    CENTER  = 1,
+    RIGHT   = 2,
+    JUSTIFY = 3,
+}
+
+---@enum audio_effect
+AUDIO_EFFECT = {
+    LOW_PASS   = 0,
+    HIGH_PASS  = 1,
+    ECHO       = 2,
+    REVERB     = 3,
+    COMPRESSOR = 4,
+}
+
+---@enum attenuation_kind
+ATTENUATION_KIND = {
+    LINEAR      = 0,
+    INVERSE     = 1,
+    EXPONENTIAL = 2,
+}
+
 ---@enum trace_log_level
 TRACE_LOG_LEVEL = {
     ALL     = 0,
@@ -92,38 +92,22 @@ function scene:begin(call, system, camera_3d)
         model:draw_mesh_instance(0.0)
     end
 
+    -- the attenuation, pan and doppler of every spatial sound and music is computed from the listener.
+    quiver.audio.set_listener(self.camera_3d.point, self.camera_3d.focus, self.camera_3d.angle)
+
     for i, sound in ipairs(self.sound) do
         local data = system:get_sound(sound.path)
 
         if data:get_playing(sound.alias) then
-            if sound.dynamic then
-                local volume = sound.volume
-                local pan = 0.5
-
-                if sound.point then
-                    if sound.distance_min and sound.distance_max then
-                        local fall_off = ((self.camera_3d.point - sound.point):magnitude() - sound.distance_max) /
-                            (sound.distance_min - sound.distance_max)
-                        volume = math.clamp(0.0, 1.0, fall_off) * volume
-
-                        quiver.draw_3d.draw_ball(sound.point, sound.distance_min, color:old(0.000, 0.000, 255.0, 127.0))
-                        quiver.draw_3d.draw_ball(sound.point, sound.distance_max, color:old(255.0, 0.000, 0.000, 33.0))
-                    end
-
-                    pan = vector_3:direction_angle(
-                        vector_3:old(self.camera_3d.point.x, 0.0, self.camera_3d.point.z),
-                        vector_3:old(sound.point.x, 0.0, sound.point.z),
-                        vector_3:old(self.camera_3d.focus.x, 0.0, self.camera_3d.focus.z) -
-                        vector_3:old(self.camera_3d.point.x, 0.0, self.camera_3d.point.z),
-                        self.camera_3d.angle
-                    )
-                    pan = math.abs(pan) / 3.14
-                end
-
-                data:set_pan(pan, sound.alias)
-                data:set_volume(volume, sound.alias)
+            if sound.dynamic and sound.point and sound.distance_min and sound.distance_max then
+                quiver.draw_3d.draw_ball(sound.point, sound.distance_min, color:old(0.000, 0.000, 255.0, 127.0))
+                quiver.draw_3d.draw_ball(sound.point, sound.distance_max, color:old(255.0, 0.000, 0.000, 33.0))
             end
         else
+            if sound.point then
+                data:set_spatial(nil, sound.alias)
+            end
+
             table.remove(self.sound, i)
         end
     end
@@ -134,34 +118,15 @@ function scene:begin(call, system, camera_3d)
         if data:get_playing() then
             data:update()
 
-            if music.dynamic then
-                local volume = music.volume
-                local pan = 0.5
-
-                if music.point then
-                    if music.distance_min and music.distance_max then
-                        local fall_off = ((self.camera_3d.point - music.point):magnitude() - music.distance_max) /
-                            (music.distance_min - music.distance_max)
-                        volume = math.clamp(0.0, 1.0, fall_off) * volume
-
-                        quiver.draw_3d.draw_ball(music.point, music.distance_min, color:old(0.000, 0.000, 255.0, 127.0))
-                        quiver.draw_3d.draw_ball(music.point, music.distance_max, color:old(255.0, 0.000, 0.000, 33.0))
-                    end
-
-                    pan = vector_3:direction_angle(
-                        vector_3:old(self.camera_3d.point.x, 0.0, self.camera_3d.point.z),
-                        vector_3:old(music.point.x, 0.0, music.point.z),
-                        vector_3:old(self.camera_3d.focus.x, 0.0, self.camera_3d.focus.z) -
-                        vector_3:old(self.camera_3d.point.x, 0.0, self.camera_3d.point.z),
-                        self.camera_3d.angle
-                    )
-                    pan = math.abs(pan) / 3.14
-                end
-
-                data:set_pan(pan)
-                data:set_volume(volume)
+            if music.dynamic and music.point and music.distance_min and music.distance_max then
+                quiver.draw_3d.draw_ball(music.point, music.distance_min, color:old(0.000, 0.000, 255.0, 127.0))
+                quiver.draw_3d.draw_ball(music.point, music.distance_max, color:old(255.0, 0.000, 0.000, 33.0))
             end
         else
+            if music.point then
+                data:set_spatial(nil)
+            end
+
             table.remove(self.music, i)
         end
     end
@@ -183,14 +148,6 @@ function scene:set_model(system, path)
     return model
 end
 
-function vector_3:direction_angle(point_a, point_b, focus, up)
-    local side = focus:cross(up):normalize()
-    local x = (point_b - point_a):dot(side)
-    local z = (point_b - point_a):dot(focus)
-
-    return math.atan2(z, x)
-end
-
 function scene:stop_sound(system, path)
     -- load the sound into memory.
     local sound = system:get_sound(path)
@@ -216,6 +173,21 @@ function scene:stop_sound_all(system)
     end
 end
 
+function scene:get_spatial(point, distance_min, distance_max)
+    if not point then
+        return nil
+    end
+
+    -- without a distance range, only pan the source, and keep the volume as-is.
+    return {
+        point = point,
+        kind = ATTENUATION_KIND.LINEAR,
+        distance_min = distance_min,
+        distance_max = distance_max,
+        rolloff = (distance_min and distance_max) and 1.0 or 0.0,
+    }
+end
+
 function scene:play_sound(system, path, point, dynamic, volume, distance_min, distance_max)
     -- load the sound into memory.
     local sound = system:get_sound(path)
@@ -236,32 +208,13 @@ function scene:play_sound(system, path, point, dynamic, volume, distance_min, di
 
     if not volume then volume = 1.0 end
 
-    local sound_volume = volume
-    local pan = 0.5
-
-    if point then
-        if distance_min and distance_max then
-            local fall_off = ((self.camera_3d.point - point):magnitude() - distance_max) / (distance_min - distance_max)
-            sound_volume = math.clamp(0.0, 1.0, fall_off) * volume
-        end
-
-        pan = vector_3:direction_angle(
-            vector_3:old(self.camera_3d.point.x, 0.0, self.camera_3d.point.z),
-            vector_3:old(point.x, 0.0, point.z),
-            vector_3:old(self.camera_3d.focus.x, 0.0, self.camera_3d.focus.z) -
-            vector_3:old(self.camera_3d.point.x, 0.0, self.camera_3d.point.z),
-            self.camera_3d.angle
-        )
-        pan = math.abs(pan) / 3.14
-    end
-
-    sound:set_pan(pan, alias)
-    sound:set_volume(sound_volume, alias)
+    sound:set_volume(volume, alias)
+    sound:set_spatial(scene:get_spatial(point, distance_min, distance_max), alias)
     sound:play(alias)
 
     table.insert(self.sound, {
         path = path,
-        point = point and vector_3:new(point.x, 0.0, point.z),
+        point = point and vector_3:new(point.x, point.y, point.z),
         dynamic = dynamic,
         volume = volume,
         distance_min = distance_min,
@@ -276,6 +229,7 @@ function scene:reset_music_all(system)
 
         data:stop()
         data:set_pitch(1.0)
+        data:set_spatial(nil)
         data:set_pan(0.5)
         data:set_volume(1.0)
     end
@@ -326,32 +280,13 @@ function scene:play_music(system, path, point, dynamic, volume, distance_min, di
 
     if not volume then volume = 1.0 end
 
-    local music_volume = volume
-    local pan = 0.5
-
-    if point then
-        if distance_min and distance_max then
-            local fall_off = ((self.camera_3d.point - point):magnitude() - distance_max) / (distance_min - distance_max)
-            music_volume = math.clamp(0.0, 1.0, fall_off) * volume
-        end
-
-        pan = vector_3:direction_angle(
-            vector_3:old(self.camera_3d.point.x, 0.0, self.camera_3d.point.z),
-            vector_3:old(point.x, 0.0, point.z),
-            vector_3:old(self.camera_3d.focus.x, 0.0, self.camera_3d.focus.z) -
-            vector_3:old(self.camera_3d.point.x, 0.0, self.camera_3d.point.z),
-            self.camera_3d.angle
-        )
-        pan = math.abs(pan) / 3.14
-    end
-
-    music:set_pan(pan)
-    music:set_volume(music_volume)
+    music:set_volume(volume)
+    music:set_spatial(scene:get_spatial(point, distance_min, distance_max))
     music:play()
 
     table.insert(self.music, {
         path = path,
-        point = point and vector_3:new(point.x, 0.0, point.z),
+        point = point and vector_3:new(point.x, point.y, point.z),
         dynamic = dynamic,
         volume = volume,
         distance_min = distance_min,
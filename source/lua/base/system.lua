@@ -156,27 +156,21 @@ function system:scan(search)
         end
     end
 
-    -- TO-DO this might conflict with quiver's own embed file loader for require...?
-    -- file-system loader
-    -- embed loader
-    -- lua loader, which will check for disk, .ZIP and embed, again.
-    -- this should *probably* take the highest priority of all...?
-    table.insert(package.loaders, function(path)
-        local asset = self.locate[path .. ".lua"]
+    -- mount the file-system for "require", which will check for disk, .ZIP and embed. re-scanning will replace the previous mount.
+    quiver.general.set_module_mount(self, function(path)
+        local asset = self.locate[path]
 
         if asset then
             if asset.kind == FILE_KIND.DISK then
-                asset = quiver.file.get_file(asset.path)
+                return quiver.file.get_file(asset.path, false)
             elseif asset.kind == FILE_KIND.PACK then
-                asset = asset.path:get_file(path .. ".lua", false, true)
+                return asset.path:get_file(path, false)
             elseif asset.kind == FILE_KIND.EMBED then
-                asset = quiver.data.get_embed_file(asset.path)
+                return quiver.data.get_embed_file(asset.path, false)
             end
-
-            return loadstring(asset, path)
-        else
-            return string.format("\n\tno file '\"%s\"' in system user-data", path)
         end
+
+        return nil
     end)
 end
 
@@ -14,7 +14,7 @@ local time = 0.0
 function quiver.info()
     -- Every entry in this table is completely optional.
     return {
-        -- Window name.
+        -- Window name. Also used as the folder name for the save, configuration and cache path.
         name       = "Quiver",
         -- Window icon. If nil, will use Quiver's logo. If empty (not-nil, empty string), will not set any icon. If not empty, will load an icon with that path.
         icon       = nil,
@@ -22,7 +22,7 @@ function quiver.info()
         size       = { 1024, 768 },
         -- Window frame-rate.
         rate       = 60,
-        -- Window mode.
+        -- Window mode. If false, Quiver will run in head-less mode: no window or audio device will be made, only the non-graphical API will be available, and quiver.general.tick() will keep the main loop at the frame-rate above. Can also be set with "--head-less", or "head_less" in the info file.
         head       = true,
         -- Vertical sync.
         sync       = false,
@@ -56,6 +56,8 @@ function quiver.info()
         mouse_pass = false,
         -- Allow video interlace for V3D.
         interlace  = false,
+        -- Additional search paths for "require", relative to the project root.
+        search     = {},
     }
 end
 
@@ -15,14 +15,14 @@ quiver = {}
 --- ---
 ---*Not available in head-less mode.*
 ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/sound.rs#L64)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/sound.rs#L66)
 ---@class quiver.sound
 quiver.sound = {}
 
 ---An unique handle for sound in memory.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/sound.rs#L81)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/sound.rs#L83)
 ---@class sound
 sound = {}
 
@@ -34,7 +34,7 @@ sound = {}
 --- ---
 ---*This function is asynchronous and can run within a co-routine.*
 ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/sound.rs#L101)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/sound.rs#L103)
 function quiver.sound.new(path,alias) end
 
 ---Create a new sound resource, from memory.
@@ -46,79 +46,103 @@ function quiver.sound.new(path,alias) end
 --- ---
 ---*This function is asynchronous and can run within a co-routine.*
 ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/sound.rs#L142)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/sound.rs#L145)
 function quiver.sound.new_from_memory(data,alias,kind) end
 
 ---Create a sound alias.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/sound.rs#L209)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/sound.rs#L236)
 function sound:create_alias() end
 
 ---Remove a sound alias.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/sound.rs#L219)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/sound.rs#L250)
 function sound:remove_alias() end
 
 ---Clear every sound alias.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/sound.rs#L233)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/sound.rs#L270)
 function sound:remove_alias() end
 
+---Set the audio bus of the sound, and of every sound alias.
+---@param bus audio_bus? # The audio bus. Use nil to remove the sound from its bus.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/sound.rs#L296)
+function sound:set_bus(bus) end
+
 ---Play the sound.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/sound.rs#L246)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/sound.rs#L318)
 function sound:play() end
 
 ---Check if sound is currently playing.
 ---@return boolean state # State of the sound.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/sound.rs#L270)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/sound.rs#L342)
 function sound:get_playing() end
 
 ---Stop the sound.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/sound.rs#L287)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/sound.rs#L359)
 function sound:stop() end
 
 ---Pause the sound.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/sound.rs#L304)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/sound.rs#L376)
 function sound:pause() end
 
 ---Resume the sound.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/sound.rs#L321)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/sound.rs#L393)
 function sound:resume() end
 
 ---Set volume for the sound. (range: 0.0 - 1.0)
 ---@param volume number # Current volume.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/sound.rs#L347)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/sound.rs#L419)
 function sound:set_volume(volume) end
 
 ---Set pitch for the sound.
 ---@param pitch number # Current pitch.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/sound.rs#L376)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/sound.rs#L448)
 function sound:set_pitch(pitch) end
 
 ---Set pan for the sound. (range: 0.0 - 1.0; 0.5 is center)
 ---@param pan number # Current pan.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/sound.rs#L405)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/sound.rs#L473)
 function sound:set_pan(pan) end
 
+---Set the sound (or sound alias) as a spatial source, with distance attenuation, pan and doppler pitch shift from the listener.
+---@param option table? # The spatial option: { point, velocity?, kind? (ATTENUATION_KIND), distance_min?, distance_max?, rolloff? }. Use nil to remove the spatial source.
+---@param alias number? # OPTIONAL: The sound alias index. Default: the sound itself.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/sound.rs#L503)
+function sound:set_spatial(option,alias) end
+
+---Set the point of the spatial source of the sound (or sound alias).
+---@param point vector_3 # The point of the source.
+---@param velocity vector_3? # OPTIONAL: The velocity of the source. Default: derived from the last source point.
+---@param alias number? # OPTIONAL: The sound alias index. Default: the sound itself.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/sound.rs#L531)
+function sound:set_spatial_point(point,velocity,alias) end
+
 ---The input API.
 ---
 --- ---
@@ -510,12 +534,21 @@ function rapier:get_collider_shape_cuboid(collider) end
 ---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/rapier.rs#L425)
 function rapier:set_collider_shape_cuboid(collider,half_shape) end
 
+---Get the shape of a collider (tri-mesh), in world space. Useful for baking a navigation mesh with quiver.navigation.new_mesh.
+---@param collider table # Collider handle.
+---@return table point_table # The point array table.
+---@return table index_table # The index array table.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/rapier.rs#L465)
+function rapier:get_collider_shape_tri_mesh(collider) end
+
 ---Get the parent of a collider.
 ---@param collider table # Collider handle.
 ---@return table rigid_body # Rigid body handle.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/rapier.rs#L466)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/rapier.rs#L511)
 function rapier:get_collider_parent(collider) end
 
 ---Get the position of a collider.
@@ -525,7 +558,7 @@ function rapier:get_collider_parent(collider) end
 ---@return number position_z # Collider position (Z).
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/rapier.rs#L497)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/rapier.rs#L542)
 function rapier:get_collider_position(collider) end
 
 ---Set the position of a collider.
@@ -533,7 +566,7 @@ function rapier:get_collider_position(collider) end
 ---@param position vector_3 # Collider position.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/rapier.rs#L524)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/rapier.rs#L569)
 function rapier:set_collider_position(collider,position) end
 
 ---Set the rotation of a collider.
@@ -541,7 +574,7 @@ function rapier:set_collider_position(collider,position) end
 ---@param rotation vector_3 # Collider rotation.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/rapier.rs#L552)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/rapier.rs#L597)
 function rapier:set_collider_rotation(collider,rotation) end
 
 ---Set the sensor state of a collider.
@@ -549,7 +582,7 @@ function rapier:set_collider_rotation(collider,rotation) end
 ---@param sensor boolean # Collider sensor state.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/rapier.rs#L581)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/rapier.rs#L626)
 function rapier:set_collider_sensor(collider,sensor) end
 
 ---Remove a collider.
@@ -557,7 +590,7 @@ function rapier:set_collider_sensor(collider,sensor) end
 ---@param wake_parent boolean # Whether or not to wake up the rigid body parent this collider is bound to.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/rapier.rs#L610)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/rapier.rs#L655)
 function rapier:collider_remove(collider,wake_parent) end
 
 ---Remove a rigid body.
@@ -565,38 +598,38 @@ function rapier:collider_remove(collider,wake_parent) end
 ---@param remove_collider boolean # Whether or not to remove every collider this rigid body is bound to.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/rapier.rs#L637)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/rapier.rs#L682)
 function rapier:rigid_body_remove(rigid_body,remove_collider) end
 
 ---Create a character controller.
 ---@return table character_controller # Character controller.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/rapier.rs#L665)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/rapier.rs#L710)
 function rapier:character_controller() end
 
 ---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/rapier.rs#L676)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/rapier.rs#L721)
 function rapier:set_character_controller_up_vector() end
 
 ---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/rapier.rs#L693)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/rapier.rs#L738)
 function rapier:set_character_controller_slope() end
 
 ---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/rapier.rs#L710)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/rapier.rs#L755)
 function rapier:set_character_auto_step() end
 
 ---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/rapier.rs#L757)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/rapier.rs#L802)
 function rapier:set_character_snap_ground() end
 
 ---Move a character controller.
@@ -611,7 +644,7 @@ function rapier:set_character_snap_ground() end
 ---@return boolean slope # Currently on slope.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/rapier.rs#L793)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/rapier.rs#L838)
 function rapier:character_controller_move(step,character,collider,translation) end
 
 ---Create a rigid body.
@@ -619,7 +652,7 @@ function rapier:character_controller_move(step,character,collider,translation) e
 ---@return table rigid_body # Rigid body handle.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/rapier.rs#L842)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/rapier.rs#L887)
 function rapier:rigid_body(kind) end
 
 ---Get the user data of a rigid_body.
@@ -627,7 +660,7 @@ function rapier:rigid_body(kind) end
 ---@return number user_data # Rigid body user data.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/rapier.rs#L866)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/rapier.rs#L911)
 function rapier:get_rigid_body_user_data(rigid_body) end
 
 ---Set the user data of a rigid_body.
@@ -635,7 +668,7 @@ function rapier:get_rigid_body_user_data(rigid_body) end
 ---@param user_data number # Rigid body user data.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/rapier.rs#L892)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/rapier.rs#L937)
 function rapier:set_rigid_body_user_data(rigid_body,user_data) end
 
 ---Set the position of a rigid_body.
@@ -643,7 +676,7 @@ function rapier:set_rigid_body_user_data(rigid_body,user_data) end
 ---@param position vector_3 # rigid_body position.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/rapier.rs#L919)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/rapier.rs#L964)
 function rapier:set_rigid_body_position(rigid_body,position) end
 
 ---Set the rotation of a rigid_body.
@@ -651,7 +684,7 @@ function rapier:set_rigid_body_position(rigid_body,position) end
 ---@param rotation vector_3 # rigid_body rotation.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/rapier.rs#L948)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/rapier.rs#L993)
 function rapier:set_rigid_body_rotation(rigid_body,rotation) end
 
 ---Get the user data of a collider.
@@ -659,7 +692,7 @@ function rapier:set_rigid_body_rotation(rigid_body,rotation) end
 ---@return number user_data # Collider user data.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/rapier.rs#L983)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/rapier.rs#L1028)
 function rapier:get_collider_user_data(collider) end
 
 ---Set the user data of a collider.
@@ -667,7 +700,7 @@ function rapier:get_collider_user_data(collider) end
 ---@param user_data number # Collider user data.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/rapier.rs#L1006)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/rapier.rs#L1051)
 function rapier:set_collider_user_data(collider,user_data) end
 
 ---Create a collider builder (cuboid).
@@ -675,7 +708,7 @@ function rapier:set_collider_user_data(collider,user_data) end
 ---@return table collider_builer # Collider builder.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/rapier.rs#L1037)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/rapier.rs#L1082)
 function rapier:collider_builder_cuboid(half_shape) end
 
 ---Create a collider builder (tri-mesh).
@@ -684,7 +717,7 @@ function rapier:collider_builder_cuboid(half_shape) end
 ---@return table collider_builer # Collider builder.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/rapier.rs#L1064)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/rapier.rs#L1109)
 function rapier:collider_builder_tri_mesh(point_table,index_table) end
 
 ---Create a collider builder (convex hull).
@@ -692,19 +725,19 @@ function rapier:collider_builder_tri_mesh(point_table,index_table) end
 ---@return table collider_builer # Collider builder.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/rapier.rs#L1112)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/rapier.rs#L1157)
 function rapier:collider_builder_convex_hull(vector_table) end
 
 ---Step the Rapier simulation.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/rapier.rs#L1137)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/rapier.rs#L1182)
 function rapier:step() end
 
 ---Render the Rapier simulation.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/rapier.rs#L1175)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/rapier.rs#L1220)
 function rapier:debug_render() end
 
 ---The Steam API.
@@ -1241,14 +1274,14 @@ function quiver.request.post(link,data,form,json,binary) end
 --- ---
 ---*Not available in head-less mode.*
 ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/texture.rs#L70)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/texture.rs#L71)
 ---@class quiver.texture
 quiver.texture = {}
 
 ---An unique handle for a texture in memory.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/texture.rs#L193)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/texture.rs#L194)
 ---@class texture
 ---@field shape_x number # Shape of the texture (X).
 ---@field shape_y number # Shape of the texture (Y).
@@ -1257,27 +1290,27 @@ texture = {}
 ---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/texture.rs#L210)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/texture.rs#L211)
 function texture:to_image() end
 
 ---Set the mipmap for a texture.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/texture.rs#L221)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/texture.rs#L222)
 function texture:set_mipmap() end
 
 ---Set the filter for a texture.
 ---@param filter texture_filter # Texture filter.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/texture.rs#L238)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/texture.rs#L239)
 function texture:set_filter(filter) end
 
 ---Set the wrap for a texture.
 ---@param wrap texture_wrap # Texture wrap.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/texture.rs#L255)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/texture.rs#L256)
 function texture:set_wrap(wrap) end
 
 ---Draw a texture.
@@ -1287,7 +1320,7 @@ function texture:set_wrap(wrap) end
 ---@param color color # TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/texture.rs#L275)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/texture.rs#L276)
 function texture:draw(point,angle,scale,color) end
 
 ---Draw a texture (pro).
@@ -1298,7 +1331,7 @@ function texture:draw(point,angle,scale,color) end
 ---@param color color # TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/texture.rs#L296)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/texture.rs#L297)
 function texture:draw_pro(box_a,box_b,point,angle,color) end
 
 ---Draw a billboard texture.
@@ -1308,7 +1341,7 @@ function texture:draw_pro(box_a,box_b,point,angle,color) end
 ---@param color color # TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/texture.rs#L327)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/texture.rs#L328)
 function texture:draw_billboard(camera,point,scale,color) end
 
 ---Draw a billboard texture (pro).
@@ -1322,7 +1355,7 @@ function texture:draw_billboard(camera,point,scale,color) end
 ---@param color color # TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/texture.rs#L356)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/texture.rs#L357)
 function texture:draw_billboard_pro(camera,source,point,up,scale,origin,angle,color) end
 
 ---Create a new texture resource.
@@ -1330,19 +1363,19 @@ function texture:draw_billboard_pro(camera,source,point,up,scale,origin,angle,co
 ---@return texture texture # Texture resource.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/texture.rs#L395)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/texture.rs#L396)
 function quiver.texture.new(path) end
 
 ---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/texture.rs#L418)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/texture.rs#L420)
 function quiver.texture.new_from_memory() end
 
 ---An unique handle for a render texture in memory.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/texture.rs#L468)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/texture.rs#L470)
 ---@class render_texture
 ---@field shape_x number # Shape of the texture (X).
 ---@field shape_y number # Shape of the texture (Y).
@@ -1352,7 +1385,7 @@ render_texture = {}
 ---@param call function # The draw code.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/texture.rs#L488)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/texture.rs#L490)
 function render_texture:begin(call) end
 
 ---Draw a texture.
@@ -1362,7 +1395,7 @@ function render_texture:begin(call) end
 ---@param color color # TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/texture.rs#L513)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/texture.rs#L515)
 function render_texture:draw(point,angle,scale,color) end
 
 ---Draw a texture (pro).
@@ -1373,7 +1406,7 @@ function render_texture:draw(point,angle,scale,color) end
 ---@param color color # TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/texture.rs#L537)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/texture.rs#L539)
 function render_texture:draw_pro(box_a,box_b,point,angle,color) end
 
 ---Create a new render texture resource.
@@ -1381,20 +1414,20 @@ function render_texture:draw_pro(box_a,box_b,point,angle,color) end
 ---@return render_texture render_texture # Render texture resource.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/texture.rs#L571)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/texture.rs#L573)
 function quiver.render_texture.new(shape) end
 
 ---The ZIP API.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/zip.rs#L64)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/zip.rs#L65)
 ---@class quiver.zip
 quiver.zip = {}
 
 ---An unique handle to a ZIP in memory.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/zip.rs#L83)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/zip.rs#L84)
 ---@class zip
 zip = {}
 
@@ -1406,14 +1439,14 @@ zip = {}
 --- ---
 ---*This function is asynchronous and can run within a co-routine.*
 ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/zip.rs#L104)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/zip.rs#L105)
 function zip:get_file(path,binary) end
 
 ---Get a list of every file in the ZIP file.
 ---@return table list # The list of every file.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/zip.rs#L141)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/zip.rs#L142)
 function zip:get_list() end
 
 ---Check if the given path is a file.
@@ -1421,7 +1454,7 @@ function zip:get_list() end
 ---@return boolean value # True if the path is a file, false otherwise.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/zip.rs#L160)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/zip.rs#L161)
 function zip:is_file(path) end
 
 ---Check if the given path is a folder.
@@ -1429,7 +1462,7 @@ function zip:is_file(path) end
 ---@return boolean value # True if the path is a folder, false otherwise.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/zip.rs#L180)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/zip.rs#L181)
 function zip:is_path(path) end
 
 ---Create a new ZIP resource.
@@ -1437,7 +1470,7 @@ function zip:is_path(path) end
 ---@return zip zip # ZIP resource.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/zip.rs#L203)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/zip.rs#L204)
 function quiver.zip.new(path) end
 
 ---The model API.
@@ -1445,14 +1478,14 @@ function quiver.zip.new(path) end
 --- ---
 ---*Not available in head-less mode.*
 ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/model.rs#L65)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/model.rs#L66)
 ---@class quiver.model
 quiver.model = {}
 
 ---An unique handle for a model in memory.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/model.rs#L96)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/model.rs#L97)
 ---@class model
 ---@field mesh_count number # Mesh count.
 ---@field bone_count number # Bone count.
@@ -1464,7 +1497,7 @@ model = {}
 ---@return model model # Model resource.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/model.rs#L114)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/model.rs#L115)
 function quiver.model.new(path) end
 
 ---Bind a texture to the model.
@@ -1473,13 +1506,13 @@ function quiver.model.new(path) end
 ---@param texture texture # Texture to bind to model.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/model.rs#L151)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/model.rs#L153)
 function model:bind(index,which,texture) end
 
 ---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/model.rs#L186)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/model.rs#L188)
 function model:draw_mesh() end
 
 ---Draw the model.
@@ -1488,7 +1521,7 @@ function model:draw_mesh() end
 ---@param color color # TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/model.rs#L219)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/model.rs#L221)
 function model:draw(point,scale,color) end
 
 ---Draw the model (wire-frame).
@@ -1497,7 +1530,7 @@ function model:draw(point,scale,color) end
 ---@param color color # TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/model.rs#L242)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/model.rs#L244)
 function model:draw_wire(point,scale,color) end
 
 ---Draw the model with a transformation.
@@ -1507,7 +1540,7 @@ function model:draw_wire(point,scale,color) end
 ---@param color color # TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/model.rs#L266)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/model.rs#L268)
 function model:draw_transform(point,angle,scale,color) end
 
 ---TO-DO
@@ -1519,7 +1552,7 @@ function model:draw_transform(point,angle,scale,color) end
 ---@return number max_z # Maximum vector. (Z)
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/model.rs#L299)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/model.rs#L301)
 function model:get_box_3() end
 
 ---Get the vertex data of a specific mesh in the model.
@@ -1527,7 +1560,7 @@ function model:get_box_3() end
 ---@return table table # Vector3 table.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/model.rs#L324)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/model.rs#L326)
 function model:mesh_vertex(index) end
 
 ---Get the index data of a specific mesh in the model.
@@ -1535,7 +1568,7 @@ function model:mesh_vertex(index) end
 ---@return table table # Number table.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/model.rs#L342)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/model.rs#L344)
 function model:mesh_index(index) end
 
 ---Get the triangle count of a specific mesh in the model.
@@ -1543,13 +1576,13 @@ function model:mesh_index(index) end
 ---@return number count # Triangle count.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/model.rs#L371)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/model.rs#L373)
 function model:mesh_triangle_count(index) end
 
 ---An unique handle for a model animation in memory.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/model.rs#L387)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/model.rs#L389)
 ---@class model_animation
 model_animation = {}
 
@@ -1558,19 +1591,19 @@ model_animation = {}
 ---@return model_animation model_animation # ModelAnimation resource.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/model.rs#L405)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/model.rs#L407)
 function quiver.model_animation.new(path) end
 
 ---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/model.rs#L451)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/model.rs#L454)
 function model_animation:get_bone_() end
 
 ---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/model.rs#L469)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/model.rs#L472)
 function model_animation:get_bone_() end
 
 ---Update model with new model animation data.
@@ -1578,7 +1611,7 @@ function model_animation:get_bone_() end
 ---@param frame number # TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/model.rs#L492)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/model.rs#L495)
 function model_animation:update(model,frame) end
 
 ---The drawing API.
@@ -1611,7 +1644,7 @@ function quiver.draw.begin(call,...) end
 ---@param ... any # Variadic data.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/draw.rs#L143)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/draw.rs#L150)
 function quiver.draw.begin_blend(call,mode,...) end
 
 ---Initialize drawing (scissor mode) to the screen.
@@ -1620,13 +1653,13 @@ function quiver.draw.begin_blend(call,mode,...) end
 ---@param ... any # Variadic data.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/draw.rs#L169)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/draw.rs#L176)
 function quiver.draw.begin_scissor(call,view,...) end
 
 ---The 3D drawing API.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/draw.rs#L196)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/draw.rs#L203)
 ---@class quiver.draw_3d
 quiver.draw_3d = {}
 
@@ -1636,7 +1669,7 @@ quiver.draw_3d = {}
 ---@param ... any # Variadic data.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/draw.rs#L238)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/draw.rs#L245)
 function quiver.draw_3d.begin(call,camera,...) end
 
 ---Get a ray for a 2D screen-space point.
@@ -1651,7 +1684,7 @@ function quiver.draw_3d.begin(call,camera,...) end
 ---@return number direction_z # The 3D ray direction. (Z).
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/draw.rs#L274)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/draw.rs#L281)
 function quiver.draw_3d.get_screen_to_world(camera,point,shape) end
 
 ---Get a 2D screen-space point for a 3D world-space point.
@@ -1662,7 +1695,7 @@ function quiver.draw_3d.get_screen_to_world(camera,point,shape) end
 ---@return number point_y # The 2D screen-space point (Y).
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/draw.rs#L317)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/draw.rs#L324)
 function quiver.draw_3d.get_world_to_screen(camera,point,shape) end
 
 ---Draw a line.
@@ -1671,31 +1704,31 @@ function quiver.draw_3d.get_world_to_screen(camera,point,shape) end
 ---@param color color # The color of the line.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/draw.rs#L348)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/draw.rs#L355)
 function quiver.draw_3d.draw_line(point_a,point_b,color) end
 
 ---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/draw.rs#L368)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/draw.rs#L375)
 function quiver.draw_3d.draw_point() end
 
 ---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/draw.rs#L384)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/draw.rs#L391)
 function quiver.draw_3d.draw_circle() end
 
 ---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/draw.rs#L404)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/draw.rs#L411)
 function quiver.draw_3d.draw_triangle() end
 
 ---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/draw.rs#L425)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/draw.rs#L432)
 function quiver.draw_3d.draw_triangle_strip() end
 
 ---Draw a cube.
@@ -1704,7 +1737,7 @@ function quiver.draw_3d.draw_triangle_strip() end
 ---@param color color # The color of the cube.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/draw.rs#L447)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/draw.rs#L454)
 function quiver.draw_3d.draw_cube(point,shape,color) end
 
 ---Draw a cube (wire-frame).
@@ -1713,49 +1746,49 @@ function quiver.draw_3d.draw_cube(point,shape,color) end
 ---@param color color # The color of the cube.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/draw.rs#L472)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/draw.rs#L479)
 function quiver.draw_3d.draw_cube_wire(point,shape,color) end
 
 ---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/draw.rs#L492)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/draw.rs#L499)
 function quiver.draw_3d.draw_sphere() end
 
 ---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/draw.rs#L511)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/draw.rs#L518)
 function quiver.draw_3d.draw_sphere_wire() end
 
 ---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/draw.rs#L530)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/draw.rs#L537)
 function quiver.draw_3d.draw_cylinder() end
 
 ---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/draw.rs#L564)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/draw.rs#L571)
 function quiver.draw_3d.draw_cylinder_wire() end
 
 ---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/draw.rs#L598)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/draw.rs#L605)
 function quiver.draw_3d.draw_capsule() end
 
 ---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/draw.rs#L632)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/draw.rs#L639)
 function quiver.draw_3d.draw_capsule_wire() end
 
 ---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/draw.rs#L666)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/draw.rs#L673)
 function quiver.draw_3d.draw_plane() end
 
 ---Draw a ray.
@@ -1763,7 +1796,7 @@ function quiver.draw_3d.draw_plane() end
 ---@param color color # The color of the ray.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/draw.rs#L690)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/draw.rs#L697)
 function quiver.draw_3d.draw_ray(ray,color) end
 
 ---Draw a grid.
@@ -1771,7 +1804,7 @@ function quiver.draw_3d.draw_ray(ray,color) end
 ---@param space number # The space shift of the grid.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/draw.rs#L710)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/draw.rs#L717)
 function quiver.draw_3d.draw_grid(slice,space) end
 
 ---Draw a 3D box.
@@ -1779,13 +1812,13 @@ function quiver.draw_3d.draw_grid(slice,space) end
 ---@param color color # The color of the ball.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/draw.rs#L727)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/draw.rs#L734)
 function quiver.draw_3d.draw_box_3(shape,color) end
 
 ---The 2D drawing API.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/draw.rs#L744)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/draw.rs#L751)
 ---@class quiver.draw_2d
 quiver.draw_2d = {}
 
@@ -1795,7 +1828,7 @@ quiver.draw_2d = {}
 ---@param ... any # Variadic data.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/draw.rs#L803)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/draw.rs#L810)
 function quiver.draw_2d.begin(call,camera,...) end
 
 ---Get a screen-space point for a 2D world-space point.
@@ -1805,7 +1838,7 @@ function quiver.draw_2d.begin(call,camera,...) end
 ---@return number point_y # The 2D screen-space point (Y).
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/draw.rs#L834)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/draw.rs#L841)
 function quiver.draw_2d.get_world_to_screen(camera,point) end
 
 ---Get a world-space point for a 2D screen-space point.
@@ -1815,7 +1848,7 @@ function quiver.draw_2d.get_world_to_screen(camera,point) end
 ---@return number point_y # The 2D world-space point (Y).
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/draw.rs#L863)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/draw.rs#L870)
 function quiver.draw_2d.get_screen_to_world(camera,point) end
 
 ---Draw pixel.
@@ -1823,7 +1856,7 @@ function quiver.draw_2d.get_screen_to_world(camera,point) end
 ---@param color color # The color of the pixel.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/draw.rs#L888)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/draw.rs#L895)
 function quiver.draw_2d.draw_pixel(point,color) end
 
 ---Draw a line.
@@ -1833,19 +1866,19 @@ function quiver.draw_2d.draw_pixel(point,color) end
 ---@param color color # The color of the line.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/draw.rs#L911)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/draw.rs#L918)
 function quiver.draw_2d.draw_line(point_a,point_b,thick,color) end
 
 ---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/draw.rs#L932)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/draw.rs#L939)
 function quiver.draw_2d.draw_line_strip() end
 
 ---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/draw.rs#L951)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/draw.rs#L958)
 function quiver.draw_2d.draw_line_bezier() end
 
 ---Draw a circle.
@@ -1854,13 +1887,13 @@ function quiver.draw_2d.draw_line_bezier() end
 ---@param color color # TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/draw.rs#L977)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/draw.rs#L984)
 function quiver.draw_2d.draw_circle(point,radius,color) end
 
 ---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/draw.rs#L997)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/draw.rs#L1004)
 function quiver.draw_2d.draw_circle_line() end
 
 ---Draw the sector of a circle.
@@ -1872,43 +1905,43 @@ function quiver.draw_2d.draw_circle_line() end
 ---@param color color # TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/draw.rs#L1025)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/draw.rs#L1032)
 function quiver.draw_2d.draw_circle_sector(point,radius,begin_angle,close_angle,segment_count,color) end
 
 ---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/draw.rs#L1059)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/draw.rs#L1066)
 function quiver.draw_2d.draw_circle_sector_line() end
 
 ---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/draw.rs#L1079)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/draw.rs#L1086)
 function quiver.draw_2d.draw_circle_gradient() end
 
 ---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/draw.rs#L1106)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/draw.rs#L1113)
 function quiver.draw_2d.draw_ellipse() end
 
 ---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/draw.rs#L1133)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/draw.rs#L1140)
 function quiver.draw_2d.draw_ellipse_line() end
 
 ---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/draw.rs#L1160)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/draw.rs#L1167)
 function quiver.draw_2d.draw_ring() end
 
 ---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/draw.rs#L1196)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/draw.rs#L1203)
 function quiver.draw_2d.draw_ring_line() end
 
 ---Draw 2D box.
@@ -1918,7 +1951,7 @@ function quiver.draw_2d.draw_ring_line() end
 ---@param color color # The color of the box.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/draw.rs#L1238)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/draw.rs#L1245)
 function quiver.draw_2d.draw_box_2(shape,point,angle,color) end
 
 ---Draw 2D box with a 4-point gradient.
@@ -1929,7 +1962,7 @@ function quiver.draw_2d.draw_box_2(shape,point,angle,color) end
 ---@param color_d color # The color D (B.R.) of the box.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/draw.rs#L1266)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/draw.rs#L1273)
 function quiver.draw_2d.draw_box_2_gradient(shape,color_a,color_b,color_c,color_d) end
 
 ---Draw 2D box (out-line).
@@ -1938,7 +1971,7 @@ function quiver.draw_2d.draw_box_2_gradient(shape,color_a,color_b,color_c,color_
 ---@param color color # The color of the box.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/draw.rs#L1306)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/draw.rs#L1313)
 function quiver.draw_2d.draw_box_2_line(shape,thick,color) end
 
 ---Draw 2D box (round).
@@ -1948,7 +1981,7 @@ function quiver.draw_2d.draw_box_2_line(shape,thick,color) end
 ---@param color color # The color of the box.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/draw.rs#L1332)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/draw.rs#L1339)
 function quiver.draw_2d.draw_box_2_round(shape,round,count,color) end
 
 ---Draw 2D box (out-line, round).
@@ -1959,7 +1992,7 @@ function quiver.draw_2d.draw_box_2_round(shape,round,count,color) end
 ---@param color color # The color of the box.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/draw.rs#L1359)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/draw.rs#L1366)
 function quiver.draw_2d.draw_box_2_line_round(shape,round,count,thick,color) end
 
 ---Draw 2D triangle.
@@ -1969,7 +2002,7 @@ function quiver.draw_2d.draw_box_2_line_round(shape,round,count,thick,color) end
 ---@param color color # The color of the triangle.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/draw.rs#L1385)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/draw.rs#L1392)
 function quiver.draw_2d.draw_triangle(point_a,point_b,point_c,color) end
 
 ---Draw 2D triangle (out-line).
@@ -1979,7 +2012,7 @@ function quiver.draw_2d.draw_triangle(point_a,point_b,point_c,color) end
 ---@param color color # The color of the triangle.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/draw.rs#L1413)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/draw.rs#L1420)
 function quiver.draw_2d.draw_triangle_line(point_a,point_b,point_c,color) end
 
 ---The data API.
@@ -1992,7 +2025,7 @@ quiver.data = {}
 ---An unique handle for a data buffer in memory.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/data.rs#L103)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/data.rs#L121)
 ---@class data
 data = {}
 
@@ -2000,14 +2033,14 @@ data = {}
 ---@return number length # The length of the data buffer.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/data.rs#L157)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/data.rs#L175)
 function data:get_length() end
 
 ---Get the data buffer.
 ---@return table buffer # The data buffer.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/data.rs#L169)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/data.rs#L187)
 function data:get_buffer() end
 
 ---Get a slice out of the data buffer, as another data buffer.
@@ -2016,10 +2049,284 @@ function data:get_buffer() end
 ---@return data slice # The slice, as another data buffer.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/data.rs#L185)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/data.rs#L203)
 function data:get_slice(index_a,index_b) end
 
----Compress a given data buffer (DEFLATE).
+---A cursor over a data buffer, for reading and writing typed values. Writing past the end of the data buffer will grow it. Every change is made in-place, on the data buffer itself.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/data.rs#L228)
+---```lua
+----- Create a cursor over a new, empty data buffer.
+---local cursor = quiver.data.new_cursor()
+---
+----- Write a few values. The data buffer will grow as needed.
+---cursor:write_u16(0xBEEF)
+---cursor:write_i32(-64)
+---cursor:write_f32(0.5)
+---cursor:write_variable(300)
+---cursor:write_variable(-2, true)
+---cursor:write_string("foo")
+---
+----- Go back to the beginning, and read them back.
+---cursor:seek(0)
+---
+---assert(cursor:read_u16() == 0xBEEF)
+---assert(cursor:read_i32() == -64)
+---assert(cursor:read_f32() == 0.5)
+---assert(cursor:read_variable() == 300)
+---assert(cursor:read_variable(true) == -2)
+---assert(cursor:read_string() == "foo")
+---assert(cursor:tell() == cursor:get_length())
+---
+----- Mutate the data buffer in-place, in big-endian order.
+---cursor:seek(0)
+---cursor:set_endian(true)
+---cursor:write_u16(0x0102)
+---
+---assert(cursor:get_data():get_buffer()[1] == 0x01)
+---assert(cursor:get_data():get_buffer()[2] == 0x02)
+---
+----- Reading past the end of the data buffer is an error.
+---cursor:seek()
+---
+---assert(not pcall(cursor.read_u8, cursor))
+---
+---```
+---@class data_cursor
+data_cursor = {}
+
+---Create a new cursor over a data buffer. The cursor will start at the beginning of the data buffer, in little-endian mode.
+---@param data data? # OPTIONAL: The data buffer. If nil, will create an empty data buffer.
+---@return data_cursor cursor # The cursor.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/data.rs#L248)
+function quiver.data.new_cursor(data) end
+
+---Get the data buffer of the cursor.
+---@return data data # The data buffer.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/data.rs#L396)
+function data_cursor:get_data() end
+
+---Get the length of the data buffer.
+---@return number length # The length of the data buffer.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/data.rs#L408)
+function data_cursor:get_length() end
+
+---Set the byte order of the cursor.
+---@param big boolean # If true, use big-endian (network order). Otherwise, use little-endian.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/data.rs#L422)
+function data_cursor:set_endian(big) end
+
+---Set the position of the cursor.
+---@param index number? # OPTIONAL: The position, from the beginning of the data buffer. If nil, will set the position to the end of the data buffer, for appending.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/data.rs#L437)
+function data_cursor:seek(index) end
+
+---Get the position of the cursor.
+---@return number index # The position, from the beginning of the data buffer.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/data.rs#L462)
+function data_cursor:tell() end
+
+---Read an unsigned 8-bit integer, and advance the cursor.
+---@return number value # The value.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/data.rs#L474)
+function data_cursor:read_u8() end
+
+---Write an unsigned 8-bit integer, and advance the cursor.
+---@param value number # The value.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/data.rs#L485)
+function data_cursor:write_u8(value) end
+
+---Read an unsigned 16-bit integer, and advance the cursor.
+---@return number value # The value.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/data.rs#L496)
+function data_cursor:read_u16() end
+
+---Write an unsigned 16-bit integer, and advance the cursor.
+---@param value number # The value.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/data.rs#L507)
+function data_cursor:write_u16(value) end
+
+---Read an unsigned 32-bit integer, and advance the cursor.
+---@return number value # The value.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/data.rs#L518)
+function data_cursor:read_u32() end
+
+---Write an unsigned 32-bit integer, and advance the cursor.
+---@param value number # The value.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/data.rs#L529)
+function data_cursor:write_u32(value) end
+
+---Read an unsigned 64-bit integer, and advance the cursor.
+---@return number value # The value.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/data.rs#L540)
+function data_cursor:read_u64() end
+
+---Write an unsigned 64-bit integer, and advance the cursor.
+---@param value number # The value.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/data.rs#L551)
+function data_cursor:write_u64(value) end
+
+---Read a signed 8-bit integer, and advance the cursor.
+---@return number value # The value.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/data.rs#L562)
+function data_cursor:read_i8() end
+
+---Write a signed 8-bit integer, and advance the cursor.
+---@param value number # The value.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/data.rs#L573)
+function data_cursor:write_i8(value) end
+
+---Read a signed 16-bit integer, and advance the cursor.
+---@return number value # The value.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/data.rs#L584)
+function data_cursor:read_i16() end
+
+---Write a signed 16-bit integer, and advance the cursor.
+---@param value number # The value.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/data.rs#L595)
+function data_cursor:write_i16(value) end
+
+---Read a signed 32-bit integer, and advance the cursor.
+---@return number value # The value.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/data.rs#L606)
+function data_cursor:read_i32() end
+
+---Write a signed 32-bit integer, and advance the cursor.
+---@param value number # The value.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/data.rs#L617)
+function data_cursor:write_i32(value) end
+
+---Read a signed 64-bit integer, and advance the cursor.
+---@return number value # The value.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/data.rs#L628)
+function data_cursor:read_i64() end
+
+---Write a signed 64-bit integer, and advance the cursor.
+---@param value number # The value.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/data.rs#L639)
+function data_cursor:write_i64(value) end
+
+---Read a 32-bit float, and advance the cursor.
+---@return number value # The value.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/data.rs#L650)
+function data_cursor:read_f32() end
+
+---Write a 32-bit float, and advance the cursor.
+---@param value number # The value.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/data.rs#L661)
+function data_cursor:write_f32(value) end
+
+---Read a 64-bit float, and advance the cursor.
+---@return number value # The value.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/data.rs#L672)
+function data_cursor:read_f64() end
+
+---Write a 64-bit float, and advance the cursor.
+---@param value number # The value.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/data.rs#L683)
+function data_cursor:write_f64(value) end
+
+---Read a variable-length integer (LEB128), and advance the cursor.
+---@param sign boolean? # OPTIONAL: If true, read a signed (zig-zag) integer.
+---@return number value # The value.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/data.rs#L698)
+function data_cursor:read_variable(sign) end
+
+---Write a variable-length integer (LEB128), and advance the cursor.
+---@param value number # The value.
+---@param sign boolean? # OPTIONAL: If true, write a signed (zig-zag) integer.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/data.rs#L719)
+function data_cursor:write_variable(value,sign) end
+
+---Read a string, and advance the cursor.
+---@param length number? # OPTIONAL: The length of the string. If nil, will read a variable-length integer as the length first.
+---@return string value # The value.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/data.rs#L747)
+function data_cursor:read_string(length) end
+
+---Write a string, and advance the cursor.
+---@param value string # The value.
+---@param length boolean? # OPTIONAL: If false, will not write the length of the string as a variable-length integer first. Default: true.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/data.rs#L767)
+function data_cursor:write_string(value,length) end
+
+---Read a slice of the data buffer as another data buffer, and advance the cursor.
+---@param length number # The length of the slice.
+---@return data value # The slice, as another data buffer.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/data.rs#L793)
+function data_cursor:read_data(length) end
+
+---Write a data buffer, and advance the cursor.
+---@param value data # The data buffer.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/data.rs#L807)
+function data_cursor:write_data(value) end
+
+---Compress a given data buffer. Zstandard and LZ4 require the codec feature.
 ---```lua
 ----- Create a table with no data in it.
 ---local data = {}
@@ -2052,13 +2359,15 @@ function data:get_slice(index_a,index_b) end
 ---
 ---```
 ---@param data data # The data buffer to compress.
+---@param kind compress_kind? # OPTIONAL: The compression method. Default: DEFLATE.
+---@param level number? # OPTIONAL: The compression level. Zstandard: 1 to 22 (default: 3), LZ4: 0 to 12 (default: 0). Ignored for DEFLATE.
 ---@return data data # The data buffer.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/data.rs#L216)
-function quiver.data.compress(data) end
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/data.rs#L833)
+function quiver.data.compress(data,kind,level) end
 
----Decompress a given data buffer (DEFLATE).
+---Decompress a given data buffer. Zstandard and LZ4 require the codec feature.
 ---```lua
 ----- Create a table with no data in it.
 ---local data = {}
@@ -2091,62 +2400,443 @@ function quiver.data.compress(data) end
 ---
 ---```
 ---@param data data # The data buffer to decompress.
+---@param kind compress_kind? # OPTIONAL: The compression method. Default: DEFLATE.
 ---@return data data # The data buffer.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/data.rs#L242)
-function quiver.data.decompress(data) end
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/data.rs#L867)
+function quiver.data.decompress(data,kind) end
 
----Encode a given data buffer (Base64).
+---Encode a given data buffer. Base64 will return a data buffer, every other method will return a string. Base64 (URL-safe) requires the codec feature.
+---```lua
+---local data = quiver.data.new({ 0, 127, 255 })
+---
+----- Encode the data as a hexadecimal string, and decode it back.
+---local text = quiver.data.encode(data, ENCODE_KIND.HEX)
+---
+---assert(text == "007fff")
+---assert(quiver.data.decode(text, ENCODE_KIND.HEX):get_buffer()[3] == 255)
+---
+----- Encode the data as URL-safe Base64 (no padding), and decode it back.
+---local text = quiver.data.encode(data, ENCODE_KIND.BASE64_URL)
+---
+---assert(text == "AH__")
+---assert(quiver.data.decode(text, ENCODE_KIND.BASE64_URL):get_buffer()[2] == 127)
+---
+---```
 ---@param data data # The data buffer to encode.
+---@param kind encode_kind? # OPTIONAL: The encode method. Default: Base64.
+---@return data | string data # The data buffer, or string.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/data.rs#L960)
+function quiver.data.encode(data,kind) end
+
+---Decode a given data buffer, or string. Base64 (URL-safe) requires the codec feature.
+---```lua
+---local data = quiver.data.new({ 0, 127, 255 })
+---
+----- Encode the data as a hexadecimal string, and decode it back.
+---local text = quiver.data.encode(data, ENCODE_KIND.HEX)
+---
+---assert(text == "007fff")
+---assert(quiver.data.decode(text, ENCODE_KIND.HEX):get_buffer()[3] == 255)
+---
+----- Encode the data as URL-safe Base64 (no padding), and decode it back.
+---local text = quiver.data.encode(data, ENCODE_KIND.BASE64_URL)
+---
+---assert(text == "AH__")
+---assert(quiver.data.decode(text, ENCODE_KIND.BASE64_URL):get_buffer()[2] == 127)
+---
+---```
+---@param data data | string # The data buffer, or string, to decode.
+---@param kind encode_kind? # OPTIONAL: The encode method. Default: Base64.
 ---@return data data # The data buffer.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/data.rs#L269)
-function quiver.data.encode(data) end
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/data.rs#L1010)
+function quiver.data.decode(data,kind) end
+
+---Hash a given data buffer. Every method will return a data buffer with the digest, which may be turned into a hexadecimal string with quiver.data.encode. SHA256, xxHash (XXH3, 64-bit, big-endian) and BLAKE3 require the codec feature.
+---```lua
+---local data = quiver.data.new({ 97, 98, 99 })
+---
+----- Hash "abc" with CRC32, MD5 and SHA1. Every digest is a data buffer, and may be turned into a hexadecimal string.
+---assert(quiver.data.encode(quiver.data.hash(data, HASH_KIND.CRC32), ENCODE_KIND.HEX) == "352441c2")
+---assert(quiver.data.encode(quiver.data.hash(data, HASH_KIND.MD5), ENCODE_KIND.HEX) == "900150983cd24fb0d6963f7d28e17f72")
+---assert(quiver.data.encode(quiver.data.hash(data, HASH_KIND.SHA1), ENCODE_KIND.HEX) == "a9993e364706816aba3e25717850c26c9cd0d89d")
+---
+----- Hash "abc" with SHA256. The digest is given back as a data buffer.
+---local hash = quiver.data.hash(data, HASH_KIND.SHA256)
+---
+---assert(quiver.data.encode(hash, ENCODE_KIND.HEX) == "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad")
+---
+----- BLAKE3 and xxHash work the same way.
+---assert(quiver.data.hash(data, HASH_KIND.BLAKE3):get_buffer()[1] ~= nil)
+---assert(#quiver.data.hash(data, HASH_KIND.XXHASH):get_buffer() == 8)
+---
+----- Sign "abc" with a secret key (HMAC-SHA256). The same key will always give the same code.
+---local code_a = quiver.data.encode(quiver.data.hash_hmac(data, "key"), ENCODE_KIND.HEX)
+---local code_b = quiver.data.encode(quiver.data.hash_hmac("abc", "key"), ENCODE_KIND.HEX)
+---
+---assert(code_a == code_b)
+---assert(code_a ~= quiver.data.encode(quiver.data.hash_hmac(data, "other"), ENCODE_KIND.HEX))
+---
+---```
+---@param data data # The data buffer to hash.
+---@param kind hash_kind? # OPTIONAL: The hash method. Default: CRC32.
+---@return data data # The digest.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/data.rs#L1089)
+function quiver.data.hash(data,kind) end
+
+---Get the HMAC-SHA256 code of a given data buffer.
+---```lua
+---local data = quiver.data.new({ 97, 98, 99 })
+---
+----- Hash "abc" with CRC32, MD5 and SHA1. Every digest is a data buffer, and may be turned into a hexadecimal string.
+---assert(quiver.data.encode(quiver.data.hash(data, HASH_KIND.CRC32), ENCODE_KIND.HEX) == "352441c2")
+---assert(quiver.data.encode(quiver.data.hash(data, HASH_KIND.MD5), ENCODE_KIND.HEX) == "900150983cd24fb0d6963f7d28e17f72")
+---assert(quiver.data.encode(quiver.data.hash(data, HASH_KIND.SHA1), ENCODE_KIND.HEX) == "a9993e364706816aba3e25717850c26c9cd0d89d")
+---
+----- Hash "abc" with SHA256. The digest is given back as a data buffer.
+---local hash = quiver.data.hash(data, HASH_KIND.SHA256)
+---
+---assert(quiver.data.encode(hash, ENCODE_KIND.HEX) == "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad")
+---
+----- BLAKE3 and xxHash work the same way.
+---assert(quiver.data.hash(data, HASH_KIND.BLAKE3):get_buffer()[1] ~= nil)
+---assert(#quiver.data.hash(data, HASH_KIND.XXHASH):get_buffer() == 8)
+---
+----- Sign "abc" with a secret key (HMAC-SHA256). The same key will always give the same code.
+---local code_a = quiver.data.encode(quiver.data.hash_hmac(data, "key"), ENCODE_KIND.HEX)
+---local code_b = quiver.data.encode(quiver.data.hash_hmac("abc", "key"), ENCODE_KIND.HEX)
+---
+---assert(code_a == code_b)
+---assert(code_a ~= quiver.data.encode(quiver.data.hash_hmac(data, "other"), ENCODE_KIND.HEX))
+---
+---```
+---@param data data # The data buffer to hash.
+---@param key data | string # The secret key, as a string or data buffer.
+---@return data data # The HMAC code.
+---
+--- ---
+---*Available with compile feature: `codec`.*
+---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/data.rs#L1147)
+function quiver.data.hash_hmac(data,key) end
+
+---A streaming compressor or decompressor, for data that does not fit in memory at once, or that arrives in chunks. LZ4 decompression will buffer every chunk until the stream is finished.
+---
+--- ---
+---*Available with compile feature: `codec`.*
+---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/data.rs#L1163)
+---```lua
+----- Create a streaming compressor (Zstandard).
+---local stream = quiver.data.new_stream(COMPRESS_KIND.ZSTD, true, 9)
+---local cursor = quiver.data.new_cursor()
+---
+----- Push a few chunks in, keeping every piece of output.
+---for x = 1, 16 do
+---    cursor:write_data(stream:push(string.rep("quiver", 64)))
+---end
+---
+---cursor:write_data(stream:finish())
+---
+----- The stream can not be used after finishing it.
+---assert(not pcall(stream.push, stream, "foo"))
+---
+----- Decompress all of the output at once, and check the size.
+---local data = quiver.data.decompress(cursor:get_data(), COMPRESS_KIND.ZSTD)
+---
+---assert(#data:get_buffer() == 16 * 64 * 6)
+---
+----- LZ4 works the same way.
+---local data = quiver.data.compress(data, COMPRESS_KIND.LZ4, 9)
+---local stream = quiver.data.new_stream(COMPRESS_KIND.LZ4, false)
+---
+---stream:push(data)
+---
+---assert(#stream:finish():get_buffer() == 16 * 64 * 6)
+---
+---```
+---@class data_stream
+data_stream = {}
+
+---Create a new streaming compressor or decompressor.
+---@param kind compress_kind # The compression method. DEFLATE is not supported.
+---@param compress boolean # If true, compress. Otherwise, decompress.
+---@param level number? # OPTIONAL: The compression level. See quiver.data.compress.
+---@return data_stream stream # The stream.
+---
+--- ---
+---*Available with compile feature: `codec`.*
+---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/data.rs#L1184)
+function quiver.data.new_stream(kind,compress,level) end
+
+---Push a chunk into the stream, and get any output that is ready.
+---@param data data | string # The chunk, as a string or data buffer.
+---@return data data # The output that is ready. Might be empty.
+---
+--- ---
+---*Available with compile feature: `codec`.*
+---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/data.rs#L1214)
+function data_stream:push(data) end
+
+---Finish the stream, and get any remaining output. The stream can not be used after this.
+---@return data data # The remaining output.
+---
+--- ---
+---*Available with compile feature: `codec`.*
+---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/data.rs#L1231)
+function data_stream:finish() end
+
+---Derive an encryption key from a passphrase (Argon2id). The same passphrase and salt will always give the same key.
+---```lua
+----- Derive a key from a passphrase. A random salt will be made for us.
+---local key, salt = quiver.data.derive_key("correct horse battery staple")
+---
+----- Deriving it again with the same salt will give back the same key.
+---local key_b = quiver.data.derive_key("correct horse battery staple", salt)
+---
+---assert(quiver.data.encode(key, ENCODE_KIND.HEX) == quiver.data.encode(key_b, ENCODE_KIND.HEX))
+---
+----- Encrypt some data, with a header that is authenticated but left in plain text.
+---local data = quiver.data.encrypt("save data", key, "header")
+---
+----- Decrypt it back.
+---local text = quiver.data.decrypt(data, key, "header")
+---
+---assert(quiver.data.from_data(text, 2) == "save data")
+---
+----- A wrong key, or a wrong header, will fail to decrypt.
+---local key_c = quiver.data.derive_key("wrong passphrase", salt)
+---
+---assert(not pcall(quiver.data.decrypt, data, key_c, "header"))
+---assert(not pcall(quiver.data.decrypt, data, key, "other"))
+---
+---```
+---@param pass string # The passphrase.
+---@param salt data? # OPTIONAL: The salt (at least 8 bytes long). If nil, will create a random 16-byte salt.
+---@return data key # The key (32 bytes long).
+---@return data salt # The salt. Store it alongside the encrypted data to derive the key again.
+---
+--- ---
+---*Available with compile feature: `crypto`.*
+---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/data.rs#L1401)
+function quiver.data.derive_key(pass,salt) end
 
----Decode a given data buffer (Base64).
----@param data data # The data buffer to decode.
+---Encrypt a given data buffer (ChaCha20-Poly1305). The result will contain a random nonce, followed by the encrypted data and its authentication tag.
+---```lua
+----- Derive a key from a passphrase. A random salt will be made for us.
+---local key, salt = quiver.data.derive_key("correct horse battery staple")
+---
+----- Deriving it again with the same salt will give back the same key.
+---local key_b = quiver.data.derive_key("correct horse battery staple", salt)
+---
+---assert(quiver.data.encode(key, ENCODE_KIND.HEX) == quiver.data.encode(key_b, ENCODE_KIND.HEX))
+---
+----- Encrypt some data, with a header that is authenticated but left in plain text.
+---local data = quiver.data.encrypt("save data", key, "header")
+---
+----- Decrypt it back.
+---local text = quiver.data.decrypt(data, key, "header")
+---
+---assert(quiver.data.from_data(text, 2) == "save data")
+---
+----- A wrong key, or a wrong header, will fail to decrypt.
+---local key_c = quiver.data.derive_key("wrong passphrase", salt)
+---
+---assert(not pcall(quiver.data.decrypt, data, key_c, "header"))
+---assert(not pcall(quiver.data.decrypt, data, key, "other"))
+---
+---```
+---@param data data | string # The data buffer to encrypt.
+---@param key data # The key (32 bytes long). See quiver.data.derive_key.
+---@param side data | string? # OPTIONAL: Additional data to authenticate, but not encrypt (a packet header, for example). Must be the same for decryption.
 ---@return data data # The data buffer.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/data.rs#L294)
-function quiver.data.decode(data) end
+---*Available with compile feature: `crypto`.*
+---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/data.rs#L1433)
+function quiver.data.encrypt(data,key,side) end
 
----Hash a given data buffer.
----@param data data # The data buffer to encode.
----@param data hash_kind? # OPTIONAL: The hash method. Default: CRC32.
----@return data data # The hash code.
+---Decrypt a given data buffer (ChaCha20-Poly1305). Will error if the key is wrong, or if the data has been tampered with.
+---```lua
+----- Derive a key from a passphrase. A random salt will be made for us.
+---local key, salt = quiver.data.derive_key("correct horse battery staple")
+---
+----- Deriving it again with the same salt will give back the same key.
+---local key_b = quiver.data.derive_key("correct horse battery staple", salt)
+---
+---assert(quiver.data.encode(key, ENCODE_KIND.HEX) == quiver.data.encode(key_b, ENCODE_KIND.HEX))
+---
+----- Encrypt some data, with a header that is authenticated but left in plain text.
+---local data = quiver.data.encrypt("save data", key, "header")
+---
+----- Decrypt it back.
+---local text = quiver.data.decrypt(data, key, "header")
+---
+---assert(quiver.data.from_data(text, 2) == "save data")
+---
+----- A wrong key, or a wrong header, will fail to decrypt.
+---local key_c = quiver.data.derive_key("wrong passphrase", salt)
+---
+---assert(not pcall(quiver.data.decrypt, data, key_c, "header"))
+---assert(not pcall(quiver.data.decrypt, data, key, "other"))
+---
+---```
+---@param data data # The data buffer to decrypt.
+---@param key data # The key (32 bytes long). See quiver.data.derive_key.
+---@param side data | string? # OPTIONAL: Additional data to authenticate. See quiver.data.encrypt.
+---@return data data # The data buffer.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/data.rs#L322)
-function quiver.data.hash(data,data) end
+---*Available with compile feature: `crypto`.*
+---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/data.rs#L1466)
+function quiver.data.decrypt(data,key,side) end
 
----Serialize a given Lua value as another format, in the form of a string.
+---Serialize a given Lua value as another format, in the form of a string. Binary formats (MessagePack, CBOR) will return a data buffer instead, and will keep any data buffer in the value as binary data.
+---```lua
+----- Serialize a table with a data buffer in it to MessagePack. The result is a data buffer.
+---local data = quiver.data.serialize({
+---    name = "foo",
+---    size = 4,
+---    blob = quiver.data.new({ 255, 0, 255, 0 }),
+---}, FORMAT_KIND.MSGPACK)
+---
+----- Deserialize it back. The data buffer will be given back as a data buffer.
+---local data = quiver.data.deserialize(data, FORMAT_KIND.MSGPACK)
+---
+---assert(data.name == "foo")
+---assert(data.size == 4)
+---assert(data.blob:get_buffer()[1] == 255)
+---
+----- CBOR works the same way.
+---local data = quiver.data.deserialize(quiver.data.serialize({ 1, 2, 3 }, FORMAT_KIND.CBOR), FORMAT_KIND.CBOR)
+---
+---assert(data[3] == 3)
+---
+----- A recursive table is an error, but a table used twice is not.
+---local loop = {}
+---local leaf = { 1 }
+---
+---loop.loop = loop
+---
+---assert(not pcall(quiver.data.serialize, loop, FORMAT_KIND.MSGPACK))
+---assert(not pcall(quiver.data.serialize, loop, FORMAT_KIND.CBOR))
+---assert(quiver.data.serialize({ a = leaf, b = leaf }, FORMAT_KIND.CBOR))
+---
+---```
 ---@param text any # Lua value to serialize.
 ---@param kind format_kind? # OPTIONAL: The format to serialize to. Default: JSON.
----@return string value # The value, in string form.
+---@return string | data value # The value, in string form.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/data.rs#L373)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/data.rs#L1576)
 function quiver.data.serialize(text,kind) end
 
----Deserialize a given format string as a Lua value.
----@param text string # String to deserialize.
+---Deserialize a given format string as a Lua value. Binary formats (MessagePack, CBOR) will take a data buffer instead, and will return any binary data in the value as a data buffer.
+---@param text string | data # String to deserialize.
 ---@param kind format_kind? # OPTIONAL: The format to deserialize from. Default: JSON.
 ---@return any value # The value, in Lua value form.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/data.rs#L421)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/data.rs#L1645)
 function quiver.data.deserialize(text,kind) end
 
+---Validate a given Lua value against a schema, such as a table given back by quiver.data.deserialize. The schema follows a subset of JSON Schema: type (nil or null, boolean, integer, number, string, array, object, data), enum, const, minimum, maximum, exclusiveMinimum, exclusiveMaximum, minLength, maxLength, pattern (as a Lua pattern), properties, required, additionalProperties, items, minItems, maxItems, allOf, anyOf, oneOf and not.
+---```lua
+----- A schema for a list of enemy.
+---local schema = {
+---    type = "object",
+---    required = { "enemy" },
+---    properties = {
+---        enemy = {
+---            type = "array",
+---            items = {
+---                type = "object",
+---                required = { "name", "health" },
+---                additionalProperties = false,
+---                properties = {
+---                    name   = { type = "string", minLength = 1 },
+---                    health = { type = "integer", minimum = 1 },
+---                    kind   = { enum = { "melee", "range" } },
+---                },
+---            },
+---        },
+---    },
+---}
+---
+----- A valid value.
+---local value = quiver.data.deserialize([[
+---{
+---    "enemy": [
+---        { "name": "grunt", "health": 10, "kind": "melee" },
+---        { "name": "sniper", "health": 5 }
+---    ]
+---}
+---]])
+---
+---local valid, error = quiver.data.validate(value, schema)
+---
+---assert(valid)
+---assert(#error == 0)
+---
+----- An invalid value. Every error will be qualified with its path.
+---local value = quiver.data.deserialize([[
+---{
+---    "enemy": [
+---        { "name": "grunt", "health": 10 },
+---        { "name": "", "health": "high", "kind": "magic", "speed": 1 }
+---    ]
+---}
+---]])
+---
+---local valid, error = quiver.data.validate(value, schema)
+---local text = table.concat(error, "\n")
+---
+---assert(not valid)
+---assert(#error == 4)
+---assert(text:find("$.enemy[2].name", 1, true))
+---assert(text:find("$.enemy[2].health: Expected integer, got string.", 1, true))
+---assert(text:find("$.enemy[2].kind", 1, true))
+---assert(text:find("$.enemy[2].speed: Unknown value.", 1, true))
+---
+----- A null value is nil, and can be matched by either "nil" or "null".
+---local value = quiver.data.deserialize([[ { "name": null } ]])
+---local valid, error = quiver.data.validate(value, { properties = { name = { type = "string" } } })
+---
+---assert(not valid)
+---assert(error[1] == "$.name: Expected string, got nil.")
+---assert(quiver.data.validate(value, { properties = { name = { type = "null" } } }))
+---assert(quiver.data.validate(value, { properties = { name = { type = "nil" } } }))
+---
+---```
+---@param value any # The value to validate.
+---@param schema table # The schema.
+---@return boolean valid # True if the value is valid.
+---@return table error # A list of every error, each one qualified with its path (e.g. '$.enemy[2].health: ...').
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/data.rs#L1958)
+function quiver.data.validate(value,schema) end
+
 ---Convert a given Lua value to a data buffer.
 ---@param data any # Lua value to convert to a data buffer.
 ---@param kind data_kind # The data kind to convert to.
 ---@return data value # The value, in data buffer form.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/data.rs#L477)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/data.rs#L2358)
 function quiver.data.to_data(data,kind) end
 
 ---Convert a given data buffer to a Lua value.
@@ -2155,7 +2845,7 @@ function quiver.data.to_data(data,kind) end
 ---@return number | string value # The value, in Lua value form.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/data.rs#L508)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/data.rs#L2389)
 function quiver.data.from_data(data,kind) end
 
 ---Get a file from the embed file.
@@ -2166,7 +2856,7 @@ function quiver.data.from_data(data,kind) end
 --- ---
 ---*Available with compile feature: `embed`.*
 ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/data.rs#L543)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/data.rs#L2424)
 function quiver.data.get_embed_file(path,binary) end
 
 ---Get a list of every file in the embed data.
@@ -2175,14 +2865,12 @@ function quiver.data.get_embed_file(path,binary) end
 --- ---
 ---*Available with compile feature: `embed`.*
 ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/data.rs#L572)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/data.rs#L2453)
 function quiver.data.get_embed_list() end
 
 ---The socket API.
 ---
 --- ---
----*Not available in head-less mode.*
----
 ---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/socket.rs#L65)
 ---@class quiver.socket
 quiver.socket = {}
@@ -2346,45 +3034,53 @@ function quiver.discord.new(ID,name,command) end
 ---The file API.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/file.rs#L64)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/file.rs#L65)
 ---@class quiver.file
 quiver.file = {}
 
 ---Get the data of a file.
 ---```lua
------ Write "123" to the file "foo.txt". Since we don't want to write binary, pass false as the third argument.
----quiver.file.set("work/foo.txt", "123", false)
+----- Write "123" to the file "foo.txt", in the temporary path.
+---local path = quiver.file.get_temp_path() .. "/foo.txt"
+---
+---quiver.file.set_file(path, "123")
 ---
------ Read the data back. Again, since we know the file isn't binary, we pass false.
----local data = quiver.file.get("work/foo.txt", false)
+----- Read the data back. Since we know the file isn't binary, we pass false.
+---local data = quiver.file.get_file(path, false)
 ---
 ---assert(data == "123")
 ---
+---quiver.file.remove_file(path)
+---
 ---```
 ---@param path string # Path to file.
 ---@param binary boolean # Read as binary.
 ---@return string data # File data.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/file.rs#L121)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/file.rs#L125)
 function quiver.file.get_file(path,binary) end
 
 ---Set the data of a file.
 ---```lua
------ Write "123" to the file "foo.txt". Since we don't want to write binary, pass false as the third argument.
----quiver.file.set("work/foo.txt", "123", false)
+----- Write "123" to the file "foo.txt", in the temporary path.
+---local path = quiver.file.get_temp_path() .. "/foo.txt"
+---
+---quiver.file.set_file(path, "123")
 ---
------ Read the data back. Again, since we know the file isn't binary, we pass false.
----local data = quiver.file.get("work/foo.txt", false)
+----- Read the data back. Since we know the file isn't binary, we pass false.
+---local data = quiver.file.get_file(path, false)
 ---
 ---assert(data == "123")
 ---
+---quiver.file.remove_file(path)
+---
 ---```
 ---@param path string # Path to file.
 ---@param data string | data # Data to copy.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/file.rs#L149)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/file.rs#L153)
 function quiver.file.set_file(path,data) end
 
 ---Move a file.
@@ -2392,7 +3088,7 @@ function quiver.file.set_file(path,data) end
 ---@param target string # The target path.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/file.rs#L179)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/file.rs#L187)
 function quiver.file.move_file(source,target) end
 
 ---Copy a file.
@@ -2400,86 +3096,131 @@ function quiver.file.move_file(source,target) end
 ---@param target string # The target path.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/file.rs#L199)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/file.rs#L207)
 function quiver.file.copy_file(source,target) end
 
 ---Remove a file.
 ---@param path string # The path to the file to remove.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/file.rs#L218)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/file.rs#L226)
 function quiver.file.remove_file(path) end
 
 ---Remove a folder.
 ---@param path string # The path to the folder to remove.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/file.rs#L236)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/file.rs#L244)
 function quiver.file.remove_path(path) end
 
----Set the state of the path sand-box.
----@param state boolean # The state of the path sand-box.
+---Get the per-user save data path, by the name in the info manifest. The path will be created if missing. Prefer this over the project path for writing any user data.
+---```lua
+----- Get the per-user save path. Quiver will create the path if missing.
+---local path = quiver.file.get_save_path()
+---
+---assert(quiver.file.get_path_exist(path))
+---
+----- Write a file to the save path. The save path is part of the sand-box, even in safe mode.
+---quiver.file.set_file(path .. "/foo.txt", "123")
+---
+---assert(quiver.file.get_file(path .. "/foo.txt", false) == "123")
+---
+---```
+---@return string path # The save data path.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/file.rs#L263)
+function quiver.file.get_save_path() end
+
+---Get the per-user configuration path, by the name in the info manifest. The path will be created if missing.
+---@return string path # The configuration path.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/file.rs#L277)
+function quiver.file.get_config_path() end
+
+---Get the per-user cache path, by the name in the info manifest. The path will be created if missing.
+---@return string path # The cache path.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/file.rs#L291)
+function quiver.file.get_cache_path() end
+
+---Get the temporary path, by the name in the info manifest. The path will be created if missing.
+---@return string path # The temporary path.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/file.rs#L254)
-function quiver.file.set_path_escape(state) end
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/file.rs#L305)
+function quiver.file.get_temp_path() end
 
 ---Set the file save call-back.
 ---@param call function # The call-back. Must accept a file-name and a data parameter, and return a boolean (true on success, false on failure).
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/file.rs#L390)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/file.rs#L455)
 function quiver.file.set_call_save_file(call) end
 
 ---Set the file load call-back.
 ---@param call function # The call-back. Must accept a file-name, and return a data buffer. Return anything else to indicate failure.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/file.rs#L410)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/file.rs#L475)
 function quiver.file.set_call_load_file(call) end
 
 ---Set the file text save call-back.
 ---@param call function # The call-back. Must accept a file-name and a string parameter, and return a boolean (true on success, false on failure).
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/file.rs#L430)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/file.rs#L495)
 function quiver.file.set_call_save_text(call) end
 
 ---Set the file load call-back.
 ---@param call function # The call-back. Must accept a file-name, and return a string. Return anything else to indicate failure.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/file.rs#L450)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/file.rs#L515)
 function quiver.file.set_call_load_text(call) end
 
 ---Check if a file does exist.
 ---```lua
------ Write "123" to the file "foo.txt". Since we don't want to write binary, pass false as the third argument.
----quiver.file.set("work/foo.txt", "123", false)
+----- Write "123" to the file "foo.txt", in the temporary path.
+---local path = quiver.file.get_temp_path() .. "/foo.txt"
 ---
----assert(quiver.file.get_file_exist("work/foo.txt"))
+---quiver.file.set_file(path, "123")
+---
+---assert(quiver.file.get_file_exist(path))
+---
+---quiver.file.remove_file(path)
+---
+---assert(not quiver.file.get_file_exist(path))
 ---
 ---```
 ---@param path string # Path to file.
 ---@return boolean exist # True if file does exist, false otherwise.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/file.rs#L474)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/file.rs#L539)
 function quiver.file.get_file_exist(path) end
 
 ---Check if a path does exist.
 ---```lua
------ Write "123" to the file "foo.txt". Since we don't want to write binary, pass false as the third argument.
----quiver.file.set("work/foo.txt", "123", false)
+----- Create the path "foo", in the temporary path.
+---local path = quiver.file.get_temp_path() .. "/foo"
+---
+---quiver.file.create_path(path)
 ---
----assert(quiver.file.get_file_exist("work/foo.txt"))
+---assert(quiver.file.get_path_exist(path))
+---
+---quiver.file.remove_path(path)
+---
+---assert(not quiver.file.get_path_exist(path))
 ---
 ---```
 ---@param path string # Path.
 ---@return boolean exist # True if path does exist, false otherwise.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/file.rs#L494)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/file.rs#L559)
 function quiver.file.get_path_exist(path) end
 
 ---Check if a file's extension is the same as a given one.
@@ -2488,7 +3229,7 @@ function quiver.file.get_path_exist(path) end
 ---@return boolean check # True if file extension is the same as the given one, false otherwise.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/file.rs#L514)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/file.rs#L579)
 function quiver.file.get_file_extension_check(path,extension) end
 
 ---Get the size of a file.
@@ -2496,7 +3237,7 @@ function quiver.file.get_file_extension_check(path,extension) end
 ---@return number size # File size.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/file.rs#L535)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/file.rs#L600)
 function quiver.file.get_file_size(path) end
 
 ---Get the extension of a file.
@@ -2504,7 +3245,7 @@ function quiver.file.get_file_size(path) end
 ---@return string extension # File extension.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/file.rs#L554)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/file.rs#L619)
 function quiver.file.get_file_extension(path) end
 
 ---Get the name of a file.
@@ -2513,57 +3254,57 @@ function quiver.file.get_file_extension(path) end
 ---@return string name # File name.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/file.rs#L577)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/file.rs#L642)
 function quiver.file.get_file_name(path,extension) end
 
 ---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/file.rs#L598)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/file.rs#L663)
 function quiver.file.get_absolute_path() end
 
 ---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/file.rs#L615)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/file.rs#L681)
 function quiver.file.get_previous_path() end
 
 ---Get the current work path.
 ---@return string path # Work path.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/file.rs#L635)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/file.rs#L702)
 function quiver.file.get_work_directory() end
 
 ---Get the current application path.
 ---@return string path # Application path.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/file.rs#L652)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/file.rs#L719)
 function quiver.file.get_application_directory() end
 
 ---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/file.rs#L666)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/file.rs#L733)
 function quiver.file.create_path() end
 
 ---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/file.rs#L689)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/file.rs#L757)
 function quiver.file.change_path() end
 
 ---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/file.rs#L712)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/file.rs#L781)
 function quiver.file.get_path_file() end
 
 ---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/file.rs#L727)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/file.rs#L797)
 function quiver.file.get_file_name_valid() end
 
 ---Scan a path.
@@ -2574,25 +3315,25 @@ function quiver.file.get_file_name_valid() end
 ---@return table list # File list.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/file.rs#L751)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/file.rs#L821)
 function quiver.file.scan_path(path,filter,recursive,absolute) end
 
 ---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/file.rs#L802)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/file.rs#L871)
 function quiver.file.get_file_drop() end
 
 ---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/file.rs#L813)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/file.rs#L882)
 function quiver.file.get_file_drop_list() end
 
 ---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/file.rs#L840)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/file.rs#L909)
 function quiver.file.get_file_modification() end
 
 ---The general API.
@@ -2606,69 +3347,107 @@ quiver.general = {}
 ---@return string input # The standard input.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/general.rs#L111)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/general.rs#L123)
 function quiver.general.standard_input() end
 
 ---Load the standard Lua library.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/general.rs#L125)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/general.rs#L137)
 function quiver.general.load_base() end
 
+---Set a module mount, for use with "require". Quiver will search the project root, every search path in the info manifest, every module mount and the embed data, in that order.
+---@param key any # The key for the mount. Setting a mount with the same key will replace the previous one.
+---@param call function? # OPTIONAL: The call-back. Must accept a file path ("foo/bar.lua"), and return the file's data as a string, or nil if missing. If nil, remove the mount.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/general.rs#L164)
+function quiver.general.set_module_mount(key,call) end
+
 ---Set the log level.
 ---@param level number # The log level.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/general.rs#L151)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/general.rs#L183)
 function quiver.general.set_log_level(level) end
 
 ---Open an URL link.
 ---@param link string # The URL link.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/general.rs#L168)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/general.rs#L200)
 function quiver.general.open_link(link) end
 
----Get the current time. Will count up since the initialization of the window.
+---Get the current time. Will count up since the initialization of the window, or since the start of the script in head-less mode.
 ---@return number time # Current time.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/general.rs#L184)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/general.rs#L216)
 function quiver.general.get_time() end
 
 ---Get the time in UNIX time-stamp format.
 ---@param add number? # OPTIONAL: Add (or subtract) by this amount.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/general.rs#L197)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/general.rs#L229)
 function quiver.general.get_time_unix(add) end
 
----Get the current frame time.
+---Get the current frame time. Will be fixed while a replay is active.
 ---@return number frame_time # Current frame time.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/general.rs#L214)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/general.rs#L246)
 function quiver.general.get_frame_time() end
 
 ---Get the current frame rate.
 ---@return number frame_rate # Current frame rate.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/general.rs#L227)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/general.rs#L264)
 function quiver.general.get_frame_rate() end
 
 ---Set the current frame rate.
 ---@param frame_rate number # Current frame rate.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/general.rs#L240)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/general.rs#L277)
 function quiver.general.set_frame_rate(frame_rate) end
 
+---Wait for the next tick of the main loop, at the frame rate from the info manifest. Only available in head-less mode, where there is no window to draw to and wait on. A frame rate of 0 will not wait at all.
+---```lua
+----- Only available in head-less mode ("--head-less", or "head_less" in the info file).
+---if quiver.general.tick then
+---    local time = quiver.general.get_time()
+---
+---    -- Run four ticks of the main loop. Each one will wait until the next tick, at the frame-rate from the info manifest.
+---    for x = 1, 4 do
+---        local frame_time = quiver.general.tick()
+---
+---        assert(frame_time > 0.0)
+---        assert(quiver.general.get_frame_time() == frame_time)
+---    end
+---
+---    assert(quiver.general.get_time() > time)
+---
+---    -- The head API will not be available at all.
+---    assert(quiver.window == nil)
+---    assert(quiver.draw == nil)
+---end
+---
+---```
+---@return number frame_time # Time since the last tick.
+---
+--- ---
+---*This function is asynchronous and can run within a co-routine.*
+---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/general.rs#L316)
+function quiver.general.tick() end
+
 ---Get the argument list.
 ---@return table list # The list of every argument.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/general.rs#L257)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/general.rs#L382)
 function quiver.general.get_argument() end
 
 ---Get the system info.
@@ -2677,21 +3456,21 @@ function quiver.general.get_argument() end
 --- ---
 ---*Available with compile feature: `system_info`.*
 ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/general.rs#L274)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/general.rs#L399)
 function quiver.general.get_system() end
 
 ---Get the currently in-use memory by the Lua VM.
 ---@return number memory # The currently in-use memory.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/general.rs#L292)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/general.rs#L417)
 function quiver.general.get_memory() end
 
 ---Get the current info manifest.
 ---@return table info # The info manifest.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/general.rs#L307)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/general.rs#L432)
 function quiver.general.get_info() end
 
 ---The collision API.
@@ -2814,14 +3593,70 @@ function quiver.collision.get_ray_quad() end
 --- ---
 ---*Not available in head-less mode.*
 ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/shader.rs#L64)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/shader.rs#L69)
 ---@class quiver.shader
 quiver.shader = {}
 
 ---An unique handle for a shader in memory.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/shader.rs#L81)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/shader.rs#L96)
+---```lua
+----- A fragment shader, with a uniform of every kind.
+---local shader = quiver.shader.new_from_memory(nil, [[
+---#version 330
+---
+---in vec2 fragTexCoord;
+---
+---uniform float time;
+---uniform vec2 offset;
+---uniform vec4 tint;
+---uniform ivec2 cell;
+---uniform bool invert;
+---uniform float weight[3];
+---uniform mat4 transform;
+---uniform sampler2D mask;
+---
+---out vec4 finalColor;
+---
+---void main()
+---{
+---    vec4 value = texture(mask, fragTexCoord + offset) * tint * transform;
+---    value.rgb *= weight[0] + weight[1] + weight[2] + float(cell.x + cell.y) + time;
+---
+---    finalColor = invert ? vec4(1.0) - value : value;
+---}
+---]])
+---
+----- Set each uniform by name, with the kind inferred from the value.
+---shader:set_uniform("time", 1.0)
+---shader:set_uniform("offset", vector_2:old(0.5, 0.5))
+---shader:set_uniform("tint", { r = 255, g = 128, b = 0, a = 255 })
+---shader:set_uniform("cell", vector_2:old(4, 8), true)
+---shader:set_uniform("invert", false)
+---shader:set_uniform("weight", { 0.25, 0.5, 0.25 })
+---shader:set_uniform("transform", matrix:old(
+---    1.0, 0.0, 0.0, 0.0,
+---    0.0, 1.0, 0.0, 0.0,
+---    0.0, 0.0, 1.0, 0.0,
+---    0.0, 0.0, 0.0, 1.0
+---))
+---shader:set_uniform("mask", quiver.image.new_color({ x = 4.0, y = 4.0 }, { r = 255, g = 255, b = 255, a = 255 }):to_texture())
+---
+---assert(shader:get_uniform_location("time") >= 0)
+---
+----- A uniform that does not exist will be ignored.
+---assert(shader:get_uniform_location("unknown") == -1)
+---shader:set_uniform("unknown", 1.0)
+---
+----- Every array element must be of the same kind.
+---assert(not pcall(shader.set_uniform, shader, "weight", { 1.0, vector_2:old(1.0, 1.0) }))
+---assert(not pcall(shader.set_uniform, shader, "time", "string"))
+---
+----- Source code that does not compile is an error.
+---assert(not pcall(quiver.shader.new_from_memory, nil, "not a shader"))
+---
+---```
 ---@class shader
 shader = {}
 
@@ -2831,20 +3666,79 @@ shader = {}
 ---@return shader shader # Shader resource.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/shader.rs#L100)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/shader.rs#L115)
 function quiver.shader.new(v_path,f_path) end
 
----TO-DO
+---Create a new shader resource, from GLSL source code rather than from a file. Use nil for either stage to use the default one.
+---```lua
+----- A fragment shader, with a uniform of every kind.
+---local shader = quiver.shader.new_from_memory(nil, [[
+---#version 330
+---
+---in vec2 fragTexCoord;
+---
+---uniform float time;
+---uniform vec2 offset;
+---uniform vec4 tint;
+---uniform ivec2 cell;
+---uniform bool invert;
+---uniform float weight[3];
+---uniform mat4 transform;
+---uniform sampler2D mask;
+---
+---out vec4 finalColor;
+---
+---void main()
+---{
+---    vec4 value = texture(mask, fragTexCoord + offset) * tint * transform;
+---    value.rgb *= weight[0] + weight[1] + weight[2] + float(cell.x + cell.y) + time;
+---
+---    finalColor = invert ? vec4(1.0) - value : value;
+---}
+---]])
+---
+----- Set each uniform by name, with the kind inferred from the value.
+---shader:set_uniform("time", 1.0)
+---shader:set_uniform("offset", vector_2:old(0.5, 0.5))
+---shader:set_uniform("tint", { r = 255, g = 128, b = 0, a = 255 })
+---shader:set_uniform("cell", vector_2:old(4, 8), true)
+---shader:set_uniform("invert", false)
+---shader:set_uniform("weight", { 0.25, 0.5, 0.25 })
+---shader:set_uniform("transform", matrix:old(
+---    1.0, 0.0, 0.0, 0.0,
+---    0.0, 1.0, 0.0, 0.0,
+---    0.0, 0.0, 1.0, 0.0,
+---    0.0, 0.0, 0.0, 1.0
+---))
+---shader:set_uniform("mask", quiver.image.new_color({ x = 4.0, y = 4.0 }, { r = 255, g = 255, b = 255, a = 255 }):to_texture())
+---
+---assert(shader:get_uniform_location("time") >= 0)
+---
+----- A uniform that does not exist will be ignored.
+---assert(shader:get_uniform_location("unknown") == -1)
+---shader:set_uniform("unknown", 1.0)
+---
+----- Every array element must be of the same kind.
+---assert(not pcall(shader.set_uniform, shader, "weight", { 1.0, vector_2:old(1.0, 1.0) }))
+---assert(not pcall(shader.set_uniform, shader, "time", "string"))
+---
+----- Source code that does not compile is an error.
+---assert(not pcall(quiver.shader.new_from_memory, nil, "not a shader"))
+---
+---```
+---@param v_code string? # OPTIONAL: The vertex shader source code.
+---@param f_code string? # OPTIONAL: The fragment shader source code.
+---@return shader shader # Shader resource.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/shader.rs#L139)
-function quiver.shader.new_from_memory() end
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/shader.rs#L170)
+function quiver.shader.new_from_memory(v_code,f_code) end
 
 ---TO-DO
 ---@param call function # The draw code.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/shader.rs#L189)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/shader.rs#L387)
 function shader:begin(call) end
 
 ---TO-DO
@@ -2852,7 +3746,7 @@ function shader:begin(call) end
 ---@return number location # TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/shader.rs#L214)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/shader.rs#L423)
 function shader:get_location_name(name) end
 
 ---TO-DO
@@ -2860,7 +3754,7 @@ function shader:get_location_name(name) end
 ---@return number location # TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/shader.rs#L231)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/shader.rs#L440)
 function shader:get_location_attribute_name(name) end
 
 ---TO-DO
@@ -2868,7 +3762,7 @@ function shader:get_location_attribute_name(name) end
 ---@return number location # TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/shader.rs#L248)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/shader.rs#L457)
 function shader:get_location(location) end
 
 ---TO-DO
@@ -2876,7 +3770,7 @@ function shader:get_location(location) end
 ---@param value number # TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/shader.rs#L263)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/shader.rs#L472)
 function shader:set_location(location,value) end
 
 ---TO-DO
@@ -2885,20 +3779,39 @@ function shader:set_location(location,value) end
 ---@param value any # TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/shader.rs#L283)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/shader.rs#L492)
 function shader:set_shader_value(location,kind,value) end
 
+---Set a uniform by name. The uniform kind is inferred from the value: a number is a float, a boolean is an int, a vector is a vec2, vec3 or vec4, a color is a normalized vec4, a matrix is a mat4, and a texture or render texture is a sampler. A sequence table of any of these (but a matrix or texture) is an array. Uniform location are cached, and a uniform that does not exist will be ignored.
+---@param name string # The uniform name.
+---@param value any # The uniform value.
+---@param integer boolean? # OPTIONAL: Set a number or vector as an int or ivec, rather than as a float or vec. Default: false.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/shader.rs#L553)
+function shader:set_uniform(name,value,integer) end
+
+---Get the location of a uniform by name. Uniform location are cached.
+---@param name string # The uniform name.
+---@return number location # The uniform location, or -1 if the uniform does not exist.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/shader.rs#L580)
+function shader:get_uniform_location(name) end
+
 ---The image API.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/image.rs#L68)
+---*Not available in head-less mode.*
+---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/image.rs#L69)
 ---@class quiver.image
 quiver.image = {}
 
 ---An unique handle for a image in memory.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/image.rs#L104)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/image.rs#L105)
 ---@class image
 ---@field shape_x number # Shape of the image (X).
 ---@field shape_y number # Shape of the image (Y).
@@ -2908,169 +3821,169 @@ image = {}
 ---@return texture texture # Texture resource.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/image.rs#L127)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/image.rs#L128)
 function image:to_texture() end
 
 ---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/image.rs#L138)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/image.rs#L139)
 function image:power_of_two() end
 
 ---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/image.rs#L156)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/image.rs#L157)
 function image:crop() end
 
 ---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/image.rs#L172)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/image.rs#L173)
 function image:crop_alpha() end
 
 ---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/image.rs#L184)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/image.rs#L185)
 function image:crop_alpha() end
 
 ---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/image.rs#L205)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/image.rs#L206)
 function image:blur_gaussian() end
 
 ---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/image.rs#L217)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/image.rs#L218)
 function image:kernel_convolution() end
 
 ---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/image.rs#L232)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/image.rs#L233)
 function image:resize() end
 
 ---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/image.rs#L253)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/image.rs#L254)
 function image:extend() end
 
 ---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/image.rs#L279)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/image.rs#L280)
 function image:mipmap() end
 
 ---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/image.rs#L291)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/image.rs#L292)
 function image:dither() end
 
 ---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/image.rs#L306)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/image.rs#L307)
 function image:flip() end
 
 ---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/image.rs#L322)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/image.rs#L323)
 function image:rotate() end
 
 ---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/image.rs#L336)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/image.rs#L337)
 function image:color_tint() end
 
 ---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/image.rs#L350)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/image.rs#L351)
 function image:color_invert() end
 
 ---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/image.rs#L362)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/image.rs#L363)
 function image:color_gray_scale() end
 
 ---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/image.rs#L374)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/image.rs#L375)
 function image:color_contrast() end
 
 ---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/image.rs#L386)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/image.rs#L387)
 function image:color_contrast() end
 
 ---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/image.rs#L406)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/image.rs#L407)
 function image:get_alpha_border() end
 
 ---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/image.rs#L418)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/image.rs#L419)
 function image:get_alpha_border() end
 
 ---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/image.rs#L432)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/image.rs#L433)
 function image:draw_pixel() end
 
 ---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/image.rs#L447)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/image.rs#L448)
 function image:draw_line() end
 
 ---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/image.rs#L472)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/image.rs#L473)
 function image:draw_circle() end
 
 ---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/image.rs#L490)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/image.rs#L491)
 function image:draw_circle_line() end
 
 ---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/image.rs#L508)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/image.rs#L509)
 function image:draw_box_2() end
 
 ---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/image.rs#L523)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/image.rs#L524)
 function image:draw_box_2_line() end
 
 ---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/image.rs#L541)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/image.rs#L542)
 function image:draw_triangle() end
 
 ---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/image.rs#L571)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/image.rs#L572)
 function image:draw_triangle_line() end
 
 ---Create a new image resource.
@@ -3080,7 +3993,7 @@ function image:draw_triangle_line() end
 --- ---
 ---*This function is asynchronous and can run within a co-routine.*
 ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/image.rs#L609)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/image.rs#L610)
 function quiver.image.new(path) end
 
 ---Create a new image resource, from memory.
@@ -3091,7 +4004,7 @@ function quiver.image.new(path) end
 --- ---
 ---*This function is asynchronous and can run within a co-routine.*
 ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/image.rs#L643)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/image.rs#L644)
 function quiver.image.new_from_memory(data,kind) end
 
 ---Create a new image resource, from the current screen buffer.
@@ -3100,7 +4013,7 @@ function quiver.image.new_from_memory(data,kind) end
 --- ---
 ---*This function is asynchronous and can run within a co-routine.*
 ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/image.rs#L673)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/image.rs#L674)
 function quiver.image.new_from_screen() end
 
 ---TO-DO
@@ -3108,7 +4021,7 @@ function quiver.image.new_from_screen() end
 --- ---
 ---*This function is asynchronous and can run within a co-routine.*
 ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/image.rs#L695)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/image.rs#L696)
 function quiver.image.new_color() end
 
 ---TO-DO
@@ -3116,7 +4029,7 @@ function quiver.image.new_color() end
 --- ---
 ---*This function is asynchronous and can run within a co-routine.*
 ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/image.rs#L720)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/image.rs#L721)
 function quiver.image.new_gradient_linear() end
 
 ---TO-DO
@@ -3124,7 +4037,7 @@ function quiver.image.new_gradient_linear() end
 --- ---
 ---*This function is asynchronous and can run within a co-routine.*
 ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/image.rs#L755)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/image.rs#L756)
 function quiver.image.new_gradient_radial() end
 
 ---TO-DO
@@ -3132,7 +4045,7 @@ function quiver.image.new_gradient_radial() end
 --- ---
 ---*This function is asynchronous and can run within a co-routine.*
 ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/image.rs#L790)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/image.rs#L791)
 function quiver.image.new_gradient_square() end
 
 ---TO-DO
@@ -3140,7 +4053,7 @@ function quiver.image.new_gradient_square() end
 --- ---
 ---*This function is asynchronous and can run within a co-routine.*
 ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/image.rs#L825)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/image.rs#L826)
 function quiver.image.new_check() end
 
 ---TO-DO
@@ -3148,7 +4061,7 @@ function quiver.image.new_check() end
 --- ---
 ---*This function is asynchronous and can run within a co-routine.*
 ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/image.rs#L862)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/image.rs#L863)
 function quiver.image.new_white_noise() end
 
 ---TO-DO
@@ -3156,7 +4069,7 @@ function quiver.image.new_white_noise() end
 --- ---
 ---*This function is asynchronous and can run within a co-routine.*
 ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/image.rs#L886)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/image.rs#L887)
 function quiver.image.new_perlin_noise() end
 
 ---TO-DO
@@ -3164,7 +4077,7 @@ function quiver.image.new_perlin_noise() end
 --- ---
 ---*This function is asynchronous and can run within a co-routine.*
 ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/image.rs#L920)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/image.rs#L921)
 function quiver.image.new_cellular() end
 
 ---TO-DO
@@ -3172,7 +4085,7 @@ function quiver.image.new_cellular() end
 --- ---
 ---*This function is asynchronous and can run within a co-routine.*
 ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/image.rs#L944)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/image.rs#L945)
 function quiver.image.new_text() end
 
 ---The font API.
@@ -3180,29 +4093,78 @@ function quiver.image.new_text() end
 --- ---
 ---*Not available in head-less mode.*
 ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/font.rs#L64)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/font.rs#L70)
 ---@class quiver.font
 quiver.font = {}
 
----An unique handle to a font in memory.
+---An unique handle to a font in memory. Any glyph missing from the font will be rasterized into the glyph atlas on demand, the first time it is drawn or measured. If the font file does not have a glyph either, each fallback font will be used instead, in order.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/font.rs#L92)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/font.rs#L178)
+---```lua
+----- Load the default font, with only the printable ASCII range.
+---local font = quiver.font.new_default(32)
+---
+---assert(font:has_codepoint("Hello"))
+---assert(not font:has_codepoint("é"))
+---
+----- Rasterize a glyph into the glyph atlas ahead of time...
+---font:load_codepoint("é")
+---
+---assert(font:has_codepoint("é"))
+---
+----- ...or let it be rasterized on demand, the first time it is measured or drawn.
+---font:measure_text("ñ", 32.0, 1.0)
+---
+---assert(font:has_codepoint(0xF1))
+---
+----- Load a font with a whole string table, and a codepoint range.
+---local font = quiver.font.new_default(32, {
+---    greeting = "¡Hola!",
+---    farewell = "Adiós",
+---    { first = 0xC0, last = 0xFF },
+---})
+---
+---assert(font:has_codepoint({ "¡Hola!", "Adiós", 0xC0, 0xFF }))
+---
+----- A glyph the font file does not have will be taken from a fallback font, if any.
+---local fallback = quiver.font.new_default(16)
+---
+---font:set_fallback({ fallback })
+---
+---local size_x, size_y = font:measure_text("Hola\nAdiós", 32.0, 1.0)
+---
+---assert(size_x > 0.0 and size_y > 32.0)
+---
+----- A codepoint range is clamped to valid codepoints, and a very large one is an error.
+---assert(not pcall(quiver.font.new_default, 32, { first = 0, last = 0x7FFFFFFF }))
+---
+----- The limit is for the whole set, not for each range in it...
+---assert(not pcall(quiver.font.new_default, 32, { { first = 0x0000, last = 0xFFFF }, { first = 0x10000, last = 0x1FFFF } }))
+---
+----- ...and for the whole glyph atlas, so a font can not grow past it either.
+---assert(not pcall(font.load_codepoint, font, { first = 0x80, last = 0x1007F }))
+---
+---```
 ---@class font
+---@field size number # The size of the font.
+---@field glyph_count number # The glyph count in the glyph atlas.
+---@field mode font_mode # The mode of the font.
 font = {}
 
 ---Draw a font.
 ---@param label string # Label of font to draw.
 ---@param point vector_2 # Point of font to draw.
----@param origin vector_2 # TO-DO
----@param angle number # TO-DO
+---@param origin vector_2 # Origin of font to draw, relative to the point.
+---@param angle number # Angle of font to draw, in degree.
 ---@param scale number # Scale of font to draw.
 ---@param space number # Space of font to draw.
 ---@param color color # Color of font to draw.
+---@param effect table? # OPTIONAL: The effect table, with any of: outline (width, in pixel of the font size, up to 2.0), outline_color, glow (width, in pixel of the font size), glow_color, shadow (a vector_2 offset) and shadow_color. Outline and glow require an SDF font.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/font.rs#L116)
-function font:draw(label,point,origin,angle,scale,space,color) end
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/font.rs#L264)
+function font:draw(label,point,origin,angle,scale,space,color,effect) end
 
 ---Measure the size of a given text on screen, with a given font.
 ---@param label string # Label of font to measure.
@@ -3212,40 +4174,160 @@ function font:draw(label,point,origin,angle,scale,space,color) end
 ---@return number size_y # Size of text (Y).
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/font.rs#L166)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/font.rs#L341)
 function font:measure_text(label,scale,space) end
 
----Create a new font resource.
+---Set the fallback font list. Any glyph missing from this font will be taken from the first fallback font that has it. A fallback font's own fallback list will not be used.
+---@param fallback table? # The fallback font list. Use nil to clear.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/font.rs#L386)
+function font:set_fallback(fallback) end
+
+---Rasterize a set of codepoint into the glyph atlas ahead of time, instead of on demand.
+---@param codepoint any # The codepoint set. See quiver.font.new.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/font.rs#L412)
+function font:load_codepoint(codepoint) end
+
+---Check if every codepoint in a set is in the glyph atlas of this font. Will not check any fallback font, and will not rasterize any glyph.
+---@param codepoint any # The codepoint set. See quiver.font.new.
+---@return boolean value # True if every codepoint is in the glyph atlas.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/font.rs#L432)
+function font:has_codepoint(codepoint) end
+
+---Get every codepoint in the glyph atlas of this font.
+---@return table codepoint # The codepoint list.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/font.rs#L451)
+function font:get_codepoint() end
+
+---Create a new font resource. The codepoint set can be a string (every codepoint used in it), a number (a single codepoint), a range table ({ first = 0x0400, last = 0x04FF }), or a table of any of those, such as a whole string table. The printable ASCII range is always loaded.
 ---@param path string # Path to font file.
 ---@param size number # Size for font.
+---@param codepoint any # OPTIONAL: The codepoint set to load. Default: printable ASCII.
+---@param mode font_mode? # OPTIONAL: The mode of the font. Default: FONT_MODE.DEFAULT.
 ---@return font font # Font resource.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/font.rs#L195)
-function quiver.font.new(path,size) end
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/font.rs#L481)
+function quiver.font.new(path,size,codepoint,mode) end
 
 ---Create a new font resource, from memory.
 ---@param data data # The data buffer.
 ---@param kind string # The kind of font file (.ttf, etc.).
 ---@param size number # Size for font.
+---@param codepoint any # OPTIONAL: The codepoint set to load. See quiver.font.new. Default: printable ASCII.
+---@param mode font_mode? # OPTIONAL: The mode of the font. Default: FONT_MODE.DEFAULT.
 ---@return font font # Font resource.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/font.rs#L226)
-function quiver.font.new_from_memory(data,kind,size) end
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/font.rs#L516)
+function quiver.font.new_from_memory(data,kind,size,codepoint,mode) end
 
 ---Create a new font resource, from the default font.
 ---@param size number # Size for font.
+---@param codepoint any # OPTIONAL: The codepoint set to load. See quiver.font.new. Default: printable ASCII.
+---@param mode font_mode? # OPTIONAL: The mode of the font. Default: FONT_MODE.DEFAULT.
 ---@return font font # Font resource.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/font.rs#L264)
-function quiver.font.new_default(size) end
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/font.rs#L542)
+function quiver.font.new_default(size,codepoint,mode) end
+
+---A text layout, with word wrap, alignment and in-line markup. Every glyph (and image) has a rectangle relative to the top-left of the layout, for hit-testing or a typewriter effect.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/font.rs#L1051)
+---```lua
+----- Lay out a text with in-line markup, wrapped to a width.
+---local font = quiver.font.new_default(10)
+---local coin = quiver.image.new_color({ x = 16.0, y = 16.0 }, { r = 255, g = 192, b = 0, a = 255 }):to_texture()
+---
+---local layout = quiver.font.new_layout(font,
+---    "Hello, [color=#FF0000]red[/color] [b]world[/b]! [image=coin] [wave]wave[/wave] [shake]shake[/shake]\nA new line, with a [[literal] bracket.", {
+---        width = 96.0,
+---        align = TEXT_ALIGN.JUSTIFY,
+---        bold  = font,
+---        image = { coin = coin },
+---    })
+---
+---assert(layout.line_count > 2)
+---assert(layout.size_x <= 96.0)
+---
+----- Every glyph has a rectangle, for hit-testing.
+---local glyph = layout:get_glyph(1)
+---
+---assert(glyph.text == "H")
+---assert(glyph.line == 1)
+---assert(layout:get_glyph_at({ x = glyph.x + 1.0, y = glyph.y + 1.0 }) == 1)
+---assert(layout:get_glyph_at({ x = -1.0, y = -1.0 }) == nil)
+---
+----- Draw only the first half of the layout, for a typewriter effect.
+---layout:draw({ x = 8.0, y = 8.0 }, { r = 255, g = 255, b = 255, a = 255 }, 1.0, math.floor(layout.count / 2))
+---
+----- An unknown image is an error.
+---assert(not pcall(quiver.font.new_layout, font, "[image=none]"))
+---
+----- A line-break inside a bracket that is not a tag is still a line-break.
+---assert(quiver.font.new_layout(font, "[not\na tag]").line_count == 2)
+---
+----- A close tag with no matching open tag is kept as-is.
+---assert(quiver.font.new_layout(font, "a[/b]").count == 5)
+---assert(quiver.font.new_layout(font, "[b]a[/foo][/b]").count == 7)
+---
+---```
+---@class font_layout
+---@field count number # The glyph count, including any space and image.
+---@field line_count number # The line count.
+---@field size_x number # Size of the layout (X).
+---@field size_y number # Size of the layout (Y).
+font_layout = {}
+
+---Draw the layout.
+---@param point vector_2 # Point of the layout to draw.
+---@param color color? # OPTIONAL: Color of the layout to draw, for any glyph without a color tag. Default: WHITE.
+---@param time number? # OPTIONAL: The time, for the shake and wave effect. Default: 0.0.
+---@param count number? # OPTIONAL: The glyph count to draw, for a typewriter effect. Default: every glyph.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/font.rs#L1113)
+function font_layout:draw(point,color,time,count) end
+
+---Get a glyph of the layout.
+---@param index number # The glyph index.
+---@return table glyph # The glyph table, with a point (x, y) relative to the top-left of the layout, a size (width, height), the line index and either the text or the image (texture) of the glyph.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/font.rs#L1228)
+function font_layout:get_glyph(index) end
+
+---Get the glyph at a point.
+---@param point vector_2 # The point, relative to the top-left of the layout.
+---@return number? index # The glyph index, or nil if there is no glyph at the point.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/font.rs#L1266)
+function font_layout:get_glyph_at(point) end
+
+---Create a new text layout. The text may have any of the following markup tag: [b] (bold), [shake], [wave] and [color=#RRGGBB] (or #RRGGBBAA), each closed with [/b], [/shake], [/wave] and [/color], and [image=name] to place an image in-line. Use [[ for a literal [. An unknown tag will be kept as-is.
+---@param font font # The font to lay out with.
+---@param text string # The text to lay out.
+---@param option table? # OPTIONAL: The option table, with any of: scale (default: the font size), space (default: 1.0), width (the width to wrap to, default: no wrap), align (a TEXT_ALIGN value, default: LEFT), line_space (default: the text line space), bold (the font for bold text, default: no bold) and image (a table of texture, by name, for the image tag).
+---@return font_layout font_layout # The text layout.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/font.rs#L1304)
+function quiver.font.new_layout(font,text,option) end
 
 ---TO-DO
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/font.rs#L295)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/font.rs#L1681)
 function quiver.font.draw_frame_rate() end
 
 ---Draw text.
@@ -3255,84 +4337,214 @@ function quiver.font.draw_frame_rate() end
 ---@param color color # The color of the text.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/font.rs#L317)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/font.rs#L1703)
 function quiver.font.draw_text(point,label,scale,color) end
 
 ---Set the vertical space between each line-break.
 ---@param space number # Vertical space.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/font.rs#L347)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/font.rs#L1733)
 function quiver.font.set_text_line_space(space) end
 
 ---The automation API.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/automation.rs#L64)
+---*Not available in head-less mode.*
+---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/automation.rs#L66)
 ---@class quiver.automation
 quiver.automation = {}
 
 ---An unique handle to an automation event list.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/automation.rs#L80)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/automation.rs#L84)
 ---@class automation_event
 automation_event = {}
 
----TO-DO
+---Create a new automation event list. For input recording and play-back with a fixed frame time and random seed, see quiver.automation.new_replay.
+---@param path string? # OPTIONAL: Path to an automation event list file to load. If nil, will create an empty list.
+---@return automation_event automation_event # The automation event list.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/automation.rs#L92)
-function quiver.automation.new() end
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/automation.rs#L102)
+function quiver.automation.new(path) end
 
----TO-DO
+---Save the automation event list to a file.
+---@param path string # Path to the file.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/automation.rs#L131)
-function automation_event:save() end
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/automation.rs#L148)
+function automation_event:save(path) end
 
----TO-DO
+---Set the automation event list as the one to record to.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/automation.rs#L146)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/automation.rs#L164)
 function automation_event:set_active() end
 
----TO-DO
+---Set the base frame for recording, which is the frame counter every event frame will be relative to.
+---@param frame number # The base frame.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/automation.rs#L158)
-function automation_event:set_frame() end
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/automation.rs#L179)
+function automation_event:set_frame(frame) end
 
----TO-DO
+---Start recording to the active automation event list.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/automation.rs#L170)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/automation.rs#L191)
 function automation_event:start() end
 
----TO-DO
+---Stop recording to the active automation event list.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/automation.rs#L182)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/automation.rs#L203)
 function automation_event:stop() end
 
----TO-DO
+---Play an event from the automation event list.
+---@param index number # The index of the event.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/automation.rs#L194)
-function automation_event:play() end
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/automation.rs#L218)
+function automation_event:play(index) end
 
----TO-DO
+---Get an event from the automation event list.
+---@param index number # The index of the event.
+---@return number frame # The frame of the event.
+---@return number kind # The kind of the event.
+---@return table parameter # The parameters of the event.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/automation.rs#L213)
-function automation_event:get_event() end
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/automation.rs#L245)
+function automation_event:get_event(index) end
+
+---A replay, for deterministic input recording and play-back. A replay will keep the random seed, as well as a fixed frame time that quiver.general.get_frame_time will give back while the replay is active. Play-back is done automatically, at the end of every quiver.draw.begin call.
+---
+--- ---
+---*Not available in head-less mode.*
+---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/automation.rs#L322)
+---```lua
+----- Create a replay, with a fixed random seed and frame time.
+---local replay = quiver.automation.new_replay(1234, 1.0 / 60.0)
+---
+----- Record the input for a few frames. The frame time will be fixed while recording.
+---replay:record()
+---
+---local value_a = math.random()
+---
+---for x = 1, 4 do
+---    assert(quiver.general.get_frame_time() == replay.frame_time)
+---
+---    quiver.draw.begin(function() end)
+---end
+---
+---replay:stop()
+---
+----- Save the replay, and load it back.
+---local path = quiver.file.get_temp_path() .. "/replay.json"
+---
+---replay:save(path)
+---
+---local replay = quiver.automation.load_replay(path)
+---
+---assert(replay.seed == 1234)
+---
+----- Play the replay back. The random seed will be the same as when recording, and input will be played back automatically at the end of every frame.
+---replay:play()
+---
+---assert(math.random() == value_a)
+---
+---while replay:get_play() do
+---    quiver.draw.begin(function() end)
+---end
+---
+---replay:stop()
+---
+---```
+---@class replay
+replay = {}
+
+---Create a new, empty replay.
+---@param seed number? # OPTIONAL: The random seed. If nil, will use the current time.
+---@param frame_time number? # OPTIONAL: The fixed frame time. If nil, will use the frame-rate from the info manifest.
+---@return replay replay # The replay.
+---
+--- ---
+---*Not available in head-less mode.*
+---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/automation.rs#L345)
+function quiver.automation.new_replay(seed,frame_time) end
+
+---Load a replay from a file.
+---@param path string # Path to the replay file.
+---@return replay replay # The replay.
+---
+--- ---
+---*Not available in head-less mode.*
+---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/automation.rs#L380)
+function quiver.automation.load_replay(path) end
+
+---Start recording input to the replay. Any previous input in the replay will be lost. Will set the random seed, and the fixed frame time.
+---
+--- ---
+---*Not available in head-less mode.*
+---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/automation.rs#L464)
+function replay:record() end
+
+---Start playing the replay back. Will set the random seed, and the fixed frame time.
+---
+--- ---
+---*Not available in head-less mode.*
+---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/automation.rs#L501)
+function replay:play() end
+
+---Stop recording, or playing, the replay. Recorded input will be kept in the replay.
+---
+--- ---
+---*Not available in head-less mode.*
+---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/automation.rs#L526)
+function replay:stop() end
+
+---Check if the replay is still playing back, with input left to play.
+---@return boolean play # True if playing, with input left to play.
+---
+--- ---
+---*Not available in head-less mode.*
+---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/automation.rs#L547)
+function replay:get_play() end
+
+---Get the current frame of the active replay.
+---@return number? frame # The current frame, or nil if no replay is active.
+---
+--- ---
+---*Not available in head-less mode.*
+---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/automation.rs#L566)
+function replay:get_frame() end
+
+---Save the replay to a file, with the random seed and the fixed frame time.
+---@param path string # Path to the replay file.
+---
+--- ---
+---*Not available in head-less mode.*
+---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/automation.rs#L581)
+function replay:save(path) end
 
 ---The window API.
 ---
 --- ---
 ---*Not available in head-less mode.*
 ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/window.rs#L68)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/window.rs#L66)
 ---@class quiver.window
 quiver.window = {}
 
@@ -3346,7 +4558,7 @@ quiver.window = {}
 --- ---
 ---*This function is asynchronous and can run within a co-routine.*
 ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/window.rs#L176)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/window.rs#L174)
 function quiver.window.file_dialog(kind,title,path,name,filter) end
 
 ---Create a new native OS text dialog.
@@ -3358,56 +4570,56 @@ function quiver.window.file_dialog(kind,title,path,name,filter) end
 --- ---
 ---*This function is asynchronous and can run within a co-routine.*
 ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/window.rs#L270)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/window.rs#L268)
 function quiver.window.text_dialog(kind,title,label,button) end
 
 ---Get if the window should close.
 ---@return boolean close # True if the window should close.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/window.rs#L320)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/window.rs#L318)
 function quiver.window.get_close() end
 
 ---Get the state of the window (full-screen).
 ---@return boolean state # State of the window.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/window.rs#L334)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/window.rs#L332)
 function quiver.window.get_fullscreen() end
 
 ---Get the state of the window (hidden).
 ---@return boolean state # State of the window.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/window.rs#L348)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/window.rs#L346)
 function quiver.window.get_hidden() end
 
 ---Get the state of the window (minimize).
 ---@return boolean state # State of the window.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/window.rs#L362)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/window.rs#L360)
 function quiver.window.get_minimize() end
 
 ---Get the state of the window (maximize).
 ---@return boolean state # State of the window.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/window.rs#L376)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/window.rs#L374)
 function quiver.window.get_maximize() end
 
 ---Get the state of the window (focus).
 ---@return boolean state # State of the window.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/window.rs#L390)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/window.rs#L388)
 function quiver.window.get_focus() end
 
 ---Get the state of the window (resize).
 ---@return boolean state # State of the window.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/window.rs#L404)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/window.rs#L402)
 function quiver.window.get_resize() end
 
 ---Get the state of a window flag.
@@ -3415,7 +4627,7 @@ function quiver.window.get_resize() end
 ---@return boolean state # Window flag state.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/window.rs#L421)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/window.rs#L419)
 function quiver.window.get_state(flag) end
 
 ---Set the state of a window flag.
@@ -3423,97 +4635,97 @@ function quiver.window.get_state(flag) end
 ---@param state boolean # Window flag state.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/window.rs#L436)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/window.rs#L434)
 function quiver.window.set_state(flag,state) end
 
 ---Set the window to full-screen mode.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/window.rs#L451)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/window.rs#L449)
 function quiver.window.set_fullscreen() end
 
 ---Set the window to border-less mode.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/window.rs#L461)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/window.rs#L459)
 function quiver.window.set_borderless() end
 
 ---Minimize the window.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/window.rs#L471)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/window.rs#L469)
 function quiver.window.set_minimize() end
 
 ---Maximize the window.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/window.rs#L481)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/window.rs#L479)
 function quiver.window.set_maximize() end
 
 ---Restore the window.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/window.rs#L491)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/window.rs#L489)
 function quiver.window.set_restore() end
 
 ---Set the window icon.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/window.rs#L501)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/window.rs#L499)
 function quiver.window.set_icon() end
 
 ---Set the window name.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/window.rs#L520)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/window.rs#L518)
 function quiver.window.set_name() end
 
 ---Set the window point.
 ---@param point vector_2 # Point of the window.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/window.rs#L539)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/window.rs#L537)
 function quiver.window.set_point(point) end
 
 ---Set the window monitor.
 ---@param index number # Index of monitor to move window to.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/window.rs#L558)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/window.rs#L556)
 function quiver.window.set_screen(index) end
 
 ---Set the minimum window shape.
 ---@param shape vector_2 # Minimum shape of the window.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/window.rs#L575)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/window.rs#L573)
 function quiver.window.set_shape_min(shape) end
 
 ---Set the maximum window shape.
 ---@param shape vector_2 # Maximum shape of the window.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/window.rs#L594)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/window.rs#L592)
 function quiver.window.set_shape_max(shape) end
 
 ---Set the current window shape.
 ---@param shape vector_2 # Shape of the window.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/window.rs#L613)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/window.rs#L611)
 function quiver.window.set_shape(shape) end
 
 ---Set the window alpha.
 ---@param alpha number # Alpha of the window.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/window.rs#L632)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/window.rs#L630)
 function quiver.window.set_alpha(alpha) end
 
 ---Focus the window.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/window.rs#L642)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/window.rs#L640)
 function quiver.window.set_focus() end
 
 ---Get the shape of the window.
@@ -3521,7 +4733,7 @@ function quiver.window.set_focus() end
 ---@return number shape_y # Shape of the window (Y).
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/window.rs#L660)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/window.rs#L658)
 function quiver.window.get_shape() end
 
 ---Get the shape of the current render view.
@@ -3529,21 +4741,21 @@ function quiver.window.get_shape() end
 ---@return number shape_y # Shape of the render view (Y).
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/window.rs#L675)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/window.rs#L673)
 function quiver.window.get_render_shape() end
 
 ---Get the available monitor amount.
 ---@return number count # Monitor count.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/window.rs#L689)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/window.rs#L687)
 function quiver.window.get_screen_count() end
 
 ---Get the current active monitor, where the window is.
 ---@return number index # Current active monitor index.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/window.rs#L703)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/window.rs#L701)
 function quiver.window.get_screen_focus() end
 
 ---Get the point of the given monitor.
@@ -3552,7 +4764,7 @@ function quiver.window.get_screen_focus() end
 ---@return number point_y # Point of the monitor (Y).
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/window.rs#L721)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/window.rs#L719)
 function quiver.window.get_screen_point(index) end
 
 ---Get the shape of the given monitor.
@@ -3561,7 +4773,7 @@ function quiver.window.get_screen_point(index) end
 ---@return number shape_y # Shape of the window (Y).
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/window.rs#L742)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/window.rs#L740)
 function quiver.window.get_screen_shape(index) end
 
 ---Get the physical shape of the given monitor.
@@ -3570,7 +4782,7 @@ function quiver.window.get_screen_shape(index) end
 ---@return number shape_y # Physical shape of the window (Y).
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/window.rs#L760)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/window.rs#L758)
 function quiver.window.get_screen_shape_physical(index) end
 
 ---Get the refresh rate of the given monitor.
@@ -3578,7 +4790,7 @@ function quiver.window.get_screen_shape_physical(index) end
 ---@return number rate # Refresh rate of the monitor.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/window.rs#L782)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/window.rs#L780)
 function quiver.window.get_screen_rate(index) end
 
 ---Get the point of the window.
@@ -3586,7 +4798,7 @@ function quiver.window.get_screen_rate(index) end
 ---@return number point_y # Point of the window (Y).
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/window.rs#L797)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/window.rs#L795)
 function quiver.window.get_point() end
 
 ---Get the DPI scale of the window.
@@ -3594,7 +4806,7 @@ function quiver.window.get_point() end
 ---@return number scale_y # Scale of the window (Y).
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/window.rs#L816)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/window.rs#L814)
 function quiver.window.get_scale() end
 
 ---Get the name of the given monitor.
@@ -3602,14 +4814,14 @@ function quiver.window.get_scale() end
 ---@return string name # Name of the monitor.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/window.rs#L837)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/window.rs#L835)
 function quiver.window.get_screen_name(index) end
 
 ---Get a screen-shot of the current frame.
 ---@param path string # Path to save the screen-shot to.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/window.rs#L857)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/window.rs#L855)
 function quiver.window.get_screen_shot(path) end
 
 ---The music API.
@@ -3617,14 +4829,14 @@ function quiver.window.get_screen_shot(path) end
 --- ---
 ---*Not available in head-less mode.*
 ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/music.rs#L64)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/music.rs#L70)
 ---@class quiver.music
 quiver.music = {}
 
 ---An unique handle for music in memory.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/music.rs#L81)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/music.rs#L93)
 ---@class music
 music = {}
 
@@ -3635,7 +4847,7 @@ music = {}
 --- ---
 ---*This function is asynchronous and can run within a co-routine.*
 ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/music.rs#L101)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/music.rs#L119)
 function quiver.music.new(path) end
 
 ---Create a new music resource, from memory.
@@ -3646,85 +4858,1599 @@ function quiver.music.new(path) end
 --- ---
 ---*This function is asynchronous and can run within a co-routine.*
 ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/music.rs#L134)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/music.rs#L153)
 function quiver.music.new_from_memory(data,kind) end
 
+---Set the audio bus of the music.
+---@param bus audio_bus? # The audio bus. Use nil to remove the music from its bus.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/music.rs#L213)
+function music:set_bus(bus) end
+
 ---Play the music.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/music.rs#L173)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/music.rs#L231)
 function music:play() end
 
 ---Check if music is currently playing.
 ---@return boolean state # State of the music.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/music.rs#L188)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/music.rs#L246)
 function music:get_playing() end
 
 ---Stop the music.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/music.rs#L195)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/music.rs#L253)
 function music:stop() end
 
 ---Pause the music.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/music.rs#L203)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/music.rs#L261)
 function music:pause() end
 
 ---Resume the music.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/music.rs#L211)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/music.rs#L269)
 function music:resume() end
 
 ---Set volume for the music. (range: 0.0 - 1.0)
 ---@param volume number # Current volume.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/music.rs#L226)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/music.rs#L284)
 function music:set_volume(volume) end
 
 ---Set pitch for the music.
 ---@param pitch number # Current pitch.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/music.rs#L241)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/music.rs#L299)
 function music:set_pitch(pitch) end
 
 ---Set pan for the music. (range: 0.0 - 1.0; 0.5 is center)
 ---@param pan number # Current pan.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/music.rs#L256)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/music.rs#L321)
 function music:set_pan(pan) end
 
 ---Update the music.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/music.rs#L264)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/music.rs#L329)
 function music:update() end
 
 ---Set position for the music.
 ---@param position number # Current position.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/music.rs#L279)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/music.rs#L344)
 function music:set_position(position) end
 
 ---Get time length for the music.
 ---@return number length # Time length.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/music.rs#L294)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/music.rs#L359)
 function music:get_length() end
 
 ---Get time played for the music.
 ---@return number played # Time played.
 ---
 --- ---
----[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/music.rs#L308)
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/music.rs#L373)
 function music:get_played() end
 
+---Set the music as a spatial source, with distance attenuation, pan and doppler pitch shift from the listener.
+---@param option table? # The spatial option: { point, velocity?, kind? (ATTENUATION_KIND), distance_min?, distance_max?, rolloff? }. Use nil to remove the spatial source.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/music.rs#L387)
+function music:set_spatial(option) end
+
+---Set the point of the spatial source of the music.
+---@param point vector_3 # The point of the source.
+---@param velocity vector_3? # OPTIONAL: The velocity of the source. Default: derived from the last source point.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/music.rs#L413)
+function music:set_spatial_point(point,velocity) end
+
+---Get the magnitude spectrum of the last sample played, with every channel down-mixed. The first call will begin listening to the music, and will be silence.
+---@param size number? # OPTIONAL: The FFT size, as a power of two (up to 16384). Default: 1024.
+---@return table spectrum # The magnitude of every frequency bin (size / 2), each bin being sample_rate / size Hz wide.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/music.rs#L437)
+function music:get_spectrum(size) end
+
+---Get the RMS (root mean square) and peak level of the last sample played, with every channel down-mixed. The first call will begin listening to the music, and will be silence.
+---@param size number? # OPTIONAL: The sample count, as a power of two (up to 16384). Default: 1024.
+---@return number rms # The RMS level.
+---@return number peak # The peak level.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/music.rs#L457)
+function music:get_level(size) end
+
+---A music player, with a queue of music track, cross-fading and loop region. Every music player will update itself at the end of every quiver.draw.begin call, and will take over the volume of every music in it.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/music.rs#L730)
+---```lua
+----- Synthesize a short music track, by exporting a wave.
+---local sample = {}
+---
+---for i = 1, 44100 do
+---    sample[i] = math.sin((i - 1) / 44100 * 220.0 * math.pi * 2.0) * 0.25
+---end
+---
+---local path = quiver.file.get_temp_path() .. "/music.wav"
+---
+---quiver.wave.new_from_data(sample, 44100, 1):export(path)
+---
+---local intro = quiver.music.new(path)
+---local theme = quiver.music.new(path)
+---
+----- Create a music player, with a half-second cross-fade by default.
+---local player = quiver.music.new_player(0.5)
+---
+---assert(player.fade == 0.5)
+---assert(player:get_current() == nil)
+---
+----- Play the intro, then queue the theme, looping from 0.25 seconds in.
+---player:queue(intro)
+---player:queue(theme, { loop_start = 0.25, fade = 0.1 })
+---
+---assert(player:get_current() == intro)
+---assert(player.count == 1)
+---assert(not pcall(player.queue, player, theme, { loop_start = 0.5, loop_end = 0.25 }))
+---assert(not pcall(player.play, player, player))
+---
+----- Move on to the theme right away.
+---player:next()
+---
+---assert(player:get_current() == theme)
+---assert(player.count == 0)
+---
+---player.volume = 0.5
+---player:pause()
+---
+---assert(player:get_pause())
+---
+---player:resume()
+---player:stop(0.0)
+---
+---assert(player:get_current() == nil)
+---
+----- Unload every track before removing the file, as the music stream will keep it open.
+---player = nil
+---intro = nil
+---theme = nil
+---
+---collectgarbage()
+---
+---quiver.file.remove_file(path)
+---
+---```
+---@class music_player
+---@field volume number # The volume of the music player. Default: 1.0.
+---@field fade number # The default cross-fade time, in second.
+---@field count number # The count of music track in the queue.
+music_player = {}
+
+---Create a new music player.
+---@param fade number? # OPTIONAL: The default cross-fade time, in second. Default: 1.0.
+---@return music_player music_player # The music player.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/music.rs#L746)
+function quiver.music.new_player(fade) end
+
+---Play a music track right away, cross-fading from the current music track. The queue is kept.
+---@param music music # The music.
+---@param option table? # OPTIONAL: The track option: { fade?, loop_start?, loop_end?, loop? }. The loop end will be the end of the music by default. Will loop by default only if a loop point is given.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/music.rs#L809)
+function music_player:play(music,option) end
+
+---Queue a music track, to be cross-faded into once the current music track is about to end. A looping music track will only end on a music_player:next call. Will play right away if there is no current music track.
+---@param music music # The music.
+---@param option table? # OPTIONAL: The track option, as with music_player:play.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/music.rs#L832)
+function music_player:queue(music,option) end
+
+---Cross-fade to the next music track in the queue, or fade out if the queue is empty.
+---@param fade number? # OPTIONAL: The cross-fade time, in second. Default: the fade time of the next music track.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/music.rs#L858)
+function music_player:next(fade) end
+
+---Fade out the current music track. The queue is kept.
+---@param fade number? # OPTIONAL: The fade-out time, in second. Default: the default cross-fade time.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/music.rs#L888)
+function music_player:stop(fade) end
+
+---Clear the queue.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/music.rs#L900)
+function music_player:clear_queue() end
+
+---Pause the music player.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/music.rs#L908)
+function music_player:pause() end
+
+---Resume the music player.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/music.rs#L916)
+function music_player:resume() end
+
+---Check if the music player is paused.
+---@return boolean state # State of the music player.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/music.rs#L931)
+function music_player:get_pause() end
+
+---Get the current music track.
+---@return music? music # The current music, if any.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/music.rs#L943)
+function music_player:get_current() end
+
+---The audio API.
+---
+--- ---
+---*Not available in head-less mode.*
+---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/audio.rs#L71)
+---@class quiver.audio
+quiver.audio = {}
+
+---A procedural audio stream. Sample are 32-bit float in the [-1.0, 1.0] range, interleaved by channel, and are pushed to a ring buffer that will be fed to the audio device from the audio thread, with silence for any missing sample. At most 16 audio stream can exist at the same time.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/audio.rs#L177)
+---```lua
+----- Create a mono audio stream, at 44100 Hz.
+---local stream = quiver.audio.new_stream(44100, 1)
+---
+---assert(stream.sample_rate == 44100)
+---assert(stream.channel == 1)
+---assert(stream.capacity == 44100)
+---
+----- Synthesize a 440 Hz sine wave, a tenth of a second long.
+---local sample = {}
+---
+---for i = 1, 4410 do
+---    sample[i] = math.sin((i - 1) / 44100 * 440.0 * math.pi * 2.0) * 0.25
+---end
+---
+---assert(stream:push(sample) == 4410)
+---assert(stream.queue == 4410)
+---
+----- Any sample past the ring buffer capacity will be dropped.
+---for _ = 1, 10 do
+---    stream:push(sample)
+---end
+---
+---assert(stream.queue == stream.capacity)
+---
+---stream:clear()
+---
+---assert(stream.queue == 0)
+---
+---stream:play()
+---
+---assert(stream:get_playing())
+---
+---stream:stop()
+---
+---```
+---@class audio_stream
+---@field sample_rate number # The sample rate.
+---@field channel number # The channel count.
+---@field capacity number # The ring buffer capacity, in frame.
+---@field queue number # The frame count in the ring buffer, yet to be played.
+---@field underrun number # The count of time the audio device ran out of sample to play.
+audio_stream = {}
+
+---Create a new audio stream.
+---@param sample_rate number # The sample rate, in Hz.
+---@param channel number # The channel count (1 for mono, 2 for stereo).
+---@param buffer number? # OPTIONAL: The size of the audio device's buffer, in frame. A smaller buffer will have less latency, but is more likely to run out. Default: raylib's default size.
+---@param capacity number? # OPTIONAL: The ring buffer capacity, in frame. Default: 1 second of audio.
+---@return audio_stream audio_stream # The audio stream.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/audio.rs#L204)
+function quiver.audio.new_stream(sample_rate,channel,buffer,capacity) end
+
+---Push sample to the ring buffer. Any sample past the ring buffer capacity will be dropped.
+---@param sample data | table # The sample, as either a data buffer of 32-bit float, or a table of number.
+---@return number count # The count of frame pushed.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/audio.rs#L319)
+function audio_stream:push(sample) end
+
+---Clear the ring buffer.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/audio.rs#L334)
+function audio_stream:clear() end
+
+---Set the audio bus of the audio stream.
+---@param bus audio_bus? # The audio bus. Use nil to remove the audio stream from its bus.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/audio.rs#L349)
+function audio_stream:set_bus(bus) end
+
+---Play the audio stream.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/audio.rs#L367)
+function audio_stream:play() end
+
+---Check if the audio stream is currently playing.
+---@return boolean state # State of the audio stream.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/audio.rs#L382)
+function audio_stream:get_playing() end
+
+---Stop the audio stream.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/audio.rs#L389)
+function audio_stream:stop() end
+
+---Pause the audio stream.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/audio.rs#L397)
+function audio_stream:pause() end
+
+---Resume the audio stream.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/audio.rs#L405)
+function audio_stream:resume() end
+
+---Set volume for the audio stream. (range: 0.0 - 1.0)
+---@param volume number # Current volume.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/audio.rs#L420)
+function audio_stream:set_volume(volume) end
+
+---Set pitch for the audio stream.
+---@param pitch number # Current pitch.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/audio.rs#L435)
+function audio_stream:set_pitch(pitch) end
+
+---Set pan for the audio stream. (range: 0.0 - 1.0; 0.5 is center)
+---@param pan number # Current pan.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/audio.rs#L457)
+function audio_stream:set_pan(pan) end
+
+---Set the audio stream as a spatial source, with distance attenuation, pan and doppler pitch shift from the listener.
+---@param option table? # The spatial option: { point, velocity?, kind? (ATTENUATION_KIND), distance_min?, distance_max?, rolloff? }. Use nil to remove the spatial source.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/audio.rs#L472)
+function audio_stream:set_spatial(option) end
+
+---Set the point of the spatial source.
+---@param point vector_3 # The point of the source.
+---@param velocity vector_3? # OPTIONAL: The velocity of the source. Default: derived from the last source point.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/audio.rs#L493)
+function audio_stream:set_spatial_point(point,velocity) end
+
+---An audio bus. Every sound, music and audio stream in a bus will have the volume, mute state, ducking and effect of the bus and of every parent bus applied to it. The master bus applies to the final mix, including any audio not in a bus. The master, music, sfx and voice bus will always exist.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/audio.rs#L679)
+---```lua
+----- The master, music, sfx and voice bus will always exist.
+---local master = quiver.audio.get_bus("master")
+---local music  = quiver.audio.get_bus("music")
+---local voice  = quiver.audio.get_bus("voice")
+---
+---assert(master.name == "master")
+---assert(not pcall(quiver.audio.get_bus, "unknown"))
+---
+----- Create a new bus, as a child of the sfx bus.
+---local ambient = quiver.audio.new_bus("ambient", quiver.audio.get_bus("sfx"))
+---
+---ambient.volume = 0.5
+---ambient.mute   = true
+---
+---assert(ambient.volume == 0.5)
+---assert(ambient.mute)
+---
+----- Making the same bus again (i.e. after a script reload) will give back the same bus, but not with another parent.
+---assert(quiver.audio.new_bus("ambient", quiver.audio.get_bus("sfx")).volume == 0.5)
+---assert(not pcall(quiver.audio.new_bus, "ambient"))
+---
+----- Lower the music whenever the voice bus is playing.
+---music:set_duck(voice, 0.75)
+---
+----- Muffle every sound, like being underwater.
+---local filter = master:add_effect(AUDIO_EFFECT.LOW_PASS, { cutoff = 800.0 })
+---
+---master:set_effect(filter, { cutoff = 400.0 })
+---master:add_effect(AUDIO_EFFECT.REVERB, { room = 0.8, mix = 0.25 })
+---master:remove_effect(filter)
+---master:clear_effect()
+---
+----- Put an audio stream in the ambient bus.
+---local stream = quiver.audio.new_stream(44100, 2)
+---
+---stream:set_bus(ambient)
+---stream:set_bus(nil)
+---
+---```
+---@class audio_bus
+---@field name string # The name of the bus.
+---@field volume number # The volume of the bus. Default: 1.0.
+---@field mute boolean # The mute state of the bus. Default: false.
+---@field level number # The peak level of the bus, over the last tenth of a second.
+audio_bus = {}
+
+---Duck the bus (lower the volume) whenever another bus is playing, such as lowering the music bus whenever the voice bus is playing.
+---@param source audio_bus? # The bus to duck for. Use nil to stop ducking.
+---@param amount number? # OPTIONAL: The amount to duck the volume by. (range: 0.0 - 1.0) Default: 0.5.
+---@param threshold number? # OPTIONAL: The source bus level over which to duck. Default: 0.01.
+---@param attack number? # OPTIONAL: The time to duck over, in second. Default: 0.1.
+---@param release number? # OPTIONAL: The time to un-duck over, in second. Default: 0.5.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/audio.rs#L873)
+function audio_bus:set_duck(source,amount,threshold,attack,release) end
+
+---Add an effect to the end of the bus's effect chain.
+---@param kind audio_effect # The kind of effect.
+---@param option table? # OPTIONAL: The option table, with any of: cutoff (in Hz, default: 1000.0) and resonance (default: 0.707) for a low-pass and high-pass filter, delay (in second, up to 10.0, default: 0.25), feedback (default: 0.5) and mix (default: 0.3) for echo, room (default: 0.5), damp (default: 0.5) and mix for reverb, and threshold (in dB, default: -12.0), ratio (default: 4.0), attack (in second, default: 0.01), release (in second, default: 0.1) and gain (make-up gain, in dB, default: 0.0) for a compressor.
+---@return number index # The effect index.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/audio.rs#L923)
+function audio_bus:add_effect(kind,option) end
+
+---Set the option of an effect. Changing the option of an effect will not reset it, so any option can be smoothly changed over time.
+---@param index number # The effect index.
+---@param option table # The option table. See audio_bus:add_effect.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/audio.rs#L956)
+function audio_bus:set_effect(index,option) end
+
+---Remove an effect. Any effect after it will have its index shifted down by one.
+---@param index number # The effect index.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/audio.rs#L977)
+function audio_bus:remove_effect(index) end
+
+---Remove every effect.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/audio.rs#L984)
+function audio_bus:clear_effect() end
+
+---Create a new audio bus. If a bus by the same name and parent already exists (i.e. from before a script reload), it will be given back instead.
+---@param name string # The name of the bus.
+---@param parent audio_bus? # OPTIONAL: The parent bus. Default: the master bus.
+---@return audio_bus audio_bus # The audio bus.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/audio.rs#L1140)
+function quiver.audio.new_bus(name,parent) end
+
+---Get an audio bus by name.
+---@param name string # The name of the bus.
+---@return audio_bus audio_bus # The audio bus.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/audio.rs#L1188)
+function quiver.audio.get_bus(name) end
+
+---Set the sample rate of the audio device, for any bus effect. Default: measured from the audio device, once any bus is in use.
+---@param sample_rate number # The sample rate, in Hz.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/audio.rs#L1210)
+function quiver.audio.set_sample_rate(sample_rate) end
+
+---Set the listener, for any spatial sound, music or audio stream. The distance attenuation and pan of every spatial source will be updated automatically, and so will the doppler pitch shift.
+---```lua
+----- Set the listener, looking down the negative Z axis.
+---quiver.audio.set_listener(vector_3:old(0.0, 0.0, 0.0), vector_3:old(0.0, 0.0, -1.0), vector_3:old(0.0, 1.0, 0.0))
+---
+----- Exaggerate the doppler pitch shift.
+---quiver.audio.set_doppler(2.0)
+---
+----- Set an audio stream as a spatial source, to the right of the listener.
+---local stream = quiver.audio.new_stream(44100, 1)
+---
+---assert(not pcall(stream.set_spatial_point, stream, vector_3:old(1.0, 0.0, 0.0)))
+---
+---stream:set_spatial({
+---    point        = vector_3:old(4.0, 0.0, 0.0),
+---    kind         = ATTENUATION_KIND.LINEAR,
+---    distance_max = 32.0,
+---})
+---
+----- Move the source, with a given velocity.
+---stream:set_spatial_point(vector_3:old(8.0, 0.0, 0.0), vector_3:old(4.0, 0.0, 0.0))
+---stream:set_pitch(1.5)
+---
+---assert(not pcall(stream.set_spatial, stream, { kind = 8 }))
+---
+---stream:set_spatial(nil)
+---
+---```
+---@param point vector_3 # The point of the listener.
+---@param focus vector_3 # The point the listener is looking at.
+---@param up vector_3 # The up direction of the listener.
+---@param velocity vector_3? # OPTIONAL: The velocity of the listener. Default: derived from the last listener point.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/audio.rs#L1516)
+function quiver.audio.set_listener(point,focus,up,velocity) end
+
+---Set the doppler pitch shift of every spatial source.
+---@param factor number # The doppler factor. Use 0.0 to disable doppler. Default: 1.0.
+---@param speed number? # OPTIONAL: The speed of sound, in unit per second. Default: 343.0.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/audio.rs#L1574)
+function quiver.audio.set_doppler(factor,speed) end
+
+---The locale API.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/locale.rs#L64)
+---@class quiver.locale
+quiver.locale = {}
+
+---A set of string table, one for each language. A key not found in a language (for example, pt-BR) will be looked up in its base language (pt), and then in the fallback language, before giving back the key itself.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/locale.rs#L90)
+---```lua
+----- Create a locale, with English as the fallback language.
+---local locale = quiver.locale.new("es-MX", "en")
+---
+----- Load a string table, such as one from quiver.data.deserialize.
+---locale:load("en", quiver.data.deserialize([[
+---{
+---    "menu": { "play": "Play", "quit": "Quit" },
+---    "greet": "Hello, locale!",
+---    "apple": { "one": "{count} apple", "other": "{count} apples" }
+---}
+---]]))
+---
+----- Load a gettext .po file.
+---locale:load_po("es", [[
+---msgid ""
+---msgstr ""
+---"Language: es\n"
+---
+---msgctxt "menu"
+---msgid "play"
+---msgstr "Jugar"
+---
+---msgid "greet"
+---msgstr "¡Hola, locale!"
+---
+---msgid "apple"
+---msgid_plural "apples"
+---msgstr[0] "{count} manzana"
+---msgstr[1] "{count} manzanas"
+---]])
+---
+----- es-MX will fall back to its base language, es.
+---assert(locale:get("menu.play") == "Jugar")
+---assert(locale:get("greet", { name = "Ana" }) == "¡Hola, Ana!")
+---assert(locale:get("apple", { count = 1 }) == "1 manzana")
+---assert(locale:get("apple", { count = 3 }) == "3 manzanas")
+---
+----- A missing key will fall back to English, and then to the key itself.
+---assert(locale:get("menu.quit") == "Quit")
+---assert(locale:get("unknown") == "unknown")
+---assert(not locale:has("menu.quit"))
+---
+---local missing = locale:get_missing()
+---
+---assert(#missing == 2)
+---assert(missing[1] == "menu.quit")
+---assert(missing[2] == "unknown")
+---
+----- Plural rule will follow the language.
+---locale.language = "en"
+---
+---assert(locale:get("apple", { count = 1 }) == "1 apple")
+---assert(locale:get("apple", { count = 0 }) == "0 apples")
+---
+----- Romanian will use "few" for 0, and for 2 to 19 (and 101 to 119, etc.).
+---locale:load("ro", { apple = { one = "{count} măr", few = "{count} mere", other = "{count} de mere" } })
+---locale.language = "ro"
+---
+---assert(locale:get("apple", { count = 1 }) == "1 măr")
+---assert(locale:get("apple", { count = 101 }) == "101 mere")
+---assert(locale:get("apple", { count = 20 }) == "20 de mere")
+---
+----- A non-ASCII part will never be taken as a script.
+---assert(quiver.locale.new("en-éé").language == "en-ÉÉ")
+---
+---assert(type(quiver.locale.get_system()) == "string")
+---
+----- A plural index past the last plural category is an error.
+---assert(not pcall(locale.load_po, locale, "es", [[
+---msgid "apple"
+---msgid_plural "apples"
+---msgstr[99999999999] "{count} manzanas"
+---]]))
+---
+---```
+---@class locale
+---@field language string # The current language, as a language tag (en-US, pt-BR, etc.).
+---@field fallback string? # The fallback language, for any key missing from the current language.
+locale = {}
+
+---Load a string table for a language, such as the result of quiver.data.deserialize. A nested table will be flattened, with each key joined by a dot. A table with only plural category key (zero, one, two, few, many, other) is a plural text, and must have at least the other key.
+---@param language string # The language of the string table.
+---@param table table # The string table.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/locale.rs#L128)
+function locale:load(language,table) end
+
+---Load a gettext .po file for a language. Every entry is keyed by its msgid, or by msgctxt.msgid if it has a context. Any fuzzy or untranslated entry is skipped. Each msgstr[n] of a plural entry is taken to be the n-th plural category of the language, in gettext order.
+---@param language string # The language of the file.
+---@param text string # The file text.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/locale.rs#L155)
+function locale:load_po(language,text) end
+
+---Get the text of a key in the current language. Any locale:get placeholder will be replaced with the value of the same name. Use {{ for a literal {. For a plural text, the count value will pick the plural form, from the CLDR plural rule of the language.
+---@param key string # The key.
+---@param value table? # OPTIONAL: The placeholder value table.
+---@return string text # The text, or the key itself if it is missing.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/locale.rs#L181)
+function locale:get(key,value) end
+
+---Check if a key is in the current language (or its base language), without falling back.
+---@param key string # The key.
+---@return boolean value # True if the key is in the current language.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/locale.rs#L211)
+function locale:has(key) end
+
+---Get every missing key of a language: any key in the fallback language but not in this one, and any key that was asked for with locale:get and not found.
+---@param language string? # OPTIONAL: The language. Default: the current language.
+---@return table list # The missing key list, sorted.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/locale.rs#L228)
+function locale:get_missing(language) end
+
+---Get every language with a string table.
+---@return table list # The language list, sorted.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/locale.rs#L253)
+function locale:get_language() end
+
+---Create a new locale.
+---@param language string? # OPTIONAL: The current language. Default: the OS language (see quiver.locale.get_system).
+---@param fallback string? # OPTIONAL: The fallback language. Default: none.
+---@return locale locale # The locale.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/locale.rs#L280)
+function quiver.locale.new(language,fallback) end
+
+---Get the OS language, as a language tag (en-US, pt-BR, etc.). Will give back en if it can not be found.
+---@return string language # The OS language.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/locale.rs#L781)
+function quiver.locale.get_system() end
+
+---The navigation API.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/navigation.rs#L66)
+---@class quiver.navigation
+quiver.navigation = {}
+
+---A 2D grid for path-finding. Every cell has a cost to walk into it, with a cost of 0.0 (or less) being a wall. Cell coordinate are in the [0, shape - 1] range.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/navigation.rs#L95)
+---```lua
+----- Create a 16x16 grid, with a wall in the middle.
+---local grid = quiver.navigation.new_grid(16, 16)
+---
+---for y = 0, 12 do
+---    grid:set_cost(8, y, 0.0)
+---end
+---
+----- Find a path around the wall.
+---local path = grid:find_path({ x = 0, y = 0 }, { x = 15, y = 0 })
+---
+---assert(path)
+---assert(path[1].x == 0 and path[1].y == 0)
+---assert(path[#path].x == 15 and path[#path].y == 0)
+---
+----- Jump point search will find a path of the same length, on an uniform-cost grid.
+---local jump = grid:find_path({ x = 0, y = 0 }, { x = 15, y = 0 }, true)
+---
+---assert(#jump == #path)
+---
+----- Make a cell more expensive to walk into. Without diagonal movement, the path will go around it.
+---grid.diagonal = DIAGONAL_RULE.NEVER
+---grid:set_cost(8, 13, 100.0)
+---
+---local path = grid:find_path({ x = 0, y = 15 }, { x = 15, y = 15 })
+---
+---for _, cell in ipairs(path) do
+---    assert(not (cell.x == 8 and cell.y == 13))
+---end
+---
+----- Close the wall off. There is no path.
+---grid:set_cost(8, 13, 0.0)
+---grid:set_cost(8, 14, 0.0)
+---grid:set_cost(8, 15, 0.0)
+---
+---assert(grid:find_path({ x = 0, y = 0 }, { x = 15, y = 0 }) == nil)
+---
+---```
+---@class navigation_grid
+---@field shape_x number # The shape of the grid. (X).
+---@field shape_y number # The shape of the grid. (Y).
+---@field diagonal diagonal_rule # The rule for diagonal movement. Default: DIAGONAL_RULE.NO_OBSTACLE.
+navigation_grid = {}
+
+---Create a new navigation grid.
+---@param shape_x number # The shape of the grid. (X).
+---@param shape_y number # The shape of the grid. (Y).
+---@param cost number? # OPTIONAL: The cost of every cell. Default: 1.0.
+---@return navigation_grid navigation_grid # The navigation grid.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/navigation.rs#L113)
+function quiver.navigation.new_grid(shape_x,shape_y,cost) end
+
+---Get the cost of a cell.
+---@param x number # The cell coordinate. (X).
+---@param y number # The cell coordinate. (Y).
+---@return number cost # The cost.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/navigation.rs#L173)
+function navigation_grid:get_cost(x,y) end
+
+---Set the cost of a cell. Use 0.0 for a wall.
+---@param x number # The cell coordinate. (X).
+---@param y number # The cell coordinate. (Y).
+---@param cost number # The cost.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/navigation.rs#L189)
+function navigation_grid:set_cost(x,y,cost) end
+
+---Set the cost of every cell from a data buffer (i.e. from noise:get_grid), in row-major order.
+---@param data data # The data buffer, with a number for each cell.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/navigation.rs#L208)
+function navigation_grid:set_cost_data(data) end
+
+---Find the cheapest path between two cell, using A*. Jump point search is much faster on a large, open grid, but will only work with DIAGONAL_RULE.NO_OBSTACLE, and will ignore the cost of every cell (other than a wall).
+---@param from vector_2 # The cell to start from.
+---@param to vector_2 # The cell to go to.
+---@param jump boolean? # OPTIONAL: Use jump point search. Default: false.
+---@return table? path # The path, as a table of vector_2 cell, including the start and the goal. Nil if there is no path.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/navigation.rs#L237)
+function navigation_grid:find_path(from,to,jump) end
+
+---A navigation mesh, baked from the walkable (front-facing, not too steep) triangle of a triangle mesh. Vertex closer than 0.001 unit are welded together, and two triangle are connected only if they share an edge.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/navigation.rs#L287)
+---```lua
+----- Make an L-shaped floor out of three quad, with two triangle each.
+---local point = {
+---    { x = 0.0, y = 0.0, z = 0.0 }, { x = 4.0, y = 0.0, z = 0.0 }, { x = 8.0, y = 0.0, z = 0.0 },
+---    { x = 0.0, y = 0.0, z = 4.0 }, { x = 4.0, y = 0.0, z = 4.0 }, { x = 8.0, y = 0.0, z = 4.0 },
+---    { x = 0.0, y = 0.0, z = 8.0 }, { x = 4.0, y = 0.0, z = 8.0 },
+---}
+---
+----- Every triangle is counter-clockwise, when seen from above.
+---local index = {
+---    0, 3, 4, 0, 4, 1,
+---    1, 4, 5, 1, 5, 2,
+---    3, 6, 7, 3, 7, 4,
+---}
+---
+---local mesh = quiver.navigation.new_mesh(point, index)
+---
+---assert(mesh.triangle_count == 6)
+---
+----- An index count that is not a multiple of 3 is an error.
+---assert(not pcall(quiver.navigation.new_mesh, point, { 0, 3 }))
+---
+----- Find a path around the inner corner of the L.
+---local path = mesh:find_path({ x = 7.0, y = 0.0, z = 3.0 }, { x = 3.0, y = 0.0, z = 7.0 })
+---
+---assert(path)
+---assert(#path == 3)
+---assert(path[2].x == 4.0 and path[2].z == 4.0)
+---
+----- Any point off the navigation mesh will be moved to the closest point on it.
+---local point = mesh:get_closest({ x = 6.0, y = 2.0, z = 6.0 })
+---
+---assert(point.y == 0.0)
+---
+----- Move an agent to the other side of the L.
+---local crowd = quiver.navigation.new_crowd(mesh)
+---local agent = crowd:add_agent({ x = 7.0, y = 0.0, z = 1.0 }, 0.5, 4.0)
+---
+---assert(crowd:set_target(agent, { x = 1.0, y = 0.0, z = 7.0 }))
+---
+---for x = 1, 600 do
+---    crowd:update(1.0 / 60.0)
+---end
+---
+---local point, _, active = crowd:get_agent(agent)
+---
+---assert(not active)
+---assert(math.abs(point.x - 1.0) < 0.1 and math.abs(point.z - 7.0) < 0.1)
+---
+---```
+---@class navigation_mesh
+---@field point_count number # The vertex count.
+---@field triangle_count number # The triangle count.
+navigation_mesh = {}
+
+---Bake a navigation mesh from a triangle mesh (i.e. from rapier:get_collider_shape_tri_mesh, or model:mesh_vertex and model:mesh_index).
+---@param point_table table # The point array table.
+---@param index_table table # The index array table, with three index for each triangle, starting at 0.
+---@param slope number? # OPTIONAL: The maximum slope of a walkable triangle, in degree. Default: 45.0.
+---@return navigation_mesh navigation_mesh # The navigation mesh.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/navigation.rs#L309)
+function quiver.navigation.new_mesh(point_table,index_table,slope) end
+
+---Bake a navigation mesh from every mesh in a model, in model space.
+---@param model model # The model.
+---@param slope number? # OPTIONAL: The maximum slope of a walkable triangle, in degree. Default: 45.0.
+---@return navigation_mesh navigation_mesh # The navigation mesh.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/navigation.rs#L349)
+function quiver.navigation.new_mesh_model(model,slope) end
+
+---Find the shortest path between two point. The path is string-pulled, so it will only have a corner where it needs to turn around an edge of the navigation mesh. Both point will be moved to the closest point on the navigation mesh.
+---@param from vector_3 # The point to start from.
+---@param to vector_3 # The point to go to.
+---@return table? path # The path, as a table of vector_3 point, including the start and the goal. Nil if there is no path.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/navigation.rs#L406)
+function navigation_mesh:find_path(from,to) end
+
+---Get the closest point on the navigation mesh to a point.
+---@param point vector_3 # The point.
+---@return vector_3? point # The closest point. Nil if the navigation mesh is empty.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/navigation.rs#L432)
+function navigation_mesh:get_closest(point) end
+
+---A crowd of agent, moving on a navigation mesh. Every agent will follow its own path with a simple steering behavior (seek, arrive, and separation from any other agent), and will be kept on the navigation mesh.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/navigation.rs#L456)
+---```lua
+----- Make an L-shaped floor out of three quad, with two triangle each.
+---local point = {
+---    { x = 0.0, y = 0.0, z = 0.0 }, { x = 4.0, y = 0.0, z = 0.0 }, { x = 8.0, y = 0.0, z = 0.0 },
+---    { x = 0.0, y = 0.0, z = 4.0 }, { x = 4.0, y = 0.0, z = 4.0 }, { x = 8.0, y = 0.0, z = 4.0 },
+---    { x = 0.0, y = 0.0, z = 8.0 }, { x = 4.0, y = 0.0, z = 8.0 },
+---}
+---
+----- Every triangle is counter-clockwise, when seen from above.
+---local index = {
+---    0, 3, 4, 0, 4, 1,
+---    1, 4, 5, 1, 5, 2,
+---    3, 6, 7, 3, 7, 4,
+---}
+---
+---local mesh = quiver.navigation.new_mesh(point, index)
+---
+---assert(mesh.triangle_count == 6)
+---
+----- An index count that is not a multiple of 3 is an error.
+---assert(not pcall(quiver.navigation.new_mesh, point, { 0, 3 }))
+---
+----- Find a path around the inner corner of the L.
+---local path = mesh:find_path({ x = 7.0, y = 0.0, z = 3.0 }, { x = 3.0, y = 0.0, z = 7.0 })
+---
+---assert(path)
+---assert(#path == 3)
+---assert(path[2].x == 4.0 and path[2].z == 4.0)
+---
+----- Any point off the navigation mesh will be moved to the closest point on it.
+---local point = mesh:get_closest({ x = 6.0, y = 2.0, z = 6.0 })
+---
+---assert(point.y == 0.0)
+---
+----- Move an agent to the other side of the L.
+---local crowd = quiver.navigation.new_crowd(mesh)
+---local agent = crowd:add_agent({ x = 7.0, y = 0.0, z = 1.0 }, 0.5, 4.0)
+---
+---assert(crowd:set_target(agent, { x = 1.0, y = 0.0, z = 7.0 }))
+---
+---for x = 1, 600 do
+---    crowd:update(1.0 / 60.0)
+---end
+---
+---local point, _, active = crowd:get_agent(agent)
+---
+---assert(not active)
+---assert(math.abs(point.x - 1.0) < 0.1 and math.abs(point.z - 7.0) < 0.1)
+---
+---```
+---@class navigation_crowd
+---@field count number # The agent count.
+navigation_crowd = {}
+
+---Create a new crowd.
+---@param navigation_mesh navigation_mesh # The navigation mesh to move on.
+---@return navigation_crowd navigation_crowd # The crowd.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/navigation.rs#L487)
+function quiver.navigation.new_crowd(navigation_mesh) end
+
+---Add an agent to the crowd.
+---@param point vector_3 # The point of the agent. Will be moved to the closest point on the navigation mesh.
+---@param radius number? # OPTIONAL: The radius of the agent. Default: 0.5.
+---@param speed number? # OPTIONAL: The maximum speed of the agent, in unit per second. Default: 4.0.
+---@return number index # The agent index.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/navigation.rs#L562)
+function navigation_crowd:add_agent(point,radius,speed) end
+
+---Remove an agent from the crowd.
+---@param index number # The agent index.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/navigation.rs#L597)
+function navigation_crowd:remove_agent(index) end
+
+---Set the target of an agent. The agent will find a path to it, and follow it on every navigation_crowd:update call.
+---@param index number # The agent index.
+---@param target vector_3? # The target. Use nil to stop the agent.
+---@return boolean path # True if a path to the target was found.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/navigation.rs#L617)
+function navigation_crowd:set_target(index,target) end
+
+---Get the state of an agent.
+---@param index number # The agent index.
+---@return vector_3 point # The point of the agent.
+---@return vector_3 velocity # The velocity of the agent.
+---@return boolean active # True if the agent is still following a path.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/navigation.rs#L648)
+function navigation_crowd:get_agent(index) end
+
+---Move every agent in the crowd.
+---@param step number # The time step, in second (i.e. from quiver.general.get_frame_time).
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/navigation.rs#L668)
+function navigation_crowd:update(step) end
+
+---The noise API.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/noise.rs#L64)
+---@class quiver.noise
+quiver.noise = {}
+
+---A procedural noise generator, for 1D, 2D, 3D or 4D point. Every value will be in the [-1.0, 1.0] range. Two generators with the same seed and setting will always give back the same value.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/noise.rs#L95)
+---```lua
+----- Create two generators with the same seed. They will give back the same value.
+---local noise_a = quiver.noise.new(NOISE_KIND.SIMPLEX, 1234)
+---local noise_b = quiver.noise.new(NOISE_KIND.SIMPLEX, 1234)
+---
+---assert(noise_a:get(0.5, 1.5) == noise_b:get(0.5, 1.5))
+---
+----- Sample the noise in 1D, 2D, 3D or 4D.
+---for _, value in ipairs({
+---    noise_a:get(0.25),
+---    noise_a:get(0.25, 0.5),
+---    noise_a:get(0.25, 0.5, 0.75),
+---    noise_a:get(0.25, 0.5, 0.75, 1.0),
+---}) do
+---    assert(value >= -1.0 and value <= 1.0)
+---end
+---
+----- Set up a ridged fractal, with domain warping.
+---noise_a.frequency  = 0.05
+---noise_a.fractal    = FRACTAL_KIND.RIDGED
+---noise_a.octave     = 5
+---noise_a.lacunarity = 2.0
+---noise_a.gain       = 0.5
+---noise_a.warp       = 0.5
+---
+----- Fill a 64x32 grid with the noise, for a height-map.
+---local grid = noise_a:get_grid({ 64, 32 }, { 100.0, 100.0 })
+---
+---assert(grid:get_length() == 64 * 32)
+---
+----- Every cell of the grid matches a single sample.
+---assert(math.abs(grid:get_buffer()[2] - noise_a:get(101.0, 100.0)) < 0.0001)
+---
+----- An empty or very large shape is an error.
+---assert(not pcall(noise_a.get_grid, noise_a, { 64, 0 }))
+---assert(not pcall(noise_a.get_grid, noise_a, { 65536, 65536 }))
+---
+----- Worley (cellular) noise, in 3D.
+---local noise_c = quiver.noise.new(NOISE_KIND.WORLEY)
+---
+---assert(noise_c:get(1.0, 2.0, 3.0) >= -1.0)
+---
+----- A non-finite coordinate is an error, but a very large one is still sampled.
+---assert(not pcall(noise_a.get, noise_a, math.huge))
+---assert(not pcall(noise_a.get_grid, noise_a, { 4 }, { 0.0 / 0.0 }))
+---assert(noise_c:get(1e300, -1e300, 1e300) >= -1.0)
+---
+---```
+---@class noise
+---@field kind noise_kind # The kind of noise.
+---@field seed number # The seed.
+---@field frequency number # The frequency. A point will be multiplied by this value before sampling. Default: 1.0.
+---@field fractal fractal_kind # The kind of fractal. Default: FRACTAL_KIND.NONE.
+---@field octave number # The number of octave (layer) for a fractal, in the [1, 16] range. Default: 4.
+---@field lacunarity number # The frequency multiplier for each octave of a fractal. Default: 2.0.
+---@field gain number # The amplitude multiplier for each octave of a fractal. Default: 0.5.
+---@field warp number # The domain warp amplitude, in noise space (after frequency). Use 0.0 to disable. Default: 0.0.
+noise = {}
+
+---Create a new noise generator.
+---@param kind noise_kind? # OPTIONAL: The kind of noise. Default: NOISE_KIND.PERLIN.
+---@param seed number? # OPTIONAL: The seed. Default: 0.
+---@return noise noise # The noise generator.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/noise.rs#L134)
+function quiver.noise.new(kind,seed) end
+
+---Get the value of the noise at a point. The number of coordinate given will set the dimension of the noise.
+---@param x number # The X coordinate.
+---@param y number? # OPTIONAL: The Y coordinate.
+---@param z number? # OPTIONAL: The Z coordinate.
+---@param w number? # OPTIONAL: The W coordinate.
+---@return number value # The value, in the [-1.0, 1.0] range.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/noise.rs#L305)
+function noise:get(x,y,z,w) end
+
+---Fill a grid with the value of the noise. The number of value in the shape will set the dimension of the noise. The grid is laid out in row-major order, with X being the fastest axis.
+---@param shape table # The shape of the grid, with 1 to 4 value (i.e. { 256, 256 }).
+---@param point table? # OPTIONAL: The point of the first cell of the grid. Default: the origin.
+---@param step number? # OPTIONAL: The distance between each cell of the grid, before frequency. Default: 1.0.
+---@return data data # The data buffer, with a value in the [-1.0, 1.0] range for each cell.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/noise.rs#L345)
+function noise:get_grid(shape,point,step) end
+
+---The random API.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/random.rs#L63)
+---@class quiver.random
+quiver.random = {}
+
+---A deterministic random number generator (PCG32). Two generators with the same seed and stream will always give back the same sequence, independent of any other generator, or of Lua's math.random.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/random.rs#L84)
+---```lua
+----- Create two generators with the same seed. They will give back the same sequence.
+---local random_a = quiver.random.new(1234)
+---local random_b = quiver.random.new(1234)
+---
+---for x = 1, 8 do
+---    assert(random_a:get_integer(1, 100) == random_b:get_integer(1, 100))
+---end
+---
+----- Save the state, and restore it later to repeat the sequence.
+---local state = random_a:get_state()
+---local value = random_a:get_number()
+---
+---random_a:set_state(state)
+---
+---assert(random_a:get_number() == value)
+---
+----- Give every system its own generator, forked from a single one.
+---local random_c = random_a:fork(1)
+---
+---assert(random_c:get_number(-1.0, 1.0) >= -1.0)
+---
+----- Pick a value from a list, with a weight for each one.
+---local value, index = random_a:get_choice({ "common", "rare" }, { 0.9, 0.1 })
+---
+---assert(value == "common" or value == "rare")
+---
+----- Shuffle a list, in-place.
+---local list = random_a:shuffle({ 1, 2, 3, 4, 5 })
+---
+---assert(#list == 5)
+---
+----- Get a point inside of a circle, and on the surface of a sphere.
+---local x, y = random_a:get_circle(2.0)
+---
+---assert(x * x + y * y <= 4.0)
+---
+---local x, y, z = random_a:get_sphere()
+---
+---assert(math.abs(x * x + y * y + z * z - 1.0) < 0.0001)
+---
+---```
+---@class random
+random = {}
+
+---Create a new random number generator.
+---@param seed number? # OPTIONAL: The seed. If nil, will use the current time.
+---@param stream number? # OPTIONAL: The stream. Generators with the same seed but a different stream will give back a different sequence.
+---@return random random # The random number generator.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/random.rs#L107)
+function quiver.random.new(seed,stream) end
+
+---Set the seed of the random number generator, resetting its sequence.
+---@param seed number # The seed.
+---@param stream number? # OPTIONAL: The stream. Default: 0.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/random.rs#L193)
+function random:set_seed(seed,stream) end
+
+---Get the current state of the random number generator, to restore it later with random:set_state.
+---@return string state # The state.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/random.rs#L211)
+function random:get_state() end
+
+---Restore the state of the random number generator.
+---@param state string # The state, from random:get_state.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/random.rs#L225)
+function random:set_state(state) end
+
+---Create a new, independent random number generator, seeded from this one. Useful for giving every system its own generator from a single seed.
+---@param stream number? # OPTIONAL: The stream of the new generator. Default: 0.
+---@return random random # The random number generator.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/random.rs#L251)
+function random:fork(stream) end
+
+---Get a random integer, in the [min, max] range.
+---@param min number # The minimum value.
+---@param max number # The maximum value.
+---@return number value # The value.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/random.rs#L269)
+function random:get_integer(min,max) end
+
+---Get a random number, in the [min, max) range.
+---@param min number? # OPTIONAL: The minimum value. Default: 0.0.
+---@param max number? # OPTIONAL: The maximum value. Default: 1.0.
+---@return number value # The value.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/random.rs#L299)
+function random:get_number(min,max) end
+
+---Get a random boolean.
+---@param chance number? # OPTIONAL: The chance of the value being true, in the [0.0, 1.0] range. Default: 0.5.
+---@return boolean value # The value.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/random.rs#L322)
+function random:get_boolean(chance) end
+
+---Get a random number, with a normal (gaussian) distribution.
+---@param mean number? # OPTIONAL: The mean. Default: 0.0.
+---@param deviation number? # OPTIONAL: The standard deviation. Default: 1.0.
+---@return number value # The value.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/random.rs#L340)
+function random:get_gaussian(mean,deviation) end
+
+---Get a random value from a list.
+---@param list table # The list of value.
+---@param weight table? # OPTIONAL: The list of weight, one for each value. If nil, every value will have the same weight.
+---@return any value # The value.
+---@return number index # The index of the value in the list.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/random.rs#L362)
+function random:get_choice(list,weight) end
+
+---Shuffle a list, in-place.
+---@param list table # The list to shuffle.
+---@return table list # The same list, shuffled.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/random.rs#L421)
+function random:shuffle(list) end
+
+---Get a random point inside of a circle, with an uniform distribution.
+---@param radius number? # OPTIONAL: The radius of the circle. Default: 1.0.
+---@return number point_x # The point (X).
+---@return number point_y # The point (Y).
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/random.rs#L450)
+function random:get_circle(radius) end
+
+---Get a random point on the surface of a sphere, with an uniform distribution.
+---@param radius number? # OPTIONAL: The radius of the sphere. Default: 1.0.
+---@return number point_x # The point (X).
+---@return number point_y # The point (Y).
+---@return number point_z # The point (Z).
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/random.rs#L472)
+function random:get_sphere(radius) end
+
+---The save API.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/save.rs#L67)
+---@class quiver.save
+quiver.save = {}
+
+---An unique handle to a save slot. A save is always written atomically, with a version and a checksum. Previous saves are kept as back-up, and will be used if the latest save is corrupt.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/save.rs#L86)
+---```lua
+----- Create a save slot handle, at version 2. It will be in the save path, and will keep 2 back-up saves.
+---local save = quiver.save.new("slot_1", 2, 2)
+---
+----- Set the migration from version 1 to version 2, in case we load an older save.
+---save:set_migration(1, function(data)
+---    data.health = data.health or 100
+---    return data
+---end)
+---
+----- Write the save.
+---save:save({ level = 4, health = 50 })
+---
+----- Read the save back.
+---local data = save:load()
+---
+---assert(data.level == 4)
+---assert(data.health == 50)
+---
+----- Remove the save slot, and every back-up.
+---save:remove()
+---
+---assert(not save:get_exist())
+---
+----- Write a save at version 1, with no health field.
+---local save_old = quiver.save.new("slot_2", 1)
+---
+---save_old:save({ level = 1 })
+---
+----- Load it back with a version 2 slot: the migration will be run.
+---local save_new = quiver.save.new("slot_2", 2)
+---
+---save_new:set_migration(1, function(data)
+---    data.health = data.health or 100
+---    return data
+---end)
+---
+---data = save_new:load()
+---
+---assert(data.level == 1)
+---assert(data.health == 100)
+---
+----- Without the migration from version 1, the load will fail.
+---local save_bad = quiver.save.new("slot_2", 2)
+---
+---local success, result = pcall(save_bad.load, save_bad)
+---
+---assert(not success)
+---assert(tostring(result):find("No migration from version 1"))
+---
+---save_new:remove()
+---
+----- Write two saves: the first one will be rotated into the back-up list.
+---save:save({ level = 5 })
+---save:save({ level = 6 })
+---
+----- Corrupt the latest save.
+---quiver.file.set_file(quiver.file.get_save_path() .. "/slot_1", "corrupt")
+---
+----- Load will fall back to the most recent back-up.
+---data = save:load()
+---
+---assert(data.level == 5)
+---
+---save:remove()
+---
+---assert(not save:get_exist())
+---
+---```
+---@class save
+save = {}
+
+---Set the migration call-back for a given version. On load, every migration from the save's version up to the current version will be run, in order.
+---@param version number # The version to migrate from. The call-back must return the data in the next version's form.
+---@param call function # The call-back. Must accept the save data, and return the migrated save data.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/save.rs#L111)
+function save:set_migration(version,call) end
+
+---Write a value to the save slot. The current save will be rotated into the back-up list.
+---@param value any # The value to save. Must be serializable.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/save.rs#L129)
+function save:save(value) end
+
+---Read the value from the save slot. If the latest save is corrupt, every back-up will be tried, from the most recent one. If the save is from an older version, every migration will be run.
+---@return any value # The value. Nil if no save exists.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/save.rs#L145)
+function save:load() end
+
+---Check if the save slot, or any back-up, does exist.
+---@return boolean exist # True if the save does exist, false otherwise.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/save.rs#L157)
+function save:get_exist() end
+
+---Remove the save slot, and every back-up.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/save.rs#L171)
+function save:remove() end
+
+---Create a new save slot handle. A relative path will be relative to the save path.
+---@param path string # Path to the save slot.
+---@param version number # The current version of the save data.
+---@param backup number? # OPTIONAL: The amount of back-up saves to keep. Default: 2.
+---@return save save # Save slot handle.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/save.rs#L205)
+function quiver.save.new(path,version,backup) end
+
+---The wave API.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/wave.rs#L68)
+---@class quiver.wave
+quiver.wave = {}
+
+---A sample buffer, decoded from a sound file. Sample are 32-bit float in the [-1.0, 1.0] range, interleaved by channel.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/wave.rs#L101)
+---```lua
+----- Synthesize a 1000 Hz sine wave at half-scale, one second long.
+---local sample = {}
+---
+---for i = 1, 48000 do
+---    sample[i] = math.sin((i - 1) / 48000 * 1000.0 * math.pi * 2.0) * 0.5
+---end
+---
+---local wave = quiver.wave.new_from_data(sample, 48000, 1)
+---
+---assert(wave.frame_count == 48000)
+---assert(wave.length == 1.0)
+---
+----- The peak of a sine wave is its amplitude, and the RMS is the amplitude over the square root of two.
+---assert(math.abs(wave:get_peak() - 0.5) < 0.001)
+---assert(math.abs(wave:get_rms() - 0.5 / math.sqrt(2.0)) < 0.001)
+---
+----- With a 4096 FFT size, each bin is 48000 / 4096 Hz wide: 1000 Hz will be close to bin 85.
+---local spectrum = wave:get_spectrum(0, 4096)
+---
+---assert(#spectrum == 2048)
+---assert(spectrum[86] > spectrum[40])
+---assert(not pcall(wave.get_spectrum, wave, 0, 1000))
+---
+----- Convert to stereo, at half the sample rate, then keep the first half.
+---wave:set_channel(2)
+---wave:resample(24000)
+---wave:crop(0, 12000)
+---
+---assert(wave.channel == 2)
+---assert(wave.sample_rate == 24000)
+---assert(wave.frame_count == 12000)
+---assert(wave:get_data():get_length() == 24000)
+---assert(not pcall(wave.crop, wave, 0, 48000))
+---
+----- Export to the temporary path, then load it back.
+---local path = quiver.file.get_temp_path() .. "/wave.wav"
+---
+---wave:export(path)
+---
+---local load = quiver.wave.new(path)
+---
+---assert(load.frame_count == 12000)
+---assert(load.channel == 2)
+---
+---quiver.file.remove_file(path)
+---
+---```
+---@class wave
+---@field sample_rate number # The sample rate.
+---@field channel number # The channel count.
+---@field frame_count number # The frame count.
+---@field length number # The time length, in second.
+wave = {}
+
+---Create a new wave resource.
+---@param path string # Path to sound file.
+---@return wave wave # Wave resource.
+---
+--- ---
+---*This function is asynchronous and can run within a co-routine.*
+---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/wave.rs#L122)
+function quiver.wave.new(path) end
+
+---Create a new wave resource, from memory.
+---@param data data # The data buffer.
+---@param kind string # The kind of sound file (.wav, etc.).
+---@return wave wave # Wave resource.
+---
+--- ---
+---*This function is asynchronous and can run within a co-routine.*
+---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/wave.rs#L155)
+function quiver.wave.new_from_memory(data,kind) end
+
+---Create a new wave resource, from a sample buffer.
+---@param sample data | table # The sample, as either a data buffer of 32-bit float, or a table of number.
+---@param sample_rate number # The sample rate, in Hz.
+---@param channel number # The channel count.
+---@return wave wave # Wave resource.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/wave.rs#L194)
+function quiver.wave.new_from_data(sample,sample_rate,channel) end
+
+---Get the sample buffer of the wave.
+---@return data data # The sample, as a data buffer of 32-bit float.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/wave.rs#L370)
+function wave:get_data() end
+
+---Get a copy of the wave.
+---@return wave wave # Wave resource.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/wave.rs#L384)
+function wave:copy() end
+
+---Crop the wave to a frame range.
+---@param frame_a number # The first frame, inclusive.
+---@param frame_b number # The last frame, exclusive.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/wave.rs#L403)
+function wave:crop(frame_a,frame_b) end
+
+---Resample the wave to another sample rate.
+---@param sample_rate number # The sample rate, in Hz.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/wave.rs#L417)
+function wave:resample(sample_rate) end
+
+---Convert the wave to another channel count. Converting to mono will down-mix every channel.
+---@param channel number # The channel count.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/wave.rs#L431)
+function wave:set_channel(channel) end
+
+---Export the wave to a file. The format is picked from the extension (.wav or .raw). The wave is written with 32-bit float samples, so .qoa (16-bit only) is not supported.
+---@param path string # Path to the file.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/wave.rs#L445)
+function wave:export(path) end
+
+---Get the RMS (root mean square) level of the wave, across every channel.
+---@param frame_a number? # OPTIONAL: The first frame, inclusive. Default: the first frame.
+---@param frame_b number? # OPTIONAL: The last frame, exclusive. Default: the last frame.
+---@return number rms # The RMS level.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/wave.rs#L479)
+function wave:get_rms(frame_a,frame_b) end
+
+---Get the peak level of the wave, across every channel.
+---@param frame_a number? # OPTIONAL: The first frame, inclusive. Default: the first frame.
+---@param frame_b number? # OPTIONAL: The last frame, exclusive. Default: the last frame.
+---@return number peak # The peak level.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/wave.rs#L500)
+function wave:get_peak(frame_a,frame_b) end
+
+---Get the magnitude spectrum of the wave, from a frame onward, with every channel down-mixed. Any frame past the end of the wave will be silence.
+---@param frame number # The first frame.
+---@param size number? # OPTIONAL: The FFT size, as a power of two (up to 16384). Default: 1024.
+---@return table spectrum # The magnitude of every frequency bin (size / 2), each bin being sample_rate / size Hz wide.
+---
+--- ---
+---[Source Code Definition](https://github.com/sockentrocken/quiver/tree/main/source/rust/base/wave.rs#L521)
+function wave:get_spectrum(frame,size) end
+
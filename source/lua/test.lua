@@ -0,0 +1,228 @@
+--[[
+-- Copyright (c) 2025 sockentrocken
+--
+-- Redistribution and use in source and binary forms, with or without
+-- modification, are permitted provided that the following conditions are met:
+--
+-- 1. Redistributions of source code must retain the above copyright notice,
+-- this list of conditions and the following disclaimer.
+--
+-- 2. Redistributions in binary form must reproduce the above copyright notice,
+-- this list of conditions and the following disclaimer in the documentation
+-- and/or other materials provided with the distribution.
+--
+-- Subject to the terms and conditions of this license, each copyright holder
+-- and contributor hereby grants to those receiving rights under this license
+-- a perpetual, worldwide, non-exclusive, no-charge, royalty-free, irrevocable
+-- (except for failure to satisfy the conditions of this license) patent license
+-- to make, have made, use, offer to sell, sell, import, and otherwise transfer
+-- this software, where such license applies only to those patent claims, already
+-- acquired or hereafter acquired, licensable by such copyright holder or
+-- contributor that are necessarily infringed by:
+--
+-- (a) their Contribution(s) (the licensed copyrights of copyright holders and
+-- non-copyrightable additions of contributors, in source or binary form) alone;
+-- or
+--
+-- (b) combination of their Contribution(s) with the work of authorship to which
+-- such Contribution(s) was added by such copyright holder or contributor, if,
+-- at the time the Contribution is added, such addition causes such combination
+-- to be necessarily infringed. The patent license shall not apply to any other
+-- combinations which include the Contribution.
+--
+-- Except as expressly stated above, no rights or licenses from any copyright
+-- holder or contributor is granted under this license, whether expressly, by
+-- implication, estoppel or otherwise.
+--
+-- DISCLAIMER
+--
+-- THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+-- AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+-- IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+-- DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDERS OR CONTRIBUTORS BE LIABLE
+-- FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+-- DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+-- SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+-- CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+-- OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+-- OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+--]]
+
+-- The test API. Only available when running Quiver with "--test".
+
+local test = {
+    case = {},
+    path = {},
+}
+
+local function get_text(value)
+    if type(value) == "string" then
+        return string.format("%q", value)
+    elseif type(value) == "table" then
+        local buffer = {}
+
+        for k, v in pairs(value) do
+            table.insert(buffer, tostring(k) .. " = " .. get_text(v))
+        end
+
+        return "{ " .. table.concat(buffer, ", ") .. " }"
+    end
+
+    return tostring(value)
+end
+
+local function is_equal(a, b)
+    if type(a) ~= "table" or type(b) ~= "table" then
+        return a == b
+    end
+
+    for k, v in pairs(a) do
+        if not is_equal(v, b[k]) then
+            return false
+        end
+    end
+
+    for k, _ in pairs(b) do
+        if a[k] == nil then
+            return false
+        end
+    end
+
+    return true
+end
+
+--[[----------------------------------------------------------------]]
+
+---@class expect
+---@field value any
+local expect_meta = {}
+expect_meta.__index = expect_meta
+
+---Create a new expectation over a value.
+---@param value any # The value.
+---@return expect value # The expectation.
+function expect(value)
+    return setmetatable({ value = value }, expect_meta)
+end
+
+---Expect the value to be equal to another value. Tables will be compared by content.
+---@param value any # The other value.
+function expect_meta:equal(value)
+    if not is_equal(self.value, value) then
+        error(string.format("Expected %s, got %s.", get_text(value), get_text(self.value)), 2)
+    end
+
+    return self
+end
+
+---Expect the value to not be equal to another value. Tables will be compared by content.
+---@param value any # The other value.
+function expect_meta:not_equal(value)
+    if is_equal(self.value, value) then
+        error(string.format("Expected anything but %s.", get_text(value)), 2)
+    end
+
+    return self
+end
+
+---Expect the value to be near another number.
+---@param value    number  # The other number.
+---@param epsilon? number  # OPTIONAL: The maximum difference. Default: 0.0001.
+function expect_meta:near(value, epsilon)
+    epsilon = epsilon or 0.0001
+
+    if type(self.value) ~= "number" or math.abs(self.value - value) > epsilon then
+        error(string.format("Expected %s (+/- %s), got %s.", get_text(value), epsilon, get_text(self.value)), 2)
+    end
+
+    return self
+end
+
+---Expect the value to be true (not nil, and not false).
+function expect_meta:truthy()
+    if not self.value then
+        error(string.format("Expected a true value, got %s.", get_text(self.value)), 2)
+    end
+
+    return self
+end
+
+---Expect the value to be false (nil, or false).
+function expect_meta:falsy()
+    if self.value then
+        error(string.format("Expected a false value, got %s.", get_text(self.value)), 2)
+    end
+
+    return self
+end
+
+---Expect the value, a function, to throw an error when called.
+---@param pattern? string # OPTIONAL: A Lua pattern the error message must match.
+function expect_meta:error(pattern)
+    local success, result = pcall(self.value)
+
+    if success then
+        error("Expected an error, got none.", 2)
+    end
+
+    if pattern and not string.find(tostring(result), pattern) then
+        error(string.format("Expected an error matching %s, got %s.", get_text(pattern), get_text(tostring(result))), 2)
+    end
+
+    return self
+end
+
+--[[----------------------------------------------------------------]]
+
+---Describe a group of test cases. Groups can be nested.
+---@param name string   # The name of the group.
+---@param call function # The function with every test case in the group.
+function describe(name, call)
+    table.insert(test.path, name)
+
+    local success, result = pcall(call)
+
+    -- an error outside of any test case, in the group itself.
+    if not success then
+        table.insert(test.case, {
+            name = table.concat(test.path, " > "),
+            fail = tostring(result),
+            time = 0.0,
+        })
+    end
+
+    table.remove(test.path)
+end
+
+---Run a test case. An error in the test case (such as a failed expectation or assert) will fail it.
+---@param name string   # The name of the test case.
+---@param call function # The test case.
+function it(name, call)
+    table.insert(test.path, name)
+
+    local time = os.clock()
+    local success, result = pcall(call)
+
+    table.insert(test.case, {
+        name = table.concat(test.path, " > "),
+        fail = (not success) and tostring(result) or nil,
+        time = os.clock() - time,
+    })
+
+    table.remove(test.path)
+end
+
+-- finish the test file, and give back every test case. a file with no test case in it will count as one test case.
+function test.finish(name, fail)
+    if fail or #test.case == 0 then
+        table.insert(test.case, {
+            name = name,
+            fail = fail,
+            time = 0.0,
+        })
+    end
+
+    return test.case
+end
+
+return test
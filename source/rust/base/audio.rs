@@ -0,0 +1,2020 @@
+/*
+* Copyright (c) 2025 sockentrocken
+*
+* Redistribution and use in source and binary forms, with or without
+* modification, are permitted provided that the following conditions are met:
+*
+* 1. Redistributions of source code must retain the above copyright notice,
+* this list of conditions and the following disclaimer.
+*
+* 2. Redistributions in binary form must reproduce the above copyright notice,
+* this list of conditions and the following disclaimer in the documentation
+* and/or other materials provided with the distribution.
+*
+* Subject to the terms and conditions of this license, each copyright holder
+* and contributor hereby grants to those receiving rights under this license
+* a perpetual, worldwide, non-exclusive, no-charge, royalty-free, irrevocable
+* (except for failure to satisfy the conditions of this license) patent license
+* to make, have made, use, offer to sell, sell, import, and otherwise transfer
+* this software, where such license applies only to those patent claims, already
+* acquired or hereafter acquired, licensable by such copyright holder or
+* contributor that are necessarily infringed by:
+*
+* (a) their Contribution(s) (the licensed copyrights of copyright holders and
+* non-copyrightable additions of contributors, in source or binary form) alone;
+* or
+*
+* (b) combination of their Contribution(s) with the work of authorship to which
+* such Contribution(s) was added by such copyright holder or contributor, if,
+* at the time the Contribution is added, such addition causes such combination
+* to be necessarily infringed. The patent license shall not apply to any other
+* combinations which include the Contribution.
+*
+* Except as expressly stated above, no rights or licenses from any copyright
+* holder or contributor is granted under this license, whether expressly, by
+* implication, estoppel or otherwise.
+*
+* DISCLAIMER
+*
+* THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+* AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+* IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+* DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDERS OR CONTRIBUTORS BE LIABLE
+* FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+* DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+* SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+* CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+* OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+* OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+*/
+
+use crate::base::data::Data;
+use crate::script::*;
+use crate::status::*;
+
+//================================================================
+
+use mlua::prelude::*;
+use raylib::prelude::*;
+use serde::Deserialize;
+use std::collections::VecDeque;
+use std::ffi::c_void;
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+use std::sync::{Arc, Mutex, Weak};
+use std::time::Instant;
+
+//================================================================
+
+/* class
+{ "version": "1.0.0", "name": "quiver.audio", "info": "The audio API.", "head": true }
+*/
+#[rustfmt::skip]
+pub fn set_global(lua: &Lua, table: &mlua::Table, _: &StatusInfo, _: Option<&ScriptInfo>) -> mlua::Result<()> {
+    let audio = lua.create_table()?;
+
+    audio.set("new_stream",      lua.create_function(self::AudioStream::new)?)?;
+    audio.set("new_bus",         lua.create_function(self::new_bus)?)?;
+    audio.set("get_bus",         lua.create_function(self::get_bus)?)?;
+    audio.set("set_sample_rate", lua.create_function(self::set_sample_rate)?)?;
+    audio.set("set_listener",    lua.create_function(self::set_listener)?)?;
+    audio.set("set_doppler",     lua.create_function(self::set_doppler)?)?;
+
+    AudioBus::set_default();
+
+    table.set("audio", audio)?;
+
+    Ok(())
+}
+
+type RLAudioStream = ffi::AudioStream;
+
+//================================================================
+
+// raylib's audio callback has no user pointer, so every callback is a separate function, each reading from its own slot.
+const SLOT_COUNT: usize = 16;
+
+type AudioRingSlot = Mutex<Option<Arc<Mutex<AudioRing>>>>;
+
+static SLOT: [AudioRingSlot; SLOT_COUNT] = [const { Mutex::new(None) }; SLOT_COUNT];
+
+#[rustfmt::skip]
+const SLOT_CALLBACK: [unsafe extern "C" fn(*mut c_void, u32); SLOT_COUNT] = [
+    stream_callback::<0>,  stream_callback::<1>,  stream_callback::<2>,  stream_callback::<3>,
+    stream_callback::<4>,  stream_callback::<5>,  stream_callback::<6>,  stream_callback::<7>,
+    stream_callback::<8>,  stream_callback::<9>,  stream_callback::<10>, stream_callback::<11>,
+    stream_callback::<12>, stream_callback::<13>, stream_callback::<14>, stream_callback::<15>,
+];
+
+// fill a stream buffer from the ring buffer of a slot, on the audio thread.
+unsafe extern "C" fn stream_callback<const N: usize>(buffer: *mut c_void, frame: u32) {
+    let ring = match SLOT[N].lock() {
+        Ok(slot) => slot.clone(),
+        Err(_) => None,
+    };
+
+    let Some(ring) = ring else {
+        return;
+    };
+
+    let Ok(mut ring) = ring.lock() else {
+        return;
+    };
+
+    let length = frame as usize * ring.channel;
+    let buffer = unsafe { std::slice::from_raw_parts_mut(buffer as *mut f32, length) };
+
+    ring.read(buffer);
+}
+
+// a ring buffer of interleaved sample, pushed from Lua and read from the audio thread.
+struct AudioRing {
+    data: VecDeque<f32>,
+    channel: usize,
+    capacity: usize,
+    underrun: usize,
+}
+
+impl AudioRing {
+    // push as many sample as will fit, and get back the count of sample pushed.
+    fn write(&mut self, data: &[f32]) -> usize {
+        let length = data.len().min(self.capacity - self.data.len());
+
+        self.data.extend(&data[..length]);
+
+        length
+    }
+
+    // read into a buffer, with silence for any missing sample.
+    fn read(&mut self, buffer: &mut [f32]) {
+        let length = buffer.len().min(self.data.len());
+
+        for (target, value) in buffer.iter_mut().zip(self.data.drain(..length)) {
+            *target = value;
+        }
+
+        if length < buffer.len() {
+            buffer[length..].fill(0.0);
+            self.underrun += 1;
+        }
+    }
+}
+
+/* class
+{
+    "version": "1.0.0",
+    "name": "audio_stream",
+    "info": "A procedural audio stream. Sample are 32-bit float in the [-1.0, 1.0] range, interleaved by channel, and are pushed to a ring buffer that will be fed to the audio device from the audio thread, with silence for any missing sample. At most 16 audio stream can exist at the same time.",
+    "member": [
+        { "name": "sample_rate", "info": "The sample rate.",                                                  "kind": "number" },
+        { "name": "channel",     "info": "The channel count.",                                                "kind": "number" },
+        { "name": "capacity",    "info": "The ring buffer capacity, in frame.",                               "kind": "number" },
+        { "name": "queue",       "info": "The frame count in the ring buffer, yet to be played.",             "kind": "number" },
+        { "name": "underrun",    "info": "The count of time the audio device ran out of sample to play.",     "kind": "number" }
+    ],
+    "test": "audio/stream.lua"
+}
+*/
+struct AudioStream {
+    stream: RLAudioStream,
+    ring: Arc<Mutex<AudioRing>>,
+    slot: usize,
+    link: Option<AudioLink>,
+    spatial: Option<AudioSpatial>,
+}
+
+unsafe impl Send for AudioStream {}
+
+impl AudioStream {
+    /* entry
+    {
+        "version": "1.0.0",
+        "name": "quiver.audio.new_stream",
+        "info": "Create a new audio stream.",
+        "member": [
+            { "name": "sample_rate", "info": "The sample rate, in Hz.",                                                    "kind": "number"  },
+            { "name": "channel",     "info": "The channel count (1 for mono, 2 for stereo).",                          "kind": "number"  },
+            { "name": "buffer",      "info": "OPTIONAL: The size of the audio device's buffer, in frame. A smaller buffer will have less latency, but is more likely to run out. Default: raylib's default size.", "kind": "number?" },
+            { "name": "capacity",    "info": "OPTIONAL: The ring buffer capacity, in frame. Default: 1 second of audio.", "kind": "number?" }
+        ],
+        "result": [
+            { "name": "audio_stream", "info": "The audio stream.", "kind": "audio_stream" }
+        ]
+    }
+    */
+    fn new(
+        _: &Lua,
+        (sample_rate, channel, buffer, capacity): (u32, u32, Option<i32>, Option<usize>),
+    ) -> mlua::Result<Self> {
+        if sample_rate == 0 || !(1..=2).contains(&channel) {
+            return Err(mlua::Error::runtime(
+                "quiver.audio.new_stream(): Sample rate must be greater than zero, and channel count must be 1 or 2.",
+            ));
+        }
+
+        let capacity = capacity
+            .unwrap_or(sample_rate as usize)
+            .max(1)
+            .checked_mul(channel as usize)
+            .ok_or(mlua::Error::runtime(
+                "quiver.audio.new_stream(): Capacity is too large.",
+            ))?;
+
+        let ring = Arc::new(Mutex::new(AudioRing {
+            data: VecDeque::new(),
+            channel: channel as usize,
+            capacity,
+            underrun: 0,
+        }));
+
+        // take the first free slot.
+        let slot = SLOT
+            .iter()
+            .position(|slot| {
+                let mut slot = slot.lock().unwrap();
+
+                if slot.is_none() {
+                    *slot = Some(ring.clone());
+                    true
+                } else {
+                    false
+                }
+            })
+            .ok_or(mlua::Error::runtime(
+                "quiver.audio.new_stream(): Too many audio stream.",
+            ))?;
+
+        unsafe {
+            ffi::SetAudioStreamBufferSizeDefault(buffer.unwrap_or_default());
+            let stream = ffi::LoadAudioStream(sample_rate, 32, channel);
+            ffi::SetAudioStreamBufferSizeDefault(0);
+
+            if !ffi::IsAudioStreamValid(stream) {
+                *SLOT[slot].lock().unwrap() = None;
+
+                return Err(mlua::Error::runtime(
+                    "quiver.audio.new_stream(): Could not create audio stream.",
+                ));
+            }
+
+            ffi::SetAudioStreamCallback(stream, Some(SLOT_CALLBACK[slot]));
+
+            Ok(Self {
+                stream,
+                ring,
+                slot,
+                link: None,
+                spatial: None,
+            })
+        }
+    }
+
+    fn get_ring(&self) -> std::sync::MutexGuard<'_, AudioRing> {
+        self.ring.lock().unwrap()
+    }
+}
+
+impl Drop for AudioStream {
+    fn drop(&mut self) {
+        // detach from the bus before the stream is gone.
+        self.spatial = None;
+        self.link = None;
+
+        unsafe {
+            ffi::UnloadAudioStream(self.stream);
+        }
+
+        *SLOT[self.slot].lock().unwrap() = None;
+    }
+}
+
+impl mlua::UserData for AudioStream {
+    fn add_fields<F: mlua::UserDataFields<Self>>(field: &mut F) {
+        field.add_field_method_get("sample_rate", |_, this| Ok(this.stream.sampleRate));
+        field.add_field_method_get("channel", |_, this| Ok(this.stream.channels));
+        field.add_field_method_get("capacity", |_, this| {
+            let ring = this.get_ring();
+            Ok(ring.capacity / ring.channel)
+        });
+        field.add_field_method_get("queue", |_, this| {
+            let ring = this.get_ring();
+            Ok(ring.data.len() / ring.channel)
+        });
+        field.add_field_method_get("underrun", |_, this| Ok(this.get_ring().underrun));
+    }
+
+    fn add_methods<M: mlua::UserDataMethods<Self>>(method: &mut M) {
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "audio_stream:push",
+            "info": "Push sample to the ring buffer. Any sample past the ring buffer capacity will be dropped.",
+            "member": [
+                { "name": "sample", "info": "The sample, as either a data buffer of 32-bit float, or a table of number.", "kind": "data | table" }
+            ],
+            "result": [
+                { "name": "count", "info": "The count of frame pushed.", "kind": "number" }
+            ]
+        }
+        */
+        method.add_method("push", |lua: &Lua, this, sample: LuaValue| {
+            let mut ring = this.get_ring();
+
+            let count = if sample.is_userdata() {
+                ring.write(&Data::<f32>::get_buffer(sample)?.0)
+            } else {
+                ring.write(&lua.from_value::<Vec<f32>>(sample)?)
+            };
+
+            Ok(count / ring.channel)
+        });
+
+        /* entry
+        { "version": "1.0.0", "name": "audio_stream:clear", "info": "Clear the ring buffer." }
+        */
+        method.add_method("clear", |_, this, ()| {
+            this.get_ring().data.clear();
+            Ok(())
+        });
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "audio_stream:set_bus",
+            "info": "Set the audio bus of the audio stream.",
+            "member": [
+                { "name": "bus", "info": "The audio bus. Use nil to remove the audio stream from its bus.", "kind": "audio_bus?" }
+            ]
+        }
+        */
+        method.add_method_mut(
+            "set_bus",
+            |_, this, bus: Option<LuaUserDataRef<AudioBus>>| {
+                this.link = None;
+
+                if let Some(bus) = bus {
+                    let mut link = AudioLink::new(&bus);
+                    link.attach(this.stream)?;
+                    this.link = Some(link);
+                }
+
+                Ok(())
+            },
+        );
+
+        /* entry
+        { "version": "1.0.0", "name": "audio_stream:play", "info": "Play the audio stream." }
+        */
+        method.add_method("play", |_, this, ()| unsafe {
+            ffi::PlayAudioStream(this.stream);
+            Ok(())
+        });
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "audio_stream:get_playing",
+            "info": "Check if the audio stream is currently playing.",
+            "result": [
+                { "name": "state", "info": "State of the audio stream.", "kind": "boolean" }
+            ]
+        }
+        */
+        method.add_method("get_playing", |_, this, ()| unsafe {
+            Ok(ffi::IsAudioStreamPlaying(this.stream))
+        });
+
+        /* entry
+        { "version": "1.0.0", "name": "audio_stream:stop", "info": "Stop the audio stream." }
+        */
+        method.add_method("stop", |_, this, ()| unsafe {
+            ffi::StopAudioStream(this.stream);
+            Ok(())
+        });
+
+        /* entry
+        { "version": "1.0.0", "name": "audio_stream:pause", "info": "Pause the audio stream." }
+        */
+        method.add_method("pause", |_, this, ()| unsafe {
+            ffi::PauseAudioStream(this.stream);
+            Ok(())
+        });
+
+        /* entry
+        { "version": "1.0.0", "name": "audio_stream:resume", "info": "Resume the audio stream." }
+        */
+        method.add_method("resume", |_, this, ()| unsafe {
+            ffi::ResumeAudioStream(this.stream);
+            Ok(())
+        });
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "audio_stream:set_volume",
+            "info": "Set volume for the audio stream. (range: 0.0 - 1.0)",
+            "member": [
+                { "name": "volume", "info": "Current volume.", "kind" : "number" }
+            ]
+        }
+        */
+        method.add_method("set_volume", |_, this, value: f32| unsafe {
+            ffi::SetAudioStreamVolume(this.stream, value);
+            Ok(())
+        });
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "audio_stream:set_pitch",
+            "info": "Set pitch for the audio stream.",
+            "member": [
+                { "name": "pitch", "info": "Current pitch.", "kind" : "number" }
+            ]
+        }
+        */
+        method.add_method("set_pitch", |_, this, value: f32| {
+            if let Some(spatial) = &this.spatial {
+                spatial.set_pitch(value);
+            } else {
+                unsafe {
+                    ffi::SetAudioStreamPitch(this.stream, value);
+                }
+            }
+
+            Ok(())
+        });
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "audio_stream:set_pan",
+            "info": "Set pan for the audio stream. (range: 0.0 - 1.0; 0.5 is center)",
+            "member": [
+                { "name": "pan", "info": "Current pan.", "kind" : "number" }
+            ]
+        }
+        */
+        method.add_method("set_pan", |_, this, value: f32| unsafe {
+            ffi::SetAudioStreamPan(this.stream, value);
+            Ok(())
+        });
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "audio_stream:set_spatial",
+            "info": "Set the audio stream as a spatial source, with distance attenuation, pan and doppler pitch shift from the listener.",
+            "member": [
+                { "name": "option", "info": "The spatial option: { point, velocity?, kind? (ATTENUATION_KIND), distance_min?, distance_max?, rolloff? }. Use nil to remove the spatial source.", "kind": "table?" }
+            ]
+        }
+        */
+        method.add_method_mut("set_spatial", |lua, this, option: LuaValue| {
+            this.spatial = None;
+
+            if !option.is_nil() {
+                this.spatial = Some(AudioSpatial::new(lua, "set_spatial", this.stream, option)?);
+            }
+
+            Ok(())
+        });
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "audio_stream:set_spatial_point",
+            "info": "Set the point of the spatial source.",
+            "member": [
+                { "name": "point",    "info": "The point of the source.",                                                       "kind": "vector_3"  },
+                { "name": "velocity", "info": "OPTIONAL: The velocity of the source. Default: derived from the last source point.", "kind": "vector_3?" }
+            ]
+        }
+        */
+        method.add_method(
+            "set_spatial_point",
+            |lua, this, (point, velocity): (LuaValue, Option<LuaValue>)| {
+                let spatial = this.spatial.as_ref().ok_or(mlua::Error::runtime(
+                    "audio_stream:set_spatial_point(): Audio stream is not a spatial source.",
+                ))?;
+
+                spatial.set_point(lua, point, velocity)
+            },
+        );
+    }
+}
+
+//================================================================
+
+// the output sample rate, for any effect, and whether it was set by the user.
+static SAMPLE_RATE: AtomicU32 = AtomicU32::new(48000);
+static SAMPLE_RATE_USER: AtomicBool = AtomicBool::new(false);
+
+// raylib has no way to get the sample rate of the audio device, so it is measured from the final mix instead: the first call time, and the frame count since.
+static SAMPLE_RATE_MEASURE: Mutex<Option<(Instant, u64)>> = Mutex::new(None);
+static SAMPLE_RATE_DONE: AtomicBool = AtomicBool::new(false);
+
+// every bus, by name.
+static BUS_LIST: Mutex<Vec<AudioBus>> = Mutex::new(Vec::new());
+
+// the master bus instance, processing the final mix, and whether it has been attached yet.
+static MASTER: Mutex<Option<BusInstance>> = Mutex::new(None);
+static MASTER_ATTACH: AtomicBool = AtomicBool::new(false);
+
+// like the audio stream callback, every audio processor is a separate function, each with its own slot.
+const PROCESSOR_COUNT: usize = 64;
+
+type ProcessorSlot = Mutex<Option<Arc<Mutex<dyn AudioProcess>>>>;
+
+static PROCESSOR: [ProcessorSlot; PROCESSOR_COUNT] = [const { Mutex::new(None) }; PROCESSOR_COUNT];
+
+macro_rules! processor_list {
+    ($($index:literal)*) => {
+        [$(processor_callback::<$index>,)*]
+    };
+}
+
+const PROCESSOR_CALLBACK: [unsafe extern "C" fn(*mut c_void, u32); PROCESSOR_COUNT] = processor_list!(
+    0  1  2  3  4  5  6  7  8  9  10 11 12 13 14 15
+    16 17 18 19 20 21 22 23 24 25 26 27 28 29 30 31
+    32 33 34 35 36 37 38 39 40 41 42 43 44 45 46 47
+    48 49 50 51 52 53 54 55 56 57 58 59 60 61 62 63
+);
+
+// an audio processor, for the audio of a sound, music or audio stream.
+trait AudioProcess: Send {
+    // process a buffer in the mixing format: 32-bit float, stereo.
+    fn process(&mut self, buffer: &mut [f32]);
+}
+
+// process the audio of a sound, music or audio stream, on the audio thread.
+unsafe extern "C" fn processor_callback<const N: usize>(buffer: *mut c_void, frame: u32) {
+    let instance = match PROCESSOR[N].lock() {
+        Ok(slot) => slot.clone(),
+        Err(_) => None,
+    };
+
+    if let Some(instance) = instance {
+        if let Ok(mut instance) = instance.lock() {
+            let length = frame as usize * 2;
+            instance.process(unsafe { std::slice::from_raw_parts_mut(buffer as *mut f32, length) });
+        }
+    }
+}
+
+// measure the sample rate of the audio device, from the frame count of the final mix over a second.
+fn set_sample_rate_measure(frame: u32) {
+    const RATE: [u32; 9] = [8000, 11025, 16000, 22050, 32000, 44100, 48000, 88200, 96000];
+
+    if SAMPLE_RATE_DONE.load(Ordering::Relaxed) {
+        return;
+    }
+
+    let Ok(mut measure) = SAMPLE_RATE_MEASURE.lock() else {
+        return;
+    };
+
+    // the frame count of the first call is left out, as it was rendered before the first call time.
+    let Some((time, count)) = measure.as_mut() else {
+        *measure = Some((Instant::now(), 0));
+        return;
+    };
+
+    let delta = time.elapsed().as_secs_f64();
+
+    if delta < 1.0 {
+        *count += frame as u64;
+        return;
+    }
+
+    let value = *count as f64 / delta;
+    let value = RATE
+        .iter()
+        .min_by(|a, b| {
+            (**a as f64 - value)
+                .abs()
+                .total_cmp(&(**b as f64 - value).abs())
+        })
+        .copied()
+        .unwrap_or(48000);
+
+    if !SAMPLE_RATE_USER.load(Ordering::Relaxed) {
+        SAMPLE_RATE.store(value, Ordering::Relaxed);
+    }
+
+    SAMPLE_RATE_DONE.store(true, Ordering::Relaxed);
+}
+
+// process the final mix in the master bus, on the audio thread.
+unsafe extern "C" fn master_callback(buffer: *mut c_void, frame: u32) {
+    set_sample_rate_measure(frame);
+
+    if let Ok(mut instance) = MASTER.lock() {
+        if let Some(instance) = instance.as_mut() {
+            let length = frame as usize * 2;
+            instance.process(unsafe { std::slice::from_raw_parts_mut(buffer as *mut f32, length) });
+        }
+    }
+}
+
+// attach an audio processor to an audio stream, in the first free slot.
+fn set_processor(
+    call: &str,
+    stream: RLAudioStream,
+    processor: Arc<Mutex<dyn AudioProcess>>,
+) -> mlua::Result<usize> {
+    let slot = PROCESSOR
+        .iter()
+        .position(|slot| {
+            let mut slot = slot.lock().unwrap();
+
+            if slot.is_none() {
+                *slot = Some(processor.clone());
+                true
+            } else {
+                false
+            }
+        })
+        .ok_or(mlua::Error::runtime(format!(
+            "{call}(): Too many audio processor."
+        )))?;
+
+    unsafe {
+        ffi::AttachAudioStreamProcessor(stream, Some(PROCESSOR_CALLBACK[slot]));
+    }
+
+    Ok(slot)
+}
+
+fn remove_processor(stream: RLAudioStream, slot: usize) {
+    unsafe {
+        ffi::DetachAudioStreamProcessor(stream, Some(PROCESSOR_CALLBACK[slot]));
+    }
+
+    *PROCESSOR[slot].lock().unwrap() = None;
+}
+
+// attach the master bus processor, once the audio device is ready.
+fn set_master() {
+    unsafe {
+        if ffi::IsAudioDeviceReady() && !MASTER_ATTACH.swap(true, Ordering::Relaxed) {
+            ffi::AttachAudioMixedProcessor(Some(master_callback));
+        }
+    }
+}
+
+/* class
+{
+    "version": "1.0.0",
+    "name": "audio_bus",
+    "info": "An audio bus. Every sound, music and audio stream in a bus will have the volume, mute state, ducking and effect of the bus and of every parent bus applied to it. The master bus applies to the final mix, including any audio not in a bus. The master, music, sfx and voice bus will always exist.",
+    "member": [
+        { "name": "name",   "info": "The name of the bus.",                                          "kind": "string"  },
+        { "name": "volume", "info": "The volume of the bus. Default: 1.0.",                          "kind": "number"  },
+        { "name": "mute",   "info": "The mute state of the bus. Default: false.",                    "kind": "boolean" },
+        { "name": "level",  "info": "The peak level of the bus, over the last tenth of a second.", "kind": "number"  }
+    ],
+    "test": "audio/bus.lua"
+}
+*/
+#[derive(Clone)]
+pub struct AudioBus(Arc<BusData>);
+
+struct BusData {
+    name: String,
+    parent: Option<AudioBus>,
+    state: Mutex<BusState>,
+}
+
+struct BusState {
+    volume: f32,
+    mute: bool,
+    effect: Vec<(i32, effect::EffectOption)>,
+    // bumped on every effect change, for any bus instance to pick up.
+    version: u64,
+    level: f32,
+    level_time: Instant,
+    duck: Option<BusDuck>,
+    duck_gain: f32,
+    duck_time: Instant,
+}
+
+#[derive(Clone)]
+struct BusDuck {
+    source: AudioBus,
+    amount: f32,
+    threshold: f32,
+    attack: f32,
+    release: f32,
+}
+
+impl AudioBus {
+    // the time a peak level is held for.
+    const LEVEL_HOLD: f32 = 0.1;
+
+    fn new(name: &str, parent: Option<AudioBus>) -> Self {
+        Self(Arc::new(BusData {
+            name: name.to_string(),
+            parent,
+            state: Mutex::new(BusState {
+                volume: 1.0,
+                mute: false,
+                effect: Vec::new(),
+                version: 0,
+                level: 0.0,
+                level_time: Instant::now(),
+                duck: None,
+                duck_gain: 1.0,
+                duck_time: Instant::now(),
+            }),
+        }))
+    }
+
+    // reset every bus to the default bus list.
+    fn set_default() {
+        // the standard library is set more than once, so keep any bus made before.
+        if !BUS_LIST.lock().unwrap().is_empty() {
+            return;
+        }
+
+        let master = Self::new("master", None);
+        let mut list = vec![master.clone()];
+
+        for name in ["music", "sfx", "voice"] {
+            list.push(Self::new(name, Some(master.clone())));
+        }
+
+        *MASTER.lock().unwrap() = Some(BusInstance::new(&master, true));
+        *BUS_LIST.lock().unwrap() = list;
+    }
+
+    fn get_state(&self) -> std::sync::MutexGuard<'_, BusState> {
+        self.0.state.lock().unwrap()
+    }
+
+    fn is_master(&self) -> bool {
+        self.0.parent.is_none()
+    }
+
+    fn get_level(&self, time: Instant) -> f32 {
+        let state = self.get_state();
+
+        if time.duration_since(state.level_time).as_secs_f32() > Self::LEVEL_HOLD {
+            0.0
+        } else {
+            state.level
+        }
+    }
+
+    fn set_level(&self, level: f32, time: Instant) {
+        let mut state = self.get_state();
+
+        if time.duration_since(state.level_time).as_secs_f32() > Self::LEVEL_HOLD {
+            state.level = level;
+            state.level_time = time;
+        } else if level >= state.level {
+            state.level = level;
+        }
+    }
+
+    // get the gain of the bus, updating the ducking gain.
+    fn get_gain(&self, time: Instant) -> f32 {
+        // read the level of the ducking source first, so as to never lock two bus at once.
+        let duck = self.get_state().duck.clone();
+        let target = match &duck {
+            Some(duck) if duck.source.get_level(time) > duck.threshold => 1.0 - duck.amount,
+            _ => 1.0,
+        };
+
+        let mut state = self.get_state();
+        let delta = time.duration_since(state.duck_time).as_secs_f32();
+
+        state.duck_time = time;
+
+        if let Some(duck) = duck {
+            let length = if target < state.duck_gain {
+                duck.attack
+            } else {
+                duck.release
+            };
+
+            state.duck_gain =
+                target + (state.duck_gain - target) * (-delta / length.max(0.0001)).exp();
+        } else {
+            state.duck_gain = 1.0;
+        }
+
+        if state.mute {
+            0.0
+        } else {
+            state.volume * state.duck_gain
+        }
+    }
+
+    fn set_effect(
+        &self,
+        call: &str,
+        index: usize,
+        option: Option<effect::EffectOption>,
+    ) -> mlua::Result<()> {
+        let mut state = self.get_state();
+        let effect = index
+            .checked_sub(1)
+            .and_then(|index| state.effect.get_mut(index))
+            .ok_or(mlua::Error::runtime(format!(
+                "audio_bus:{call}(): Invalid effect index."
+            )))?;
+
+        match option {
+            Some(option) => effect.1 = option,
+            None => {
+                state.effect.remove(index - 1);
+            }
+        }
+
+        state.version += 1;
+
+        Ok(())
+    }
+}
+
+impl mlua::UserData for AudioBus {
+    fn add_fields<F: mlua::UserDataFields<Self>>(field: &mut F) {
+        field.add_field_method_get("name", |_, this| Ok(this.0.name.clone()));
+        field.add_field_method_get("volume", |_, this| Ok(this.get_state().volume));
+        field.add_field_method_set("volume", |_, this, value: f32| {
+            set_master();
+            this.get_state().volume = value.max(0.0);
+            Ok(())
+        });
+        field.add_field_method_get("mute", |_, this| Ok(this.get_state().mute));
+        field.add_field_method_set("mute", |_, this, value: bool| {
+            set_master();
+            this.get_state().mute = value;
+            Ok(())
+        });
+        field.add_field_method_get("level", |_, this| Ok(this.get_level(Instant::now())));
+    }
+
+    fn add_methods<M: mlua::UserDataMethods<Self>>(method: &mut M) {
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "audio_bus:set_duck",
+            "info": "Duck the bus (lower the volume) whenever another bus is playing, such as lowering the music bus whenever the voice bus is playing.",
+            "member": [
+                { "name": "source",    "info": "The bus to duck for. Use nil to stop ducking.",                           "kind": "audio_bus?" },
+                { "name": "amount",    "info": "OPTIONAL: The amount to duck the volume by. (range: 0.0 - 1.0) Default: 0.5.", "kind": "number?"    },
+                { "name": "threshold", "info": "OPTIONAL: The source bus level over which to duck. Default: 0.01.",           "kind": "number?"    },
+                { "name": "attack",    "info": "OPTIONAL: The time to duck over, in second. Default: 0.1.",                   "kind": "number?"    },
+                { "name": "release",   "info": "OPTIONAL: The time to un-duck over, in second. Default: 0.5.",                "kind": "number?"    }
+            ]
+        }
+        */
+        method.add_method(
+            "set_duck",
+            |_,
+             this,
+             (source, amount, threshold, attack, release): (
+                Option<LuaUserDataRef<AudioBus>>,
+                Option<f32>,
+                Option<f32>,
+                Option<f32>,
+                Option<f32>,
+            )| {
+                set_master();
+
+                let duck = source.map(|source| BusDuck {
+                    source: (*source).clone(),
+                    amount: amount.unwrap_or(0.5).clamp(0.0, 1.0),
+                    threshold: threshold.unwrap_or(0.01),
+                    attack: attack.unwrap_or(0.1),
+                    release: release.unwrap_or(0.5),
+                });
+
+                if duck
+                    .as_ref()
+                    .is_some_and(|x| Arc::ptr_eq(&x.source.0, &this.0))
+                {
+                    return Err(mlua::Error::runtime(
+                        "audio_bus:set_duck(): A bus can not duck for itself.",
+                    ));
+                }
+
+                this.get_state().duck = duck;
+
+                Ok(())
+            },
+        );
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "audio_bus:add_effect",
+            "info": "Add an effect to the end of the bus's effect chain.",
+            "member": [
+                { "name": "kind",   "info": "The kind of effect.", "kind": "audio_effect" },
+                { "name": "option", "info": "OPTIONAL: The option table, with any of: cutoff (in Hz, default: 1000.0) and resonance (default: 0.707) for a low-pass and high-pass filter, delay (in second, up to 10.0, default: 0.25), feedback (default: 0.5) and mix (default: 0.3) for echo, room (default: 0.5), damp (default: 0.5) and mix for reverb, and threshold (in dB, default: -12.0), ratio (default: 4.0), attack (in second, default: 0.01), release (in second, default: 0.1) and gain (make-up gain, in dB, default: 0.0) for a compressor.", "kind": "table?" }
+            ],
+            "result": [
+                { "name": "index", "info": "The effect index.", "kind": "number" }
+            ]
+        }
+        */
+        method.add_method(
+            "add_effect",
+            |lua: &Lua, this, (kind, option): (i32, Option<LuaValue>)| {
+                if !(effect::LOW_PASS..=effect::COMPRESSOR).contains(&kind) {
+                    return Err(mlua::Error::runtime(
+                        "audio_bus:add_effect(): Unknown effect kind.",
+                    ));
+                }
+
+                let option = effect::EffectOption::new(lua, option)?;
+
+                set_master();
+
+                let mut state = this.get_state();
+
+                state.effect.push((kind, option));
+                state.version += 1;
+
+                Ok(state.effect.len())
+            },
+        );
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "audio_bus:set_effect",
+            "info": "Set the option of an effect. Changing the option of an effect will not reset it, so any option can be smoothly changed over time.",
+            "member": [
+                { "name": "index",  "info": "The effect index.",                                  "kind": "number" },
+                { "name": "option", "info": "The option table. See audio_bus:add_effect.", "kind": "table"  }
+            ]
+        }
+        */
+        method.add_method(
+            "set_effect",
+            |lua: &Lua, this, (index, option): (usize, LuaValue)| {
+                this.set_effect(
+                    "set_effect",
+                    index,
+                    Some(effect::EffectOption::new(lua, Some(option))?),
+                )
+            },
+        );
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "audio_bus:remove_effect",
+            "info": "Remove an effect. Any effect after it will have its index shifted down by one.",
+            "member": [
+                { "name": "index", "info": "The effect index.", "kind": "number" }
+            ]
+        }
+        */
+        method.add_method("remove_effect", |_, this, index: usize| {
+            this.set_effect("remove_effect", index, None)
+        });
+
+        /* entry
+        { "version": "1.0.0", "name": "audio_bus:clear_effect", "info": "Remove every effect." }
+        */
+        method.add_method("clear_effect", |_, this, ()| {
+            let mut state = this.get_state();
+
+            state.effect.clear();
+            state.version += 1;
+
+            Ok(())
+        });
+    }
+}
+
+// the state of a bus for a single audio source (or the final mix, for the master bus), as any effect has its own state.
+struct BusInstance {
+    chain: Vec<BusLink>,
+}
+
+struct BusLink {
+    bus: AudioBus,
+    version: Option<u64>,
+    // the sample rate every effect was made with.
+    rate: u32,
+    effect: Vec<(i32, effect::Effect)>,
+}
+
+impl BusInstance {
+    // the master bus is only ever applied to the final mix.
+    fn new(bus: &AudioBus, master: bool) -> Self {
+        let mut chain = Vec::new();
+        let mut bus = Some(bus.clone());
+
+        while let Some(link) = bus {
+            if master || !link.is_master() {
+                chain.push(BusLink {
+                    bus: link.clone(),
+                    version: None,
+                    rate: 0,
+                    effect: Vec::new(),
+                });
+            }
+
+            bus = link.0.parent.clone();
+        }
+
+        Self { chain }
+    }
+}
+
+impl AudioProcess for BusInstance {
+    fn process(&mut self, buffer: &mut [f32]) {
+        let time = Instant::now();
+        let sample_rate = SAMPLE_RATE.load(Ordering::Relaxed);
+        let rate = sample_rate as f32;
+        let mut gain = 1.0;
+
+        for link in &mut self.chain {
+            gain *= link.bus.get_gain(time);
+
+            let state = link.bus.get_state();
+
+            // pick up any effect or sample rate change, keeping the state of any effect of the same kind.
+            if link.version != Some(state.version) || link.rate != sample_rate {
+                link.version = Some(state.version);
+                link.rate = sample_rate;
+                link.effect.truncate(state.effect.len());
+
+                for (i, (kind, option)) in state.effect.iter().enumerate() {
+                    match link.effect.get_mut(i) {
+                        Some(effect) if effect.0 == *kind => {
+                            effect.1.set_option(*kind, option, rate)
+                        }
+                        Some(effect) => *effect = (*kind, effect::Effect::new(*kind, option, rate)),
+                        None => link
+                            .effect
+                            .push((*kind, effect::Effect::new(*kind, option, rate))),
+                    }
+                }
+            }
+
+            drop(state);
+
+            for (_, effect) in &mut link.effect {
+                effect.process(buffer);
+            }
+        }
+
+        let mut peak: f32 = 0.0;
+
+        for value in buffer.iter_mut() {
+            *value *= gain;
+            peak = peak.max(value.abs());
+        }
+
+        for link in &self.chain {
+            link.bus.set_level(peak, time);
+        }
+    }
+}
+
+// the link between an audio bus and the audio stream of a sound (and any alias), music or audio stream.
+pub struct AudioLink {
+    bus: AudioBus,
+    list: Vec<(RLAudioStream, usize)>,
+}
+
+unsafe impl Send for AudioLink {}
+
+impl AudioLink {
+    pub fn new(bus: &AudioBus) -> Self {
+        set_master();
+
+        Self {
+            bus: bus.clone(),
+            list: Vec::new(),
+        }
+    }
+
+    pub fn attach(&mut self, stream: RLAudioStream) -> mlua::Result<()> {
+        let instance = Arc::new(Mutex::new(BusInstance::new(&self.bus, false)));
+        let slot = set_processor("set_bus", stream, instance)?;
+
+        self.list.push((stream, slot));
+
+        Ok(())
+    }
+
+    pub fn detach(&mut self, stream: RLAudioStream) {
+        if let Some(index) = self.list.iter().position(|x| x.0.buffer == stream.buffer) {
+            let (stream, slot) = self.list.remove(index);
+
+            remove_processor(stream, slot);
+        }
+    }
+}
+
+impl Drop for AudioLink {
+    fn drop(&mut self) {
+        while let Some(stream) = self.list.first().map(|x| x.0) {
+            self.detach(stream);
+        }
+    }
+}
+
+/* entry
+{
+    "version": "1.0.0",
+    "name": "quiver.audio.new_bus",
+    "info": "Create a new audio bus. If a bus by the same name and parent already exists (i.e. from before a script reload), it will be given back instead.",
+    "member": [
+        { "name": "name",   "info": "The name of the bus.",                                "kind": "string"     },
+        { "name": "parent", "info": "OPTIONAL: The parent bus. Default: the master bus.", "kind": "audio_bus?" }
+    ],
+    "result": [
+        { "name": "audio_bus", "info": "The audio bus.", "kind": "audio_bus" }
+    ]
+}
+*/
+fn new_bus(
+    _: &Lua,
+    (name, parent): (String, Option<LuaUserDataRef<AudioBus>>),
+) -> mlua::Result<AudioBus> {
+    let mut list = BUS_LIST.lock().unwrap();
+
+    let parent = match parent {
+        Some(parent) => (*parent).clone(),
+        None => list[0].clone(),
+    };
+
+    // the bus list outlives a script reload, so making the same bus again will give back the bus from before.
+    if let Some(bus) = list.iter().find(|x| x.0.name == name) {
+        let same = bus
+            .0
+            .parent
+            .as_ref()
+            .is_some_and(|x| Arc::ptr_eq(&x.0, &parent.0));
+
+        if same {
+            return Ok(bus.clone());
+        }
+
+        return Err(mlua::Error::runtime(format!(
+            "quiver.audio.new_bus(): Bus \"{name}\" already exists, with another parent."
+        )));
+    }
+
+    let bus = AudioBus::new(&name, Some(parent));
+
+    list.push(bus.clone());
+
+    Ok(bus)
+}
+
+/* entry
+{
+    "version": "1.0.0",
+    "name": "quiver.audio.get_bus",
+    "info": "Get an audio bus by name.",
+    "member": [
+        { "name": "name", "info": "The name of the bus.", "kind": "string" }
+    ],
+    "result": [
+        { "name": "audio_bus", "info": "The audio bus.", "kind": "audio_bus" }
+    ]
+}
+*/
+fn get_bus(_: &Lua, name: String) -> mlua::Result<AudioBus> {
+    BUS_LIST
+        .lock()
+        .unwrap()
+        .iter()
+        .find(|x| x.0.name == name)
+        .cloned()
+        .ok_or(mlua::Error::runtime(format!(
+            "quiver.audio.get_bus(): Unknown bus \"{name}\"."
+        )))
+}
+
+/* entry
+{
+    "version": "1.0.0",
+    "name": "quiver.audio.set_sample_rate",
+    "info": "Set the sample rate of the audio device, for any bus effect. Default: measured from the audio device, once any bus is in use.",
+    "member": [
+        { "name": "sample_rate", "info": "The sample rate, in Hz.", "kind": "number" }
+    ]
+}
+*/
+fn set_sample_rate(_: &Lua, value: u32) -> mlua::Result<()> {
+    SAMPLE_RATE.store(value.max(1), Ordering::Relaxed);
+    SAMPLE_RATE_USER.store(true, Ordering::Relaxed);
+
+    Ok(())
+}
+
+//================================================================
+
+// the listener, for any spatial audio.
+static LISTENER: Mutex<Option<Listener>> = Mutex::new(None);
+
+// the doppler factor, and the speed of sound.
+static DOPPLER: Mutex<(f32, f32)> = Mutex::new((1.0, 343.0));
+
+// every spatial source, for the listener to update the doppler pitch of.
+static SPATIAL_LIST: Mutex<Vec<Weak<Mutex<SpatialSource>>>> = Mutex::new(Vec::new());
+
+#[derive(Clone, Copy)]
+struct Listener {
+    point: Vector3,
+    front: Vector3,
+    up: Vector3,
+    velocity: Vector3,
+    time: Instant,
+}
+
+impl Listener {
+    fn get() -> Self {
+        LISTENER.lock().unwrap().unwrap_or(Self {
+            point: Vector3::zero(),
+            front: Vector3::new(0.0, 0.0, -1.0),
+            up: Vector3::up(),
+            velocity: Vector3::zero(),
+            time: Instant::now(),
+        })
+    }
+}
+
+// get a velocity from the last point, if a velocity was not given.
+fn get_velocity(
+    point: Vector3,
+    velocity: Option<Vector3>,
+    last: Option<(Vector3, Instant)>,
+    time: Instant,
+) -> Vector3 {
+    if let Some(velocity) = velocity {
+        return velocity;
+    }
+
+    match last {
+        Some((last, last_time)) => {
+            let delta = time.duration_since(last_time).as_secs_f32();
+
+            if delta > 0.0 {
+                (point - last) / delta
+            } else {
+                Vector3::zero()
+            }
+        }
+        None => Vector3::zero(),
+    }
+}
+
+#[derive(Clone, Copy, Deserialize)]
+#[serde(default)]
+struct SpatialOption {
+    point: Vector3,
+    velocity: Option<Vector3>,
+    kind: i32,
+    distance_min: f32,
+    distance_max: f32,
+    rolloff: f32,
+}
+
+impl Default for SpatialOption {
+    fn default() -> Self {
+        Self {
+            point: Vector3::zero(),
+            velocity: None,
+            kind: SpatialSource::KIND_INVERSE,
+            distance_min: 1.0,
+            distance_max: 100.0,
+            rolloff: 1.0,
+        }
+    }
+}
+
+// the state of a spatial source, shared between the main thread and the audio thread.
+struct SpatialSource {
+    stream: RLAudioStream,
+    option: SpatialOption,
+    velocity: Vector3,
+    time: Instant,
+    // the pitch before any doppler shift.
+    pitch: f32,
+    // the last gain and pan, to smoothly move from.
+    last: Option<(f32, f32)>,
+}
+
+unsafe impl Send for SpatialSource {}
+
+impl SpatialSource {
+    const KIND_LINEAR: i32 = 0;
+    const KIND_INVERSE: i32 = 1;
+    const KIND_EXPONENTIAL: i32 = 2;
+
+    // get the distance attenuation gain, and the pan (-1.0 for left, 1.0 for right).
+    fn get_gain_pan(&self, listener: &Listener) -> (f32, f32) {
+        let option = &self.option;
+        let delta = option.point - listener.point;
+        let distance = delta.length();
+        let minimum = option.distance_min.max(0.0001);
+        let maximum = option.distance_max.max(minimum);
+        let clamp = distance.clamp(minimum, maximum);
+
+        let gain = match option.kind {
+            Self::KIND_LINEAR => {
+                1.0 - option.rolloff * (clamp - minimum) / (maximum - minimum).max(0.0001)
+            }
+            Self::KIND_EXPONENTIAL => (clamp / minimum).powf(-option.rolloff),
+            _ => minimum / (minimum + option.rolloff * (clamp - minimum)),
+        };
+
+        let side = listener.front.cross(listener.up).normalized();
+        let pan = if distance > 0.0001 {
+            (delta / distance).dot(side)
+        } else {
+            0.0
+        };
+
+        (gain.clamp(0.0, 1.0), pan.clamp(-1.0, 1.0))
+    }
+
+    // get the doppler pitch shift, from the velocity of the source and of the listener.
+    fn get_doppler(&self, listener: &Listener) -> f32 {
+        let (factor, speed) = *DOPPLER.lock().unwrap();
+        let delta = listener.point - self.option.point;
+        let distance = delta.length();
+
+        if factor <= 0.0 || distance <= 0.0001 {
+            return 1.0;
+        }
+
+        let direction = delta / distance;
+        let limit = speed * 0.9 / factor;
+        let velocity_listener = listener.velocity.dot(direction).clamp(-limit, limit) * factor;
+        let velocity_source = self.velocity.dot(direction).clamp(-limit, limit) * factor;
+
+        ((speed - velocity_listener) / (speed - velocity_source)).clamp(0.5, 2.0)
+    }
+
+    // get the pitch with the doppler shift applied. the pitch must be set with the source unlocked, as the audio thread will lock the source from within raylib's own audio lock.
+    fn get_pitch(&self, listener: &Listener) -> f32 {
+        self.pitch * self.get_doppler(listener)
+    }
+}
+
+fn set_stream_pitch(stream: RLAudioStream, pitch: f32) {
+    unsafe {
+        ffi::SetAudioStreamPitch(stream, pitch);
+    }
+}
+
+impl AudioProcess for SpatialSource {
+    fn process(&mut self, buffer: &mut [f32]) {
+        let (gain, pan) = self.get_gain_pan(&Listener::get());
+        let (last_gain, last_pan) = self.last.unwrap_or((gain, pan));
+        let length = (buffer.len() / 2).max(1) as f32;
+
+        self.last = Some((gain, pan));
+
+        // down-mix to mono, then pan with an equal-power law, moving from the last gain and pan over the buffer.
+        for (i, frame) in buffer.chunks_exact_mut(2).enumerate() {
+            let time = (i + 1) as f32 / length;
+            let gain = last_gain + (gain - last_gain) * time;
+            let pan = last_pan + (pan - last_pan) * time;
+            let angle = (pan + 1.0) * std::f32::consts::FRAC_PI_4;
+            let value = (frame[0] + frame[1]) * 0.5 * gain * std::f32::consts::SQRT_2;
+
+            frame[0] = value * angle.cos();
+            frame[1] = value * angle.sin();
+        }
+    }
+}
+
+// the spatial state of the audio stream of a sound (or sound alias), music or audio stream.
+pub struct AudioSpatial {
+    source: Arc<Mutex<SpatialSource>>,
+    stream: RLAudioStream,
+    slot: usize,
+}
+
+unsafe impl Send for AudioSpatial {}
+
+impl AudioSpatial {
+    pub fn new(
+        lua: &Lua,
+        call: &str,
+        stream: RLAudioStream,
+        option: LuaValue,
+    ) -> mlua::Result<Self> {
+        let option: SpatialOption = lua.from_value(option)?;
+
+        if !(SpatialSource::KIND_LINEAR..=SpatialSource::KIND_EXPONENTIAL).contains(&option.kind) {
+            return Err(mlua::Error::runtime(format!(
+                "{call}(): Unknown attenuation kind."
+            )));
+        }
+
+        let source = Arc::new(Mutex::new(SpatialSource {
+            stream,
+            option,
+            velocity: option.velocity.unwrap_or(Vector3::zero()),
+            time: Instant::now(),
+            pitch: 1.0,
+            last: None,
+        }));
+
+        let pitch = source.lock().unwrap().get_pitch(&Listener::get());
+
+        set_stream_pitch(stream, pitch);
+
+        let slot = set_processor(call, stream, source.clone())?;
+
+        SPATIAL_LIST.lock().unwrap().push(Arc::downgrade(&source));
+
+        Ok(Self {
+            source,
+            stream,
+            slot,
+        })
+    }
+
+    pub fn is_stream(&self, stream: &RLAudioStream) -> bool {
+        self.stream.buffer == stream.buffer
+    }
+
+    pub fn set_point(
+        &self,
+        lua: &Lua,
+        point: LuaValue,
+        velocity: Option<LuaValue>,
+    ) -> mlua::Result<()> {
+        let point: Vector3 = lua.from_value(point)?;
+        let velocity: Option<Vector3> = match velocity {
+            Some(velocity) => Some(lua.from_value(velocity)?),
+            None => None,
+        };
+        let time = Instant::now();
+
+        let pitch = {
+            let mut source = self.source.lock().unwrap();
+
+            source.velocity = get_velocity(
+                point,
+                velocity,
+                Some((source.option.point, source.time)),
+                time,
+            );
+            source.option.point = point;
+            source.time = time;
+            source.get_pitch(&Listener::get())
+        };
+
+        set_stream_pitch(self.stream, pitch);
+
+        Ok(())
+    }
+
+    pub fn set_pitch(&self, pitch: f32) {
+        let pitch = {
+            let mut source = self.source.lock().unwrap();
+
+            source.pitch = pitch;
+            source.get_pitch(&Listener::get())
+        };
+
+        set_stream_pitch(self.stream, pitch);
+    }
+}
+
+impl Drop for AudioSpatial {
+    fn drop(&mut self) {
+        remove_processor(self.stream, self.slot);
+
+        let pitch = self.source.lock().unwrap().pitch;
+
+        set_stream_pitch(self.stream, pitch);
+    }
+}
+
+/* entry
+{
+    "version": "1.0.0",
+    "name": "quiver.audio.set_listener",
+    "info": "Set the listener, for any spatial sound, music or audio stream. The distance attenuation and pan of every spatial source will be updated automatically, and so will the doppler pitch shift.",
+    "member": [
+        { "name": "point",    "info": "The point of the listener.",                                                          "kind": "vector_3"  },
+        { "name": "focus",    "info": "The point the listener is looking at.",                                               "kind": "vector_3"  },
+        { "name": "up",       "info": "The up direction of the listener.",                                                   "kind": "vector_3"  },
+        { "name": "velocity", "info": "OPTIONAL: The velocity of the listener. Default: derived from the last listener point.", "kind": "vector_3?" }
+ ],
+    "test": "audio/spatial.lua"
+}
+*/
+fn set_listener(
+    lua: &Lua,
+    (point, focus, up, velocity): (LuaValue, LuaValue, LuaValue, Option<LuaValue>),
+) -> mlua::Result<()> {
+    let point: Vector3 = lua.from_value(point)?;
+    let focus: Vector3 = lua.from_value(focus)?;
+    let up: Vector3 = lua.from_value(up)?;
+    let velocity: Option<Vector3> = match velocity {
+        Some(velocity) => Some(lua.from_value(velocity)?),
+        None => None,
+    };
+    let time = Instant::now();
+
+    let listener = {
+        let mut listener = LISTENER.lock().unwrap();
+        let last = listener.map(|x| (x.point, x.time));
+        let value = Listener {
+            point,
+            front: (focus - point).normalized(),
+            up: up.normalized(),
+            velocity: get_velocity(point, velocity, last, time),
+            time,
+        };
+
+        *listener = Some(value);
+        value
+    };
+
+    let list: Vec<_> = {
+        let mut list = SPATIAL_LIST.lock().unwrap();
+
+        list.retain(|x| x.strong_count() > 0);
+        list.iter().filter_map(|x| x.upgrade()).collect()
+    };
+
+    for source in list {
+        let (stream, pitch) = {
+            let source = source.lock().unwrap();
+            (source.stream, source.get_pitch(&listener))
+        };
+
+        set_stream_pitch(stream, pitch);
+    }
+
+    Ok(())
+}
+
+/* entry
+{
+    "version": "1.0.0",
+    "name": "quiver.audio.set_doppler",
+    "info": "Set the doppler pitch shift of every spatial source.",
+    "member": [
+        { "name": "factor", "info": "The doppler factor. Use 0.0 to disable doppler. Default: 1.0.",         "kind": "number"  },
+        { "name": "speed",  "info": "OPTIONAL: The speed of sound, in unit per second. Default: 343.0.", "kind": "number?" }
+    ]
+}
+*/
+fn set_doppler(_: &Lua, (factor, speed): (f32, Option<f32>)) -> mlua::Result<()> {
+    *DOPPLER.lock().unwrap() = (factor.max(0.0), speed.unwrap_or(343.0).max(0.0001));
+
+    Ok(())
+}
+
+//================================================================
+
+// the sample count kept by an audio tap, enough for the largest spectrum size.
+const TAP_CAPACITY: usize = 16384;
+
+// the last sample played, down-mixed to mono.
+struct TapSource {
+    data: VecDeque<f32>,
+}
+
+impl AudioProcess for TapSource {
+    fn process(&mut self, buffer: &mut [f32]) {
+        self.data
+            .extend(buffer.chunks_exact(2).map(|x| (x[0] + x[1]) * 0.5));
+
+        if self.data.len() > TAP_CAPACITY {
+            let length = self.data.len() - TAP_CAPACITY;
+            self.data.drain(..length);
+        }
+    }
+}
+
+// a tap on the audio stream of a sound, music or audio stream, for analysis.
+pub struct AudioTap {
+    source: Arc<Mutex<TapSource>>,
+    stream: RLAudioStream,
+    slot: usize,
+}
+
+unsafe impl Send for AudioTap {}
+
+impl AudioTap {
+    pub fn new(call: &str, stream: RLAudioStream) -> mlua::Result<Self> {
+        let source = Arc::new(Mutex::new(TapSource {
+            data: VecDeque::with_capacity(TAP_CAPACITY),
+        }));
+        let slot = set_processor(call, stream, source.clone())?;
+
+        Ok(Self {
+            source,
+            stream,
+            slot,
+        })
+    }
+
+    // get the last sample played, with silence for any sample yet to be played.
+    pub fn get_sample(&self, size: usize) -> Vec<f32> {
+        let source = self.source.lock().unwrap();
+        let length = size.min(source.data.len());
+        let mut data = vec![0.0; size - length];
+
+        data.extend(source.data.range(source.data.len() - length..));
+
+        data
+    }
+}
+
+impl Drop for AudioTap {
+    fn drop(&mut self) {
+        remove_processor(self.stream, self.slot);
+    }
+}
+
+//================================================================
+
+// DSP effect, for an interleaved stereo buffer.
+mod effect {
+    use mlua::prelude::*;
+    use serde::Deserialize;
+
+    pub const LOW_PASS: i32 = 0;
+    pub const HIGH_PASS: i32 = 1;
+    pub const ECHO: i32 = 2;
+    pub const REVERB: i32 = 3;
+    pub const COMPRESSOR: i32 = 4;
+
+    #[derive(Clone, Copy, Deserialize)]
+    #[serde(default)]
+    pub struct EffectOption {
+        cutoff: f32,
+        resonance: f32,
+        delay: f32,
+        feedback: f32,
+        mix: f32,
+        room: f32,
+        damp: f32,
+        threshold: f32,
+        ratio: f32,
+        attack: f32,
+        release: f32,
+        gain: f32,
+    }
+
+    impl EffectOption {
+        // the longest echo delay, in second, as the delay line is allocated on the audio thread.
+        const DELAY_MAX: f32 = 10.0;
+
+        pub fn new(lua: &Lua, option: Option<LuaValue>) -> mlua::Result<Self> {
+            let mut option: Self = match option {
+                Some(option) => lua.from_value(option)?,
+                None => Self::default(),
+            };
+
+            option.delay = option.delay.clamp(0.0, Self::DELAY_MAX);
+
+            Ok(option)
+        }
+    }
+
+    impl Default for EffectOption {
+        fn default() -> Self {
+            Self {
+                cutoff: 1000.0,
+                resonance: std::f32::consts::FRAC_1_SQRT_2,
+                delay: 0.25,
+                feedback: 0.5,
+                mix: 0.3,
+                room: 0.5,
+                damp: 0.5,
+                threshold: -12.0,
+                ratio: 4.0,
+                attack: 0.01,
+                release: 0.1,
+                gain: 0.0,
+            }
+        }
+    }
+
+    pub enum Effect {
+        Filter(Filter),
+        Echo(Echo),
+        Reverb(Reverb),
+        Compressor(Compressor),
+    }
+
+    impl Effect {
+        pub fn new(kind: i32, option: &EffectOption, rate: f32) -> Self {
+            match kind {
+                LOW_PASS | HIGH_PASS => Self::Filter(Filter::new(kind, option, rate)),
+                ECHO => Self::Echo(Echo::new(option, rate)),
+                REVERB => Self::Reverb(Reverb::new(option, rate)),
+                _ => Self::Compressor(Compressor::new(option, rate)),
+            }
+        }
+
+        // update the option of an effect in place, keeping any state (delay line, etc.) where possible.
+        pub fn set_option(&mut self, kind: i32, option: &EffectOption, rate: f32) {
+            match self {
+                Self::Filter(filter) => filter.set_option(kind, option, rate),
+                Self::Echo(echo) => echo.set_option(option, rate),
+                Self::Reverb(reverb) => reverb.set_option(option),
+                Self::Compressor(compressor) => compressor.set_option(option, rate),
+            }
+        }
+
+        pub fn process(&mut self, buffer: &mut [f32]) {
+            match self {
+                Self::Filter(filter) => filter.process(buffer),
+                Self::Echo(echo) => echo.process(buffer),
+                Self::Reverb(reverb) => reverb.process(buffer),
+                Self::Compressor(compressor) => compressor.process(buffer),
+            }
+        }
+    }
+
+    //================================================================
+
+    // a RBJ bi-quad filter.
+    pub struct Filter {
+        coefficient: [f32; 5],
+        // the last two input and output, for each channel.
+        state: [[f32; 4]; 2],
+    }
+
+    impl Filter {
+        fn new(kind: i32, option: &EffectOption, rate: f32) -> Self {
+            let mut filter = Self {
+                coefficient: [0.0; 5],
+                state: [[0.0; 4]; 2],
+            };
+
+            filter.set_option(kind, option, rate);
+            filter
+        }
+
+        fn set_option(&mut self, kind: i32, option: &EffectOption, rate: f32) {
+            let cutoff = option.cutoff.clamp(10.0, rate * 0.49);
+            let w = std::f32::consts::TAU * cutoff / rate;
+            let alpha = w.sin() / (2.0 * option.resonance.max(0.01));
+            let cos = w.cos();
+
+            let (b0, b1) = if kind == LOW_PASS {
+                ((1.0 - cos) * 0.5, 1.0 - cos)
+            } else {
+                ((1.0 + cos) * 0.5, -(1.0 + cos))
+            };
+            let a0 = 1.0 + alpha;
+
+            self.coefficient = [
+                b0 / a0,
+                b1 / a0,
+                b0 / a0,
+                -2.0 * cos / a0,
+                (1.0 - alpha) / a0,
+            ];
+        }
+
+        fn process(&mut self, buffer: &mut [f32]) {
+            let [b0, b1, b2, a1, a2] = self.coefficient;
+
+            for frame in buffer.chunks_exact_mut(2) {
+                for (value, [x1, x2, y1, y2]) in frame.iter_mut().zip(self.state.iter_mut()) {
+                    let x = *value;
+                    let y = b0 * x + b1 * *x1 + b2 * *x2 - a1 * *y1 - a2 * *y2;
+
+                    *x2 = *x1;
+                    *x1 = x;
+                    *y2 = *y1;
+                    *y1 = y;
+                    *value = y;
+                }
+            }
+        }
+    }
+
+    //================================================================
+
+    pub struct Echo {
+        buffer: Vec<[f32; 2]>,
+        index: usize,
+        feedback: f32,
+        mix: f32,
+    }
+
+    impl Echo {
+        fn new(option: &EffectOption, rate: f32) -> Self {
+            let mut echo = Self {
+                buffer: Vec::new(),
+                index: 0,
+                feedback: 0.0,
+                mix: 0.0,
+            };
+
+            echo.set_option(option, rate);
+            echo
+        }
+
+        fn set_option(&mut self, option: &EffectOption, rate: f32) {
+            let length = ((option.delay.max(0.001) * rate) as usize).max(1);
+
+            if length != self.buffer.len() {
+                self.buffer = vec![[0.0; 2]; length];
+                self.index = 0;
+            }
+
+            self.feedback = option.feedback.clamp(0.0, 0.99);
+            self.mix = option.mix;
+        }
+
+        fn process(&mut self, buffer: &mut [f32]) {
+            for frame in buffer.chunks_exact_mut(2) {
+                let delay = &mut self.buffer[self.index];
+
+                for (value, delay) in frame.iter_mut().zip(delay.iter_mut()) {
+                    let input = *value;
+
+                    *value = input + *delay * self.mix;
+                    *delay = input + *delay * self.feedback;
+                }
+
+                self.index = (self.index + 1) % self.buffer.len();
+            }
+        }
+    }
+
+    //================================================================
+
+    // a Freeverb-style reverb: eight parallel comb filter, into four series all-pass filter, for each channel.
+    pub struct Reverb {
+        comb: [Vec<Comb>; 2],
+        pass: [Vec<Pass>; 2],
+        mix: f32,
+    }
+
+    struct Comb {
+        buffer: Vec<f32>,
+        index: usize,
+        store: f32,
+        feedback: f32,
+        damp: f32,
+    }
+
+    struct Pass {
+        buffer: Vec<f32>,
+        index: usize,
+    }
+
+    impl Reverb {
+        // the delay line length, in sample at 44100 Hz, and the extra length for the right channel.
+        const COMB: [usize; 8] = [1116, 1188, 1277, 1356, 1422, 1491, 1557, 1617];
+        const PASS: [usize; 4] = [556, 441, 341, 225];
+        const SPREAD: usize = 23;
+
+        fn new(option: &EffectOption, rate: f32) -> Self {
+            let scale = rate / 44100.0;
+            let get_length = |length: usize, channel: usize| {
+                (((length + channel * Self::SPREAD) as f32 * scale) as usize).max(1)
+            };
+
+            let mut reverb = Self {
+                comb: [0, 1].map(|channel| {
+                    Self::COMB
+                        .iter()
+                        .map(|x| Comb {
+                            buffer: vec![0.0; get_length(*x, channel)],
+                            index: 0,
+                            store: 0.0,
+                            feedback: 0.0,
+                            damp: 0.0,
+                        })
+                        .collect()
+                }),
+                pass: [0, 1].map(|channel| {
+                    Self::PASS
+                        .iter()
+                        .map(|x| Pass {
+                            buffer: vec![0.0; get_length(*x, channel)],
+                            index: 0,
+                        })
+                        .collect()
+                }),
+                mix: 0.0,
+            };
+
+            reverb.set_option(option);
+            reverb
+        }
+
+        fn set_option(&mut self, option: &EffectOption) {
+            for comb in self.comb.iter_mut().flatten() {
+                comb.feedback = 0.7 + option.room.clamp(0.0, 1.0) * 0.28;
+                comb.damp = option.damp.clamp(0.0, 1.0) * 0.4;
+            }
+
+            self.mix = option.mix;
+        }
+
+        fn process(&mut self, buffer: &mut [f32]) {
+            for frame in buffer.chunks_exact_mut(2) {
+                let input = (frame[0] + frame[1]) * 0.015;
+
+                for (channel, value) in frame.iter_mut().enumerate() {
+                    let mut wet = 0.0;
+
+                    for comb in &mut self.comb[channel] {
+                        let output = comb.buffer[comb.index];
+
+                        comb.store = output * (1.0 - comb.damp) + comb.store * comb.damp;
+                        comb.buffer[comb.index] = input + comb.store * comb.feedback;
+                        comb.index = (comb.index + 1) % comb.buffer.len();
+
+                        wet += output;
+                    }
+
+                    for pass in &mut self.pass[channel] {
+                        let output = pass.buffer[pass.index];
+
+                        pass.buffer[pass.index] = wet + output * 0.5;
+                        pass.index = (pass.index + 1) % pass.buffer.len();
+
+                        wet = output - wet;
+                    }
+
+                    *value = *value * (1.0 - self.mix) + wet * self.mix * 3.0;
+                }
+            }
+        }
+    }
+
+    //================================================================
+
+    pub struct Compressor {
+        threshold: f32,
+        ratio: f32,
+        attack: f32,
+        release: f32,
+        gain: f32,
+        envelope: f32,
+    }
+
+    impl Compressor {
+        fn new(option: &EffectOption, rate: f32) -> Self {
+            let mut compressor = Self {
+                threshold: 0.0,
+                ratio: 1.0,
+                attack: 0.0,
+                release: 0.0,
+                gain: 1.0,
+                envelope: 0.0,
+            };
+
+            compressor.set_option(option, rate);
+            compressor
+        }
+
+        fn set_option(&mut self, option: &EffectOption, rate: f32) {
+            let get_time = |time: f32| (-1.0 / (time.max(0.0001) * rate)).exp();
+
+            self.threshold = option.threshold;
+            self.ratio = option.ratio.max(1.0);
+            self.attack = get_time(option.attack);
+            self.release = get_time(option.release);
+            self.gain = 10.0_f32.powf(option.gain / 20.0);
+        }
+
+        fn process(&mut self, buffer: &mut [f32]) {
+            for frame in buffer.chunks_exact_mut(2) {
+                let level = frame[0].abs().max(frame[1].abs());
+                let time = if level > self.envelope {
+                    self.attack
+                } else {
+                    self.release
+                };
+
+                self.envelope = level + (self.envelope - level) * time;
+
+                // the gain reduction, in dB, for any level over the threshold.
+                let over = 20.0 * self.envelope.max(1e-6).log10() - self.threshold;
+                let reduce = if over > 0.0 {
+                    -over * (1.0 - 1.0 / self.ratio)
+                } else {
+                    0.0
+                };
+                let gain = 10.0_f32.powf(reduce / 20.0) * self.gain;
+
+                frame[0] *= gain;
+                frame[1] *= gain;
+            }
+        }
+    }
+}
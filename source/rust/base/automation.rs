@@ -48,6 +48,7 @@
 * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 */
 
+use crate::sandbox::*;
 use crate::script::*;
 use crate::status::*;
 
@@ -55,17 +56,20 @@ use crate::status::*;
 
 use mlua::prelude::*;
 use raylib::prelude::*;
+use serde::{Deserialize, Serialize};
 
 //================================================================
 
 /* class
-{ "version": "1.0.0", "name": "quiver.automation", "info": "The automation API." }
+{ "version": "1.0.0", "name": "quiver.automation", "info": "The automation API.", "head": true }
 */
 #[rustfmt::skip]
 pub fn set_global(lua: &Lua, table: &mlua::Table, _: &StatusInfo, _: Option<&ScriptInfo>) -> mlua::Result<()> {
     let automation = lua.create_table()?;
 
-    automation.set("new", lua.create_function(self::AutomationEvent::new)?)?;
+    automation.set("new",         lua.create_function(self::AutomationEvent::new)?)?;
+    automation.set("new_replay",  lua.create_function(self::Replay::new)?)?;
+    automation.set("load_replay", lua.create_function(self::Replay::load)?)?;
 
     table.set("automation", automation)?;
 
@@ -86,14 +90,24 @@ impl AutomationEvent {
     {
         "version": "1.0.0",
         "name": "quiver.automation.new",
-        "info": "TO-DO"
+        "info": "Create a new automation event list. For input recording and play-back with a fixed frame time and random seed, see quiver.automation.new_replay.",
+        "member": [
+            { "name": "path", "info": "OPTIONAL: Path to an automation event list file to load. If nil, will create an empty list.", "kind": "string?" }
+        ],
+        "result": [
+            { "name": "automation_event", "info": "The automation event list.", "kind": "automation_event" }
+        ]
     }
     */
     fn new(lua: &Lua, path: Option<String>) -> mlua::Result<Self> {
         unsafe {
             let path = match path {
                 Some(name) => {
-                    let pointer = Script::rust_to_c_string(&ScriptData::get_path(lua, &name)?)?;
+                    let pointer = Script::rust_to_c_string(&ScriptData::get_path(
+                        lua,
+                        &name,
+                        SandboxAccess::Read,
+                    )?)?;
 
                     pointer.into_raw()
                 }
@@ -125,11 +139,15 @@ impl mlua::UserData for AutomationEvent {
         {
             "version": "1.0.0",
             "name": "automation_event:save",
-            "info": "TO-DO"
+            "info": "Save the automation event list to a file.",
+            "member": [
+                { "name": "path", "info": "Path to the file.", "kind": "string" }
+            ]
         }
         */
-        method.add_method_mut("save", |_: &Lua, this, path: String| unsafe {
-            let path = Script::rust_to_c_string(&path)?;
+        method.add_method_mut("save", |lua: &Lua, this, path: String| unsafe {
+            let path =
+                Script::rust_to_c_string(&ScriptData::get_path(lua, &path, SandboxAccess::Write)?)?;
 
             ffi::ExportAutomationEventList(this.0, path.as_ptr());
 
@@ -140,7 +158,7 @@ impl mlua::UserData for AutomationEvent {
         {
             "version": "1.0.0",
             "name": "automation_event:set_active",
-            "info": "TO-DO"
+            "info": "Set the automation event list as the one to record to."
         }
         */
         method.add_method_mut("set_active", |_: &Lua, this, _: ()| unsafe {
@@ -152,7 +170,10 @@ impl mlua::UserData for AutomationEvent {
         {
             "version": "1.0.0",
             "name": "automation_event:set_frame",
-            "info": "TO-DO"
+            "info": "Set the base frame for recording, which is the frame counter every event frame will be relative to.",
+            "member": [
+                { "name": "frame", "info": "The base frame.", "kind": "number" }
+            ]
         }
         */
         method.add_method("set_frame", |_: &Lua, _, frame: i32| unsafe {
@@ -164,7 +185,7 @@ impl mlua::UserData for AutomationEvent {
         {
             "version": "1.0.0",
             "name": "automation_event:start",
-            "info": "TO-DO"
+            "info": "Start recording to the active automation event list."
         }
         */
         method.add_method("start", |_: &Lua, _, _: ()| unsafe {
@@ -176,7 +197,7 @@ impl mlua::UserData for AutomationEvent {
         {
             "version": "1.0.0",
             "name": "automation_event:stop",
-            "info": "TO-DO"
+            "info": "Stop recording to the active automation event list."
         }
         */
         method.add_method("stop", |_: &Lua, _, _: ()| unsafe {
@@ -188,7 +209,10 @@ impl mlua::UserData for AutomationEvent {
         {
             "version": "1.0.0",
             "name": "automation_event:play",
-            "info": "TO-DO"
+            "info": "Play an event from the automation event list.",
+            "member": [
+                { "name": "index", "info": "The index of the event.", "kind": "number" }
+            ]
         }
         */
         method.add_method("play", |_: &Lua, this, frame: u32| unsafe {
@@ -207,7 +231,15 @@ impl mlua::UserData for AutomationEvent {
         {
             "version": "1.0.0",
             "name": "automation_event:get_event",
-            "info": "TO-DO"
+            "info": "Get an event from the automation event list.",
+            "member": [
+                { "name": "index", "info": "The index of the event.", "kind": "number" }
+            ],
+            "result": [
+                { "name": "frame",     "info": "The frame of the event.",     "kind": "number" },
+                { "name": "kind",      "info": "The kind of the event.",      "kind": "number" },
+                { "name": "parameter", "info": "The parameters of the event.", "kind": "table"  }
+            ]
         }
         */
         method.add_method("get_event", |_: &Lua, this, frame: u32| unsafe {
@@ -222,3 +254,340 @@ impl mlua::UserData for AutomationEvent {
         });
     }
 }
+
+//================================================================
+
+#[derive(Serialize, Deserialize, Clone, Copy)]
+struct ReplayEvent {
+    frame: u32,
+    kind: u32,
+    parameter: [i32; 4],
+}
+
+// the active replay, recording or playing. kept in the Lua app data so that the main loop can advance it.
+struct ReplayState {
+    list: Option<ffi::AutomationEventList>,
+    event: Vec<ReplayEvent>,
+    index: usize,
+    frame: u32,
+    frame_time: f32,
+}
+
+unsafe impl Send for ReplayState {}
+
+impl ReplayState {
+    // get the list of every recorded event, and free the RL automation event list.
+    fn take(&mut self) -> Vec<ReplayEvent> {
+        if let Some(list) = self.list.take() {
+            unsafe {
+                ffi::StopAutomationEventRecording();
+
+                let event = (0..list.count as usize)
+                    .map(|x| {
+                        let event = *list.events.wrapping_add(x);
+
+                        ReplayEvent {
+                            frame: event.frame,
+                            kind: event.type_,
+                            parameter: event.params,
+                        }
+                    })
+                    .collect();
+
+                ffi::UnloadAutomationEventList(list);
+
+                event
+            }
+        } else {
+            Vec::new()
+        }
+    }
+}
+
+impl Drop for ReplayState {
+    fn drop(&mut self) {
+        self.take();
+    }
+}
+
+/* class
+{
+    "version": "1.0.0",
+    "name": "replay",
+    "info": "A replay, for deterministic input recording and play-back. A replay will keep the random seed, as well as a fixed frame time that quiver.general.get_frame_time will give back while the replay is active. Play-back is done automatically, at the end of every quiver.draw.begin call.",
+    "test": "automation/replay.lua",
+    "head": true
+}
+*/
+#[derive(Serialize, Deserialize)]
+pub struct Replay {
+    seed: u32,
+    frame_time: f32,
+    event: Vec<ReplayEvent>,
+}
+
+impl Replay {
+    /* entry
+    {
+        "version": "1.0.0",
+        "name": "quiver.automation.new_replay",
+        "info": "Create a new, empty replay.",
+        "member": [
+            { "name": "seed",       "info": "OPTIONAL: The random seed. If nil, will use the current time.",                       "kind": "number?" },
+            { "name": "frame_time", "info": "OPTIONAL: The fixed frame time. If nil, will use the frame-rate from the info manifest.", "kind": "number?" }
+        ],
+        "result": [
+            { "name": "replay", "info": "The replay.", "kind": "replay" }
+        ],
+        "head": true
+    }
+    */
+    fn new(lua: &Lua, (seed, frame_time): (Option<u32>, Option<f32>)) -> mlua::Result<Self> {
+        let seed = seed.unwrap_or_else(|| {
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map(|x| x.as_secs() as u32)
+                .unwrap_or_default()
+        });
+
+        let frame_time = frame_time.unwrap_or_else(|| {
+            let script_data = lua.app_data_ref::<ScriptData>().unwrap();
+
+            1.0 / script_data.script_info.rate.max(1) as f32
+        });
+
+        Ok(Self {
+            seed,
+            frame_time,
+            event: Vec::new(),
+        })
+    }
+
+    /* entry
+    {
+        "version": "1.0.0",
+        "name": "quiver.automation.load_replay",
+        "info": "Load a replay from a file.",
+        "member": [
+            { "name": "path", "info": "Path to the replay file.", "kind": "string" }
+        ],
+        "result": [
+            { "name": "replay", "info": "The replay.", "kind": "replay" }
+        ],
+        "head": true
+    }
+    */
+    fn load(lua: &Lua, path: String) -> mlua::Result<Self> {
+        let path = ScriptData::get_path(lua, &path, SandboxAccess::Read)?;
+        let data = std::fs::read_to_string(&path).map_err(|e| {
+            mlua::Error::runtime(format!(
+                "quiver.automation.load_replay(): Could not read file \"{path}\": {e}"
+            ))
+        })?;
+
+        serde_json::from_str(&data).map_err(|e| {
+            mlua::Error::runtime(format!(
+                "quiver.automation.load_replay(): Could not parse file \"{path}\": {e}"
+            ))
+        })
+    }
+
+    // set the random seed for every random source.
+    fn set_seed(&self, lua: &Lua) -> mlua::Result<()> {
+        unsafe {
+            ffi::SetRandomSeed(self.seed);
+        }
+
+        let math: mlua::Table = lua.globals().get("math")?;
+        let seed: mlua::Function = math.get("randomseed")?;
+        seed.call::<()>(self.seed)?;
+
+        Ok(())
+    }
+
+    fn set_state(lua: &Lua, state: Option<ReplayState>) {
+        // drop any replay that is still active.
+        lua.remove_app_data::<ReplayState>();
+
+        if let Some(state) = state {
+            lua.set_app_data(state);
+        }
+    }
+
+    // get the fixed frame time of the active replay, if any.
+    pub fn get_frame_time(lua: &Lua) -> Option<f32> {
+        lua.app_data_ref::<ReplayState>().map(|x| x.frame_time)
+    }
+
+    // advance the active replay by one frame. called at the end of every frame.
+    pub fn update(lua: &Lua) {
+        if let Some(mut state) = lua.app_data_mut::<ReplayState>() {
+            if state.list.is_none() {
+                while let Some(event) = state.event.get(state.index).copied() {
+                    if event.frame > state.frame {
+                        break;
+                    }
+
+                    unsafe {
+                        ffi::PlayAutomationEvent(ffi::AutomationEvent {
+                            frame: event.frame,
+                            type_: event.kind,
+                            params: event.parameter,
+                        });
+                    }
+
+                    state.index += 1;
+                }
+            }
+
+            state.frame += 1;
+        }
+    }
+}
+
+impl mlua::UserData for Replay {
+    fn add_fields<F: mlua::UserDataFields<Self>>(field: &mut F) {
+        field.add_field_method_get("seed", |_, this| Ok(this.seed));
+        field.add_field_method_get("frame_time", |_, this| Ok(this.frame_time));
+        field.add_field_method_get("count", |_, this| Ok(this.event.len()));
+    }
+
+    fn add_methods<M: mlua::UserDataMethods<Self>>(method: &mut M) {
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "replay:record",
+            "info": "Start recording input to the replay. Any previous input in the replay will be lost. Will set the random seed, and the fixed frame time.",
+            "head": true
+        }
+        */
+        method.add_method_mut("record", |lua: &Lua, this, _: ()| {
+            this.set_seed(lua)?;
+            this.event.clear();
+
+            Self::set_state(
+                lua,
+                Some(ReplayState {
+                    list: Some(unsafe { ffi::LoadAutomationEventList(std::ptr::null()) }),
+                    event: Vec::new(),
+                    index: 0,
+                    frame: 0,
+                    frame_time: this.frame_time,
+                }),
+            );
+
+            // RL will hold a pointer to the list while recording, so it must point to the one in the app data.
+            let mut state = lua.app_data_mut::<ReplayState>().unwrap();
+
+            if let Some(list) = state.list.as_mut() {
+                unsafe {
+                    ffi::SetAutomationEventList(list);
+                    ffi::SetAutomationEventBaseFrame(0);
+                    ffi::StartAutomationEventRecording();
+                }
+            }
+
+            Ok(())
+        });
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "replay:play",
+            "info": "Start playing the replay back. Will set the random seed, and the fixed frame time.",
+            "head": true
+        }
+        */
+        method.add_method_mut("play", |lua: &Lua, this, _: ()| {
+            this.set_seed(lua)?;
+
+            Self::set_state(
+                lua,
+                Some(ReplayState {
+                    list: None,
+                    event: this.event.clone(),
+                    index: 0,
+                    frame: 0,
+                    frame_time: this.frame_time,
+                }),
+            );
+
+            Ok(())
+        });
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "replay:stop",
+            "info": "Stop recording, or playing, the replay. Recorded input will be kept in the replay.",
+            "head": true
+        }
+        */
+        method.add_method_mut("stop", |lua: &Lua, this, _: ()| {
+            if let Some(mut state) = lua.remove_app_data::<ReplayState>() {
+                if state.list.is_some() {
+                    this.event = state.take();
+                }
+            }
+
+            Ok(())
+        });
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "replay:get_play",
+            "info": "Check if the replay is still playing back, with input left to play.",
+            "result": [
+                { "name": "play", "info": "True if playing, with input left to play.", "kind": "boolean" }
+            ],
+            "head": true
+        }
+        */
+        method.add_method("get_play", |lua: &Lua, _, _: ()| {
+            if let Some(state) = lua.app_data_ref::<ReplayState>() {
+                Ok(state.list.is_none() && state.index < state.event.len())
+            } else {
+                Ok(false)
+            }
+        });
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "replay:get_frame",
+            "info": "Get the current frame of the active replay.",
+            "result": [
+                { "name": "frame", "info": "The current frame, or nil if no replay is active.", "kind": "number?" }
+            ],
+            "head": true
+        }
+        */
+        method.add_method("get_frame", |lua: &Lua, _, _: ()| {
+            Ok(lua.app_data_ref::<ReplayState>().map(|x| x.frame))
+        });
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "replay:save",
+            "info": "Save the replay to a file, with the random seed and the fixed frame time.",
+            "member": [
+                { "name": "path", "info": "Path to the replay file.", "kind": "string" }
+            ],
+            "head": true
+        }
+        */
+        method.add_method("save", |lua: &Lua, this, path: String| {
+            let path = ScriptData::get_path(lua, &path, SandboxAccess::Write)?;
+            let data =
+                serde_json::to_string(this).map_err(|e| mlua::Error::runtime(e.to_string()))?;
+
+            std::fs::write(&path, data).map_err(|e| {
+                mlua::Error::runtime(format!(
+                    "replay:save(): Could not write file \"{path}\": {e}"
+                ))
+            })
+        });
+    }
+}
@@ -66,6 +66,7 @@ pub fn set_global(lua: &Lua, table: &mlua::Table, _: &StatusInfo, _: Option<&Scr
     let data = lua.create_table()?;
     
     data.set("new",            lua.create_function(self::Data::<u8>::new)?)?;
+    data.set("new_cursor",     lua.create_function(self::DataCursor::new)?)?;
 
     // CompressData
     data.set("compress",       lua.create_function(self::compress)?)?;
@@ -77,8 +78,25 @@ pub fn set_global(lua: &Lua, table: &mlua::Table, _: &StatusInfo, _: Option<&Scr
     data.set("decode",         lua.create_function(self::decode)?)?;
     // ComputeCRC32/MD5/SHA1
     data.set("hash",           lua.create_function(self::hash)?)?;
+
+    #[cfg(feature = "codec")]
+    data.set("hash_hmac",      lua.create_function(self::hash_hmac)?)?;
+
+    #[cfg(feature = "codec")]
+    data.set("new_stream",     lua.create_function(self::DataStream::new)?)?;
+
+    #[cfg(feature = "crypto")]
+    data.set("derive_key",     lua.create_function(self::derive_key)?)?;
+
+    #[cfg(feature = "crypto")]
+    data.set("encrypt",        lua.create_function(self::encrypt)?)?;
+
+    #[cfg(feature = "crypto")]
+    data.set("decrypt",        lua.create_function(self::decrypt)?)?;
+
     data.set("serialize",      lua.create_function(self::serialize)?)?;
     data.set("deserialize",    lua.create_function(self::deserialize)?)?;
+    data.set("validate",       lua.create_function(self::validate)?)?;
     data.set("to_data",        lua.create_function(self::to_data)?)?;
     data.set("from_data",      lua.create_function(self::from_data)?)?;
 
@@ -199,265 +217,2128 @@ impl<T: Clone + IntoLua + Send + 'static> mlua::UserData for Data<T> {
 
 //================================================================
 
-/* entry
+/* class
 {
     "version": "1.0.0",
-    "name": "quiver.data.compress",
-    "info": "Compress a given data buffer (DEFLATE).",
-    "member": [
-        { "name": "data", "info": "The data buffer to compress.", "kind": "data" }
-    ],
-    "result": [
-        { "name": "data", "info": "The data buffer.", "kind": "data" }
-    ],
-    "test": "data/compress_decompress.lua"
+    "name": "data_cursor",
+    "info": "A cursor over a data buffer, for reading and writing typed values. Writing past the end of the data buffer will grow it. Every change is made in-place, on the data buffer itself.",
+    "test": "data/cursor.lua"
 }
 */
-fn compress(lua: &Lua, data: LuaValue) -> mlua::Result<Data<u8>> {
-    unsafe {
-        let data = Data::get_buffer(data)?;
-        let data = &data.0;
-        let mut out = 0;
-        let value = ffi::CompressData(data.as_ptr(), data.len() as i32, &mut out);
-        let slice = std::slice::from_raw_parts(value, out as usize).to_vec();
+pub struct DataCursor {
+    data: mlua::AnyUserData,
+    index: usize,
+    big: bool,
+}
 
-        Data::new(lua, slice)
+impl DataCursor {
+    /* entry
+    {
+        "version": "1.0.0",
+        "name": "quiver.data.new_cursor",
+        "info": "Create a new cursor over a data buffer. The cursor will start at the beginning of the data buffer, in little-endian mode.",
+        "member": [
+            { "name": "data", "info": "OPTIONAL: The data buffer. If nil, will create an empty data buffer.", "kind": "data?" }
+        ],
+        "result": [
+            { "name": "cursor", "info": "The cursor.", "kind": "data_cursor" }
+        ]
     }
-}
+    */
+    fn new(lua: &Lua, data: Option<mlua::AnyUserData>) -> mlua::Result<Self> {
+        let data = match data {
+            Some(data) => {
+                if !data.is::<Data<u8>>() {
+                    return Err(mlua::Error::runtime(
+                        "DataCursor::new(): Value is not a Data user-data.",
+                    ));
+                }
 
-/* entry
-{
-    "version": "1.0.0",
-    "name": "quiver.data.decompress",
-    "info": "Decompress a given data buffer (DEFLATE).",
-    "member": [
-        { "name": "data", "info": "The data buffer to decompress.", "kind": "data" }
-    ],
-    "result": [
-        { "name": "data", "info": "The data buffer.", "kind": "data" }
-    ],
-    "test": "data/compress_decompress.lua"
-}
-*/
-fn decompress(lua: &Lua, data: LuaValue) -> mlua::Result<Data<u8>> {
-    unsafe {
-        let data = Data::get_buffer(data)?;
-        let data = &data.0;
-        let mut out = 0;
-        let value = ffi::DecompressData(data.as_ptr(), data.len() as i32, &mut out);
-        let slice = Vec::from_raw_parts(value, out as usize, out as usize);
+                data
+            }
+            None => lua.create_userdata(Data::<u8>::new(lua, Vec::new())?)?,
+        };
 
-        Data::new(lua, slice)
+        Ok(Self {
+            data,
+            index: 0,
+            big: false,
+        })
     }
-}
-
-//================================================================
 
-/* entry
-{
-    "version": "1.0.0",
-    "name": "quiver.data.encode",
-    "info": "Encode a given data buffer (Base64).",
-    "member": [
-        { "name": "data", "info": "The data buffer to encode.", "kind": "data" }
-    ],
-    "result": [
-        { "name": "data", "info": "The data buffer.", "kind": "data" }
-    ]
-}
-*/
-fn encode(lua: &Lua, data: LuaValue) -> mlua::Result<Data<i8>> {
-    unsafe {
-        let data = Data::get_buffer(data)?;
-        let data = &data.0;
-        let mut out = 0;
-        let value = ffi::EncodeDataBase64(data.as_ptr(), data.len() as i32, &mut out);
-        let slice = Vec::from_raw_parts(value, out as usize, out as usize);
+    fn read<const N: usize, T>(
+        &mut self,
+        little: fn([u8; N]) -> T,
+        big: fn([u8; N]) -> T,
+    ) -> mlua::Result<T> {
+        let value: [u8; N] = self.read_slice(N)?.try_into().unwrap();
 
-        Data::new(lua, slice)
+        if self.big {
+            Ok(big(value))
+        } else {
+            Ok(little(value))
+        }
     }
-}
 
-/* entry
-{
-    "version": "1.0.0",
-    "name": "quiver.data.decode",
-    "info": "Decode a given data buffer (Base64).",
-    "member": [
-        { "name": "data", "info": "The data buffer to decode.", "kind": "data" }
-    ],
-    "result": [
-        { "name": "data", "info": "The data buffer.", "kind": "data" }
-    ]
-}
-*/
-fn decode(lua: &Lua, data: LuaValue) -> mlua::Result<Data<u8>> {
-    unsafe {
-        let data = Data::get_buffer(data)?;
-        let data = &data.0;
-        let mut out = 0;
-        let value = ffi::DecodeDataBase64(data.as_ptr(), &mut out);
-        let slice = Vec::from_raw_parts(value, out as usize, out as usize);
+    fn read_slice(&mut self, length: usize) -> mlua::Result<Vec<u8>> {
+        let data = self.data.borrow::<Data<u8>>()?;
+
+        let value = self
+            .index
+            .checked_add(length)
+            .and_then(|index| data.0.get(self.index..index));
+
+        if let Some(value) = value {
+            self.index += length;
 
-        Data::new(lua, slice)
+            Ok(value.to_vec())
+        } else {
+            Err(mlua::Error::runtime(format!(
+                "data_cursor:read(): Can not read {length} byte(s) at index {}, buffer length is {}.",
+                self.index,
+                data.0.len()
+            )))
+        }
     }
-}
 
-//================================================================
+    // read an unsigned LEB128 variable-length integer.
+    fn read_variable(&mut self) -> mlua::Result<u64> {
+        let mut value: u64 = 0;
 
-/* entry
-{
-    "version": "1.0.0",
-    "name": "quiver.data.hash",
-    "info": "Hash a given data buffer.",
-    "member": [
-        { "name": "data", "info": "The data buffer to encode.",                 "kind": "data"       },
-        { "name": "data", "info": "OPTIONAL: The hash method. Default: CRC32.", "kind": "hash_kind?" }
-    ],
-    "result": [
-        { "name": "data", "info": "The hash code.", "kind": "data" }
-    ]
-}
-*/
-fn hash(lua: &Lua, (data, kind): (LuaValue, Option<i32>)) -> mlua::Result<LuaValue> {
-    let mut data = Data::get_buffer_mut(data)?;
-    let data = &mut data.0;
-    let kind = kind.unwrap_or_default();
+        for x in 0..10 {
+            let byte = self.read_slice(1)?[0];
 
-    unsafe {
-        match kind {
-            0 => lua.to_value(&ffi::ComputeCRC32(data.as_mut_ptr(), data.len() as i32)),
-            1 => {
-                let value = ffi::ComputeMD5(data.as_mut_ptr(), data.len() as i32);
-                let value = vec![
-                    *value.wrapping_add(0),
-                    *value.wrapping_add(1),
-                    *value.wrapping_add(2),
-                    *value.wrapping_add(3),
-                ];
+            value |= ((byte & 0x7F) as u64) << (x * 7);
 
-                lua.to_value(&value)
+            if byte & 0x80 == 0 {
+                return Ok(value);
             }
-            _ => {
-                let value = ffi::ComputeSHA1(data.as_mut_ptr(), data.len() as i32);
-                let value = vec![
-                    *value.wrapping_add(0),
-                    *value.wrapping_add(1),
-                    *value.wrapping_add(2),
-                    *value.wrapping_add(3),
-                    *value.wrapping_add(4),
-                ];
+        }
+
+        Err(mlua::Error::runtime(
+            "data_cursor:read_variable(): Variable-length integer is too long.",
+        ))
+    }
+
+    fn write(&mut self, value: &[u8]) -> mlua::Result<()> {
+        let mut data = self.data.borrow_mut::<Data<u8>>()?;
+        let data = &mut data.0;
+        let Some(index) = self.index.checked_add(value.len()) else {
+            return Err(mlua::Error::runtime(
+                "data_cursor:write(): Buffer length is too large.",
+            ));
+        };
+
+        if index > data.len() {
+            data.resize(index, 0);
+        }
+
+        data[self.index..index].copy_from_slice(value);
+
+        self.index = index;
 
-                lua.to_value(&value)
+        Ok(())
+    }
+
+    // write an unsigned LEB128 variable-length integer.
+    fn write_variable(&mut self, mut value: u64) -> mlua::Result<()> {
+        let mut data = Vec::new();
+
+        loop {
+            let byte = (value & 0x7F) as u8;
+            value >>= 7;
+
+            if value == 0 {
+                data.push(byte);
+                break;
             }
+
+            data.push(byte | 0x80);
         }
+
+        self.write(&data)
     }
 }
 
-//================================================================
+// every fixed-size read and write method, in both byte orders.
+macro_rules! cursor_method {
+    ($method:ident, $($kind:ident)*) => {
+        $(
+            $method.add_method_mut(concat!("read_", stringify!($kind)), |_: &Lua, this, _: ()| {
+                this.read($kind::from_le_bytes, $kind::from_be_bytes)
+            });
 
-/* entry
-{
-    "version": "1.0.0",
-    "name": "quiver.data.serialize",
-    "info": "Serialize a given Lua value as another format, in the form of a string.",
-    "member": [
-        { "name": "text", "info": "Lua value to serialize.",                              "kind": "any"          },
-        { "name": "kind", "info": "OPTIONAL: The format to serialize to. Default: JSON.", "kind": "format_kind?" }
-    ],
-    "result": [
-        { "name": "value", "info": "The value, in string form.", "kind": "string" }
-    ]
+            $method.add_method_mut(concat!("write_", stringify!($kind)), |_: &Lua, this, value: $kind| {
+                this.write(&if this.big {
+                    value.to_be_bytes()
+                } else {
+                    value.to_le_bytes()
+                })
+            });
+        )*
+    };
 }
-*/
-#[cfg(feature = "serialization")]
-fn serialize(lua: &Lua, (text, kind): (LuaValue, Option<i32>)) -> mlua::Result<String> {
-    let kind = kind.unwrap_or_default();
 
-    match kind {
-        0 => {
-            let text: serde_json::Value = lua.from_value(text)?;
-            serde_json::to_string(&text).map_err(|e| mlua::Error::runtime(e.to_string()))
-        }
-        1 => {
-            let text: serde_json::Value = lua.from_value(text)?;
-            serde_yaml::to_string(&text).map_err(|e| mlua::Error::runtime(e.to_string()))
+impl mlua::UserData for DataCursor {
+    fn add_fields<F: mlua::UserDataFields<Self>>(_: &mut F) {}
+
+    fn add_methods<M: mlua::UserDataMethods<Self>>(method: &mut M) {
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "data_cursor:get_data",
+            "info": "Get the data buffer of the cursor.",
+            "result": [
+                { "name": "data", "info": "The data buffer.", "kind": "data" }
+            ]
         }
-        2 => {
-            let text: serde_json::Value = lua.from_value(text)?;
-            toml::to_string(&text).map_err(|e| mlua::Error::runtime(e.to_string()))
+        */
+        method.add_method("get_data", |_: &Lua, this, _: ()| Ok(this.data.clone()));
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "data_cursor:get_length",
+            "info": "Get the length of the data buffer.",
+            "result": [
+                { "name": "length", "info": "The length of the data buffer.", "kind": "number" }
+            ]
         }
-        3 => {
-            let text: serde_json::Value = lua.from_value(text)?;
-            serde_xml_rs::to_string(&text).map_err(|e| mlua::Error::runtime(e.to_string()))
+        */
+        method.add_method("get_length", |_: &Lua, this, _: ()| {
+            Ok(this.data.borrow::<Data<u8>>()?.0.len())
+        });
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "data_cursor:set_endian",
+            "info": "Set the byte order of the cursor.",
+            "member": [
+                { "name": "big", "info": "If true, use big-endian (network order). Otherwise, use little-endian.", "kind": "boolean" }
+            ]
         }
-        _ => {
-            let text: serde_json::Value = lua.from_value(text)?;
-            serde_ini::to_string(&text).map_err(|e| mlua::Error::runtime(e.to_string()))
+        */
+        method.add_method_mut("set_endian", |_: &Lua, this, big: bool| {
+            this.big = big;
+            Ok(())
+        });
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "data_cursor:seek",
+            "info": "Set the position of the cursor.",
+            "member": [
+                { "name": "index", "info": "OPTIONAL: The position, from the beginning of the data buffer. If nil, will set the position to the end of the data buffer, for appending.", "kind": "number?" }
+            ]
         }
-    }
-}
+        */
+        method.add_method_mut("seek", |_: &Lua, this, index: Option<usize>| {
+            let length = this.data.borrow::<Data<u8>>()?.0.len();
+            let index = index.unwrap_or(length);
 
-#[cfg(not(feature = "serialization"))]
-fn serialize(lua: &Lua, (text, _): (LuaValue, Option<i32>)) -> mlua::Result<String> {
-    let text: serde_json::Value = lua.from_value(text)?;
-    serde_json::to_string(&text).map_err(|e| mlua::Error::runtime(e.to_string()))
-}
+            if index > length {
+                return Err(mlua::Error::runtime(format!(
+                    "data_cursor:seek(): Index {index} is past the end of the buffer ({length})."
+                )));
+            }
 
-/* entry
-{
-    "version": "1.0.0",
-    "name": "quiver.data.deserialize",
-    "info": "Deserialize a given format string as a Lua value.",
-    "member": [
-        { "name": "text", "info": "String to deserialize.",                                   "kind": "string"       },
-        { "name": "kind", "info": "OPTIONAL: The format to deserialize from. Default: JSON.", "kind": "format_kind?" }
-    ],
-    "result": [
-        { "name": "value", "info": "The value, in Lua value form.", "kind": "any" }
-    ]
-}
-*/
-#[cfg(feature = "serialization")]
-fn deserialize(lua: &Lua, (text, kind): (String, Option<i32>)) -> mlua::Result<LuaValue> {
-    let kind = kind.unwrap_or_default();
+            this.index = index;
 
-    match kind {
-        0 => {
-            let text: serde_json::Value =
-                serde_json::from_str(&text).map_err(|e| mlua::Error::runtime(e.to_string()))?;
-            lua.to_value(&text)
+            Ok(())
+        });
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "data_cursor:tell",
+            "info": "Get the position of the cursor.",
+            "result": [
+                { "name": "index", "info": "The position, from the beginning of the data buffer.", "kind": "number" }
+            ]
         }
-        1 => {
-            let text: serde_json::Value =
-                serde_yaml::from_str(&text).map_err(|e| mlua::Error::runtime(e.to_string()))?;
-            lua.to_value(&text)
+        */
+        method.add_method("tell", |_: &Lua, this, _: ()| Ok(this.index));
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "data_cursor:read_u8",
+            "info": "Read an unsigned 8-bit integer, and advance the cursor.",
+            "result": [
+                { "name": "value", "info": "The value.", "kind": "number" }
+            ]
         }
-        2 => {
-            let text: serde_json::Value =
-                toml::from_str(&text).map_err(|e| mlua::Error::runtime(e.to_string()))?;
-            lua.to_value(&text)
+        */
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "data_cursor:write_u8",
+            "info": "Write an unsigned 8-bit integer, and advance the cursor.",
+            "member": [
+                { "name": "value", "info": "The value.", "kind": "number" }
+            ]
         }
-        3 => {
-            let text: serde_json::Value =
-                serde_xml_rs::from_str(&text).map_err(|e| mlua::Error::runtime(e.to_string()))?;
-            lua.to_value(&text)
+        */
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "data_cursor:read_u16",
+            "info": "Read an unsigned 16-bit integer, and advance the cursor.",
+            "result": [
+                { "name": "value", "info": "The value.", "kind": "number" }
+            ]
         }
-        _ => {
-            let text: serde_json::Value =
-                serde_ini::from_str(&text).map_err(|e| mlua::Error::runtime(e.to_string()))?;
-            lua.to_value(&text)
+        */
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "data_cursor:write_u16",
+            "info": "Write an unsigned 16-bit integer, and advance the cursor.",
+            "member": [
+                { "name": "value", "info": "The value.", "kind": "number" }
+            ]
         }
-    }
-}
+        */
 
-#[cfg(not(feature = "serialization"))]
-fn deserialize(lua: &Lua, (text, _): (String, Option<i32>)) -> mlua::Result<LuaValue> {
-    let text: serde_json::Value =
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "data_cursor:read_u32",
+            "info": "Read an unsigned 32-bit integer, and advance the cursor.",
+            "result": [
+                { "name": "value", "info": "The value.", "kind": "number" }
+            ]
+        }
+        */
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "data_cursor:write_u32",
+            "info": "Write an unsigned 32-bit integer, and advance the cursor.",
+            "member": [
+                { "name": "value", "info": "The value.", "kind": "number" }
+            ]
+        }
+        */
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "data_cursor:read_u64",
+            "info": "Read an unsigned 64-bit integer, and advance the cursor.",
+            "result": [
+                { "name": "value", "info": "The value.", "kind": "number" }
+            ]
+        }
+        */
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "data_cursor:write_u64",
+            "info": "Write an unsigned 64-bit integer, and advance the cursor.",
+            "member": [
+                { "name": "value", "info": "The value.", "kind": "number" }
+            ]
+        }
+        */
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "data_cursor:read_i8",
+            "info": "Read a signed 8-bit integer, and advance the cursor.",
+            "result": [
+                { "name": "value", "info": "The value.", "kind": "number" }
+            ]
+        }
+        */
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "data_cursor:write_i8",
+            "info": "Write a signed 8-bit integer, and advance the cursor.",
+            "member": [
+                { "name": "value", "info": "The value.", "kind": "number" }
+            ]
+        }
+        */
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "data_cursor:read_i16",
+            "info": "Read a signed 16-bit integer, and advance the cursor.",
+            "result": [
+                { "name": "value", "info": "The value.", "kind": "number" }
+            ]
+        }
+        */
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "data_cursor:write_i16",
+            "info": "Write a signed 16-bit integer, and advance the cursor.",
+            "member": [
+                { "name": "value", "info": "The value.", "kind": "number" }
+            ]
+        }
+        */
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "data_cursor:read_i32",
+            "info": "Read a signed 32-bit integer, and advance the cursor.",
+            "result": [
+                { "name": "value", "info": "The value.", "kind": "number" }
+            ]
+        }
+        */
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "data_cursor:write_i32",
+            "info": "Write a signed 32-bit integer, and advance the cursor.",
+            "member": [
+                { "name": "value", "info": "The value.", "kind": "number" }
+            ]
+        }
+        */
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "data_cursor:read_i64",
+            "info": "Read a signed 64-bit integer, and advance the cursor.",
+            "result": [
+                { "name": "value", "info": "The value.", "kind": "number" }
+            ]
+        }
+        */
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "data_cursor:write_i64",
+            "info": "Write a signed 64-bit integer, and advance the cursor.",
+            "member": [
+                { "name": "value", "info": "The value.", "kind": "number" }
+            ]
+        }
+        */
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "data_cursor:read_f32",
+            "info": "Read a 32-bit float, and advance the cursor.",
+            "result": [
+                { "name": "value", "info": "The value.", "kind": "number" }
+            ]
+        }
+        */
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "data_cursor:write_f32",
+            "info": "Write a 32-bit float, and advance the cursor.",
+            "member": [
+                { "name": "value", "info": "The value.", "kind": "number" }
+            ]
+        }
+        */
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "data_cursor:read_f64",
+            "info": "Read a 64-bit float, and advance the cursor.",
+            "result": [
+                { "name": "value", "info": "The value.", "kind": "number" }
+            ]
+        }
+        */
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "data_cursor:write_f64",
+            "info": "Write a 64-bit float, and advance the cursor.",
+            "member": [
+                { "name": "value", "info": "The value.", "kind": "number" }
+            ]
+        }
+        */
+        cursor_method!(method, u8 u16 u32 u64 i8 i16 i32 i64 f32 f64);
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "data_cursor:read_variable",
+            "info": "Read a variable-length integer (LEB128), and advance the cursor.",
+            "member": [
+                { "name": "sign", "info": "OPTIONAL: If true, read a signed (zig-zag) integer.", "kind": "boolean?" }
+            ],
+            "result": [
+                { "name": "value", "info": "The value.", "kind": "number" }
+            ]
+        }
+        */
+        method.add_method_mut("read_variable", |_: &Lua, this, sign: Option<bool>| {
+            let value = this.read_variable()?;
+
+            if sign.unwrap_or_default() {
+                Ok(((value >> 1) as i64 ^ -((value & 1) as i64)) as f64)
+            } else {
+                Ok(value as f64)
+            }
+        });
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "data_cursor:write_variable",
+            "info": "Write a variable-length integer (LEB128), and advance the cursor.",
+            "member": [
+                { "name": "value", "info": "The value.",                                           "kind": "number"   },
+                { "name": "sign",  "info": "OPTIONAL: If true, write a signed (zig-zag) integer.", "kind": "boolean?" }
+            ]
+        }
+        */
+        method.add_method_mut(
+            "write_variable",
+            |_: &Lua, this, (value, sign): (i64, Option<bool>)| {
+                if sign.unwrap_or_default() {
+                    this.write_variable(((value << 1) ^ (value >> 63)) as u64)
+                } else if value < 0 {
+                    Err(mlua::Error::runtime(
+                        "data_cursor:write_variable(): Value is negative, but sign is not set.",
+                    ))
+                } else {
+                    this.write_variable(value as u64)
+                }
+            },
+        );
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "data_cursor:read_string",
+            "info": "Read a string, and advance the cursor.",
+            "member": [
+                { "name": "length", "info": "OPTIONAL: The length of the string. If nil, will read a variable-length integer as the length first.", "kind": "number?" }
+            ],
+            "result": [
+                { "name": "value", "info": "The value.", "kind": "string" }
+            ]
+        }
+        */
+        method.add_method_mut("read_string", |lua: &Lua, this, length: Option<usize>| {
+            let length = match length {
+                Some(length) => length,
+                None => this.read_variable()? as usize,
+            };
+
+            lua.create_string(this.read_slice(length)?)
+        });
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "data_cursor:write_string",
+            "info": "Write a string, and advance the cursor.",
+            "member": [
+                { "name": "value",  "info": "The value.",                                                                                   "kind": "string"   },
+                { "name": "length", "info": "OPTIONAL: If false, will not write the length of the string as a variable-length integer first. Default: true.", "kind": "boolean?" }
+            ]
+        }
+        */
+        method.add_method_mut(
+            "write_string",
+            |_: &Lua, this, (value, length): (mlua::String, Option<bool>)| {
+                let value = value.as_bytes();
+
+                if length.unwrap_or(true) {
+                    this.write_variable(value.len() as u64)?;
+                }
+
+                this.write(&value)
+            },
+        );
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "data_cursor:read_data",
+            "info": "Read a slice of the data buffer as another data buffer, and advance the cursor.",
+            "member": [
+                { "name": "length", "info": "The length of the slice.", "kind": "number" }
+            ],
+            "result": [
+                { "name": "value", "info": "The slice, as another data buffer.", "kind": "data" }
+            ]
+        }
+        */
+        method.add_method_mut("read_data", |lua: &Lua, this, length: usize| {
+            Data::new(lua, this.read_slice(length)?)
+        });
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "data_cursor:write_data",
+            "info": "Write a data buffer, and advance the cursor.",
+            "member": [
+                { "name": "value", "info": "The data buffer.", "kind": "data" }
+            ]
+        }
+        */
+        method.add_method_mut("write_data", |_: &Lua, this, value: LuaValue| {
+            let value = Data::<u8>::get_buffer(value)?.0.clone();
+
+            this.write(&value)
+        });
+    }
+}
+
+//================================================================
+
+/* entry
+{
+    "version": "1.0.0",
+    "name": "quiver.data.compress",
+    "info": "Compress a given data buffer. Zstandard and LZ4 require the codec feature.",
+    "member": [
+        { "name": "data",  "info": "The data buffer to compress.",                                                                    "kind": "data"           },
+        { "name": "kind",  "info": "OPTIONAL: The compression method. Default: DEFLATE.",                                             "kind": "compress_kind?" },
+        { "name": "level", "info": "OPTIONAL: The compression level. Zstandard: 1 to 22 (default: 3), LZ4: 0 to 12 (default: 0). Ignored for DEFLATE.", "kind": "number?"        }
+    ],
+    "result": [
+        { "name": "data", "info": "The data buffer.", "kind": "data" }
+    ],
+    "test": "data/compress_decompress.lua"
+}
+*/
+#[cfg_attr(not(feature = "codec"), allow(unused_variables))]
+fn compress(
+    lua: &Lua,
+    (data, kind, level): (LuaValue, Option<i32>, Option<i32>),
+) -> mlua::Result<Data<u8>> {
+    let data = Data::get_buffer(data)?;
+
+    match kind.unwrap_or_default() {
+        0 => Data::new(lua, compress_buffer(&data.0)),
+        #[cfg(feature = "codec")]
+        1 => Data::new(lua, codec::compress_zstd(&data.0, level)?),
+        #[cfg(feature = "codec")]
+        2 => Data::new(lua, codec::compress_lz4(&data.0, level)?),
+        kind => Err(mlua::Error::runtime(format!(
+            "compress(): Unknown or unavailable compression kind \"{kind}\"."
+        ))),
+    }
+}
+
+/* entry
+{
+    "version": "1.0.0",
+    "name": "quiver.data.decompress",
+    "info": "Decompress a given data buffer. Zstandard and LZ4 require the codec feature.",
+    "member": [
+        { "name": "data", "info": "The data buffer to decompress.",                          "kind": "data"           },
+        { "name": "kind", "info": "OPTIONAL: The compression method. Default: DEFLATE.", "kind": "compress_kind?" }
+    ],
+    "result": [
+        { "name": "data", "info": "The data buffer.", "kind": "data" }
+    ],
+    "test": "data/compress_decompress.lua"
+}
+*/
+fn decompress(lua: &Lua, (data, kind): (LuaValue, Option<i32>)) -> mlua::Result<Data<u8>> {
+    let data = Data::get_buffer(data)?;
+
+    match kind.unwrap_or_default() {
+        0 => Data::new(lua, decompress_buffer(&data.0)?),
+        #[cfg(feature = "codec")]
+        1 => Data::new(lua, codec::decompress_zstd(&data.0)?),
+        #[cfg(feature = "codec")]
+        2 => Data::new(lua, codec::decompress_lz4(&data.0)?),
+        kind => Err(mlua::Error::runtime(format!(
+            "decompress(): Unknown or unavailable compression kind \"{kind}\"."
+        ))),
+    }
+}
+
+// compress a buffer (DEFLATE).
+pub fn compress_buffer(data: &[u8]) -> Vec<u8> {
+    unsafe {
+        let mut out = 0;
+        let value = ffi::CompressData(data.as_ptr(), data.len() as i32, &mut out);
+        let slice = std::slice::from_raw_parts(value, out as usize).to_vec();
+
+        ffi::MemFree(value as *mut std::ffi::c_void);
+
+        slice
+    }
+}
+
+// decompress a buffer (DEFLATE).
+pub fn decompress_buffer(data: &[u8]) -> mlua::Result<Vec<u8>> {
+    unsafe {
+        let mut out = 0;
+        let value = ffi::DecompressData(data.as_ptr(), data.len() as i32, &mut out);
+
+        if value.is_null() {
+            return Err(mlua::Error::runtime(
+                "decompress_buffer(): Could not decompress buffer.",
+            ));
+        }
+
+        let slice = std::slice::from_raw_parts(value, out as usize).to_vec();
+
+        ffi::MemFree(value as *mut std::ffi::c_void);
+
+        Ok(slice)
+    }
+}
+
+// get the CRC32 hash of a buffer.
+pub fn hash_buffer(data: &[u8]) -> u32 {
+    let mut data = data.to_vec();
+
+    unsafe { ffi::ComputeCRC32(data.as_mut_ptr(), data.len() as i32) }
+}
+
+// get the byte slice of a string, or a data buffer.
+fn get_byte(value: &LuaValue) -> mlua::Result<Vec<u8>> {
+    match value {
+        LuaValue::String(value) => Ok(value.as_bytes().to_vec()),
+        LuaValue::UserData(value) => {
+            if let Ok(value) = value.borrow::<Data<u8>>() {
+                Ok(value.0.clone())
+            } else if let Ok(value) = value.borrow::<Data<i8>>() {
+                Ok(value.0.iter().map(|x| *x as u8).collect())
+            } else {
+                Err(mlua::Error::runtime(
+                    "get_byte(): Value is not a Data user-data.",
+                ))
+            }
+        }
+        _ => Err(mlua::Error::runtime(
+            "get_byte(): Value is not a string or a Data user-data.",
+        )),
+    }
+}
+
+//================================================================
+
+/* entry
+{
+    "version": "1.0.0",
+    "name": "quiver.data.encode",
+    "info": "Encode a given data buffer. Base64 will return a data buffer, every other method will return a string. Base64 (URL-safe) requires the codec feature.",
+    "member": [
+        { "name": "data", "info": "The data buffer to encode.",                       "kind": "data"         },
+        { "name": "kind", "info": "OPTIONAL: The encode method. Default: Base64.", "kind": "encode_kind?" }
+    ],
+    "result": [
+        { "name": "data", "info": "The data buffer, or string.", "kind": "data | string" }
+    ],
+    "test": "codec/encode_decode.lua"
+}
+*/
+fn encode(lua: &Lua, (data, kind): (LuaValue, Option<i32>)) -> mlua::Result<LuaValue> {
+    match kind.unwrap_or_default() {
+        0 => unsafe {
+            let data = Data::get_buffer(data)?;
+            let data = &data.0;
+            let mut out = 0;
+            let value = ffi::EncodeDataBase64(data.as_ptr(), data.len() as i32, &mut out);
+            let slice = std::slice::from_raw_parts(value, out as usize).to_vec();
+
+            ffi::MemFree(value as *mut std::ffi::c_void);
+
+            Ok(LuaValue::UserData(
+                lua.create_userdata(Data::new(lua, slice)?)?,
+            ))
+        },
+        #[cfg(feature = "codec")]
+        1 => {
+            use base64::Engine;
+
+            let data = get_byte(&data)?;
+
+            lua.to_value(&base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(data))
+        }
+        2 => {
+            let data = get_byte(&data)?;
+            let text: String = data.iter().map(|x| format!("{x:02x}")).collect();
+
+            lua.to_value(&text)
+        }
+        kind => Err(mlua::Error::runtime(format!(
+            "encode(): Unknown or unavailable encode kind \"{kind}\"."
+        ))),
+    }
+}
+
+/* entry
+{
+    "version": "1.0.0",
+    "name": "quiver.data.decode",
+    "info": "Decode a given data buffer, or string. Base64 (URL-safe) requires the codec feature.",
+    "member": [
+        { "name": "data", "info": "The data buffer, or string, to decode.",     "kind": "data | string" },
+        { "name": "kind", "info": "OPTIONAL: The encode method. Default: Base64.", "kind": "encode_kind?"  }
+    ],
+    "result": [
+        { "name": "data", "info": "The data buffer.", "kind": "data" }
+    ],
+    "test": "codec/encode_decode.lua"
+}
+*/
+fn decode(lua: &Lua, (data, kind): (LuaValue, Option<i32>)) -> mlua::Result<Data<u8>> {
+    match kind.unwrap_or_default() {
+        0 => unsafe {
+            // DecodeDataBase64 expects a null-terminated string.
+            let mut data = get_byte(&data)?;
+            data.push(0);
+
+            let mut out = 0;
+            let value = ffi::DecodeDataBase64(data.as_ptr(), &mut out);
+
+            if value.is_null() {
+                return Err(mlua::Error::runtime("decode(): Could not decode Base64."));
+            }
+
+            let slice = std::slice::from_raw_parts(value, out as usize).to_vec();
+
+            ffi::MemFree(value as *mut std::ffi::c_void);
+
+            Data::new(lua, slice)
+        },
+        #[cfg(feature = "codec")]
+        1 => {
+            use base64::Engine;
+
+            let data = get_byte(&data)?;
+
+            Data::new(
+                lua,
+                base64::engine::general_purpose::URL_SAFE_NO_PAD
+                    .decode(data.trim_ascii())
+                    .map_err(|e| mlua::Error::runtime(format!("decode(): {e}")))?,
+            )
+        }
+        2 => {
+            let data = get_byte(&data)?;
+
+            if data.len() % 2 != 0 {
+                return Err(mlua::Error::runtime(
+                    "decode(): Hexadecimal string has an odd length.",
+                ));
+            }
+
+            let data: Result<Vec<u8>, _> = data
+                .chunks(2)
+                .map(|x| {
+                    std::str::from_utf8(x)
+                        .ok()
+                        .and_then(|x| u8::from_str_radix(x, 16).ok())
+                        .ok_or(mlua::Error::runtime(
+                            "decode(): Hexadecimal string has an invalid character.",
+                        ))
+                })
+                .collect();
+
+            Data::new(lua, data?)
+        }
+        kind => Err(mlua::Error::runtime(format!(
+            "decode(): Unknown or unavailable encode kind \"{kind}\"."
+        ))),
+    }
+}
+
+//================================================================
+
+/* entry
+{
+    "version": "1.0.0",
+    "name": "quiver.data.hash",
+    "info": "Hash a given data buffer. Every method will return a data buffer with the digest, which may be turned into a hexadecimal string with quiver.data.encode. SHA256, xxHash (XXH3, 64-bit, big-endian) and BLAKE3 require the codec feature.",
+    "member": [
+        { "name": "data", "info": "The data buffer to hash.",                   "kind": "data"       },
+        { "name": "kind", "info": "OPTIONAL: The hash method. Default: CRC32.", "kind": "hash_kind?" }
+    ],
+    "result": [
+        { "name": "data", "info": "The digest.", "kind": "data" }
+    ],
+    "test": "codec/hash.lua"
+}
+*/
+fn hash(lua: &Lua, (data, kind): (LuaValue, Option<i32>)) -> mlua::Result<LuaValue> {
+    let mut data = Data::get_buffer_mut(data)?;
+    let data = &mut data.0;
+    let kind = kind.unwrap_or_default();
+
+    // every digest is in its standard byte order: big-endian for CRC32 and SHA1, little-endian for MD5.
+    let value = unsafe {
+        match kind {
+            0 => ffi::ComputeCRC32(data.as_mut_ptr(), data.len() as i32)
+                .to_be_bytes()
+                .to_vec(),
+            1 => {
+                let value = ffi::ComputeMD5(data.as_mut_ptr(), data.len() as i32);
+
+                std::slice::from_raw_parts(value, 4)
+                    .iter()
+                    .flat_map(|x| x.to_le_bytes())
+                    .collect()
+            }
+            2 => {
+                let value = ffi::ComputeSHA1(data.as_mut_ptr(), data.len() as i32);
+
+                std::slice::from_raw_parts(value, 5)
+                    .iter()
+                    .flat_map(|x| x.to_be_bytes())
+                    .collect()
+            }
+            #[cfg(feature = "codec")]
+            3..=5 => codec::hash(data, kind),
+            _ => {
+                return Err(mlua::Error::runtime(format!(
+                    "hash(): Unknown or unavailable hash kind \"{kind}\"."
+                )));
+            }
+        }
+    };
+
+    Ok(LuaValue::UserData(
+        lua.create_userdata(Data::new(lua, value)?)?,
+    ))
+}
+
+/* entry
+{
+    "version": "1.0.0",
+    "feature": "codec",
+    "name": "quiver.data.hash_hmac",
+    "info": "Get the HMAC-SHA256 code of a given data buffer.",
+    "member": [
+        { "name": "data", "info": "The data buffer to hash.",             "kind": "data"          },
+        { "name": "key",  "info": "The secret key, as a string or data buffer.", "kind": "data | string" }
+    ],
+    "result": [
+        { "name": "data", "info": "The HMAC code.", "kind": "data" }
+    ],
+    "test": "codec/hash.lua"
+}
+*/
+#[cfg(feature = "codec")]
+fn hash_hmac(lua: &Lua, (data, key): (LuaValue, LuaValue)) -> mlua::Result<Data<u8>> {
+    Data::new(lua, codec::hash_hmac(&get_byte(&data)?, &get_byte(&key)?)?)
+}
+
+//================================================================
+
+/* class
+{
+    "version": "1.0.0",
+    "feature": "codec",
+    "name": "data_stream",
+    "info": "A streaming compressor or decompressor, for data that does not fit in memory at once, or that arrives in chunks. LZ4 decompression will buffer every chunk until the stream is finished.",
+    "test": "codec/stream.lua"
+}
+*/
+#[cfg(feature = "codec")]
+pub struct DataStream(Option<codec::Stream>);
+
+#[cfg(feature = "codec")]
+impl DataStream {
+    /* entry
+    {
+        "version": "1.0.0",
+        "feature": "codec",
+        "name": "quiver.data.new_stream",
+        "info": "Create a new streaming compressor or decompressor.",
+        "member": [
+            { "name": "kind",     "info": "The compression method. DEFLATE is not supported.",          "kind": "compress_kind" },
+            { "name": "compress", "info": "If true, compress. Otherwise, decompress.",                  "kind": "boolean"       },
+            { "name": "level",    "info": "OPTIONAL: The compression level. See quiver.data.compress.", "kind": "number?"       }
+        ],
+        "result": [
+            { "name": "stream", "info": "The stream.", "kind": "data_stream" }
+        ]
+    }
+    */
+    fn new(_: &Lua, (kind, compress, level): (i32, bool, Option<i32>)) -> mlua::Result<Self> {
+        Ok(Self(Some(codec::Stream::new(kind, compress, level)?)))
+    }
+
+    fn get_stream(&mut self) -> mlua::Result<&mut codec::Stream> {
+        self.0.as_mut().ok_or(mlua::Error::runtime(
+            "data_stream: Stream has already been finished.",
+        ))
+    }
+}
+
+#[cfg(feature = "codec")]
+impl mlua::UserData for DataStream {
+    fn add_fields<F: mlua::UserDataFields<Self>>(_: &mut F) {}
+
+    fn add_methods<M: mlua::UserDataMethods<Self>>(method: &mut M) {
+        /* entry
+        {
+            "version": "1.0.0",
+            "feature": "codec",
+            "name": "data_stream:push",
+            "info": "Push a chunk into the stream, and get any output that is ready.",
+            "member": [
+                { "name": "data", "info": "The chunk, as a string or data buffer.", "kind": "data | string" }
+            ],
+            "result": [
+                { "name": "data", "info": "The output that is ready. Might be empty.", "kind": "data" }
+            ]
+        }
+        */
+        method.add_method_mut("push", |lua: &Lua, this, data: LuaValue| {
+            let data = get_byte(&data)?;
+
+            Data::new(lua, this.get_stream()?.push(&data)?)
+        });
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "feature": "codec",
+            "name": "data_stream:finish",
+            "info": "Finish the stream, and get any remaining output. The stream can not be used after this.",
+            "result": [
+                { "name": "data", "info": "The remaining output.", "kind": "data" }
+            ]
+        }
+        */
+        method.add_method_mut("finish", |lua: &Lua, this, _: ()| {
+            this.get_stream()?;
+
+            Data::new(lua, this.0.take().unwrap().finish()?)
+        });
+    }
+}
+
+#[cfg(feature = "codec")]
+mod codec {
+    use hmac::Mac;
+    use sha2::Digest;
+    use std::io::{Read, Write};
+    use std::sync::{Arc, Mutex};
+
+    fn get_error(call: &str, error: impl std::fmt::Display) -> mlua::Error {
+        mlua::Error::runtime(format!("{call}(): {error}"))
+    }
+
+    pub fn compress_zstd(data: &[u8], level: Option<i32>) -> mlua::Result<Vec<u8>> {
+        zstd::encode_all(data, level.unwrap_or(3)).map_err(|e| get_error("compress", e))
+    }
+
+    pub fn decompress_zstd(data: &[u8]) -> mlua::Result<Vec<u8>> {
+        zstd::decode_all(data).map_err(|e| get_error("decompress", e))
+    }
+
+    pub fn compress_lz4(data: &[u8], level: Option<i32>) -> mlua::Result<Vec<u8>> {
+        let mut stream = Stream::new(2, true, level)?;
+        let mut value = stream.push(data)?;
+        value.extend(stream.finish()?);
+
+        Ok(value)
+    }
+
+    pub fn decompress_lz4(data: &[u8]) -> mlua::Result<Vec<u8>> {
+        let mut value = Vec::new();
+
+        lz4::Decoder::new(data)
+            .and_then(|mut decoder| decoder.read_to_end(&mut value))
+            .map_err(|e| get_error("decompress", e))?;
+
+        Ok(value)
+    }
+
+    pub fn hash(data: &[u8], kind: i32) -> Vec<u8> {
+        match kind {
+            3 => sha2::Sha256::digest(data).to_vec(),
+            4 => xxhash_rust::xxh3::xxh3_64(data).to_be_bytes().to_vec(),
+            _ => blake3::hash(data).as_bytes().to_vec(),
+        }
+    }
+
+    pub fn hash_hmac(data: &[u8], key: &[u8]) -> mlua::Result<Vec<u8>> {
+        let mut hmac = hmac::Hmac::<sha2::Sha256>::new_from_slice(key)
+            .map_err(|e| get_error("hash_hmac", e))?;
+
+        hmac.update(data);
+
+        Ok(hmac.finalize().into_bytes().to_vec())
+    }
+
+    //================================================================
+
+    // a shared output buffer, as the LZ4 encoder will not give mutable access to its writer.
+    #[derive(Default, Clone)]
+    struct Sink(Arc<Mutex<Vec<u8>>>);
+
+    impl Sink {
+        fn take(&self) -> Vec<u8> {
+            std::mem::take(&mut self.0.lock().unwrap())
+        }
+    }
+
+    impl Write for Sink {
+        fn write(&mut self, data: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().extend_from_slice(data);
+            Ok(data.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    pub enum Stream {
+        ZstdCompress(zstd::stream::write::Encoder<'static, Sink>, Sink),
+        ZstdDecompress(zstd::stream::write::Decoder<'static, Sink>, Sink),
+        Lz4Compress(lz4::Encoder<Sink>, Sink),
+        Lz4Decompress(Vec<u8>),
+    }
+
+    impl Stream {
+        pub fn new(kind: i32, compress: bool, level: Option<i32>) -> mlua::Result<Self> {
+            let sink = Sink::default();
+
+            match (kind, compress) {
+                (1, true) => Ok(Self::ZstdCompress(
+                    zstd::stream::write::Encoder::new(sink.clone(), level.unwrap_or(3))
+                        .map_err(|e| get_error("new_stream", e))?,
+                    sink,
+                )),
+                (1, false) => Ok(Self::ZstdDecompress(
+                    zstd::stream::write::Decoder::new(sink.clone())
+                        .map_err(|e| get_error("new_stream", e))?,
+                    sink,
+                )),
+                (2, true) => Ok(Self::Lz4Compress(
+                    lz4::EncoderBuilder::new()
+                        .level(level.unwrap_or_default().max(0) as u32)
+                        .build(sink.clone())
+                        .map_err(|e| get_error("new_stream", e))?,
+                    sink,
+                )),
+                (2, false) => Ok(Self::Lz4Decompress(Vec::new())),
+                _ => Err(mlua::Error::runtime(format!(
+                    "new_stream(): Unknown or unsupported compression kind \"{kind}\"."
+                ))),
+            }
+        }
+
+        pub fn push(&mut self, data: &[u8]) -> mlua::Result<Vec<u8>> {
+            let result = match self {
+                Self::ZstdCompress(encoder, sink) => encoder.write_all(data).map(|_| sink.take()),
+                Self::ZstdDecompress(decoder, sink) => decoder
+                    .write_all(data)
+                    .and_then(|_| decoder.flush())
+                    .map(|_| sink.take()),
+                Self::Lz4Compress(encoder, sink) => encoder.write_all(data).map(|_| sink.take()),
+                Self::Lz4Decompress(buffer) => {
+                    buffer.extend_from_slice(data);
+                    Ok(Vec::new())
+                }
+            };
+
+            result.map_err(|e| get_error("data_stream:push", e))
+        }
+
+        pub fn finish(self) -> mlua::Result<Vec<u8>> {
+            let result = match self {
+                Self::ZstdCompress(encoder, sink) => encoder.finish().map(|_| sink.take()),
+                Self::ZstdDecompress(mut decoder, sink) => decoder.flush().map(|_| sink.take()),
+                Self::Lz4Compress(encoder, sink) => encoder.finish().1.map(|_| sink.take()),
+                Self::Lz4Decompress(buffer) => return decompress_lz4(&buffer),
+            };
+
+            result.map_err(|e| get_error("data_stream:finish", e))
+        }
+    }
+}
+
+//================================================================
+
+/* entry
+{
+    "version": "1.0.0",
+    "feature": "crypto",
+    "name": "quiver.data.derive_key",
+    "info": "Derive an encryption key from a passphrase (Argon2id). The same passphrase and salt will always give the same key.",
+    "member": [
+        { "name": "pass", "info": "The passphrase.",                                                                      "kind": "string" },
+        { "name": "salt", "info": "OPTIONAL: The salt (at least 8 bytes long). If nil, will create a random 16-byte salt.", "kind": "data?"  }
+    ],
+    "result": [
+        { "name": "key",  "info": "The key (32 bytes long).",                                       "kind": "data" },
+        { "name": "salt", "info": "The salt. Store it alongside the encrypted data to derive the key again.", "kind": "data" }
+    ],
+    "test": "crypto/encrypt_decrypt.lua"
+}
+*/
+#[cfg(feature = "crypto")]
+fn derive_key(
+    lua: &Lua,
+    (pass, salt): (mlua::String, Option<LuaValue>),
+) -> mlua::Result<(Data<u8>, Data<u8>)> {
+    let salt = match salt {
+        Some(salt) => get_byte(&salt)?,
+        None => crypto::get_random(16),
+    };
+
+    let key = crypto::derive_key(&pass.as_bytes(), &salt)?;
+
+    Ok((Data::new(lua, key)?, Data::new(lua, salt)?))
+}
+
+/* entry
+{
+    "version": "1.0.0",
+    "feature": "crypto",
+    "name": "quiver.data.encrypt",
+    "info": "Encrypt a given data buffer (ChaCha20-Poly1305). The result will contain a random nonce, followed by the encrypted data and its authentication tag.",
+    "member": [
+        { "name": "data", "info": "The data buffer to encrypt.",                                                                            "kind": "data | string"  },
+        { "name": "key",  "info": "The key (32 bytes long). See quiver.data.derive_key.",                                                "kind": "data"           },
+        { "name": "side", "info": "OPTIONAL: Additional data to authenticate, but not encrypt (a packet header, for example). Must be the same for decryption.", "kind": "data | string?" }
+    ],
+    "result": [
+        { "name": "data", "info": "The data buffer.", "kind": "data" }
+    ],
+    "test": "crypto/encrypt_decrypt.lua"
+}
+*/
+#[cfg(feature = "crypto")]
+fn encrypt(
+    lua: &Lua,
+    (data, key, side): (LuaValue, LuaValue, Option<LuaValue>),
+) -> mlua::Result<Data<u8>> {
+    let side = match side {
+        Some(side) => get_byte(&side)?,
+        None => Vec::new(),
+    };
+
+    Data::new(
+        lua,
+        crypto::encrypt(&get_byte(&data)?, &get_byte(&key)?, &side)?,
+    )
+}
+
+/* entry
+{
+    "version": "1.0.0",
+    "feature": "crypto",
+    "name": "quiver.data.decrypt",
+    "info": "Decrypt a given data buffer (ChaCha20-Poly1305). Will error if the key is wrong, or if the data has been tampered with.",
+    "member": [
+        { "name": "data", "info": "The data buffer to decrypt.",                                           "kind": "data"           },
+        { "name": "key",  "info": "The key (32 bytes long). See quiver.data.derive_key.",               "kind": "data"           },
+        { "name": "side", "info": "OPTIONAL: Additional data to authenticate. See quiver.data.encrypt.", "kind": "data | string?" }
+    ],
+    "result": [
+        { "name": "data", "info": "The data buffer.", "kind": "data" }
+    ],
+    "test": "crypto/encrypt_decrypt.lua"
+}
+*/
+#[cfg(feature = "crypto")]
+fn decrypt(
+    lua: &Lua,
+    (data, key, side): (LuaValue, LuaValue, Option<LuaValue>),
+) -> mlua::Result<Data<u8>> {
+    let side = match side {
+        Some(side) => get_byte(&side)?,
+        None => Vec::new(),
+    };
+
+    Data::new(
+        lua,
+        crypto::decrypt(&get_byte(&data)?, &get_byte(&key)?, &side)?,
+    )
+}
+
+#[cfg(feature = "crypto")]
+mod crypto {
+    use chacha20poly1305::aead::rand_core::RngCore;
+    use chacha20poly1305::aead::{Aead, AeadCore, KeyInit, OsRng, Payload};
+    use chacha20poly1305::{ChaCha20Poly1305, Key, Nonce};
+
+    const KEY_SIZE: usize = 32;
+    const NONCE_SIZE: usize = 12;
+
+    pub fn get_random(length: usize) -> Vec<u8> {
+        let mut value = vec![0; length];
+        OsRng.fill_bytes(&mut value);
+
+        value
+    }
+
+    pub fn derive_key(pass: &[u8], salt: &[u8]) -> mlua::Result<Vec<u8>> {
+        let mut key = vec![0; KEY_SIZE];
+
+        argon2::Argon2::default()
+            .hash_password_into(pass, salt, &mut key)
+            .map_err(|e| mlua::Error::runtime(format!("derive_key(): {e}")))?;
+
+        Ok(key)
+    }
+
+    fn get_cipher(call: &str, key: &[u8]) -> mlua::Result<ChaCha20Poly1305> {
+        if key.len() != KEY_SIZE {
+            return Err(mlua::Error::runtime(format!(
+                "{call}(): Key must be {KEY_SIZE} bytes long, got {}.",
+                key.len()
+            )));
+        }
+
+        Ok(ChaCha20Poly1305::new(Key::from_slice(key)))
+    }
+
+    pub fn encrypt(data: &[u8], key: &[u8], side: &[u8]) -> mlua::Result<Vec<u8>> {
+        let cipher = get_cipher("encrypt", key)?;
+        let nonce = ChaCha20Poly1305::generate_nonce(&mut OsRng);
+        let value = cipher
+            .encrypt(
+                &nonce,
+                Payload {
+                    msg: data,
+                    aad: side,
+                },
+            )
+            .map_err(|_| mlua::Error::runtime("encrypt(): Could not encrypt data."))?;
+
+        let mut data = nonce.to_vec();
+        data.extend(value);
+
+        Ok(data)
+    }
+
+    pub fn decrypt(data: &[u8], key: &[u8], side: &[u8]) -> mlua::Result<Vec<u8>> {
+        let cipher = get_cipher("decrypt", key)?;
+
+        if data.len() < NONCE_SIZE {
+            return Err(mlua::Error::runtime(
+                "decrypt(): Data is too short to have been encrypted.",
+            ));
+        }
+
+        let (nonce, data) = data.split_at(NONCE_SIZE);
+
+        cipher
+            .decrypt(Nonce::from_slice(nonce), Payload { msg: data, aad: side })
+            .map_err(|_| {
+                mlua::Error::runtime(
+                    "decrypt(): Could not decrypt data. The key is wrong, or the data has been tampered with.",
+                )
+            })
+    }
+}
+
+//================================================================
+
+/* entry
+{
+    "version": "1.0.0",
+    "name": "quiver.data.serialize",
+    "info": "Serialize a given Lua value as another format, in the form of a string. Binary formats (MessagePack, CBOR) will return a data buffer instead, and will keep any data buffer in the value as binary data.",
+    "member": [
+        { "name": "text", "info": "Lua value to serialize.",                              "kind": "any"          },
+        { "name": "kind", "info": "OPTIONAL: The format to serialize to. Default: JSON.", "kind": "format_kind?" }
+    ],
+    "result": [
+        { "name": "value", "info": "The value, in string form.", "kind": "string | data" }
+    ],
+    "test": "serialization/serialize_binary.lua"
+}
+*/
+#[cfg(feature = "serialization")]
+fn serialize(lua: &Lua, (text, kind): (LuaValue, Option<i32>)) -> mlua::Result<LuaValue> {
+    let kind = kind.unwrap_or_default();
+
+    let text = match kind {
+        0 => {
+            let text: serde_json::Value = lua.from_value(text)?;
+            serde_json::to_string(&text).map_err(|e| mlua::Error::runtime(e.to_string()))
+        }
+        1 => {
+            let text: serde_json::Value = lua.from_value(text)?;
+            serde_yaml::to_string(&text).map_err(|e| mlua::Error::runtime(e.to_string()))
+        }
+        2 => {
+            let text: serde_json::Value = lua.from_value(text)?;
+            toml::to_string(&text).map_err(|e| mlua::Error::runtime(e.to_string()))
+        }
+        3 => {
+            let text: serde_json::Value = lua.from_value(text)?;
+            serde_xml_rs::to_string(&text).map_err(|e| mlua::Error::runtime(e.to_string()))
+        }
+        4 => {
+            let text: serde_json::Value = lua.from_value(text)?;
+            serde_ini::to_string(&text).map_err(|e| mlua::Error::runtime(e.to_string()))
+        }
+        5 => {
+            let mut data = Vec::new();
+            rmpv::encode::write_value(&mut data, &binary::to_msgpack(&text, &mut Vec::new())?)
+                .map_err(|e| mlua::Error::runtime(e.to_string()))?;
+
+            return Ok(LuaValue::UserData(
+                lua.create_userdata(Data::new(lua, data)?)?,
+            ));
+        }
+        6 => {
+            let mut data = Vec::new();
+            ciborium::into_writer(&binary::to_cbor(&text, &mut Vec::new())?, &mut data)
+                .map_err(|e| mlua::Error::runtime(e.to_string()))?;
+
+            return Ok(LuaValue::UserData(
+                lua.create_userdata(Data::new(lua, data)?)?,
+            ));
+        }
+        _ => Err(mlua::Error::runtime("serialize(): Unknown format kind.")),
+    }?;
+
+    lua.to_value(&text)
+}
+
+#[cfg(not(feature = "serialization"))]
+fn serialize(lua: &Lua, (text, _): (LuaValue, Option<i32>)) -> mlua::Result<String> {
+    let text: serde_json::Value = lua.from_value(text)?;
+    serde_json::to_string(&text).map_err(|e| mlua::Error::runtime(e.to_string()))
+}
+
+/* entry
+{
+    "version": "1.0.0",
+    "name": "quiver.data.deserialize",
+    "info": "Deserialize a given format string as a Lua value. Binary formats (MessagePack, CBOR) will take a data buffer instead, and will return any binary data in the value as a data buffer.",
+    "member": [
+        { "name": "text", "info": "String to deserialize.",                                   "kind": "string | data" },
+        { "name": "kind", "info": "OPTIONAL: The format to deserialize from. Default: JSON.", "kind": "format_kind?"  }
+    ],
+    "result": [
+        { "name": "value", "info": "The value, in Lua value form.", "kind": "any" }
+    ]
+}
+*/
+#[cfg(feature = "serialization")]
+fn deserialize(lua: &Lua, (text, kind): (LuaValue, Option<i32>)) -> mlua::Result<LuaValue> {
+    let kind = kind.unwrap_or_default();
+
+    // get the raw data, either from a string or from a data buffer.
+    let data = if let LuaValue::String(text) = &text {
+        text.as_bytes().to_vec()
+    } else {
+        Data::<u8>::get_buffer(text)?.0.clone()
+    };
+
+    match kind {
+        5 => {
+            let data = rmpv::decode::read_value(&mut data.as_slice())
+                .map_err(|e| mlua::Error::runtime(e.to_string()))?;
+
+            return binary::from_msgpack(lua, data);
+        }
+        6 => {
+            let data: ciborium::Value = ciborium::from_reader(data.as_slice())
+                .map_err(|e| mlua::Error::runtime(e.to_string()))?;
+
+            return binary::from_cbor(lua, data);
+        }
+        _ => {}
+    }
+
+    let text = String::from_utf8(data).map_err(|e| mlua::Error::runtime(e.to_string()))?;
+
+    match kind {
+        0 => {
+            let text: serde_json::Value =
+                serde_json::from_str(&text).map_err(|e| mlua::Error::runtime(e.to_string()))?;
+            lua.to_value(&text)
+        }
+        1 => {
+            let text: serde_json::Value =
+                serde_yaml::from_str(&text).map_err(|e| mlua::Error::runtime(e.to_string()))?;
+            lua.to_value(&text)
+        }
+        2 => {
+            let text: serde_json::Value =
+                toml::from_str(&text).map_err(|e| mlua::Error::runtime(e.to_string()))?;
+            lua.to_value(&text)
+        }
+        3 => {
+            let text: serde_json::Value =
+                serde_xml_rs::from_str(&text).map_err(|e| mlua::Error::runtime(e.to_string()))?;
+            lua.to_value(&text)
+        }
+        4 => {
+            let text: serde_json::Value =
+                serde_ini::from_str(&text).map_err(|e| mlua::Error::runtime(e.to_string()))?;
+            lua.to_value(&text)
+        }
+        _ => Err(mlua::Error::runtime("deserialize(): Unknown format kind.")),
+    }
+}
+
+#[cfg(not(feature = "serialization"))]
+fn deserialize(lua: &Lua, (text, _): (String, Option<i32>)) -> mlua::Result<LuaValue> {
+    let text: serde_json::Value =
         serde_json::from_str(&text).map_err(|e| mlua::Error::runtime(e.to_string()))?;
     lua.to_value(&text)
 }
 
+// conversion between a Lua value and a binary format value. this does not go through serde_json, to keep any data buffer as binary data.
+#[cfg(feature = "serialization")]
+mod binary {
+    use super::Data;
+    use mlua::prelude::*;
+    use std::ffi::c_void;
+
+    // the deepest table nesting that can be serialized.
+    const DEPTH: usize = 256;
+
+    // get a table as a list, if every key in the table is a sequence from 1 to N.
+    fn get_list(table: &mlua::Table) -> mlua::Result<Option<Vec<LuaValue>>> {
+        let length = table.raw_len();
+
+        if length == 0 || table.pairs::<LuaValue, LuaValue>().count() != length {
+            return Ok(None);
+        }
+
+        let mut list = Vec::with_capacity(length);
+
+        for x in 1..=length {
+            list.push(table.raw_get(x)?);
+        }
+
+        Ok(Some(list))
+    }
+
+    fn get_map(table: &mlua::Table) -> mlua::Result<Vec<(LuaValue, LuaValue)>> {
+        table.pairs::<LuaValue, LuaValue>().collect()
+    }
+
+    // enter a table, with every table it is nested in, to refuse a recursive table.
+    fn set_visit(visit: &mut Vec<*const c_void>, table: &mlua::Table) -> mlua::Result<()> {
+        let pointer = table.to_pointer();
+
+        if visit.contains(&pointer) {
+            return Err(mlua::Error::runtime(
+                "serialize(): Can not serialize a recursive table.",
+            ));
+        }
+
+        if visit.len() >= DEPTH {
+            return Err(mlua::Error::runtime(
+                "serialize(): Table is nested too deep.",
+            ));
+        }
+
+        visit.push(pointer);
+
+        Ok(())
+    }
+
+    fn get_data(value: &mlua::AnyUserData) -> mlua::Result<Vec<u8>> {
+        match value.borrow::<Data<u8>>() {
+            Ok(data) => Ok(data.0.clone()),
+            Err(_) => Err(mlua::Error::runtime(
+                "serialize(): Only a data buffer user-data can be serialized.",
+            )),
+        }
+    }
+
+    fn set_data(lua: &Lua, data: Vec<u8>) -> mlua::Result<LuaValue> {
+        Ok(LuaValue::UserData(
+            lua.create_userdata(Data::new(lua, data)?)?,
+        ))
+    }
+
+    pub fn to_msgpack(
+        value: &LuaValue,
+        visit: &mut Vec<*const c_void>,
+    ) -> mlua::Result<rmpv::Value> {
+        Ok(match value {
+            LuaValue::Nil => rmpv::Value::Nil,
+            LuaValue::Boolean(value) => rmpv::Value::Boolean(*value),
+            LuaValue::Integer(value) => rmpv::Value::from(*value as i64),
+            LuaValue::Number(value) => rmpv::Value::F64(*value),
+            // a string that is not UTF-8 can only be stored as binary data.
+            LuaValue::String(value) => match value.to_str() {
+                Ok(text) => rmpv::Value::from(text.to_string()),
+                Err(_) => rmpv::Value::Binary(value.as_bytes().to_vec()),
+            },
+            LuaValue::Table(table) => {
+                set_visit(visit, table)?;
+
+                let value = match get_list(table)? {
+                    Some(list) => rmpv::Value::Array(
+                        list.iter()
+                            .map(|x| to_msgpack(x, visit))
+                            .collect::<mlua::Result<_>>()?,
+                    ),
+                    None => rmpv::Value::Map(
+                        get_map(table)?
+                            .iter()
+                            .map(|(k, v)| Ok((to_msgpack(k, visit)?, to_msgpack(v, visit)?)))
+                            .collect::<mlua::Result<_>>()?,
+                    ),
+                };
+
+                visit.pop();
+
+                value
+            }
+            LuaValue::UserData(value) => rmpv::Value::Binary(get_data(value)?),
+            _ => {
+                return Err(mlua::Error::runtime(format!(
+                    "serialize(): Can not serialize value of type \"{}\".",
+                    value.type_name()
+                )));
+            }
+        })
+    }
+
+    pub fn from_msgpack(lua: &Lua, value: rmpv::Value) -> mlua::Result<LuaValue> {
+        Ok(match value {
+            rmpv::Value::Nil => LuaValue::Nil,
+            rmpv::Value::Boolean(value) => LuaValue::Boolean(value),
+            rmpv::Value::Integer(value) => match value.as_i64() {
+                Some(value) => LuaValue::Integer(value as mlua::Integer),
+                None => LuaValue::Number(value.as_f64().unwrap_or_default()),
+            },
+            rmpv::Value::F32(value) => LuaValue::Number(value as f64),
+            rmpv::Value::F64(value) => LuaValue::Number(value),
+            rmpv::Value::String(value) => LuaValue::String(lua.create_string(value.as_bytes())?),
+            rmpv::Value::Binary(value) => set_data(lua, value)?,
+            rmpv::Value::Ext(_, value) => set_data(lua, value)?,
+            rmpv::Value::Array(value) => {
+                let table = lua.create_table_with_capacity(value.len(), 0)?;
+
+                for value in value {
+                    table.raw_push(from_msgpack(lua, value)?)?;
+                }
+
+                LuaValue::Table(table)
+            }
+            rmpv::Value::Map(value) => {
+                let table = lua.create_table_with_capacity(0, value.len())?;
+
+                for (k, v) in value {
+                    table.raw_set(from_msgpack(lua, k)?, from_msgpack(lua, v)?)?;
+                }
+
+                LuaValue::Table(table)
+            }
+        })
+    }
+
+    pub fn to_cbor(
+        value: &LuaValue,
+        visit: &mut Vec<*const c_void>,
+    ) -> mlua::Result<ciborium::Value> {
+        Ok(match value {
+            LuaValue::Nil => ciborium::Value::Null,
+            LuaValue::Boolean(value) => ciborium::Value::Bool(*value),
+            LuaValue::Integer(value) => ciborium::Value::Integer((*value as i64).into()),
+            LuaValue::Number(value) => ciborium::Value::Float(*value),
+            // a string that is not UTF-8 can only be stored as binary data.
+            LuaValue::String(value) => match value.to_str() {
+                Ok(text) => ciborium::Value::Text(text.to_string()),
+                Err(_) => ciborium::Value::Bytes(value.as_bytes().to_vec()),
+            },
+            LuaValue::Table(table) => {
+                set_visit(visit, table)?;
+
+                let value = match get_list(table)? {
+                    Some(list) => ciborium::Value::Array(
+                        list.iter()
+                            .map(|x| to_cbor(x, visit))
+                            .collect::<mlua::Result<_>>()?,
+                    ),
+                    None => ciborium::Value::Map(
+                        get_map(table)?
+                            .iter()
+                            .map(|(k, v)| Ok((to_cbor(k, visit)?, to_cbor(v, visit)?)))
+                            .collect::<mlua::Result<_>>()?,
+                    ),
+                };
+
+                visit.pop();
+
+                value
+            }
+            LuaValue::UserData(value) => ciborium::Value::Bytes(get_data(value)?),
+            _ => {
+                return Err(mlua::Error::runtime(format!(
+                    "serialize(): Can not serialize value of type \"{}\".",
+                    value.type_name()
+                )));
+            }
+        })
+    }
+
+    pub fn from_cbor(lua: &Lua, value: ciborium::Value) -> mlua::Result<LuaValue> {
+        Ok(match value {
+            ciborium::Value::Null => LuaValue::Nil,
+            ciborium::Value::Bool(value) => LuaValue::Boolean(value),
+            ciborium::Value::Integer(value) => match i64::try_from(value) {
+                Ok(value) => LuaValue::Integer(value as mlua::Integer),
+                Err(_) => LuaValue::Number(i128::from(value) as f64),
+            },
+            ciborium::Value::Float(value) => LuaValue::Number(value),
+            ciborium::Value::Text(value) => LuaValue::String(lua.create_string(value)?),
+            ciborium::Value::Bytes(value) => set_data(lua, value)?,
+            // a tag only carries meaning for the application, use the tagged value.
+            ciborium::Value::Tag(_, value) => from_cbor(lua, *value)?,
+            ciborium::Value::Array(value) => {
+                let table = lua.create_table_with_capacity(value.len(), 0)?;
+
+                for value in value {
+                    table.raw_push(from_cbor(lua, value)?)?;
+                }
+
+                LuaValue::Table(table)
+            }
+            ciborium::Value::Map(value) => {
+                let table = lua.create_table_with_capacity(0, value.len())?;
+
+                for (k, v) in value {
+                    table.raw_set(from_cbor(lua, k)?, from_cbor(lua, v)?)?;
+                }
+
+                LuaValue::Table(table)
+            }
+            _ => {
+                return Err(mlua::Error::runtime("deserialize(): Unknown CBOR value."));
+            }
+        })
+    }
+}
+
+//================================================================
+
+/* entry
+{
+    "version": "1.0.0",
+    "name": "quiver.data.validate",
+    "info": "Validate a given Lua value against a schema, such as a table given back by quiver.data.deserialize. The schema follows a subset of JSON Schema: type (nil or null, boolean, integer, number, string, array, object, data), enum, const, minimum, maximum, exclusiveMinimum, exclusiveMaximum, minLength, maxLength, pattern (as a Lua pattern), properties, required, additionalProperties, items, minItems, maxItems, allOf, anyOf, oneOf and not.",
+    "member": [
+        { "name": "value",  "info": "The value to validate.", "kind": "any"   },
+        { "name": "schema", "info": "The schema.",            "kind": "table" }
+    ],
+    "result": [
+        { "name": "valid", "info": "True if the value is valid.",                                                          "kind": "boolean" },
+        { "name": "error", "info": "A list of every error, each one qualified with its path (e.g. '$.enemy[2].health: ...').", "kind": "table"   }
+    ],
+    "test": "data/validate.lua"
+}
+*/
+fn validate(
+    lua: &Lua,
+    (value, schema): (LuaValue, mlua::Table),
+) -> mlua::Result<(bool, Vec<String>)> {
+    let mut error = Vec::new();
+
+    schema::validate(lua, &value, &schema, "$", &mut error)?;
+
+    Ok((error.is_empty(), error))
+}
+
+mod schema {
+    use super::Data;
+    use mlua::prelude::*;
+
+    // get the length of a table, if every key in the table is a sequence from 1 to N.
+    fn get_list(table: &mlua::Table) -> Option<usize> {
+        let length = table.raw_len();
+
+        if table.pairs::<LuaValue, LuaValue>().count() == length {
+            Some(length)
+        } else {
+            None
+        }
+    }
+
+    fn get_kind(value: &LuaValue) -> &'static str {
+        match value {
+            LuaValue::Nil => "nil",
+            // the null value of a deserialized file, from quiver.data.deserialize.
+            LuaValue::LightUserData(value) if value.0.is_null() => "nil",
+            LuaValue::Boolean(_) => "boolean",
+            LuaValue::Integer(_) => "integer",
+            LuaValue::Number(value) => {
+                if value.fract() == 0.0 {
+                    "integer"
+                } else {
+                    "number"
+                }
+            }
+            LuaValue::String(_) => "string",
+            LuaValue::Table(table) => {
+                if get_list(table).is_some_and(|x| x > 0) {
+                    "array"
+                } else {
+                    "object"
+                }
+            }
+            LuaValue::UserData(value) => {
+                if value.is::<Data<u8>>() {
+                    "data"
+                } else {
+                    "user-data"
+                }
+            }
+            _ => "unknown",
+        }
+    }
+
+    fn is_kind(value: &LuaValue, kind: &str) -> bool {
+        let have = get_kind(value);
+
+        match kind {
+            "number" => have == "number" || have == "integer",
+            // "null" is the JSON Schema name for nil.
+            "null" => have == "nil",
+            // an empty table can be either an array, or an object.
+            "array" | "object" => {
+                have == kind
+                    || matches!(value, LuaValue::Table(table) if get_list(table) == Some(0))
+            }
+            _ => have == kind,
+        }
+    }
+
+    fn get_path(path: &str, key: &LuaValue) -> String {
+        match key {
+            LuaValue::Integer(key) => format!("{path}[{key}]"),
+            LuaValue::String(key) => {
+                let key = key.to_string_lossy();
+
+                if !key.is_empty()
+                    && !key.starts_with(|x: char| x.is_ascii_digit())
+                    && key.chars().all(|x| x.is_ascii_alphanumeric() || x == '_')
+                {
+                    format!("{path}.{key}")
+                } else {
+                    format!("{path}[\"{key}\"]")
+                }
+            }
+            key => format!("{path}[{}]", key.to_string().unwrap_or_default()),
+        }
+    }
+
+    fn get_text(value: &LuaValue) -> String {
+        match value {
+            LuaValue::String(value) => format!("\"{}\"", value.to_string_lossy()),
+            LuaValue::Nil | LuaValue::Boolean(_) | LuaValue::Integer(_) | LuaValue::Number(_) => {
+                value.to_string().unwrap_or_default()
+            }
+            value => get_kind(value).to_string(),
+        }
+    }
+
+    fn get_number(value: &LuaValue) -> Option<f64> {
+        match value {
+            LuaValue::Integer(value) => Some(*value as f64),
+            LuaValue::Number(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    fn is_equal(a: &LuaValue, b: &LuaValue) -> bool {
+        match (get_number(a), get_number(b)) {
+            (Some(a), Some(b)) => a == b,
+            _ => a == b,
+        }
+    }
+
+    // validate a list of sub-schema, giving back the error list of each one.
+    fn validate_list(
+        lua: &Lua,
+        value: &LuaValue,
+        list: &mlua::Table,
+        path: &str,
+    ) -> mlua::Result<Vec<Vec<String>>> {
+        let mut result = Vec::new();
+
+        for schema in list.sequence_values::<mlua::Table>() {
+            let mut error = Vec::new();
+            validate(lua, value, &schema?, path, &mut error)?;
+            result.push(error);
+        }
+
+        Ok(result)
+    }
+
+    pub fn validate(
+        lua: &Lua,
+        value: &LuaValue,
+        schema: &mlua::Table,
+        path: &str,
+        error: &mut Vec<String>,
+    ) -> mlua::Result<()> {
+        //================================================================
+        // generic.
+
+        if let Some(kind) = schema.get::<Option<LuaValue>>("type")? {
+            let list: Vec<String> = match kind {
+                LuaValue::Table(kind) => kind.sequence_values().collect::<mlua::Result<_>>()?,
+                kind => vec![String::from_lua(kind, lua)?],
+            };
+
+            if !list.iter().any(|x| is_kind(value, x)) {
+                error.push(format!(
+                    "{path}: Expected {}, got {}.",
+                    list.join(" or "),
+                    get_kind(value)
+                ));
+
+                // every other check will be meaningless with the wrong kind.
+                return Ok(());
+            }
+        }
+
+        if let Some(list) = schema.get::<Option<mlua::Table>>("enum")? {
+            let list: Vec<LuaValue> = list.sequence_values().collect::<mlua::Result<_>>()?;
+
+            if !list.iter().any(|x| is_equal(value, x)) {
+                let list: Vec<String> = list.iter().map(get_text).collect();
+
+                error.push(format!(
+                    "{path}: Expected one of {}, got {}.",
+                    list.join(", "),
+                    get_text(value)
+                ));
+            }
+        }
+
+        let constant: LuaValue = schema.get("const")?;
+
+        if !constant.is_nil() && !is_equal(value, &constant) {
+            error.push(format!(
+                "{path}: Expected {}, got {}.",
+                get_text(&constant),
+                get_text(value)
+            ));
+        }
+
+        //================================================================
+        // number.
+
+        if let Some(number) = get_number(value) {
+            if let Some(limit) = schema
+                .get::<Option<f64>>("minimum")?
+                .filter(|limit| number < *limit)
+            {
+                error.push(format!(
+                    "{path}: {number} is less than the minimum of {limit}."
+                ));
+            }
+
+            if let Some(limit) = schema
+                .get::<Option<f64>>("maximum")?
+                .filter(|limit| number > *limit)
+            {
+                error.push(format!(
+                    "{path}: {number} is greater than the maximum of {limit}."
+                ));
+            }
+
+            if let Some(limit) = schema
+                .get::<Option<f64>>("exclusiveMinimum")?
+                .filter(|limit| number <= *limit)
+            {
+                error.push(format!("{path}: {number} must be greater than {limit}."));
+            }
+
+            if let Some(limit) = schema
+                .get::<Option<f64>>("exclusiveMaximum")?
+                .filter(|limit| number >= *limit)
+            {
+                error.push(format!("{path}: {number} must be less than {limit}."));
+            }
+        }
+
+        //================================================================
+        // string.
+
+        if let LuaValue::String(text) = value {
+            let length = text.to_string_lossy().chars().count();
+
+            if let Some(limit) = schema
+                .get::<Option<usize>>("minLength")?
+                .filter(|limit| length < *limit)
+            {
+                error.push(format!(
+                    "{path}: String is {length} character(s) long, minimum is {limit}."
+                ));
+            }
+
+            if let Some(limit) = schema
+                .get::<Option<usize>>("maxLength")?
+                .filter(|limit| length > *limit)
+            {
+                error.push(format!(
+                    "{path}: String is {length} character(s) long, maximum is {limit}."
+                ));
+            }
+
+            if let Some(pattern) = schema.get::<Option<mlua::String>>("pattern")? {
+                let find: mlua::Function =
+                    lua.globals().get::<mlua::Table>("string")?.get("find")?;
+
+                if find.call::<LuaValue>((text, &pattern))?.is_nil() {
+                    error.push(format!(
+                        "{path}: {} does not match the pattern \"{}\".",
+                        get_text(value),
+                        pattern.to_string_lossy()
+                    ));
+                }
+            }
+        }
+
+        //================================================================
+        // table.
+
+        if let LuaValue::Table(table) = value {
+            if let Some(list) = schema.get::<Option<mlua::Table>>("required")? {
+                for key in list.sequence_values::<mlua::String>() {
+                    let key = LuaValue::String(key?);
+
+                    if table.raw_get::<LuaValue>(&key)?.is_nil() {
+                        error.push(format!("{}: Missing required value.", get_path(path, &key)));
+                    }
+                }
+            }
+
+            let property = schema.get::<Option<mlua::Table>>("properties")?;
+            let additional: LuaValue = schema.get("additionalProperties")?;
+
+            if property.is_some() || !additional.is_nil() {
+                for pair in table.pairs::<LuaValue, LuaValue>() {
+                    let (key, entry) = pair?;
+                    let entry_path = get_path(path, &key);
+
+                    let property = match &property {
+                        Some(property) => property.get::<Option<mlua::Table>>(&key)?,
+                        None => None,
+                    };
+
+                    match (property, &additional) {
+                        (Some(property), _) => {
+                            validate(lua, &entry, &property, &entry_path, error)?
+                        }
+                        (None, LuaValue::Boolean(false)) => {
+                            error.push(format!("{entry_path}: Unknown value."))
+                        }
+                        (None, LuaValue::Table(additional)) => {
+                            validate(lua, &entry, additional, &entry_path, error)?
+                        }
+                        _ => {}
+                    }
+                }
+            }
+
+            if let Some(length) = get_list(table) {
+                if let Some(item) = schema.get::<Option<mlua::Table>>("items")? {
+                    for x in 1..=length {
+                        let key = LuaValue::Integer(x as mlua::Integer);
+
+                        validate(
+                            lua,
+                            &table.raw_get(&key)?,
+                            &item,
+                            &get_path(path, &key),
+                            error,
+                        )?;
+                    }
+                }
+
+                if let Some(limit) = schema
+                    .get::<Option<usize>>("minItems")?
+                    .filter(|limit| length < *limit)
+                {
+                    error.push(format!(
+                        "{path}: List has {length} item(s), minimum is {limit}."
+                    ));
+                }
+
+                if let Some(limit) = schema
+                    .get::<Option<usize>>("maxItems")?
+                    .filter(|limit| length > *limit)
+                {
+                    error.push(format!(
+                        "{path}: List has {length} item(s), maximum is {limit}."
+                    ));
+                }
+            }
+        }
+
+        //================================================================
+        // composition.
+
+        if let Some(list) = schema.get::<Option<mlua::Table>>("allOf")? {
+            for result in validate_list(lua, value, &list, path)? {
+                error.extend(result);
+            }
+        }
+
+        if let Some(list) = schema.get::<Option<mlua::Table>>("anyOf")? {
+            let result = validate_list(lua, value, &list, path)?;
+
+            if !result.iter().any(|x| x.is_empty()) {
+                error.push(format!("{path}: Does not match any schema in \"anyOf\"."));
+            }
+        }
+
+        if let Some(list) = schema.get::<Option<mlua::Table>>("oneOf")? {
+            let count = validate_list(lua, value, &list, path)?
+                .iter()
+                .filter(|x| x.is_empty())
+                .count();
+
+            if count != 1 {
+                error.push(format!(
+                    "{path}: Matches {count} schema in \"oneOf\", expected exactly one."
+                ));
+            }
+        }
+
+        if let Some(not) = schema.get::<Option<mlua::Table>>("not")? {
+            let mut result = Vec::new();
+            validate(lua, value, &not, path, &mut result)?;
+
+            if result.is_empty() {
+                error.push(format!("{path}: Matches the schema in \"not\"."));
+            }
+        }
+
+        Ok(())
+    }
+}
+
 //================================================================
 
 /* entry
@@ -114,7 +114,7 @@ fn clear(lua: &Lua, color: LuaValue) -> mlua::Result<()> {
 }
 */
 fn begin(
-    _: &Lua,
+    lua: &Lua,
     (call, variadic): (mlua::Function, mlua::Variadic<LuaValue>),
 ) -> mlua::Result<()> {
     unsafe {
@@ -123,8 +123,15 @@ fn begin(
         call.call::<()>(variadic)?;
 
         ffi::EndDrawing();
-        Ok(())
     }
+
+    // advance the active replay, if any, for the next frame.
+    crate::base::automation::Replay::update(lua);
+
+    // update every music player.
+    crate::base::music::MusicPlayer::update(lua);
+
+    Ok(())
 }
 
 // to-do: blend mode enumerator. error if mode is outside of range.
@@ -48,6 +48,7 @@
 * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 */
 
+use crate::sandbox::*;
 use crate::script::*;
 use crate::status::*;
 
@@ -71,7 +72,10 @@ pub fn set_global(lua: &Lua, table: &mlua::Table, _: &StatusInfo, _: Option<&Scr
     file.set("copy_file",       lua.create_function(self::copy_file)?)?;
     file.set("remove_file",     lua.create_function(self::remove_file)?)?;
     file.set("remove_path",     lua.create_function(self::remove_path)?)?;
-    file.set("set_path_escape", lua.create_function(self::set_path_escape)?)?;
+    file.set("get_save_path",   lua.create_function(self::get_save_path)?)?;
+    file.set("get_config_path", lua.create_function(self::get_config_path)?)?;
+    file.set("get_cache_path",  lua.create_function(self::get_cache_path)?)?;
+    file.set("get_temp_path",   lua.create_function(self::get_temp_path)?)?;
 
     //================================================================
     
@@ -120,14 +124,14 @@ pub fn set_global(lua: &Lua, table: &mlua::Table, _: &StatusInfo, _: Option<&Scr
 */
 fn get_file(lua: &Lua, (path, binary): (String, bool)) -> mlua::Result<LuaValue> {
     if binary {
-        let data = std::fs::read(ScriptData::get_path(lua, &path)?)
+        let data = std::fs::read(ScriptData::get_path(lua, &path, SandboxAccess::Read)?)
             .map_err(|e| mlua::Error::runtime(e.to_string()))?;
         let data = crate::base::data::Data::new(lua, data)?;
         let data = lua.create_userdata(data)?;
 
         Ok(mlua::Value::UserData(data))
     } else {
-        let data = std::fs::read_to_string(ScriptData::get_path(lua, &path)?)
+        let data = std::fs::read_to_string(ScriptData::get_path(lua, &path, SandboxAccess::Read)?)
             .map_err(|e| mlua::Error::runtime(e.to_string()))?;
 
         lua.to_value(&data)
@@ -148,16 +152,20 @@ fn get_file(lua: &Lua, (path, binary): (String, bool)) -> mlua::Result<LuaValue>
 */
 fn set_file(lua: &Lua, (path, data): (String, LuaValue)) -> mlua::Result<()> {
     match data {
-        LuaValue::String(data) => {
-            std::fs::write(ScriptData::get_path(lua, &path)?, data.to_string_lossy())
-                .map_err(|e| mlua::Error::runtime(e.to_string()))
-        }
+        LuaValue::String(data) => std::fs::write(
+            ScriptData::get_path(lua, &path, SandboxAccess::Write)?,
+            data.to_string_lossy(),
+        )
+        .map_err(|e| mlua::Error::runtime(e.to_string())),
         LuaValue::UserData(data) => {
             let data = crate::base::data::Data::get_buffer(mlua::Value::UserData(data))?;
             let data = &data.0;
 
-            std::fs::write(ScriptData::get_path(lua, &path)?, data)
-                .map_err(|e| mlua::Error::runtime(e.to_string()))
+            std::fs::write(
+                ScriptData::get_path(lua, &path, SandboxAccess::Write)?,
+                data,
+            )
+            .map_err(|e| mlua::Error::runtime(e.to_string()))
         }
         _ => Err(mlua::Error::runtime("set_file(): Unknown data type.")),
     }?;
@@ -177,8 +185,8 @@ fn set_file(lua: &Lua, (path, data): (String, LuaValue)) -> mlua::Result<()> {
 }
 */
 fn move_file(lua: &Lua, (source, target): (String, String)) -> mlua::Result<()> {
-    let source = ScriptData::get_path(lua, &source)?;
-    let target = ScriptData::get_path(lua, &target)?;
+    let source = ScriptData::get_path(lua, &source, SandboxAccess::Write)?;
+    let target = ScriptData::get_path(lua, &target, SandboxAccess::Write)?;
 
     std::fs::rename(source, target).map_err(mlua::Error::runtime)?;
 
@@ -197,8 +205,8 @@ fn move_file(lua: &Lua, (source, target): (String, String)) -> mlua::Result<()>
 }
 */
 fn copy_file(lua: &Lua, (source, target): (String, String)) -> mlua::Result<()> {
-    let source = ScriptData::get_path(lua, &source)?;
-    let target = ScriptData::get_path(lua, &target)?;
+    let source = ScriptData::get_path(lua, &source, SandboxAccess::Read)?;
+    let target = ScriptData::get_path(lua, &target, SandboxAccess::Write)?;
 
     std::fs::copy(source, target).map_err(mlua::Error::runtime)?;
 
@@ -216,7 +224,7 @@ fn copy_file(lua: &Lua, (source, target): (String, String)) -> mlua::Result<()>
 }
 */
 fn remove_file(lua: &Lua, path: String) -> mlua::Result<()> {
-    let path = ScriptData::get_path(lua, &path)?;
+    let path = ScriptData::get_path(lua, &path, SandboxAccess::Write)?;
 
     std::fs::remove_file(path).map_err(mlua::Error::runtime)?;
 
@@ -234,7 +242,7 @@ fn remove_file(lua: &Lua, path: String) -> mlua::Result<()> {
 }
 */
 fn remove_path(lua: &Lua, path: String) -> mlua::Result<()> {
-    let path = ScriptData::get_path(lua, &path)?;
+    let path = ScriptData::get_path(lua, &path, SandboxAccess::Write)?;
 
     std::fs::remove_dir_all(path).map_err(mlua::Error::runtime)?;
 
@@ -244,15 +252,72 @@ fn remove_path(lua: &Lua, path: String) -> mlua::Result<()> {
 /* entry
 {
     "version": "1.0.0",
-    "name": "quiver.file.set_path_escape",
-    "info": "Set the state of the path sand-box.",
-    "member": [
-        { "name": "state", "info": "The state of the path sand-box.", "kind": "boolean" }
+    "name": "quiver.file.get_save_path",
+    "info": "Get the per-user save data path, by the name in the info manifest. The path will be created if missing. Prefer this over the project path for writing any user data.",
+    "test": "file/get_save_path.lua",
+    "result": [
+        { "name": "path", "info": "The save data path.", "kind": "string" }
     ]
 }
 */
-fn set_path_escape(lua: &Lua, state: bool) -> mlua::Result<()> {
-    ScriptData::set_path_escape(lua, state)
+fn get_save_path(lua: &Lua, _: ()) -> mlua::Result<String> {
+    get_root_path(lua, Sandbox::ROOT_SAVE)
+}
+
+/* entry
+{
+    "version": "1.0.0",
+    "name": "quiver.file.get_config_path",
+    "info": "Get the per-user configuration path, by the name in the info manifest. The path will be created if missing.",
+    "result": [
+        { "name": "path", "info": "The configuration path.", "kind": "string" }
+    ]
+}
+*/
+fn get_config_path(lua: &Lua, _: ()) -> mlua::Result<String> {
+    get_root_path(lua, Sandbox::ROOT_CONFIG)
+}
+
+/* entry
+{
+    "version": "1.0.0",
+    "name": "quiver.file.get_cache_path",
+    "info": "Get the per-user cache path, by the name in the info manifest. The path will be created if missing.",
+    "result": [
+        { "name": "path", "info": "The cache path.", "kind": "string" }
+    ]
+}
+*/
+fn get_cache_path(lua: &Lua, _: ()) -> mlua::Result<String> {
+    get_root_path(lua, Sandbox::ROOT_CACHE)
+}
+
+/* entry
+{
+    "version": "1.0.0",
+    "name": "quiver.file.get_temp_path",
+    "info": "Get the temporary path, by the name in the info manifest. The path will be created if missing.",
+    "result": [
+        { "name": "path", "info": "The temporary path.", "kind": "string" }
+    ]
+}
+*/
+fn get_temp_path(lua: &Lua, _: ()) -> mlua::Result<String> {
+    get_root_path(lua, Sandbox::ROOT_TEMP)
+}
+
+fn get_root_path(lua: &Lua, name: &str) -> mlua::Result<String> {
+    let script_data = lua.app_data_ref::<ScriptData>().unwrap();
+    let path = &script_data.sandbox.get_root(name).path;
+
+    std::fs::create_dir_all(path).map_err(|e| {
+        mlua::Error::runtime(format!(
+            "get_root_path(): Error on path \"{}\" creation: {e}",
+            path.display()
+        ))
+    })?;
+
+    Ok(path.display().to_string())
 }
 
 //================================================================
@@ -472,7 +537,7 @@ fn set_call_load_text(_: &Lua, call: mlua::Function) -> mlua::Result<()> {
 }
 */
 fn get_file_exist(lua: &Lua, path: String) -> mlua::Result<bool> {
-    let path = Script::rust_to_c_string(&ScriptData::get_path(lua, &path)?)?;
+    let path = Script::rust_to_c_string(&ScriptData::get_path(lua, &path, SandboxAccess::Read)?)?;
 
     unsafe { Ok(ffi::FileExists(path.as_ptr())) }
 }
@@ -492,7 +557,7 @@ fn get_file_exist(lua: &Lua, path: String) -> mlua::Result<bool> {
 }
 */
 fn get_path_exist(lua: &Lua, path: String) -> mlua::Result<bool> {
-    let path = Script::rust_to_c_string(&ScriptData::get_path(lua, &path)?)?;
+    let path = Script::rust_to_c_string(&ScriptData::get_path(lua, &path, SandboxAccess::Read)?)?;
 
     unsafe { Ok(ffi::DirectoryExists(path.as_ptr())) }
 }
@@ -512,7 +577,7 @@ fn get_path_exist(lua: &Lua, path: String) -> mlua::Result<bool> {
 }
 */
 fn get_file_extension_check(lua: &Lua, (path, extension): (String, String)) -> mlua::Result<bool> {
-    let path = Script::rust_to_c_string(&ScriptData::get_path(lua, &path)?)?;
+    let path = Script::rust_to_c_string(&ScriptData::get_path(lua, &path, SandboxAccess::Read)?)?;
     let extension =
         Script::rust_to_c_string(&extension).map_err(|e| mlua::Error::runtime(e.to_string()))?;
 
@@ -533,7 +598,7 @@ fn get_file_extension_check(lua: &Lua, (path, extension): (String, String)) -> m
 }
 */
 fn get_file_size(lua: &Lua, path: String) -> mlua::Result<i32> {
-    let path = Script::rust_to_c_string(&ScriptData::get_path(lua, &path)?)?;
+    let path = Script::rust_to_c_string(&ScriptData::get_path(lua, &path, SandboxAccess::Read)?)?;
 
     unsafe { Ok(ffi::GetFileLength(path.as_ptr())) }
 }
@@ -552,7 +617,7 @@ fn get_file_size(lua: &Lua, path: String) -> mlua::Result<i32> {
 }
 */
 fn get_file_extension(lua: &Lua, path: String) -> mlua::Result<String> {
-    let path = Script::rust_to_c_string(&ScriptData::get_path(lua, &path)?)?;
+    let path = Script::rust_to_c_string(&ScriptData::get_path(lua, &path, SandboxAccess::Read)?)?;
 
     unsafe {
         let result = ffi::GetFileExtension(path.as_ptr());
@@ -575,7 +640,7 @@ fn get_file_extension(lua: &Lua, path: String) -> mlua::Result<String> {
 }
 */
 fn get_file_name(lua: &Lua, (path, extension): (String, bool)) -> mlua::Result<String> {
-    let path = Script::rust_to_c_string(&ScriptData::get_path(lua, &path)?)?;
+    let path = Script::rust_to_c_string(&ScriptData::get_path(lua, &path, SandboxAccess::Read)?)?;
 
     unsafe {
         if extension {
@@ -598,7 +663,8 @@ fn get_file_name(lua: &Lua, (path, extension): (String, bool)) -> mlua::Result<S
 fn get_absolute_path(lua: &Lua, path: String) -> mlua::Result<String> {
     unsafe {
         let value = ffi::GetDirectoryPath(
-            Script::rust_to_c_string(&ScriptData::get_path(lua, &path)?)?.as_ptr(),
+            Script::rust_to_c_string(&ScriptData::get_path(lua, &path, SandboxAccess::Read)?)?
+                .as_ptr(),
         );
 
         Script::c_to_rust_string(value)
@@ -615,7 +681,8 @@ fn get_absolute_path(lua: &Lua, path: String) -> mlua::Result<String> {
 fn get_previous_path(lua: &Lua, path: String) -> mlua::Result<String> {
     unsafe {
         let value = ffi::GetPrevDirectoryPath(
-            Script::rust_to_c_string(&ScriptData::get_path(lua, &path)?)?.as_ptr(),
+            Script::rust_to_c_string(&ScriptData::get_path(lua, &path, SandboxAccess::Read)?)?
+                .as_ptr(),
         );
 
         Script::c_to_rust_string(value)
@@ -666,7 +733,8 @@ fn get_application_directory(_: &Lua, _: ()) -> mlua::Result<String> {
 fn create_path(lua: &Lua, path: String) -> mlua::Result<()> {
     unsafe {
         let value = ffi::MakeDirectory(
-            Script::rust_to_c_string(&ScriptData::get_path(lua, &path)?)?.as_ptr(),
+            Script::rust_to_c_string(&ScriptData::get_path(lua, &path, SandboxAccess::Write)?)?
+                .as_ptr(),
         );
 
         if value == 0 {
@@ -689,7 +757,8 @@ fn create_path(lua: &Lua, path: String) -> mlua::Result<()> {
 fn change_path(lua: &Lua, path: String) -> mlua::Result<()> {
     unsafe {
         let value = ffi::ChangeDirectory(
-            Script::rust_to_c_string(&ScriptData::get_path(lua, &path)?)?.as_ptr(),
+            Script::rust_to_c_string(&ScriptData::get_path(lua, &path, SandboxAccess::Read)?)?
+                .as_ptr(),
         );
 
         if value {
@@ -712,7 +781,8 @@ fn change_path(lua: &Lua, path: String) -> mlua::Result<()> {
 fn get_path_file(lua: &Lua, path: String) -> mlua::Result<bool> {
     unsafe {
         Ok(ffi::IsPathFile(
-            Script::rust_to_c_string(&ScriptData::get_path(lua, &path)?)?.as_ptr(),
+            Script::rust_to_c_string(&ScriptData::get_path(lua, &path, SandboxAccess::Read)?)?
+                .as_ptr(),
         ))
     }
 }
@@ -752,7 +822,8 @@ fn scan_path(
     lua: &Lua,
     (path, filter, recursive, relative): (String, Option<String>, bool, bool),
 ) -> mlua::Result<LuaValue> {
-    let c_path = Script::rust_to_c_string(&ScriptData::get_path(lua, &path)?)?;
+    let path = ScriptData::get_path(lua, &path, SandboxAccess::Read)?;
+    let c_path = Script::rust_to_c_string(&path)?;
     let mut data: Vec<String> = Vec::new();
 
     unsafe {
@@ -773,11 +844,9 @@ fn scan_path(
             let result_path = Script::c_to_rust_string(result_path)?;
 
             if relative {
-                let path: Vec<&str> = result_path.split(&path).collect();
-
-                if let Some(path) = path.get(1) {
+                if let Some(path) = result_path.strip_prefix(&path) {
                     // remove the leading back-slash.
-                    let path = &path[1..path.len()];
+                    let path = path.trim_start_matches(['/', '\\']);
 
                     data.push(path.to_string());
                 }
@@ -840,7 +909,8 @@ fn get_file_drop_list(lua: &Lua, _: ()) -> mlua::Result<LuaValue> {
 fn get_file_modification(lua: &Lua, path: String) -> mlua::Result<i32> {
     unsafe {
         let time: i32 = ffi::GetFileModTime(
-            Script::rust_to_c_string(&ScriptData::get_path(lua, &path)?)?.as_ptr(),
+            Script::rust_to_c_string(&ScriptData::get_path(lua, &path, SandboxAccess::Read)?)?
+                .as_ptr(),
         )
         .try_into()
         .unwrap();
@@ -48,6 +48,9 @@
 * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 */
 
+use crate::base::shader::{RLShader, Shader};
+use crate::base::texture::Texture;
+use crate::sandbox::*;
 use crate::script::*;
 use crate::status::*;
 
@@ -55,6 +58,9 @@ use crate::status::*;
 
 use mlua::prelude::*;
 use raylib::prelude::*;
+use serde::Deserialize;
+use std::sync::atomic::{AtomicI32, Ordering};
+use std::sync::{Arc, Weak};
 
 //================================================================
 
@@ -66,9 +72,10 @@ pub fn set_global(lua: &Lua, table: &mlua::Table, _: &StatusInfo, _: Option<&Scr
     let font = lua.create_table()?;
 
     font.set("new_default",         lua.create_function(self::Font::new_default)?)?;     // GetFontDefault
-    font.set("new",                 lua.create_function(self::Font::new)?)?;             // LoadFont/*Ex TO-DO convert to Ex
+    font.set("new",                 lua.create_function(self::Font::new)?)?;             // LoadFontFromMemory
     //font.set("new_from_image",      lua.create_function(self::Font::new_from_image)?)?;  // LoadFontFromImage
     font.set("new_from_memory",     lua.create_function(self::Font::new_from_memory)?)?; // LoadFontFromMemory
+    font.set("new_layout",          lua.create_function(self::FontLayout::new)?)?;
     
     //================================================================
 
@@ -86,15 +93,155 @@ pub fn set_global(lua: &Lua, table: &mlua::Table, _: &StatusInfo, _: Option<&Scr
 
 type RLFont = raylib::core::text::Font;
 
+// the vertical space between each line-break, mirrored from SetTextLineSpacing.
+static LINE_SPACE: AtomicI32 = AtomicI32::new(2);
+
+// the head of the SDF shader, for a desktop (GLSL 330) build.
+#[cfg(not(any(target_os = "android", target_os = "emscripten")))]
+const SHADER_SDF_HEAD: &str = r#"
+#version 330
+
+in vec2 fragTexCoord;
+in vec4 fragColor;
+
+out vec4 finalColor;
+"#;
+
+// the head of the SDF shader, for an OpenGL ES 2.0 (GLSL 100) build.
+#[cfg(any(target_os = "android", target_os = "emscripten"))]
+const SHADER_SDF_HEAD: &str = r#"
+#version 100
+#extension GL_OES_standard_derivatives : enable
+
+precision mediump float;
+
+varying vec2 fragTexCoord;
+varying vec4 fragColor;
+
+#define texture texture2D
+#define finalColor gl_FragColor
+"#;
+
+// signed distance field shader, with an optional outline and glow.
+const SHADER_SDF: &str = r#"
+uniform sampler2D texture0;
+uniform vec4 colDiffuse;
+uniform float outline;
+uniform vec4 outlineColor;
+uniform float glow;
+uniform vec4 glowColor;
+
+void main()
+{
+    // the distance field is in the alpha channel, with the glyph edge at 0.5.
+    float distance = texture(texture0, fragTexCoord).a;
+    float smoothing = length(vec2(dFdx(distance), dFdy(distance)));
+    float fill = smoothstep(0.5 - smoothing, 0.5 + smoothing, distance);
+    vec4 color = fragColor * colDiffuse;
+
+    color.a *= fill;
+
+    if (outline > 0.0)
+    {
+        float edge = smoothstep(0.5 - outline - smoothing, 0.5 - outline + smoothing, distance);
+
+        color = vec4(mix(outlineColor.rgb, color.rgb, fill), mix(outlineColor.a * edge, color.a, fill));
+    }
+
+    if (glow > 0.0)
+    {
+        float halo = smoothstep(0.5 - outline - glow, 0.5 - outline, distance) * glowColor.a;
+
+        color = vec4(mix(glowColor.rgb, color.rgb, color.a), color.a + halo * (1.0 - color.a));
+    }
+
+    finalColor = color;
+}
+"#;
+
+// the distance field value for each pixel away from the glyph edge (FONT_SDF_PIXEL_DIST_SCALE).
+const SDF_PIXEL: f32 = 64.0 / 255.0;
+
 /* class
-{ "version": "1.0.0", "name": "font", "info": "An unique handle to a font in memory." }
+{
+    "version": "1.0.0",
+    "name": "font",
+    "info": "An unique handle to a font in memory. Any glyph missing from the font will be rasterized into the glyph atlas on demand, the first time it is drawn or measured. If the font file does not have a glyph either, each fallback font will be used instead, in order.",
+    "member": [
+        { "name": "size",        "info": "The size of the font.",                  "kind": "number" },
+        { "name": "glyph_count", "info": "The glyph count in the glyph atlas.", "kind": "number"    },
+        { "name": "mode",        "info": "The mode of the font.",                "kind": "font_mode" }
+    ],
+    "test": "font/codepoint.lua"
+}
 */
-struct Font(RLFont);
+struct Font {
+    font: RLFont,
+    source: FontSource,
+    shader: Option<Arc<FontShader>>,
+    fallback: Vec<mlua::AnyUserData>,
+}
+
+// the font file, kept to rasterize any missing glyph on demand.
+struct FontSource {
+    data: Vec<u8>,
+    kind: String,
+    size: i32,
+    mode: i32,
+    // every codepoint that has been rasterized (or tried), sorted.
+    codepoint: Vec<i32>,
+}
+
+// the SDF shader of a font, with the location of every effect uniform.
+struct FontShader {
+    shader: RLShader,
+    location: [i32; 4],
+}
+
+// the SDF shader in use by every SDF font, compiled only once.
+struct FontShaderShare(Weak<FontShader>);
+
+unsafe impl Send for FontShaderShare {}
+
+// a font to draw with, from either the main font or a fallback font.
+#[derive(Clone, Copy)]
+struct FontDraw {
+    font: ffi::Font,
+    shader: Option<(ffi::Shader, [i32; 4])>,
+}
+
+#[derive(Deserialize)]
+#[serde(default)]
+struct FontEffect {
+    outline: f32,
+    outline_color: Color,
+    glow: f32,
+    glow_color: Color,
+    shadow: Option<Vector2>,
+    shadow_color: Color,
+}
+
+impl Default for FontEffect {
+    fn default() -> Self {
+        Self {
+            outline: 0.0,
+            outline_color: Color::BLACK,
+            glow: 0.0,
+            glow_color: Color::WHITE,
+            shadow: None,
+            shadow_color: Color::new(0, 0, 0, 128),
+        }
+    }
+}
 
 unsafe impl Send for Font {}
 
 impl mlua::UserData for Font {
-    fn add_fields<F: mlua::UserDataFields<Self>>(_: &mut F) {}
+    fn add_fields<F: mlua::UserDataFields<Self>>(field: &mut F) {
+        field.add_field_method_get("size", |_, this| Ok(this.font.baseSize));
+        field.add_field_method_get("glyph_count", |_, this| Ok(this.font.glyphCount));
+        field.add_field_method_get("mode", |_, this| Ok(this.source.mode));
+    }
 
     fn add_methods<M: mlua::UserDataMethods<Self>>(method: &mut M) {
         /* entry
@@ -103,21 +250,22 @@ impl mlua::UserData for Font {
             "name": "font:draw",
             "info": "Draw a font.",
             "member": [
-                { "name": "label",  "info": "Label of font to draw.", "kind": "string"   },
-                { "name": "point",  "info": "Point of font to draw.", "kind": "vector_2" },
-                { "name": "origin", "info": "TO-DO", "kind": "vector_2" },
-                { "name": "angle",  "info": "TO-DO", "kind": "number" },
-                { "name": "scale",  "info": "Scale of font to draw.", "kind": "number"   },
-                { "name": "space",  "info": "Space of font to draw.", "kind": "number"   },
-                { "name": "color",  "info": "Color of font to draw.", "kind": "color"    }
+                { "name": "label",  "info": "Label of font to draw.",                       "kind": "string"   },
+                { "name": "point",  "info": "Point of font to draw.",                       "kind": "vector_2" },
+                { "name": "origin", "info": "Origin of font to draw, relative to the point.", "kind": "vector_2" },
+                { "name": "angle",  "info": "Angle of font to draw, in degree.",            "kind": "number"   },
+                { "name": "scale",  "info": "Scale of font to draw.",                       "kind": "number"   },
+                { "name": "space",  "info": "Space of font to draw.",                       "kind": "number"   },
+                { "name": "color",  "info": "Color of font to draw.",                       "kind": "color"    },
+                { "name": "effect", "info": "OPTIONAL: The effect table, with any of: outline (width, in pixel of the font size, up to 2.0), outline_color, glow (width, in pixel of the font size), glow_color, shadow (a vector_2 offset) and shadow_color. Outline and glow require an SDF font.", "kind": "table?" }
             ]
         }
         */
-        method.add_method(
+        method.add_method_mut(
             "draw",
             |lua: &Lua,
              this,
-             (text, point, origin, angle, scale, space, color): (
+             (text, point, origin, angle, scale, space, color, effect): (
                 String,
                 LuaValue,
                 LuaValue,
@@ -125,25 +273,52 @@ impl mlua::UserData for Font {
                 f32,
                 f32,
                 LuaValue,
+                Option<LuaValue>,
             )| {
                 let point: Vector2 = lua.from_value(point)?;
                 let origin: Vector2 = lua.from_value(origin)?;
                 let color: Color = lua.from_value(color)?;
-                let text = Script::rust_to_c_string(&text)?;
+                let effect: FontEffect = match effect {
+                    Some(effect) => lua.from_value(effect)?,
+                    None => FontEffect::default(),
+                };
+
+                this.set_cache(&text)?;
+
+                let fallback = this.get_fallback();
+                let list = this.get_list(&fallback);
 
                 unsafe {
-                    ffi::DrawTextPro(
-                        *this.0,
-                        text.as_ptr(),
-                        point.into(),
-                        origin.into(),
-                        angle,
-                        scale,
-                        space,
-                        color.into(),
+                    ffi::rlPushMatrix();
+                    ffi::rlTranslatef(point.x, point.y, 0.0);
+                    ffi::rlRotatef(angle, 0.0, 0.0, 1.0);
+                    ffi::rlTranslatef(-origin.x, -origin.y, 0.0);
+
+                    // draw the shadow first, with the same outline as the text.
+                    if let Some(shadow) = effect.shadow {
+                        Self::set_effect(
+                            &list,
+                            effect.outline,
+                            effect.shadow_color,
+                            0.0,
+                            effect.shadow_color,
+                        );
+                        Self::draw_text(&list, &text, shadow, scale, space, effect.shadow_color);
+                    }
+
+                    Self::set_effect(
+                        &list,
+                        effect.outline,
+                        effect.outline_color,
+                        effect.glow,
+                        effect.glow_color,
                     );
-                    Ok(())
+                    Self::draw_text(&list, &text, Vector2::zero(), scale, space, color);
+
+                    ffi::rlPopMatrix();
                 }
+
+                Ok(())
             },
         );
 
@@ -163,49 +338,162 @@ impl mlua::UserData for Font {
             ]
         }
         */
-        method.add_method(
+        method.add_method_mut(
             "measure_text",
             |_: &Lua, this, (text, scale, space): (String, f32, f32)| {
-                let text = Script::rust_to_c_string(&text)?;
+                this.set_cache(&text)?;
 
-                unsafe {
-                    let result = ffi::MeasureTextEx(*this.0, text.as_ptr(), scale, space);
-                    Ok((result.x, result.y))
+                let fallback = this.get_fallback();
+                let list = this.get_list(&fallback);
+                let mut size_x: f32 = 0.0;
+                let mut size_y = scale;
+
+                for line in text.split('\n') {
+                    let mut width = 0.0;
+                    let mut count = 0;
+
+                    for character in line.chars() {
+                        let (draw, index) = Self::get_glyph_list(&list, character as i32);
+
+                        width += Self::get_advance(&draw.font, index, scale);
+                        count += 1;
+                    }
+
+                    if count > 0 {
+                        width += (count - 1) as f32 * space;
+                    }
+
+                    size_x = size_x.max(width);
                 }
+
+                size_y += (text.matches('\n').count()) as f32
+                    * (scale + LINE_SPACE.load(Ordering::Relaxed) as f32);
+
+                Ok((size_x, size_y))
             },
         );
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "font:set_fallback",
+            "info": "Set the fallback font list. Any glyph missing from this font will be taken from the first fallback font that has it. A fallback font's own fallback list will not be used.",
+            "member": [
+                { "name": "fallback", "info": "The fallback font list. Use nil to clear.", "kind": "table?" }
+            ]
+        }
+        */
+        method.add_method_mut(
+            "set_fallback",
+            |_: &Lua, this, fallback: Option<Vec<mlua::AnyUserData>>| {
+                let fallback = fallback.unwrap_or_default();
+
+                if fallback.iter().any(|x| !x.is::<Self>()) {
+                    return Err(mlua::Error::runtime(
+                        "font:set_fallback(): Fallback list must only have font user-data.",
+                    ));
+                }
+
+                this.fallback = fallback;
+                Ok(())
+            },
+        );
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "font:load_codepoint",
+            "info": "Rasterize a set of codepoint into the glyph atlas ahead of time, instead of on demand.",
+            "member": [
+                { "name": "codepoint", "info": "The codepoint set. See quiver.font.new.", "kind": "any" }
+            ]
+        }
+        */
+        method.add_method_mut("load_codepoint", |_: &Lua, this, codepoint: LuaValue| {
+            let mut list = Vec::new();
+            Self::get_codepoint(&codepoint, &mut list)?;
+            this.set_grow(list)?;
+            Ok(())
+        });
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "font:has_codepoint",
+            "info": "Check if every codepoint in a set is in the glyph atlas of this font. Will not check any fallback font, and will not rasterize any glyph.",
+            "member": [
+                { "name": "codepoint", "info": "The codepoint set. See quiver.font.new.", "kind": "any" }
+            ],
+            "result": [
+                { "name": "value", "info": "True if every codepoint is in the glyph atlas.", "kind": "boolean" }
+            ]
+        }
+        */
+        method.add_method("has_codepoint", |_: &Lua, this, codepoint: LuaValue| {
+            let mut list = Vec::new();
+            Self::get_codepoint(&codepoint, &mut list)?;
+
+            Ok(list
+                .iter()
+                .all(|x| Self::get_glyph(&this.font, *x).is_some()))
+        });
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "font:get_codepoint",
+            "info": "Get every codepoint in the glyph atlas of this font.",
+            "result": [
+                { "name": "codepoint", "info": "The codepoint list.", "kind": "table" }
+            ]
+        }
+        */
+        method.add_method("get_codepoint", |_: &Lua, this, _: ()| unsafe {
+            let glyph = std::slice::from_raw_parts(this.font.glyphs, this.font.glyphCount as usize);
+
+            Ok(glyph.iter().map(|x| x.value).collect::<Vec<i32>>())
+        });
     }
 }
 
 impl Font {
+    // the last valid Unicode codepoint.
+    const CODEPOINT_MAX: i32 = 0x10FFFF;
+    // the largest codepoint set a font can load, as every glyph is rasterized into the glyph atlas.
+    const CODEPOINT_LIMIT: usize = 0x10000;
+
     /* entry
     {
         "version": "1.0.0",
         "name": "quiver.font.new",
-        "info": "Create a new font resource.",
+        "info": "Create a new font resource. The codepoint set can be a string (every codepoint used in it), a number (a single codepoint), a range table ({ first = 0x0400, last = 0x04FF }), or a table of any of those, such as a whole string table. The printable ASCII range is always loaded.",
         "member": [
-            { "name": "path", "info": "Path to font file.", "kind": "string" },
-            { "name": "size", "info": "Size for font.",     "kind": "number" }
+            { "name": "path",      "info": "Path to font file.",                                              "kind": "string" },
+            { "name": "size",      "info": "Size for font.",                                                  "kind": "number" },
+            { "name": "codepoint", "info": "OPTIONAL: The codepoint set to load. Default: printable ASCII.", "kind": "any"        },
+            { "name": "mode",      "info": "OPTIONAL: The mode of the font. Default: FONT_MODE.DEFAULT.",   "kind": "font_mode?" }
         ],
         "result": [
             { "name": "font", "info": "Font resource.", "kind": "font" }
         ]
     }
     */
-    fn new(lua: &Lua, (path, size): (String, i32)) -> mlua::Result<Self> {
-        unsafe {
-            let name = Script::rust_to_c_string(&ScriptData::get_path(lua, &path)?)?;
-
-            let data = ffi::LoadFontEx(name.as_ptr(), size, std::ptr::null_mut(), 0);
+    fn new(
+        lua: &Lua,
+        (path, size, codepoint, mode): (String, i32, LuaValue, Option<i32>),
+    ) -> mlua::Result<Self> {
+        let name = ScriptData::get_path(lua, &path, SandboxAccess::Read)?;
+        let data = std::fs::read(&name).map_err(|_| {
+            mlua::Error::runtime(format!("Font::new(): Could not load file \"{path}\"."))
+        })?;
+        let kind = std::path::Path::new(&name)
+            .extension()
+            .map(|x| format!(".{}", x.to_string_lossy()))
+            .unwrap_or(".ttf".to_string());
 
-            if ffi::IsFontValid(data) {
-                Ok(Self(RLFont::from_raw(data)))
-            } else {
-                Err(mlua::Error::RuntimeError(format!(
-                    "Font::new(): Could not load file \"{path}\"."
-                )))
-            }
-        }
+        Self::new_source(lua, data, kind, size, &codepoint, mode)?.ok_or(mlua::Error::runtime(
+            format!("Font::new(): Could not load file \"{path}\"."),
+        ))
     }
 
     /* entry
@@ -214,74 +502,1172 @@ impl Font {
         "name": "quiver.font.new_from_memory",
         "info": "Create a new font resource, from memory.",
         "member": [
-            { "name": "data", "info": "The data buffer.",                    "kind": "data"   },
-            { "name": "kind", "info": "The kind of font file (.ttf, etc.).", "kind": "string" },
-            { "name": "size", "info": "Size for font.",                      "kind": "number" }
+            { "name": "data",      "info": "The data buffer.",                                                "kind": "data"   },
+            { "name": "kind",      "info": "The kind of font file (.ttf, etc.).",                             "kind": "string" },
+            { "name": "size",      "info": "Size for font.",                                                  "kind": "number" },
+            { "name": "codepoint", "info": "OPTIONAL: The codepoint set to load. See quiver.font.new. Default: printable ASCII.", "kind": "any" },
+            { "name": "mode",      "info": "OPTIONAL: The mode of the font. Default: FONT_MODE.DEFAULT.", "kind": "font_mode?" }
         ],
         "result": [
             { "name": "font", "info": "Font resource.", "kind": "font" }
         ]
     }
     */
-    fn new_from_memory(_: &Lua, (data, kind, size): (LuaValue, String, i32)) -> mlua::Result<Self> {
-        let data = crate::base::data::Data::get_buffer(data)?;
+    fn new_from_memory(
+        lua: &Lua,
+        (data, kind, size, codepoint, mode): (LuaValue, String, i32, LuaValue, Option<i32>),
+    ) -> mlua::Result<Self> {
+        let data = crate::base::data::Data::get_buffer(data)?.0.clone();
+
+        Self::new_source(lua, data, kind, size, &codepoint, mode)?.ok_or(mlua::Error::runtime(
+            "Font::new_from_memory(): Could not load file.",
+        ))
+    }
 
+    /* entry
+    {
+        "version": "1.0.0",
+        "name": "quiver.font.new_default",
+        "info": "Create a new font resource, from the default font.",
+        "member": [
+            { "name": "size",      "info": "Size for font.",                                                  "kind": "number" },
+            { "name": "codepoint", "info": "OPTIONAL: The codepoint set to load. See quiver.font.new. Default: printable ASCII.", "kind": "any" },
+            { "name": "mode",      "info": "OPTIONAL: The mode of the font. Default: FONT_MODE.DEFAULT.", "kind": "font_mode?" }
+        ],
+        "result": [
+            { "name": "font", "info": "Font resource.", "kind": "font" }
+        ]
+    }
+    */
+    fn new_default(
+        lua: &Lua,
+        (size, codepoint, mode): (i32, LuaValue, Option<i32>),
+    ) -> mlua::Result<Self> {
+        let data = Status::FONT.to_vec();
+
+        Self::new_source(lua, data, ".ttf".to_string(), size, &codepoint, mode)?.ok_or(
+            mlua::Error::runtime("Font::new_from_default(): Could not load file."),
+        )
+    }
+
+    fn new_source(
+        lua: &Lua,
+        data: Vec<u8>,
+        kind: String,
+        size: i32,
+        codepoint: &LuaValue,
+        mode: Option<i32>,
+    ) -> mlua::Result<Option<Self>> {
+        let mode = mode.unwrap_or(FontSource::MODE_DEFAULT);
+
+        if ![
+            FontSource::MODE_DEFAULT,
+            FontSource::MODE_BITMAP,
+            FontSource::MODE_SDF,
+        ]
+        .contains(&mode)
+        {
+            return Err(mlua::Error::runtime("Font::new(): Invalid font mode."));
+        }
+
+        // always load the printable ASCII range.
+        let mut list: Vec<i32> = (32..127).collect();
+
+        Self::get_codepoint(codepoint, &mut list)?;
+
+        list.sort_unstable();
+        list.dedup();
+
+        let source = FontSource {
+            data,
+            kind,
+            size,
+            mode,
+            codepoint: list,
+        };
+
+        let Some(font) = source.load() else {
+            return Ok(None);
+        };
+
+        let shader = if mode == FontSource::MODE_SDF {
+            Some(FontShader::new(lua)?)
+        } else {
+            None
+        };
+
+        Ok(Some(Self {
+            font: unsafe { RLFont::from_raw(font) },
+            source,
+            shader,
+            fallback: Vec::new(),
+        }))
+    }
+
+    // get every codepoint from a codepoint set.
+    fn get_codepoint(value: &LuaValue, list: &mut Vec<i32>) -> mlua::Result<()> {
+        match value {
+            LuaValue::Nil => {}
+            LuaValue::Integer(value) => list.push(*value as i32),
+            LuaValue::Number(value) => list.push(*value as i32),
+            LuaValue::String(value) => list.extend(value.to_str()?.chars().map(|x| x as i32)),
+            LuaValue::Table(value) => {
+                if let (Some(first), Some(last)) = (
+                    value.get::<Option<i32>>("first")?,
+                    value.get::<Option<i32>>("last")?,
+                ) {
+                    let first = first.clamp(0, Self::CODEPOINT_MAX);
+                    let last = last.clamp(0, Self::CODEPOINT_MAX);
+
+                    // check the range before building it, so a huge range will not be allocated at all.
+                    Self::get_limit(list.len() + (last - first + 1).max(0) as usize)?;
+
+                    list.extend(first..=last);
+                } else {
+                    for entry in value.pairs::<LuaValue, LuaValue>() {
+                        let (_, entry) = entry?;
+                        Self::get_codepoint(&entry, list)?;
+                    }
+                }
+            }
+            _ => {
+                return Err(mlua::Error::runtime(
+                    "Font::get_codepoint(): Invalid codepoint set.",
+                ));
+            }
+        }
+
+        Self::get_limit(list.len())
+    }
+
+    // check a codepoint set against the codepoint limit.
+    fn get_limit(length: usize) -> mlua::Result<()> {
+        if length > Self::CODEPOINT_LIMIT {
+            return Err(mlua::Error::runtime(format!(
+                "Font::get_codepoint(): Codepoint set is too large (more than {}).",
+                Self::CODEPOINT_LIMIT
+            )));
+        }
+
+        Ok(())
+    }
+
+    // get the glyph index of a codepoint, if the glyph atlas has it.
+    fn get_glyph(font: &ffi::Font, codepoint: i32) -> Option<usize> {
         unsafe {
-            let data = &data.0;
+            let index = ffi::GetGlyphIndex(*font, codepoint) as usize;
+            let glyph = *font.glyphs.add(index);
+            let rec = *font.recs.add(index);
 
-            let data = ffi::LoadFontFromMemory(
-                Script::rust_to_c_string(&kind)?.as_ptr(),
-                data.as_ptr(),
-                data.len() as i32,
-                size,
-                std::ptr::null_mut(),
+            // a glyph missing from the font file has no image.
+            let blank = codepoint == ' ' as i32 || codepoint == '\t' as i32;
+
+            (glyph.value == codepoint && (rec.width > 0.0 || blank)).then_some(index)
+        }
+    }
+
+    // get the first font with a glyph for a codepoint, or the missing glyph of the main font.
+    fn get_glyph_list(list: &[FontDraw], codepoint: i32) -> (FontDraw, usize) {
+        for draw in list {
+            if let Some(index) = Self::get_glyph(&draw.font, codepoint) {
+                return (*draw, index);
+            }
+        }
+
+        unsafe {
+            (
+                list[0],
+                ffi::GetGlyphIndex(list[0].font, codepoint) as usize,
+            )
+        }
+    }
+
+    fn get_advance(font: &ffi::Font, index: usize, scale: f32) -> f32 {
+        unsafe {
+            let glyph = *font.glyphs.add(index);
+            let scale = scale / font.baseSize as f32;
+
+            if glyph.advanceX == 0 {
+                (*font.recs.add(index)).width * scale
+            } else {
+                glyph.advanceX as f32 * scale
+            }
+        }
+    }
+
+    fn get_fallback(&self) -> Vec<mlua::UserDataRef<Self>> {
+        self.fallback
+            .iter()
+            .filter_map(|x| x.borrow::<Self>().ok())
+            .collect()
+    }
+
+    fn get_list(&self, fallback: &[mlua::UserDataRef<Self>]) -> Vec<FontDraw> {
+        std::iter::once(self)
+            .chain(fallback.iter().map(|x| &**x))
+            .map(|x| FontDraw {
+                font: *x.font,
+                shader: x.shader.as_ref().map(|x| (*x.shader, x.location)),
+            })
+            .collect()
+    }
+
+    // set the effect uniform of every SDF font in a list.
+    fn set_effect(
+        list: &[FontDraw],
+        outline: f32,
+        outline_color: Color,
+        glow: f32,
+        glow_color: Color,
+    ) {
+        for (shader, location) in list.iter().filter_map(|x| x.shader) {
+            let value = [outline * SDF_PIXEL, glow * SDF_PIXEL];
+            let color = [outline_color, glow_color].map(|x| {
+                Vector4::new(
+                    x.r as f32 / 255.0,
+                    x.g as f32 / 255.0,
+                    x.b as f32 / 255.0,
+                    x.a as f32 / 255.0,
+                )
+            });
+
+            unsafe {
+                for (i, value) in value.iter().enumerate() {
+                    ffi::SetShaderValue(
+                        shader,
+                        location[i * 2],
+                        value as *const f32 as *const std::ffi::c_void,
+                        ffi::ShaderUniformDataType::SHADER_UNIFORM_FLOAT as i32,
+                    );
+                    ffi::SetShaderValue(
+                        shader,
+                        location[i * 2 + 1],
+                        &color[i] as *const Vector4 as *const std::ffi::c_void,
+                        ffi::ShaderUniformDataType::SHADER_UNIFORM_VEC4 as i32,
+                    );
+                }
+            }
+        }
+    }
+
+    // draw a text, glyph by glyph, with the SDF shader of each glyph's font (if any).
+    fn draw_text(
+        list: &[FontDraw],
+        text: &str,
+        point: Vector2,
+        scale: f32,
+        space: f32,
+        color: Color,
+    ) {
+        let mut offset = point;
+        let mut active: Option<u32> = None;
+
+        for character in text.chars() {
+            if character == '\n' {
+                offset.x = point.x;
+                offset.y += scale + LINE_SPACE.load(Ordering::Relaxed) as f32;
+                continue;
+            }
+
+            let (draw, index) = Self::get_glyph_list(list, character as i32);
+
+            Self::draw_glyph(&draw, character, offset, scale, color, &mut active);
+
+            offset.x += Self::get_advance(&draw.font, index, scale) + space;
+        }
+
+        Self::draw_glyph_end(&mut active);
+    }
+
+    // draw a single glyph, only switching shader when the font changes, to keep draw call batching.
+    fn draw_glyph(
+        draw: &FontDraw,
+        character: char,
+        point: Vector2,
+        scale: f32,
+        color: Color,
+        active: &mut Option<u32>,
+    ) {
+        if character == ' ' || character == '\t' {
+            return;
+        }
+
+        let shader = draw.shader.map(|x| x.0.id);
+
+        unsafe {
+            if shader != *active {
+                Self::draw_glyph_end(active);
+
+                if let Some((shader, _)) = draw.shader {
+                    ffi::BeginShaderMode(shader);
+                }
+
+                *active = shader;
+            }
+
+            ffi::DrawTextCodepoint(
+                draw.font,
+                character as i32,
+                point.into(),
+                scale,
+                color.into(),
+            );
+        }
+    }
+
+    // end the SDF shader, and restore the shader of any shader:begin call this is drawn in.
+    fn draw_glyph_end(active: &mut Option<u32>) {
+        if active.take().is_some() {
+            unsafe {
+                ffi::EndShaderMode();
+
+                if let Some(shader) = Shader::get_active() {
+                    ffi::BeginShaderMode(shader);
+                }
+            }
+        }
+    }
+
+    // rasterize every glyph in a text that neither this font nor any fallback font has yet.
+    fn set_cache(&mut self, text: &str) -> mlua::Result<()> {
+        let mut list: Vec<i32> = text
+            .chars()
+            .map(|x| x as i32)
+            .filter(|x| *x != '\n' as i32 && Self::get_glyph(&self.font, *x).is_none())
+            .collect();
+
+        if list.is_empty() {
+            return Ok(());
+        }
+
+        list.sort_unstable();
+        list.dedup();
+
+        for fallback in self.get_fallback() {
+            list.retain(|x| Self::get_glyph(&fallback.font, *x).is_none());
+        }
+
+        let mut list = self.set_grow(list)?;
+
+        for fallback in &self.fallback {
+            if list.is_empty() {
+                break;
+            }
+
+            if let Ok(mut fallback) = fallback.borrow_mut::<Self>() {
+                list = fallback.set_grow(list)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    // add a list of codepoint to the glyph atlas, and get back every codepoint the font file does not have.
+    fn set_grow(&mut self, list: Vec<i32>) -> mlua::Result<Vec<i32>> {
+        let length = self.source.codepoint.len();
+        let mut codepoint = self.source.codepoint.clone();
+
+        // only try each codepoint once, so a glyph the font file does not have will not re-build the atlas every time.
+        for entry in &list {
+            if let Err(index) = codepoint.binary_search(entry) {
+                codepoint.insert(index, *entry);
+            }
+        }
+
+        if codepoint.len() > Self::CODEPOINT_LIMIT {
+            return Err(mlua::Error::runtime(format!(
+                "Font::set_grow(): Glyph atlas is too large (more than {} codepoint).",
+                Self::CODEPOINT_LIMIT
+            )));
+        }
+
+        self.source.codepoint = codepoint;
+
+        if self.source.codepoint.len() != length {
+            let font = self.source.load().ok_or(mlua::Error::runtime(
+                "Font::set_grow(): Could not re-build glyph atlas.",
+            ))?;
+
+            self.font = unsafe { RLFont::from_raw(font) };
+        }
+
+        Ok(list
+            .into_iter()
+            .filter(|x| Self::get_glyph(&self.font, *x).is_none())
+            .collect())
+    }
+}
+
+impl FontShader {
+    // get the SDF shader in use by any other SDF font, or compile it if there is none.
+    fn new(lua: &Lua) -> mlua::Result<Arc<Self>> {
+        if let Some(share) = lua.app_data_ref::<FontShaderShare>() {
+            if let Some(shader) = share.0.upgrade() {
+                return Ok(shader);
+            }
+        }
+
+        let shader = Arc::new(Self::load()?);
+
+        lua.set_app_data(FontShaderShare(Arc::downgrade(&shader)));
+
+        Ok(shader)
+    }
+
+    fn load() -> mlua::Result<Self> {
+        let code = Script::rust_to_c_string(&format!("{SHADER_SDF_HEAD}{SHADER_SDF}"))?;
+
+        unsafe {
+            let shader = ffi::LoadShaderFromMemory(std::ptr::null(), code.as_ptr());
+
+            if !ffi::IsShaderValid(shader) {
+                return Err(mlua::Error::runtime(
+                    "FontShader::new(): Could not load SDF shader.",
+                ));
+            }
+
+            let shader = RLShader::from_raw(shader);
+            let location = [
+                shader.get_shader_location("outline"),
+                shader.get_shader_location("outlineColor"),
+                shader.get_shader_location("glow"),
+                shader.get_shader_location("glowColor"),
+            ];
+
+            Ok(Self { shader, location })
+        }
+    }
+}
+
+impl FontSource {
+    const MODE_DEFAULT: i32 = 0;
+    const MODE_BITMAP: i32 = 1;
+    const MODE_SDF: i32 = 2;
+    // the padding around each glyph in the glyph atlas (FONT_TTF_DEFAULT_CHARS_PADDING).
+    const PADDING: i32 = 4;
+
+    fn load(&self) -> Option<ffi::Font> {
+        if self.mode == Self::MODE_DEFAULT {
+            self.load_default()
+        } else {
+            self.load_mode()
+        }
+    }
+
+    // build the glyph atlas by hand, as LoadFontFromMemory will only make an anti-aliased font.
+    fn load_mode(&self) -> Option<ffi::Font> {
+        unsafe {
+            let glyph = ffi::LoadFontData(
+                self.data.as_ptr(),
+                self.data.len() as i32,
+                self.size,
+                self.codepoint.as_ptr() as *mut i32,
+                self.codepoint.len() as i32,
+                self.mode,
+            );
+
+            if glyph.is_null() {
+                return None;
+            }
+
+            // a SDF glyph already has its own padding.
+            let padding = if self.mode == Self::MODE_SDF {
+                0
+            } else {
+                Self::PADDING
+            };
+
+            let mut rec = std::ptr::null_mut();
+            let atlas = ffi::GenImageFontAtlas(
+                glyph,
+                &mut rec,
+                self.codepoint.len() as i32,
+                self.size,
+                padding,
                 0,
             );
+            let texture = ffi::LoadTextureFromImage(atlas);
+
+            ffi::UnloadImage(atlas);
 
-            if ffi::IsFontValid(data) {
-                Ok(Self(RLFont::from_raw(data)))
+            if self.mode == Self::MODE_SDF {
+                ffi::SetTextureFilter(texture, ffi::TextureFilter::TEXTURE_FILTER_BILINEAR as i32);
+            }
+
+            let font = ffi::Font {
+                baseSize: self.size,
+                glyphCount: self.codepoint.len() as i32,
+                glyphPadding: padding,
+                texture,
+                recs: rec,
+                glyphs: glyph,
+            };
+
+            if ffi::IsFontValid(font) {
+                Some(font)
             } else {
-                Err(mlua::Error::RuntimeError(
-                    "Font::new_from_memory(): Could not load file.".to_string(),
-                ))
+                // free the glyph array, the rectangle array and the atlas texture.
+                ffi::UnloadFont(font);
+
+                None
             }
         }
     }
 
+    fn load_default(&self) -> Option<ffi::Font> {
+        let kind = Script::rust_to_c_string(&self.kind).ok()?;
+
+        unsafe {
+            let data = ffi::LoadFontFromMemory(
+                kind.as_ptr(),
+                self.data.as_ptr(),
+                self.data.len() as i32,
+                self.size,
+                self.codepoint.as_ptr() as *mut i32,
+                self.codepoint.len() as i32,
+            );
+
+            ffi::IsFontValid(data).then_some(data)
+        }
+    }
+}
+
+//================================================================
+
+/* class
+{
+    "version": "1.0.0",
+    "name": "font_layout",
+    "info": "A text layout, with word wrap, alignment and in-line markup. Every glyph (and image) has a rectangle relative to the top-left of the layout, for hit-testing or a typewriter effect.",
+    "member": [
+        { "name": "count",      "info": "The glyph count, including any space and image.", "kind": "number" },
+        { "name": "line_count", "info": "The line count.",                                 "kind": "number" },
+        { "name": "size_x",     "info": "Size of the layout (X).",                         "kind": "number" },
+        { "name": "size_y",     "info": "Size of the layout (Y).",                         "kind": "number" }
+    ],
+    "test": "font/layout.lua"
+}
+*/
+struct FontLayout {
+    font: mlua::AnyUserData,
+    bold: Option<mlua::AnyUserData>,
+    image: Vec<mlua::AnyUserData>,
+    glyph: Vec<LayoutGlyph>,
+    scale: f32,
+    size: Vector2,
+    line_count: usize,
+}
+
+#[derive(Clone, Copy, Default)]
+struct LayoutStyle {
+    color: Option<Color>,
+    bold: bool,
+    shake: bool,
+    wave: bool,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum LayoutKind {
+    Glyph(char),
+    Image(usize),
+    Break,
+}
+
+// an item of a layout, measured but not yet placed.
+struct LayoutItem {
+    kind: LayoutKind,
+    style: LayoutStyle,
+    width: f32,
+}
+
+// a glyph (or image) of a layout, placed relative to the top-left of the layout.
+struct LayoutGlyph {
+    kind: LayoutKind,
+    style: LayoutStyle,
+    rec: Rectangle,
+    line: usize,
+}
+
+impl mlua::UserData for FontLayout {
+    fn add_fields<F: mlua::UserDataFields<Self>>(field: &mut F) {
+        field.add_field_method_get("count", |_, this| Ok(this.glyph.len()));
+        field.add_field_method_get("line_count", |_, this| Ok(this.line_count));
+        field.add_field_method_get("size_x", |_, this| Ok(this.size.x));
+        field.add_field_method_get("size_y", |_, this| Ok(this.size.y));
+    }
+
+    fn add_methods<M: mlua::UserDataMethods<Self>>(method: &mut M) {
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "font_layout:draw",
+            "info": "Draw the layout.",
+            "member": [
+                { "name": "point", "info": "Point of the layout to draw.",                                                         "kind": "vector_2" },
+                { "name": "color", "info": "OPTIONAL: Color of the layout to draw, for any glyph without a color tag. Default: WHITE.", "kind": "color?"   },
+                { "name": "time",  "info": "OPTIONAL: The time, for the shake and wave effect. Default: 0.0.",                     "kind": "number?"  },
+                { "name": "count", "info": "OPTIONAL: The glyph count to draw, for a typewriter effect. Default: every glyph.",     "kind": "number?"  }
+            ]
+        }
+        */
+        method.add_method(
+            "draw",
+            |lua: &Lua,
+             this,
+             (point, color, time, count): (
+                LuaValue,
+                Option<LuaValue>,
+                Option<f32>,
+                Option<usize>,
+            )| {
+                let point: Vector2 = lua.from_value(point)?;
+                let color: Color = match color {
+                    Some(color) => lua.from_value(color)?,
+                    None => Color::WHITE,
+                };
+                let time = time.unwrap_or_default();
+                let count = count.unwrap_or(this.glyph.len());
+
+                let image = this
+                    .image
+                    .iter()
+                    .map(|x| x.borrow::<Texture>().map(|x| x.0))
+                    .collect::<mlua::Result<Vec<_>>>()?;
+
+                let font = this.font.borrow::<Font>()?;
+                let font_fallback = font.get_fallback();
+                let font_list = font.get_list(&font_fallback);
+
+                let bold = this.bold.as_ref().map(|x| x.borrow::<Font>()).transpose()?;
+                let bold_fallback = bold.as_ref().map(|x| x.get_fallback()).unwrap_or_default();
+                let bold_list = bold.as_ref().map(|x| x.get_list(&bold_fallback));
+
+                // clear any effect left over from a previous font:draw call.
+                for list in std::iter::once(&font_list).chain(bold_list.as_ref()) {
+                    Font::set_effect(list, 0.0, Color::BLACK, 0.0, Color::WHITE);
+                }
+
+                let mut active: Option<u32> = None;
+
+                for (i, glyph) in this.glyph.iter().take(count).enumerate() {
+                    let offset = glyph.style.get_offset(i, time, this.scale);
+                    let offset = Vector2::new(
+                        point.x + glyph.rec.x + offset.x,
+                        point.y + glyph.rec.y + offset.y,
+                    );
+
+                    match glyph.kind {
+                        LayoutKind::Glyph(character) => {
+                            let list = match &bold_list {
+                                Some(bold_list) if glyph.style.bold => bold_list,
+                                _ => &font_list,
+                            };
+                            let (draw, _) = Font::get_glyph_list(list, character as i32);
+
+                            Font::draw_glyph(
+                                &draw,
+                                character,
+                                offset,
+                                this.scale,
+                                glyph.style.color.unwrap_or(color),
+                                &mut active,
+                            );
+                        }
+                        LayoutKind::Image(index) => {
+                            let texture = image[index];
+
+                            // an image should never be drawn with the SDF shader.
+                            Font::draw_glyph_end(&mut active);
+
+                            unsafe {
+                                ffi::DrawTexturePro(
+                                    texture,
+                                    Rectangle::new(
+                                        0.0,
+                                        0.0,
+                                        texture.width as f32,
+                                        texture.height as f32,
+                                    )
+                                    .into(),
+                                    Rectangle::new(
+                                        offset.x,
+                                        offset.y,
+                                        glyph.rec.width,
+                                        glyph.rec.height,
+                                    )
+                                    .into(),
+                                    Vector2::zero().into(),
+                                    0.0,
+                                    glyph.style.color.unwrap_or(Color::WHITE).into(),
+                                );
+                            }
+                        }
+                        LayoutKind::Break => {}
+                    }
+                }
+
+                Font::draw_glyph_end(&mut active);
+
+                Ok(())
+            },
+        );
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "font_layout:get_glyph",
+            "info": "Get a glyph of the layout.",
+            "member": [
+                { "name": "index", "info": "The glyph index.", "kind": "number" }
+            ],
+            "result": [
+                { "name": "glyph", "info": "The glyph table, with a point (x, y) relative to the top-left of the layout, a size (width, height), the line index and either the text or the image (texture) of the glyph.", "kind": "table" }
+            ]
+        }
+        */
+        method.add_method("get_glyph", |lua: &Lua, this, index: usize| {
+            let glyph = index
+                .checked_sub(1)
+                .and_then(|index| this.glyph.get(index))
+                .ok_or(mlua::Error::runtime(
+                    "font_layout:get_glyph(): Invalid glyph index.",
+                ))?;
+
+            let table = lua.create_table()?;
+
+            table.set("x", glyph.rec.x)?;
+            table.set("y", glyph.rec.y)?;
+            table.set("width", glyph.rec.width)?;
+            table.set("height", glyph.rec.height)?;
+            table.set("line", glyph.line + 1)?;
+
+            match glyph.kind {
+                LayoutKind::Glyph(character) => table.set("text", character.to_string())?,
+                LayoutKind::Image(index) => table.set("image", this.image[index].clone())?,
+                LayoutKind::Break => {}
+            }
+
+            Ok(table)
+        });
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "font_layout:get_glyph_at",
+            "info": "Get the glyph at a point.",
+            "member": [
+                { "name": "point", "info": "The point, relative to the top-left of the layout.", "kind": "vector_2" }
+            ],
+            "result": [
+                { "name": "index", "info": "The glyph index, or nil if there is no glyph at the point.", "kind": "number?" }
+            ]
+        }
+        */
+        method.add_method("get_glyph_at", |lua: &Lua, this, point: LuaValue| {
+            let point: Vector2 = lua.from_value(point)?;
+
+            Ok(this
+                .glyph
+                .iter()
+                .position(|x| {
+                    point.x >= x.rec.x
+                        && point.y >= x.rec.y
+                        && point.x < x.rec.x + x.rec.width
+                        && point.y < x.rec.y + x.rec.height
+                })
+                .map(|x| x + 1))
+        });
+    }
+}
+
+impl FontLayout {
+    const ALIGN_LEFT: i32 = 0;
+    const ALIGN_CENTER: i32 = 1;
+    const ALIGN_RIGHT: i32 = 2;
+    const ALIGN_JUSTIFY: i32 = 3;
+
     /* entry
     {
         "version": "1.0.0",
-        "name": "quiver.font.new_default",
-        "info": "Create a new font resource, from the default font.",
+        "name": "quiver.font.new_layout",
+        "info": "Create a new text layout. The text may have any of the following markup tag: [b] (bold), [shake], [wave] and [color=#RRGGBB] (or #RRGGBBAA), each closed with [/b], [/shake], [/wave] and [/color], and [image=name] to place an image in-line. Use [[ for a literal [. An unknown tag will be kept as-is.",
         "member": [
-            { "name": "size", "info": "Size for font.", "kind": "number" }
+            { "name": "font",   "info": "The font to lay out with.", "kind": "font"   },
+            { "name": "text",   "info": "The text to lay out.",      "kind": "string" },
+            { "name": "option", "info": "OPTIONAL: The option table, with any of: scale (default: the font size), space (default: 1.0), width (the width to wrap to, default: no wrap), align (a TEXT_ALIGN value, default: LEFT), line_space (default: the text line space), bold (the font for bold text, default: no bold) and image (a table of texture, by name, for the image tag).", "kind": "table?" }
         ],
         "result": [
-            { "name": "font", "info": "Font resource.", "kind": "font" }
+            { "name": "font_layout", "info": "The text layout.", "kind": "font_layout" }
         ]
     }
     */
-    fn new_default(_: &Lua, size: i32) -> mlua::Result<Self> {
-        let data = Status::FONT;
+    fn new(
+        lua: &Lua,
+        (font, text, option): (mlua::AnyUserData, String, Option<LuaTable>),
+    ) -> mlua::Result<Self> {
+        let option = match option {
+            Some(option) => option,
+            None => lua.create_table()?,
+        };
 
-        unsafe {
-            let data = ffi::LoadFontFromMemory(
-                Script::rust_to_c_string(".ttf")?.as_ptr(),
-                data.as_ptr(),
-                data.len() as i32,
-                size,
-                std::ptr::null_mut(),
-                0,
-            );
+        let bold: Option<mlua::AnyUserData> = option.get("bold")?;
+        let align = option
+            .get::<Option<i32>>("align")?
+            .unwrap_or(Self::ALIGN_LEFT);
+        let width: Option<f32> = option.get("width")?;
+        let space = option.get::<Option<f32>>("space")?.unwrap_or(1.0);
+        let line_space = option
+            .get::<Option<f32>>("line_space")?
+            .unwrap_or(LINE_SPACE.load(Ordering::Relaxed) as f32);
 
-            if ffi::IsFontValid(data) {
-                Ok(Self(RLFont::from_raw(data)))
-            } else {
-                Err(mlua::Error::RuntimeError(
-                    "Font::new_from_default(): Could not load file.".to_string(),
-                ))
+        if !font.is::<Font>() || bold.as_ref().is_some_and(|x| !x.is::<Font>()) {
+            return Err(mlua::Error::runtime(
+                "quiver.font.new_layout(): Font and bold font must be a font user-data.",
+            ));
+        }
+
+        if !(Self::ALIGN_LEFT..=Self::ALIGN_JUSTIFY).contains(&align) {
+            return Err(mlua::Error::runtime(
+                "quiver.font.new_layout(): Invalid text alignment.",
+            ));
+        }
+
+        let (markup, name) = Self::get_markup(&text)?;
+
+        let image_table: Option<LuaTable> = option.get("image")?;
+        let image = name
+            .iter()
+            .map(|name| {
+                let image: Option<mlua::AnyUserData> = match &image_table {
+                    Some(table) => table.get(name.as_str())?,
+                    None => None,
+                };
+
+                image
+                    .filter(|x| x.is::<Texture>())
+                    .ok_or(mlua::Error::runtime(format!(
+                        "quiver.font.new_layout(): Unknown image \"{name}\"."
+                    )))
+            })
+            .collect::<mlua::Result<Vec<_>>>()?;
+
+        // rasterize every glyph ahead of time, each with the font it will be drawn with.
+        let is_bold = |style: &LayoutStyle| style.bold && bold.is_some();
+        let get_text = |value: bool| -> String {
+            markup
+                .iter()
+                .filter_map(|(kind, style)| match kind {
+                    LayoutKind::Glyph(character) if is_bold(style) == value => Some(*character),
+                    _ => None,
+                })
+                .collect()
+        };
+
+        font.borrow_mut::<Font>()?.set_cache(&get_text(false))?;
+
+        if let Some(bold) = &bold {
+            bold.borrow_mut::<Font>()?.set_cache(&get_text(true))?;
+        }
+
+        let main = font.borrow::<Font>()?;
+        let main_fallback = main.get_fallback();
+        let main_list = main.get_list(&main_fallback);
+
+        let bold_font = bold.as_ref().map(|x| x.borrow::<Font>()).transpose()?;
+        let bold_fallback = bold_font
+            .as_ref()
+            .map(|x| x.get_fallback())
+            .unwrap_or_default();
+        let bold_list = bold_font.as_ref().map(|x| x.get_list(&bold_fallback));
+
+        let scale = option
+            .get::<Option<f32>>("scale")?
+            .unwrap_or(main.font.baseSize as f32);
+
+        let item = markup
+            .into_iter()
+            .map(|(kind, style)| {
+                let width = match kind {
+                    LayoutKind::Glyph(character) => {
+                        let list = match &bold_list {
+                            Some(bold_list) if style.bold => bold_list,
+                            _ => &main_list,
+                        };
+                        let (draw, index) = Font::get_glyph_list(list, character as i32);
+
+                        Font::get_advance(&draw.font, index, scale)
+                    }
+                    LayoutKind::Image(index) => {
+                        let texture = image[index].borrow::<Texture>()?;
+
+                        // keep the aspect ratio of the image, at the height of a line.
+                        scale * texture.0.width as f32 / texture.0.height.max(1) as f32
+                    }
+                    LayoutKind::Break => 0.0,
+                };
+
+                Ok(LayoutItem { kind, style, width })
+            })
+            .collect::<mlua::Result<Vec<_>>>()?;
+
+        let line = Self::get_line(&item, width, space);
+
+        // the length of every line, up to (and not including) any trailing space.
+        let line_end: Vec<usize> = line
+            .iter()
+            .map(|(line, _)| {
+                line.iter()
+                    .rposition(|x| !item[*x].is_space())
+                    .map_or(0, |x| x + 1)
+            })
+            .collect();
+        let line_width: Vec<f32> = line
+            .iter()
+            .zip(&line_end)
+            .map(|((line, _), end)| {
+                let width: f32 = line[..*end].iter().map(|x| item[*x].width + space).sum();
+
+                (width - space).max(0.0)
+            })
+            .collect();
+
+        let size_x = line_width.iter().fold(0.0_f32, |a, b| a.max(*b));
+        let frame = width.unwrap_or(size_x);
+        let mut glyph = Vec::with_capacity(item.len());
+
+        for (i, (list, explicit)) in line.iter().enumerate() {
+            let rest = (frame - line_width[i]).max(0.0);
+            let inner = list[..line_end[i]]
+                .iter()
+                .filter(|x| item[**x].is_space())
+                .count();
+
+            // the last line of a paragraph is never justified.
+            let (mut x, extra) = match align {
+                Self::ALIGN_CENTER => (rest * 0.5, 0.0),
+                Self::ALIGN_RIGHT => (rest, 0.0),
+                Self::ALIGN_JUSTIFY if !explicit && inner > 0 => (0.0, rest / inner as f32),
+                _ => (0.0, 0.0),
+            };
+            let y = i as f32 * (scale + line_space);
+
+            for (j, index) in list.iter().enumerate() {
+                let entry = &item[*index];
+
+                glyph.push(LayoutGlyph {
+                    kind: entry.kind,
+                    style: entry.style,
+                    rec: Rectangle::new(x, y, entry.width, scale),
+                    line: i,
+                });
+
+                x += entry.width + space;
+
+                if entry.is_space() && j < line_end[i] {
+                    x += extra;
+                }
+            }
+        }
+
+        let line_count = line.len();
+
+        Ok(Self {
+            font,
+            bold,
+            image,
+            glyph,
+            scale,
+            size: Vector2::new(
+                size_x,
+                line_count as f32 * scale + (line_count - 1) as f32 * line_space,
+            ),
+            line_count,
+        })
+    }
+
+    // parse a text with markup into a list of glyph, image and line-break, and get the name of every image.
+    fn get_markup(text: &str) -> mlua::Result<(Vec<(LayoutKind, LayoutStyle)>, Vec<String>)> {
+        let character: Vec<char> = text.chars().collect();
+        let mut list = Vec::new();
+        let mut image: Vec<String> = Vec::new();
+        let mut style = LayoutStyle::default();
+        let mut stack: Vec<(String, LayoutStyle)> = Vec::new();
+        let mut i = 0;
+
+        while i < character.len() {
+            let value = character[i];
+
+            if value == '\n' {
+                list.push((LayoutKind::Break, style));
+                i += 1;
+                continue;
+            }
+
+            if value != '[' {
+                list.push((LayoutKind::Glyph(value), style));
+                i += 1;
+                continue;
             }
+
+            // "[[" is a literal "[".
+            if character.get(i + 1) == Some(&'[') {
+                list.push((LayoutKind::Glyph('['), style));
+                i += 2;
+                continue;
+            }
+
+            let Some(close) = character[i..].iter().position(|x| *x == ']') else {
+                list.push((LayoutKind::Glyph(value), style));
+                i += 1;
+                continue;
+            };
+
+            let tag: String = character[i + 1..i + close].iter().collect();
+            let (name, value) = tag.split_once('=').unwrap_or((&tag, ""));
+
+            match name {
+                "b" | "shake" | "wave" | "color" => {
+                    stack.push((name.to_string(), style));
+
+                    match name {
+                        "b" => style.bold = true,
+                        "shake" => style.shake = true,
+                        "wave" => style.wave = true,
+                        _ => style.color = Some(Self::get_color(value)?),
+                    }
+                }
+                "image" => {
+                    let index = match image.iter().position(|x| x == value) {
+                        Some(index) => index,
+                        None => {
+                            image.push(value.to_string());
+                            image.len() - 1
+                        }
+                    };
+
+                    list.push((LayoutKind::Image(index), style));
+                }
+                // a close tag with no matching open tag is not a tag, and is kept as-is.
+                _ if name.starts_with('/') && stack.iter().any(|x| x.0 == name[1..]) => {
+                    // restore the style from before the matching open tag.
+                    if let Some(index) = stack.iter().rposition(|x| x.0 == name[1..]) {
+                        style = stack[index].1;
+                        stack.truncate(index);
+                    }
+                }
+                _ => {
+                    // not a tag, so draw it as-is, with any line-break in it.
+                    for value in &character[i..=i + close] {
+                        if *value == '\n' {
+                            list.push((LayoutKind::Break, style));
+                        } else {
+                            list.push((LayoutKind::Glyph(*value), style));
+                        }
+                    }
+                }
+            }
+
+            i += close + 1;
+        }
+
+        Ok((list, image))
+    }
+
+    // parse a #RRGGBB or #RRGGBBAA color.
+    fn get_color(text: &str) -> mlua::Result<Color> {
+        let value = text.trim_start_matches('#');
+        let error = || {
+            mlua::Error::runtime(format!(
+                "quiver.font.new_layout(): Invalid color \"{text}\"."
+            ))
+        };
+
+        if !(value.len() == 6 || value.len() == 8) || !value.is_ascii() {
+            return Err(error());
         }
+
+        let mut channel = [255u8; 4];
+
+        for (i, channel) in channel.iter_mut().enumerate().take(value.len() / 2) {
+            *channel = u8::from_str_radix(&value[i * 2..i * 2 + 2], 16).map_err(|_| error())?;
+        }
+
+        Ok(Color::new(channel[0], channel[1], channel[2], channel[3]))
+    }
+
+    // split a list of item into line, wrapping at a space where possible. every line also says if it ended with a line-break.
+    fn get_line(item: &[LayoutItem], width: Option<f32>, space: f32) -> Vec<(Vec<usize>, bool)> {
+        let mut list = Vec::new();
+        let mut line: Vec<usize> = Vec::new();
+        let mut point = 0.0;
+        let mut last_space: Option<usize> = None;
+
+        for (i, entry) in item.iter().enumerate() {
+            if entry.kind == LayoutKind::Break {
+                list.push((std::mem::take(&mut line), true));
+                point = 0.0;
+                last_space = None;
+                continue;
+            }
+
+            let over = width.is_some_and(|width| point + entry.width > width);
+
+            if over && !entry.is_space() && !line.is_empty() {
+                // move the last word to a new line, or break the word itself if it has no space before it.
+                let rest = match last_space {
+                    Some(index) => line.split_off(index + 1),
+                    None => Vec::new(),
+                };
+
+                list.push((std::mem::replace(&mut line, rest), false));
+                point = line.iter().map(|x| item[*x].width + space).sum();
+                last_space = None;
+            }
+
+            line.push(i);
+            point += entry.width + space;
+
+            if entry.is_space() {
+                last_space = Some(line.len() - 1);
+            }
+        }
+
+        list.push((line, true));
+        list
+    }
+}
+
+impl LayoutItem {
+    fn is_space(&self) -> bool {
+        matches!(self.kind, LayoutKind::Glyph(' ' | '\t'))
+    }
+}
+
+impl LayoutStyle {
+    // get the animated offset of a glyph, for the shake and wave effect.
+    fn get_offset(&self, index: usize, time: f32, scale: f32) -> Vector2 {
+        let mut offset = Vector2::zero();
+
+        if self.wave {
+            offset.y += (time * 8.0 + index as f32 * 0.5).sin() * scale * 0.1;
+        }
+
+        if self.shake {
+            // hash the glyph index and the time step (20 per second) together.
+            let mut hash = (index as u64) ^ ((time * 20.0).floor() as i64 as u64).rotate_left(32);
+
+            hash = (hash ^ (hash >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+            hash = (hash ^ (hash >> 27)).wrapping_mul(0x94D049BB133111EB);
+            hash ^= hash >> 31;
+
+            let x = (hash & 0xFFFF) as f32 / 65535.0 * 2.0 - 1.0;
+            let y = ((hash >> 16) & 0xFFFF) as f32 / 65535.0 * 2.0 - 1.0;
+
+            offset.x += x * scale * 0.05;
+            offset.y += y * scale * 0.05;
+        }
+
+        offset
     }
 }
 
@@ -349,5 +1735,7 @@ fn set_text_line_space(_: &Lua, space: i32) -> mlua::Result<()> {
         ffi::SetTextLineSpacing(space);
     }
 
+    LINE_SPACE.store(space, Ordering::Relaxed);
+
     Ok(())
 }
@@ -68,20 +68,32 @@ use sysinfo::System;
 { "version": "1.0.0", "name": "quiver.general", "info": "The general API." }
 */
 #[rustfmt::skip]
-pub fn set_global(lua: &Lua, table: &mlua::Table, _: &StatusInfo, _: Option<&ScriptInfo>) -> mlua::Result<()> {
+pub fn set_global(lua: &Lua, table: &mlua::Table, status_info: &StatusInfo, script_info: Option<&ScriptInfo>) -> mlua::Result<()> {
     let general = lua.create_table()?;
 
     general.set("load_base",       lua.create_function(self::load_base)?)?;
+    general.set("set_module_mount", lua.create_function(self::set_module_mount)?)?;
     general.set("set_log_level",   lua.create_function(self::set_log_level)?)?;
     general.set("open_link",       lua.create_function(self::open_link)?)?;
 
     general.set("standard_input",       lua.create_function(self::standard_input)?)?;
 
-    general.set("get_frame_time",  lua.create_function(self::get_frame_time)?)?;
-    general.set("get_frame_rate",  lua.create_function(self::get_frame_rate)?)?;
-    general.set("set_frame_rate",  lua.create_function(self::set_frame_rate)?)?;
-    
-    general.set("get_time",      lua.create_function(self::get_time)?)?;
+    if Script::get_head(status_info, script_info) {
+        general.set("get_frame_time",  lua.create_function(self::get_frame_time)?)?;
+        general.set("get_frame_rate",  lua.create_function(self::get_frame_rate)?)?;
+        general.set("set_frame_rate",  lua.create_function(self::set_frame_rate)?)?;
+        general.set("get_time",        lua.create_function(self::get_time)?)?;
+    } else {
+        // head-less mode, there is no RL window to keep the time with.
+        lua.set_app_data(Clock::new(script_info.map(|info| info.rate).unwrap_or(60)));
+
+        general.set("tick",            lua.create_async_function(self::Clock::tick)?)?;
+        general.set("get_frame_time",  lua.create_function(self::Clock::get_frame_time)?)?;
+        general.set("get_frame_rate",  lua.create_function(self::Clock::get_frame_rate)?)?;
+        general.set("set_frame_rate",  lua.create_function(self::Clock::set_frame_rate)?)?;
+        general.set("get_time",        lua.create_function(self::Clock::get_time)?)?;
+    }
+
     general.set("get_time_unix", lua.create_function(self::get_time_unix)?)?;
     general.set("get_argument",  lua.create_function(self::get_argument)?)?;
 
@@ -138,6 +150,26 @@ fn load_base(lua: &Lua, _: ()) -> mlua::Result<()> {
     Ok(())
 }
 
+/* entry
+{
+    "version": "1.0.0",
+    "name": "quiver.general.set_module_mount",
+    "info": "Set a module mount, for use with \"require\". Quiver will search the project root, every search path in the info manifest, every module mount and the embed data, in that order.",
+    "member": [
+        { "name": "key",  "info": "The key for the mount. Setting a mount with the same key will replace the previous one.",                                              "kind": "any"       },
+        { "name": "call", "info": "OPTIONAL: The call-back. Must accept a file path (\"foo/bar.lua\"), and return the file's data as a string, or nil if missing. If nil, remove the mount.", "kind": "function?" }
+    ]
+}
+*/
+fn set_module_mount(
+    lua: &Lua,
+    (key, call): (LuaValue, Option<mlua::Function>),
+) -> mlua::Result<()> {
+    let mount: mlua::Table = lua.named_registry_value(crate::script::Script::NAME_MOUNT)?;
+
+    mount.set(key, call)
+}
+
 /* entry
 {
     "version": "1.0.0",
@@ -175,7 +207,7 @@ fn open_link(_: &Lua, link: String) -> mlua::Result<()> {
 /* entry
 {
     "version": "1.0.0", "name": "quiver.general.get_time",
-    "info": "Get the current time. Will count up since the initialization of the window.",
+    "info": "Get the current time. Will count up since the initialization of the window, or since the start of the script in head-less mode.",
     "result": [
         { "name": "time", "info": "Current time.", "kind": "number" }
     ]
@@ -205,13 +237,18 @@ fn get_time_unix(_: &Lua, add: Option<i64>) -> mlua::Result<String> {
 /* entry
 {
     "version": "1.0.0", "name": "quiver.general.get_frame_time",
-    "info": "Get the current frame time.",
+    "info": "Get the current frame time. Will be fixed while a replay is active.",
     "result": [
         { "name": "frame_time", "info": "Current frame time.", "kind": "number" }
     ]
 }
 */
-fn get_frame_time(_: &Lua, _: ()) -> mlua::Result<f32> {
+fn get_frame_time(lua: &Lua, _: ()) -> mlua::Result<f32> {
+    // an active replay will always use a fixed frame time.
+    if let Some(frame_time) = crate::base::automation::Replay::get_frame_time(lua) {
+        return Ok(frame_time);
+    }
+
     unsafe { Ok(ffi::GetFrameTime()) }
 }
 
@@ -244,6 +281,94 @@ fn set_frame_rate(_: &Lua, rate: i32) -> mlua::Result<()> {
     }
 }
 
+// a fixed-rate clock, to keep the main loop's time with in head-less mode.
+struct Clock {
+    time: tokio::time::Instant,
+    last: tokio::time::Instant,
+    frame: f32,
+    rate: u32,
+}
+
+impl Clock {
+    fn new(rate: u32) -> Self {
+        let time = tokio::time::Instant::now();
+
+        Self {
+            time,
+            last: time,
+            frame: 0.0,
+            rate,
+        }
+    }
+
+    /* entry
+    {
+        "version": "1.0.0",
+        "name": "quiver.general.tick",
+        "info": "Wait for the next tick of the main loop, at the frame rate from the info manifest. Only available in head-less mode, where there is no window to draw to and wait on. A frame rate of 0 will not wait at all.",
+        "result": [
+            { "name": "frame_time", "info": "Time since the last tick.", "kind": "number" }
+        ],
+        "test": "general/tick.lua",
+        "routine": true
+    }
+    */
+    async fn tick(lua: Lua, _: ()) -> mlua::Result<f32> {
+        let (last, rate) = {
+            let clock = lua.app_data_ref::<Clock>().unwrap();
+            (clock.last, clock.rate)
+        };
+
+        let mut next = tokio::time::Instant::now();
+
+        if rate > 0 {
+            let step = std::time::Duration::from_secs_f64(1.0 / rate as f64);
+
+            // keep a fixed step from the last tick, unless we have fallen behind by more than a tick.
+            if next < last + step * 2 {
+                next = last + step;
+            }
+
+            tokio::time::sleep_until(next).await;
+        }
+
+        let mut clock = lua.app_data_mut::<Clock>().unwrap();
+
+        clock.frame = (next - clock.last).as_secs_f32();
+        clock.last = next;
+
+        Ok(clock.frame)
+    }
+
+    fn get_frame_time(lua: &Lua, _: ()) -> mlua::Result<f32> {
+        Ok(lua.app_data_ref::<Clock>().unwrap().frame)
+    }
+
+    fn get_frame_rate(lua: &Lua, _: ()) -> mlua::Result<i32> {
+        let frame = lua.app_data_ref::<Clock>().unwrap().frame;
+
+        if frame > 0.0 {
+            Ok((1.0 / frame).round() as i32)
+        } else {
+            Ok(0)
+        }
+    }
+
+    fn set_frame_rate(lua: &Lua, rate: i32) -> mlua::Result<()> {
+        lua.app_data_mut::<Clock>().unwrap().rate = rate.max(0) as u32;
+        Ok(())
+    }
+
+    fn get_time(lua: &Lua, _: ()) -> mlua::Result<f64> {
+        Ok(lua
+            .app_data_ref::<Clock>()
+            .unwrap()
+            .time
+            .elapsed()
+            .as_secs_f64())
+    }
+}
+
 /* entry
 {
     "version": "1.0.0",
@@ -48,6 +48,7 @@
 * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 */
 
+use crate::sandbox::*;
 use crate::script::*;
 use crate::status::*;
 
@@ -63,7 +64,7 @@ type RLImage = ffi::Image;
 //================================================================
 
 /* class
-{ "version": "1.0.0", "name": "quiver.image", "info": "The image API." }
+{ "version": "1.0.0", "name": "quiver.image", "info": "The image API.", "head": true }
 */
 #[rustfmt::skip]
 pub fn set_global(lua: &Lua, table: &mlua::Table, _: &StatusInfo, _: Option<&ScriptInfo>) -> mlua::Result<()> {
@@ -607,7 +608,7 @@ impl Image {
     }
     */
     async fn new(lua: Lua, path: String) -> mlua::Result<Self> {
-        let name = ScriptData::get_path(&lua, &path)?;
+        let name = ScriptData::get_path(&lua, &path, SandboxAccess::Read)?;
         let name = Script::rust_to_c_string(&name)?;
 
         tokio::task::spawn_blocking(move || unsafe {
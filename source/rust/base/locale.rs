@@ -0,0 +1,783 @@
+/*
+* Copyright (c) 2025 sockentrocken
+*
+* Redistribution and use in source and binary forms, with or without
+* modification, are permitted provided that the following conditions are met:
+*
+* 1. Redistributions of source code must retain the above copyright notice,
+* this list of conditions and the following disclaimer.
+*
+* 2. Redistributions in binary form must reproduce the above copyright notice,
+* this list of conditions and the following disclaimer in the documentation
+* and/or other materials provided with the distribution.
+*
+* Subject to the terms and conditions of this license, each copyright holder
+* and contributor hereby grants to those receiving rights under this license
+* a perpetual, worldwide, non-exclusive, no-charge, royalty-free, irrevocable
+* (except for failure to satisfy the conditions of this license) patent license
+* to make, have made, use, offer to sell, sell, import, and otherwise transfer
+* this software, where such license applies only to those patent claims, already
+* acquired or hereafter acquired, licensable by such copyright holder or
+* contributor that are necessarily infringed by:
+*
+* (a) their Contribution(s) (the licensed copyrights of copyright holders and
+* non-copyrightable additions of contributors, in source or binary form) alone;
+* or
+*
+* (b) combination of their Contribution(s) with the work of authorship to which
+* such Contribution(s) was added by such copyright holder or contributor, if,
+* at the time the Contribution is added, such addition causes such combination
+* to be necessarily infringed. The patent license shall not apply to any other
+* combinations which include the Contribution.
+*
+* Except as expressly stated above, no rights or licenses from any copyright
+* holder or contributor is granted under this license, whether expressly, by
+* implication, estoppel or otherwise.
+*
+* DISCLAIMER
+*
+* THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+* AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+* IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+* DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDERS OR CONTRIBUTORS BE LIABLE
+* FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+* DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+* SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+* CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+* OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+* OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+*/
+
+use crate::script::*;
+use crate::status::*;
+
+//================================================================
+
+use mlua::prelude::*;
+use std::collections::{BTreeSet, HashMap};
+
+//================================================================
+
+/* class
+{ "version": "1.0.0", "name": "quiver.locale", "info": "The locale API." }
+*/
+#[rustfmt::skip]
+pub fn set_global(lua: &Lua, table: &mlua::Table, _: &StatusInfo, _: Option<&ScriptInfo>) -> mlua::Result<()> {
+    let locale = lua.create_table()?;
+
+    locale.set("new",        lua.create_function(self::Locale::new)?)?;
+    locale.set("get_system", lua.create_function(self::get_system)?)?;
+
+    table.set("locale", locale)?;
+
+    Ok(())
+}
+
+//================================================================
+
+/* class
+{
+    "version": "1.0.0",
+    "name": "locale",
+    "info": "A set of string table, one for each language. A key not found in a language (for example, pt-BR) will be looked up in its base language (pt), and then in the fallback language, before giving back the key itself.",
+    "member": [
+        { "name": "language", "info": "The current language, as a language tag (en-US, pt-BR, etc.).",  "kind": "string"  },
+        { "name": "fallback", "info": "The fallback language, for any key missing from the current language.", "kind": "string?" }
+    ],
+    "test": "locale/locale.lua"
+}
+*/
+struct Locale {
+    language: String,
+    fallback: Option<String>,
+    table: HashMap<String, HashMap<String, LocaleText>>,
+    // every key that was asked for but not found, by language.
+    missing: HashMap<String, BTreeSet<String>>,
+}
+
+// a translated text, with a form for each plural category (zero, one, two, few, many, other).
+#[derive(Default)]
+struct LocaleText([Option<String>; 6]);
+
+impl mlua::UserData for Locale {
+    fn add_fields<F: mlua::UserDataFields<Self>>(field: &mut F) {
+        field.add_field_method_get("language", |_, this| Ok(this.language.clone()));
+        field.add_field_method_set("language", |_, this, value: String| {
+            this.language = Self::get_tag(&value);
+            Ok(())
+        });
+        field.add_field_method_get("fallback", |_, this| Ok(this.fallback.clone()));
+        field.add_field_method_set("fallback", |_, this, value: Option<String>| {
+            this.fallback = value.map(|x| Self::get_tag(&x));
+            Ok(())
+        });
+    }
+
+    fn add_methods<M: mlua::UserDataMethods<Self>>(method: &mut M) {
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "locale:load",
+            "info": "Load a string table for a language, such as the result of quiver.data.deserialize. A nested table will be flattened, with each key joined by a dot. A table with only plural category key (zero, one, two, few, many, other) is a plural text, and must have at least the other key.",
+            "member": [
+                { "name": "language", "info": "The language of the string table.", "kind": "string" },
+                { "name": "table",    "info": "The string table.",                  "kind": "table"  }
+            ]
+        }
+        */
+        method.add_method_mut(
+            "load",
+            |_: &Lua, this, (language, table): (String, LuaTable)| {
+                let mut list = HashMap::new();
+
+                Self::get_table(&table, "", &mut list)?;
+
+                this.table
+                    .entry(Self::get_tag(&language))
+                    .or_default()
+                    .extend(list);
+
+                Ok(())
+            },
+        );
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "locale:load_po",
+            "info": "Load a gettext .po file for a language. Every entry is keyed by its msgid, or by msgctxt.msgid if it has a context. Any fuzzy or untranslated entry is skipped. Each msgstr[n] of a plural entry is taken to be the n-th plural category of the language, in gettext order.",
+            "member": [
+                { "name": "language", "info": "The language of the file.", "kind": "string" },
+                { "name": "text",     "info": "The file text.",            "kind": "string" }
+            ]
+        }
+        */
+        method.add_method_mut(
+            "load_po",
+            |_: &Lua, this, (language, text): (String, String)| {
+                let language = Self::get_tag(&language);
+                let list = Self::get_po(&language, &text)?;
+
+                this.table.entry(language).or_default().extend(list);
+
+                Ok(())
+            },
+        );
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "locale:get",
+            "info": "Get the text of a key in the current language. Any {name} placeholder will be replaced with the value of the same name. Use {{ for a literal {. For a plural text, the count value will pick the plural form, from the CLDR plural rule of the language.",
+            "member": [
+                { "name": "key",   "info": "The key.",                                     "kind": "string" },
+                { "name": "value", "info": "OPTIONAL: The placeholder value table.",       "kind": "table?" }
+            ],
+            "result": [
+                { "name": "text", "info": "The text, or the key itself if it is missing.", "kind": "string" }
+            ]
+        }
+        */
+        method.add_method_mut(
+            "get",
+            |_: &Lua, this, (key, value): (String, Option<LuaTable>)| {
+                let count = match &value {
+                    Some(value) => value.get::<Option<f64>>("count")?,
+                    None => None,
+                };
+
+                let text = this.get_text(&key, count);
+
+                match value {
+                    Some(value) => Self::get_format(&text, &value),
+                    None => Ok(text),
+                }
+            },
+        );
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "locale:has",
+            "info": "Check if a key is in the current language (or its base language), without falling back.",
+            "member": [
+                { "name": "key", "info": "The key.", "kind": "string" }
+            ],
+            "result": [
+                { "name": "value", "info": "True if the key is in the current language.", "kind": "boolean" }
+            ]
+        }
+        */
+        method.add_method("has", |_: &Lua, this, key: String| {
+            Ok(this.get_entry(&this.language, &key).is_some())
+        });
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "locale:get_missing",
+            "info": "Get every missing key of a language: any key in the fallback language but not in this one, and any key that was asked for with locale:get and not found.",
+            "member": [
+                { "name": "language", "info": "OPTIONAL: The language. Default: the current language.", "kind": "string?" }
+            ],
+            "result": [
+                { "name": "list", "info": "The missing key list, sorted.", "kind": "table" }
+            ]
+        }
+        */
+        method.add_method("get_missing", |_: &Lua, this, language: Option<String>| {
+            let language = language.map_or(this.language.clone(), |x| Self::get_tag(&x));
+            let mut list = this.missing.get(&language).cloned().unwrap_or_default();
+
+            if let Some(fallback) = this.fallback.as_ref().filter(|x| **x != language) {
+                for key in this.table.get(fallback).into_iter().flat_map(|x| x.keys()) {
+                    if this.get_entry(&language, key).is_none() {
+                        list.insert(key.clone());
+                    }
+                }
+            }
+
+            Ok(list.into_iter().collect::<Vec<_>>())
+        });
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "locale:get_language",
+            "info": "Get every language with a string table.",
+            "result": [
+                { "name": "list", "info": "The language list, sorted.", "kind": "table" }
+            ]
+        }
+        */
+        method.add_method("get_language", |_: &Lua, this, _: ()| {
+            let mut list: Vec<String> = this.table.keys().cloned().collect();
+
+            list.sort();
+
+            Ok(list)
+        });
+    }
+}
+
+impl Locale {
+    const CATEGORY: [&str; 6] = ["zero", "one", "two", "few", "many", "other"];
+
+    /* entry
+    {
+        "version": "1.0.0",
+        "name": "quiver.locale.new",
+        "info": "Create a new locale.",
+        "member": [
+            { "name": "language", "info": "OPTIONAL: The current language. Default: the OS language (see quiver.locale.get_system).", "kind": "string?" },
+            { "name": "fallback", "info": "OPTIONAL: The fallback language. Default: none.",                                      "kind": "string?" }
+        ],
+        "result": [
+            { "name": "locale", "info": "The locale.", "kind": "locale" }
+        ]
+    }
+    */
+    fn new(_: &Lua, (language, fallback): (Option<String>, Option<String>)) -> mlua::Result<Self> {
+        Ok(Self {
+            language: language.map_or_else(get_system_tag, |x| Self::get_tag(&x)),
+            fallback: fallback.map(|x| Self::get_tag(&x)),
+            table: HashMap::new(),
+            missing: HashMap::new(),
+        })
+    }
+
+    // normalize a language tag, or an OS locale name (en_US.UTF-8), to the en-US form.
+    fn get_tag(text: &str) -> String {
+        let text = text.split(['.', '@']).next().unwrap_or_default();
+        let mut list = text.split(['_', '-']).filter(|x| !x.is_empty());
+        let mut tag = list.next().unwrap_or_default().to_lowercase();
+
+        for part in list {
+            tag.push('-');
+
+            // a region (US, BR) is upper-case, a script (Hans, Latn) is title-case. only an ASCII part may be a script, so as to slice it safely.
+            if part.len() == 4 && part.is_ascii() {
+                tag.push_str(&part[..1].to_uppercase());
+                tag.push_str(&part[1..].to_lowercase());
+            } else {
+                tag.push_str(&part.to_uppercase());
+            }
+        }
+
+        tag
+    }
+
+    // get the base language of a language tag (pt-BR to pt).
+    fn get_base(language: &str) -> &str {
+        language.split('-').next().unwrap_or_default()
+    }
+
+    // flatten a (nested) Lua string table.
+    fn get_table(
+        table: &LuaTable,
+        path: &str,
+        list: &mut HashMap<String, LocaleText>,
+    ) -> mlua::Result<()> {
+        for pair in table.pairs::<String, LuaValue>() {
+            let (key, value) = pair?;
+            let key = if path.is_empty() {
+                key
+            } else {
+                format!("{path}.{key}")
+            };
+
+            match value {
+                LuaValue::Table(value) => {
+                    if let Some(text) = Self::get_table_plural(&value)? {
+                        list.insert(key, text);
+                    } else {
+                        Self::get_table(&value, &key, list)?;
+                    }
+                }
+                LuaValue::String(value) => {
+                    let mut text = LocaleText::default();
+                    text.0[5] = Some(value.to_str()?.to_string());
+                    list.insert(key, text);
+                }
+                _ => {
+                    return Err(mlua::Error::runtime(format!(
+                        "locale:load(): Key \"{key}\" must be a string or a table."
+                    )));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    // get a plural text from a table, if every key of the table is a plural category.
+    fn get_table_plural(table: &LuaTable) -> mlua::Result<Option<LocaleText>> {
+        let mut text = LocaleText::default();
+
+        for pair in table.pairs::<LuaValue, LuaValue>() {
+            let (key, value) = pair?;
+
+            let (LuaValue::String(key), LuaValue::String(value)) = (key, value) else {
+                return Ok(None);
+            };
+
+            let Some(index) = Self::CATEGORY.iter().position(|x| *x == key.to_str()?) else {
+                return Ok(None);
+            };
+
+            text.0[index] = Some(value.to_str()?.to_string());
+        }
+
+        Ok(text.0[5].is_some().then_some(text))
+    }
+
+    // parse a gettext .po file.
+    fn get_po(language: &str, text: &str) -> mlua::Result<HashMap<String, LocaleText>> {
+        let category = plural::get_category(language);
+        let mut list = HashMap::new();
+        let mut entry = PoEntry::default();
+        // the field that a continued "..." line will be appended to.
+        let mut field: Option<PoField> = None;
+
+        for (i, line) in text.lines().enumerate() {
+            let line = line.trim();
+            let error = |info: &str| {
+                mlua::Error::runtime(format!("locale:load_po(): {info} at line {}.", i + 1))
+            };
+
+            if line.is_empty() {
+                continue;
+            }
+
+            if let Some(flag) = line.strip_prefix("#,") {
+                entry.set_end(&mut list, category);
+                entry.fuzzy = flag.split(',').any(|x| x.trim() == "fuzzy");
+                field = None;
+                continue;
+            }
+
+            if line.starts_with('#') {
+                continue;
+            }
+
+            if line.starts_with('"') {
+                let value = Self::get_po_string(line).ok_or(error("Invalid string"))?;
+                let target = field.ok_or(error("String with no keyword"))?;
+
+                entry.get_field(target).push_str(&value);
+                continue;
+            }
+
+            let (keyword, value) = line
+                .split_once(char::is_whitespace)
+                .ok_or(error("Invalid line"))?;
+            let value = Self::get_po_string(value.trim()).ok_or(error("Invalid string"))?;
+
+            let target = match keyword {
+                "msgctxt" => PoField::Context,
+                "msgid" => PoField::Key,
+                "msgid_plural" => PoField::KeyPlural,
+                "msgstr" => PoField::Text(0),
+                _ => {
+                    let index = keyword
+                        .strip_prefix("msgstr[")
+                        .and_then(|x| x.strip_suffix(']'))
+                        .and_then(|x| x.parse::<usize>().ok())
+                        .filter(|x| *x < Locale::CATEGORY.len())
+                        .ok_or(error("Unknown keyword"))?;
+
+                    PoField::Text(index)
+                }
+            };
+
+            // a new msgctxt or msgid after any msgstr is a new entry.
+            if matches!(target, PoField::Context | PoField::Key) && !entry.text.is_empty() {
+                entry.set_end(&mut list, category);
+            }
+
+            entry.get_field(target).push_str(&value);
+            field = Some(target);
+        }
+
+        entry.set_end(&mut list, category);
+
+        Ok(list)
+    }
+
+    // parse a quoted .po string, with any escape sequence.
+    fn get_po_string(text: &str) -> Option<String> {
+        let text = text.strip_prefix('"')?.strip_suffix('"')?;
+        let mut value = String::with_capacity(text.len());
+        let mut list = text.chars();
+
+        while let Some(character) = list.next() {
+            if character != '\\' {
+                value.push(character);
+                continue;
+            }
+
+            value.push(match list.next()? {
+                'n' => '\n',
+                't' => '\t',
+                'r' => '\r',
+                other => other,
+            });
+        }
+
+        Some(value)
+    }
+
+    fn get_entry(&self, language: &str, key: &str) -> Option<&LocaleText> {
+        let base = Self::get_base(language);
+
+        self.table
+            .get(language)
+            .and_then(|x| x.get(key))
+            .or_else(|| self.table.get(base).and_then(|x| x.get(key)))
+    }
+
+    // get the text of a key, recording it as missing if not in the current language.
+    fn get_text(&mut self, key: &str, count: Option<f64>) -> String {
+        let mut language = self.language.clone();
+
+        if self.get_entry(&language, key).is_none() {
+            self.missing
+                .entry(language.clone())
+                .or_default()
+                .insert(key.to_string());
+
+            if let Some(fallback) = &self.fallback {
+                language = fallback.clone();
+            }
+        }
+
+        let Some(entry) = self.get_entry(&language, key) else {
+            return key.to_string();
+        };
+
+        let index = match count {
+            Some(count) => plural::get_plural(&language, count),
+            None => 5,
+        };
+
+        // use the "other" form for any missing form, and failing that, the last form.
+        entry.0[index]
+            .as_ref()
+            .or(entry.0[5].as_ref())
+            .or(entry.0.iter().rev().flatten().next())
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    // replace every {name} placeholder in a text.
+    fn get_format(text: &str, value: &LuaTable) -> mlua::Result<String> {
+        let mut result = String::with_capacity(text.len());
+        let mut rest = text;
+
+        while let Some(index) = rest.find('{') {
+            result.push_str(&rest[..index]);
+            rest = &rest[index..];
+
+            if let Some(other) = rest.strip_prefix("{{") {
+                result.push('{');
+                rest = other;
+                continue;
+            }
+
+            let name = rest[1..].find('}').map(|x| &rest[1..=x]);
+
+            match name {
+                Some(name) if !name.is_empty() && !name.contains('{') => {
+                    match value.get::<LuaValue>(name)? {
+                        LuaValue::Nil => result.push_str(&rest[..name.len() + 2]),
+                        other => result.push_str(&other.to_string()?),
+                    }
+
+                    rest = &rest[name.len() + 2..];
+                }
+                _ => {
+                    result.push('{');
+                    rest = &rest[1..];
+                }
+            }
+        }
+
+        result.push_str(rest);
+
+        Ok(result)
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum PoField {
+    Context,
+    Key,
+    KeyPlural,
+    Text(usize),
+}
+
+#[derive(Default)]
+struct PoEntry {
+    context: Option<String>,
+    key: String,
+    key_plural: Option<String>,
+    text: Vec<String>,
+    fuzzy: bool,
+}
+
+impl PoEntry {
+    fn get_field(&mut self, field: PoField) -> &mut String {
+        match field {
+            PoField::Context => self.context.get_or_insert_default(),
+            PoField::Key => &mut self.key,
+            PoField::KeyPlural => self.key_plural.get_or_insert_default(),
+            PoField::Text(index) => {
+                if self.text.len() <= index {
+                    self.text.resize(index + 1, String::new());
+                }
+
+                &mut self.text[index]
+            }
+        }
+    }
+
+    // add the entry to the list (if it is translated, not fuzzy and not the header), and start a new one.
+    fn set_end(&mut self, list: &mut HashMap<String, LocaleText>, category: &[usize]) {
+        let entry = std::mem::take(self);
+
+        if entry.fuzzy || entry.key.is_empty() || entry.text.iter().all(|x| x.is_empty()) {
+            return;
+        }
+
+        let key = match entry.context {
+            Some(context) => format!("{context}.{}", entry.key),
+            None => entry.key,
+        };
+
+        let mut text = LocaleText::default();
+
+        if entry.key_plural.is_some() {
+            for (i, value) in entry.text.into_iter().enumerate() {
+                if let Some(index) = category.get(i) {
+                    text.0[*index] = Some(value).filter(|x| !x.is_empty());
+                }
+            }
+        } else {
+            text.0[5] = entry.text.into_iter().next();
+        }
+
+        list.insert(key, text);
+    }
+}
+
+// the CLDR cardinal plural rule of each language, with each category as an index into Locale::CATEGORY.
+mod plural {
+    const ZERO: usize = 0;
+    const ONE: usize = 1;
+    const TWO: usize = 2;
+    const FEW: usize = 3;
+    const MANY: usize = 4;
+    const OTHER: usize = 5;
+
+    // get the plural category list of a language, in gettext msgstr[n] order.
+    pub fn get_category(language: &str) -> &'static [usize] {
+        match super::Locale::get_base(language) {
+            "ja" | "zh" | "ko" | "th" | "vi" | "id" | "ms" | "lo" | "km" | "my" => &[OTHER],
+            "ru" | "uk" | "be" | "pl" => &[ONE, FEW, MANY, OTHER],
+            "hr" | "sr" | "bs" | "ro" => &[ONE, FEW, OTHER],
+            "cs" | "sk" => &[ONE, FEW, OTHER, MANY],
+            "ar" => &[ZERO, ONE, TWO, FEW, MANY, OTHER],
+            "he" => &[ONE, TWO, OTHER],
+            _ => &[ONE, OTHER],
+        }
+    }
+
+    // get the plural category of a count in a language.
+    pub fn get_plural(language: &str, count: f64) -> usize {
+        let n = count.abs();
+        // the integer digit, and whether there are any fraction digit.
+        let i = n.trunc() as u64;
+        let v = n.fract() != 0.0;
+        let i_10 = i % 10;
+        let i_100 = i % 100;
+
+        match super::Locale::get_base(language) {
+            "ja" | "zh" | "ko" | "th" | "vi" | "id" | "ms" | "lo" | "km" | "my" => OTHER,
+            "fr" | "pt" => {
+                if i <= 1 {
+                    ONE
+                } else {
+                    OTHER
+                }
+            }
+            "ru" | "uk" | "be" => {
+                if v {
+                    OTHER
+                } else if i_10 == 1 && i_100 != 11 {
+                    ONE
+                } else if (2..=4).contains(&i_10) && !(12..=14).contains(&i_100) {
+                    FEW
+                } else {
+                    MANY
+                }
+            }
+            "pl" => {
+                if v {
+                    OTHER
+                } else if i == 1 {
+                    ONE
+                } else if (2..=4).contains(&i_10) && !(12..=14).contains(&i_100) {
+                    FEW
+                } else {
+                    MANY
+                }
+            }
+            "hr" | "sr" | "bs" => {
+                if !v && i_10 == 1 && i_100 != 11 {
+                    ONE
+                } else if !v && (2..=4).contains(&i_10) && !(12..=14).contains(&i_100) {
+                    FEW
+                } else {
+                    OTHER
+                }
+            }
+            "ro" => {
+                if !v && i == 1 {
+                    ONE
+                } else if v || i == 0 || (i != 1 && (1..=19).contains(&i_100)) {
+                    FEW
+                } else {
+                    OTHER
+                }
+            }
+            "cs" | "sk" => {
+                if v {
+                    MANY
+                } else if i == 1 {
+                    ONE
+                } else if (2..=4).contains(&i) {
+                    FEW
+                } else {
+                    OTHER
+                }
+            }
+            "ar" => {
+                if v {
+                    OTHER
+                } else if i == 0 {
+                    ZERO
+                } else if i == 1 {
+                    ONE
+                } else if i == 2 {
+                    TWO
+                } else if (3..=10).contains(&i_100) {
+                    FEW
+                } else if (11..=99).contains(&i_100) {
+                    MANY
+                } else {
+                    OTHER
+                }
+            }
+            "he" => {
+                if !v && i == 1 {
+                    ONE
+                } else if !v && i == 2 {
+                    TWO
+                } else {
+                    OTHER
+                }
+            }
+            _ => {
+                if !v && i == 1 {
+                    ONE
+                } else {
+                    OTHER
+                }
+            }
+        }
+    }
+}
+
+// get the OS language, as a language tag.
+fn get_system_tag() -> String {
+    #[cfg(target_os = "windows")]
+    {
+        unsafe extern "system" {
+            fn GetUserDefaultLocaleName(name: *mut u16, length: i32) -> i32;
+        }
+
+        // LOCALE_NAME_MAX_LENGTH.
+        let mut name = [0u16; 85];
+        let length = unsafe { GetUserDefaultLocaleName(name.as_mut_ptr(), name.len() as i32) };
+
+        if length > 1 {
+            return Locale::get_tag(&String::from_utf16_lossy(&name[..length as usize - 1]));
+        }
+    }
+
+    // the POSIX locale environment variable, in order of priority.
+    ["LC_ALL", "LC_MESSAGES", "LANG"]
+        .iter()
+        .filter_map(|x| std::env::var(x).ok())
+        .find(|x| {
+            // the C locale may still have a codeset or modifier (C.UTF-8).
+            let name = x.split(['.', '@']).next().unwrap_or_default();
+
+            !name.is_empty() && name != "C" && name != "POSIX"
+        })
+        .map_or("en".to_string(), |x| Locale::get_tag(&x))
+}
+
+/* entry
+{
+    "version": "1.0.0",
+    "name": "quiver.locale.get_system",
+    "info": "Get the OS language, as a language tag (en-US, pt-BR, etc.). Will give back en if it can not be found.",
+    "result": [
+        { "name": "language", "info": "The OS language.", "kind": "string" }
+    ]
+}
+*/
+fn get_system(_: &Lua, _: ()) -> mlua::Result<String> {
+    Ok(get_system_tag())
+}
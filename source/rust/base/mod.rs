@@ -49,6 +49,7 @@
 */
 
 /* base library */
+pub mod audio;
 pub mod automation;
 pub mod collision;
 pub mod data;
@@ -58,12 +59,18 @@ pub mod font;
 pub mod general;
 pub mod image;
 pub mod input;
+pub mod locale;
 pub mod model;
 pub mod music;
+pub mod navigation;
+pub mod noise;
+pub mod random;
+pub mod save;
 pub mod shader;
 pub mod socket;
 pub mod sound;
 pub mod texture;
+pub mod wave;
 pub mod window;
 
 #[cfg(feature = "rapier3d")]
@@ -48,6 +48,7 @@
 * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 */
 
+use crate::sandbox::*;
 use crate::script::*;
 use crate::status::*;
 
@@ -112,7 +113,8 @@ impl Model {
     }
     */
     fn new(lua: &Lua, path: String) -> mlua::Result<Self> {
-        let name = Script::rust_to_c_string(&ScriptData::get_path(lua, &path)?)?;
+        let name =
+            Script::rust_to_c_string(&ScriptData::get_path(lua, &path, SandboxAccess::Read)?)?;
 
         unsafe {
             let data = ffi::LoadModel(name.as_ptr());
@@ -403,7 +405,8 @@ impl ModelAnimation {
     }
     */
     fn new(lua: &Lua, path: String) -> mlua::Result<Vec<Self>> {
-        let name = Script::rust_to_c_string(&ScriptData::get_path(lua, &path)?)?;
+        let name =
+            Script::rust_to_c_string(&ScriptData::get_path(lua, &path, SandboxAccess::Read)?)?;
 
         unsafe {
             let mut count = 0;
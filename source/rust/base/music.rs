@@ -48,6 +48,9 @@
 * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 */
 
+use crate::base::audio::{AudioBus, AudioLink, AudioSpatial, AudioTap};
+use crate::base::wave;
+use crate::sandbox::*;
 use crate::script::*;
 use crate::status::*;
 
@@ -55,6 +58,9 @@ use crate::status::*;
 
 use mlua::prelude::*;
 use raylib::prelude::*;
+use serde::Deserialize;
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex, Weak};
 
 //================================================================
 
@@ -67,6 +73,12 @@ pub fn set_global(lua: &Lua, table: &mlua::Table, _: &StatusInfo, _: Option<&Scr
 
     music.set("new",             lua.create_async_function(self::Music::new)?)?;
     music.set("new_from_memory", lua.create_async_function(self::Music::new_from_memory)?)?;
+    music.set("new_player",      lua.create_function(self::MusicPlayer::new)?)?;
+
+    // the standard library is set more than once, so keep any music player made before.
+    if lua.app_data_ref::<PlayerList>().is_none() {
+        lua.set_app_data(PlayerList::default());
+    }
 
     table.set("music", music)?;
 
@@ -79,7 +91,13 @@ type RLMusic = ffi::Music;
 { "version": "1.0.0", "name": "music", "info": "An unique handle for music in memory." }
 */
 #[allow(dead_code)]
-struct Music(RLMusic, Option<Vec<u8>>);
+struct Music(
+    RLMusic,
+    Option<Vec<u8>>,
+    Option<AudioLink>,
+    Option<AudioSpatial>,
+    Option<AudioTap>,
+);
 
 unsafe impl Send for Music {}
 
@@ -99,13 +117,14 @@ impl Music {
     }
     */
     async fn new(lua: Lua, path: String) -> mlua::Result<Self> {
-        let name = Script::rust_to_c_string(&ScriptData::get_path(&lua, &path)?)?;
+        let name =
+            Script::rust_to_c_string(&ScriptData::get_path(&lua, &path, SandboxAccess::Read)?)?;
 
         tokio::task::spawn_blocking(move || unsafe {
             let data = ffi::LoadMusicStream(name.as_ptr());
 
             if ffi::IsMusicValid(data) {
-                Ok(Self(data, None))
+                Ok(Self(data, None, None, None, None))
             } else {
                 Err(mlua::Error::RuntimeError(format!(
                     "Music::new(): Could not load file \"{path}\"."
@@ -143,7 +162,7 @@ impl Music {
             );
 
             if ffi::IsMusicValid(data) {
-                Ok(Self(data, Some(buffer)))
+                Ok(Self(data, Some(buffer), None, None, None))
             } else {
                 Err(mlua::Error::RuntimeError(
                     "Music::new_from_memory(): Could not load file.".to_string(),
@@ -153,10 +172,24 @@ impl Music {
         .await
         .unwrap()
     }
+
+    // get the audio tap, attaching it on the first call.
+    fn get_tap(&mut self) -> mlua::Result<&AudioTap> {
+        if self.4.is_none() {
+            self.4 = Some(AudioTap::new("get_spectrum", self.0.stream)?);
+        }
+
+        Ok(self.4.as_ref().unwrap())
+    }
 }
 
 impl Drop for Music {
     fn drop(&mut self) {
+        // detach from the bus before the music is gone.
+        self.4 = None;
+        self.3 = None;
+        self.2 = None;
+
         unsafe {
             ffi::UnloadMusicStream(self.0);
         }
@@ -167,6 +200,31 @@ impl mlua::UserData for Music {
     fn add_fields<F: mlua::UserDataFields<Self>>(_: &mut F) {}
 
     fn add_methods<M: mlua::UserDataMethods<Self>>(method: &mut M) {
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "music:set_bus",
+            "info": "Set the audio bus of the music.",
+            "member": [
+                { "name": "bus", "info": "The audio bus. Use nil to remove the music from its bus.", "kind": "audio_bus?" }
+            ]
+        }
+        */
+        method.add_method_mut(
+            "set_bus",
+            |_, this, bus: Option<LuaUserDataRef<AudioBus>>| {
+                this.2 = None;
+
+                if let Some(bus) = bus {
+                    let mut link = AudioLink::new(&bus);
+                    link.attach(this.0.stream)?;
+                    this.2 = Some(link);
+                }
+
+                Ok(())
+            },
+        );
+
         /* entry
         { "version": "1.0.0", "name": "music:play", "info": "Play the music." }
         */
@@ -238,8 +296,15 @@ impl mlua::UserData for Music {
             ]
         }
         */
-        method.add_method("set_pitch", |_, this, value: f32| unsafe {
-            ffi::SetMusicPitch(this.0, value);
+        method.add_method("set_pitch", |_, this, value: f32| {
+            if let Some(spatial) = &this.3 {
+                spatial.set_pitch(value);
+            } else {
+                unsafe {
+                    ffi::SetMusicPitch(this.0, value);
+                }
+            }
+
             Ok(())
         });
 
@@ -308,5 +373,575 @@ impl mlua::UserData for Music {
         method.add_method("get_played", |_, this, _: ()| unsafe {
             Ok(ffi::GetMusicTimePlayed(this.0))
         });
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "music:set_spatial",
+            "info": "Set the music as a spatial source, with distance attenuation, pan and doppler pitch shift from the listener.",
+            "member": [
+                { "name": "option", "info": "The spatial option: { point, velocity?, kind? (ATTENUATION_KIND), distance_min?, distance_max?, rolloff? }. Use nil to remove the spatial source.", "kind": "table?" }
+            ]
+        }
+        */
+        method.add_method_mut("set_spatial", |lua, this, option: LuaValue| {
+            this.3 = None;
+
+            if !option.is_nil() {
+                this.3 = Some(AudioSpatial::new(
+                    lua,
+                    "set_spatial",
+                    this.0.stream,
+                    option,
+                )?);
+            }
+
+            Ok(())
+        });
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "music:set_spatial_point",
+            "info": "Set the point of the spatial source of the music.",
+            "member": [
+                { "name": "point",    "info": "The point of the source.",                                                       "kind": "vector_3"  },
+                { "name": "velocity", "info": "OPTIONAL: The velocity of the source. Default: derived from the last source point.", "kind": "vector_3?" }
+            ]
+        }
+        */
+        method.add_method(
+            "set_spatial_point",
+            |lua, this, (point, velocity): (LuaValue, Option<LuaValue>)| {
+                let spatial = this.3.as_ref().ok_or(mlua::Error::runtime(
+                    "music:set_spatial_point(): Music is not a spatial source.",
+                ))?;
+
+                spatial.set_point(lua, point, velocity)
+            },
+        );
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "music:get_spectrum",
+            "info": "Get the magnitude spectrum of the last sample played, with every channel down-mixed. The first call will begin listening to the music, and will be silence.",
+            "member": [
+                { "name": "size", "info": "OPTIONAL: The FFT size, as a power of two (up to 16384). Default: 1024.", "kind": "number?" }
+            ],
+            "result": [
+                { "name": "spectrum", "info": "The magnitude of every frequency bin (size / 2), each bin being sample_rate / size Hz wide.", "kind": "table" }
+            ]
+        }
+        */
+        method.add_method_mut("get_spectrum", |_, this, size: Option<usize>| {
+            let size = wave::get_size("music:get_spectrum", size)?;
+
+            Ok(wave::get_spectrum(&this.get_tap()?.get_sample(size), size))
+        });
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "music:get_level",
+            "info": "Get the RMS (root mean square) and peak level of the last sample played, with every channel down-mixed. The first call will begin listening to the music, and will be silence.",
+            "member": [
+                { "name": "size", "info": "OPTIONAL: The sample count, as a power of two (up to 16384). Default: 1024.", "kind": "number?" }
+            ],
+            "result": [
+                { "name": "rms",  "info": "The RMS level.",  "kind": "number" },
+                { "name": "peak", "info": "The peak level.", "kind": "number" }
+            ]
+        }
+        */
+        method.add_method_mut("get_level", |_, this, size: Option<usize>| {
+            let size = wave::get_size("music:get_level", size)?;
+            let data = this.get_tap()?.get_sample(size);
+
+            Ok((wave::get_rms(&data), wave::get_peak(&data)))
+        });
+    }
+}
+
+//================================================================
+
+// every music player, for updating at the end of every frame.
+#[derive(Default)]
+struct PlayerList(Vec<Weak<Mutex<PlayerState>>>);
+
+#[derive(Default, Deserialize)]
+#[serde(default)]
+struct TrackOption {
+    fade: Option<f32>,
+    loop_start: Option<f32>,
+    loop_end: Option<f32>,
+    #[serde(rename = "loop")]
+    repeat: Option<bool>,
+}
+
+// a music track in a music player.
+struct PlayerTrack {
+    music: AnyUserData,
+    // the fade-in time.
+    fade: f32,
+    gain: f32,
+    // the gain change per second.
+    speed: f32,
+    // the loop start and loop end, if looping.
+    region: Option<(f32, Option<f32>)>,
+    // the last time played, for finding out when the music has wrapped around.
+    last: f32,
+}
+
+impl PlayerTrack {
+    fn new(
+        lua: &Lua,
+        call: &str,
+        music: AnyUserData,
+        option: Option<LuaValue>,
+        fade: f32,
+    ) -> mlua::Result<Self> {
+        if !music.is::<Music>() {
+            return Err(mlua::Error::runtime(format!(
+                "music_player:{call}(): Value is not a music."
+            )));
+        }
+
+        let option: TrackOption = match option {
+            Some(option) => lua.from_value(option)?,
+            None => TrackOption::default(),
+        };
+
+        let point = option.loop_start.is_some() || option.loop_end.is_some();
+        let region = if option.repeat.unwrap_or(point) {
+            Some((
+                option.loop_start.unwrap_or_default().max(0.0),
+                option.loop_end,
+            ))
+        } else {
+            None
+        };
+
+        if let Some((start, Some(end))) = region {
+            if end <= start {
+                return Err(mlua::Error::runtime(format!(
+                    "music_player:{call}(): Loop end must be past the loop start."
+                )));
+            }
+        }
+
+        Ok(Self {
+            music,
+            fade: option.fade.unwrap_or(fade).max(0.0),
+            gain: 0.0,
+            speed: 0.0,
+            region,
+            last: 0.0,
+        })
+    }
+
+    fn get_music(&self) -> Option<RLMusic> {
+        self.music.borrow::<Music>().ok().map(|x| x.0)
+    }
+
+    fn is_music(&self, other: &Self) -> bool {
+        self.music.to_pointer() == other.music.to_pointer()
+    }
+
+    fn play(&mut self, volume: f32) {
+        if self.fade > 0.0 {
+            self.gain = 0.0;
+            self.speed = 1.0 / self.fade;
+        } else {
+            self.gain = 1.0;
+            self.speed = 0.0;
+        }
+
+        self.last = 0.0;
+
+        if let Ok(mut music) = self.music.borrow_mut::<Music>() {
+            // raylib will wrap a looping music around to the start, which we will then move to the loop start.
+            music.0.looping = self.region.is_some();
+
+            unsafe {
+                ffi::PlayMusicStream(music.0);
+                ffi::SetMusicVolume(music.0, volume * self.gain);
+            }
+        }
+    }
+
+    fn stop(&self) {
+        if let Some(music) = self.get_music() {
+            unsafe {
+                ffi::StopMusicStream(music);
+            }
+        }
+    }
+
+    // get the time left to play, or infinity if looping.
+    fn get_remain(&self) -> f32 {
+        match (self.region, self.get_music()) {
+            (None, Some(music)) => unsafe {
+                ffi::GetMusicTimeLength(music) - ffi::GetMusicTimePlayed(music)
+            },
+            _ => f32::INFINITY,
+        }
+    }
+
+    // update the stream, gain and loop region, and get whether the music is still playing.
+    fn update(&mut self, delta: f32, volume: f32) -> bool {
+        let Some(music) = self.get_music() else {
+            return false;
+        };
+
+        self.gain = (self.gain + self.speed * delta).clamp(0.0, 1.0);
+
+        unsafe {
+            ffi::UpdateMusicStream(music);
+            ffi::SetMusicVolume(music, volume * self.gain);
+
+            if let Some((start, end)) = self.region {
+                let played = ffi::GetMusicTimePlayed(music);
+                let end = end.unwrap_or(ffi::GetMusicTimeLength(music));
+
+                if played >= end {
+                    ffi::SeekMusicStream(music, start + (played - end));
+                } else if played < self.last && start > 0.0 {
+                    // raylib has wrapped around the end of the music.
+                    ffi::SeekMusicStream(music, start + played);
+                }
+
+                self.last = ffi::GetMusicTimePlayed(music);
+            }
+
+            ffi::IsMusicStreamPlaying(music)
+        }
+    }
+}
+
+struct PlayerState {
+    current: Option<PlayerTrack>,
+    // every track that is fading out.
+    previous: Vec<PlayerTrack>,
+    queue: VecDeque<PlayerTrack>,
+    volume: f32,
+    fade: f32,
+    pause: bool,
+}
+
+impl PlayerState {
+    // fade out the current track, if any, over a time.
+    fn set_fade_out(&mut self, fade: f32) {
+        if let Some(mut current) = self.current.take() {
+            if fade > 0.0 {
+                current.speed = -1.0 / fade;
+                self.previous.push(current);
+            } else {
+                current.stop();
+            }
+        }
+    }
+
+    // cross-fade from the current track, if any, to another track.
+    fn set_track(&mut self, mut track: PlayerTrack) {
+        self.set_fade_out(track.fade);
+
+        // the same music can not both fade out and fade in.
+        self.previous.retain(|x| !x.is_music(&track));
+
+        track.play(self.volume);
+        self.current = Some(track);
+    }
+
+    fn update(&mut self, delta: f32) {
+        if self.pause {
+            return;
+        }
+
+        let volume = self.volume;
+
+        self.previous.retain_mut(|track| {
+            let playing = track.update(delta, volume);
+
+            if track.gain <= 0.0 {
+                track.stop();
+            }
+
+            playing && track.gain > 0.0
+        });
+
+        if let Some(current) = &mut self.current {
+            let playing = current.update(delta, volume);
+            let remain = current.get_remain();
+
+            // move on to the next track, early enough to cross-fade into it.
+            let next = match self.queue.front() {
+                Some(next) => !playing || remain <= next.fade,
+                None => false,
+            };
+
+            if next {
+                let next = self.queue.pop_front().unwrap();
+                self.set_track(next);
+            } else if !playing {
+                self.current = None;
+            }
+        }
+    }
+
+    fn set_pause(&mut self, pause: bool) {
+        self.pause = pause;
+
+        for track in self.current.iter().chain(&self.previous) {
+            if let Some(music) = track.get_music() {
+                unsafe {
+                    if pause {
+                        ffi::PauseMusicStream(music);
+                    } else {
+                        ffi::ResumeMusicStream(music);
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl Drop for PlayerState {
+    fn drop(&mut self) {
+        for track in self.current.iter().chain(&self.previous) {
+            track.stop();
+        }
+    }
+}
+
+/* class
+{
+    "version": "1.0.0",
+    "name": "music_player",
+    "info": "A music player, with a queue of music track, cross-fading and loop region. Every music player will update itself at the end of every quiver.draw.begin call, and will take over the volume of every music in it.",
+    "member": [
+        { "name": "volume", "info": "The volume of the music player. Default: 1.0.",                        "kind": "number"  },
+        { "name": "fade",   "info": "The default cross-fade time, in second.",                              "kind": "number"  },
+        { "name": "count",  "info": "The count of music track in the queue.",                               "kind": "number"  }
+    ],
+    "test": "music/player.lua"
+}
+*/
+pub struct MusicPlayer(Arc<Mutex<PlayerState>>);
+
+impl MusicPlayer {
+    /* entry
+    {
+        "version": "1.0.0",
+        "name": "quiver.music.new_player",
+        "info": "Create a new music player.",
+        "member": [
+            { "name": "fade", "info": "OPTIONAL: The default cross-fade time, in second. Default: 1.0.", "kind": "number?" }
+        ],
+        "result": [
+            { "name": "music_player", "info": "The music player.", "kind": "music_player" }
+        ]
+    }
+    */
+    fn new(lua: &Lua, fade: Option<f32>) -> mlua::Result<Self> {
+        let state = Arc::new(Mutex::new(PlayerState {
+            current: None,
+            previous: Vec::new(),
+            queue: VecDeque::new(),
+            volume: 1.0,
+            fade: fade.unwrap_or(1.0).max(0.0),
+            pause: false,
+        }));
+
+        if let Some(mut list) = lua.app_data_mut::<PlayerList>() {
+            list.0.push(Arc::downgrade(&state));
+        }
+
+        Ok(Self(state))
+    }
+
+    fn get_state(&self) -> std::sync::MutexGuard<'_, PlayerState> {
+        self.0.lock().unwrap()
+    }
+
+    // update every music player. called at the end of every frame.
+    pub fn update(lua: &Lua) {
+        let delta = crate::base::automation::Replay::get_frame_time(lua)
+            .unwrap_or_else(|| unsafe { ffi::GetFrameTime() });
+
+        if let Some(mut list) = lua.app_data_mut::<PlayerList>() {
+            list.0.retain(|x| x.strong_count() > 0);
+
+            for player in list.0.iter().filter_map(|x| x.upgrade()) {
+                player.lock().unwrap().update(delta);
+            }
+        }
+    }
+}
+
+impl mlua::UserData for MusicPlayer {
+    fn add_fields<F: mlua::UserDataFields<Self>>(field: &mut F) {
+        field.add_field_method_get("volume", |_, this| Ok(this.get_state().volume));
+        field.add_field_method_set("volume", |_, this, value: f32| {
+            this.get_state().volume = value.clamp(0.0, 1.0);
+            Ok(())
+        });
+        field.add_field_method_get("fade", |_, this| Ok(this.get_state().fade));
+        field.add_field_method_set("fade", |_, this, value: f32| {
+            this.get_state().fade = value.max(0.0);
+            Ok(())
+        });
+        field.add_field_method_get("count", |_, this| Ok(this.get_state().queue.len()));
+    }
+
+    fn add_methods<M: mlua::UserDataMethods<Self>>(method: &mut M) {
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "music_player:play",
+            "info": "Play a music track right away, cross-fading from the current music track. The queue is kept.",
+            "member": [
+                { "name": "music",  "info": "The music.",                                                                                                                                                                   "kind": "music"  },
+                { "name": "option", "info": "OPTIONAL: The track option: { fade?, loop_start?, loop_end?, loop? }. The loop end will be the end of the music by default. Will loop by default only if a loop point is given.", "kind": "table?" }
+            ]
+        }
+        */
+        method.add_method(
+            "play",
+            |lua, this, (music, option): (AnyUserData, Option<LuaValue>)| {
+                let mut state = this.get_state();
+                let track = PlayerTrack::new(lua, "play", music, option, state.fade)?;
+
+                state.set_track(track);
+
+                Ok(())
+            },
+        );
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "music_player:queue",
+            "info": "Queue a music track, to be cross-faded into once the current music track is about to end. A looping music track will only end on a music_player:next call. Will play right away if there is no current music track.",
+            "member": [
+                { "name": "music",  "info": "The music.",                                                   "kind": "music"  },
+                { "name": "option", "info": "OPTIONAL: The track option, as with music_player:play.", "kind": "table?" }
+            ]
+        }
+        */
+        method.add_method(
+            "queue",
+            |lua, this, (music, option): (AnyUserData, Option<LuaValue>)| {
+                let mut state = this.get_state();
+                let track = PlayerTrack::new(lua, "queue", music, option, state.fade)?;
+
+                if state.current.is_none() {
+                    state.set_track(track);
+                } else {
+                    state.queue.push_back(track);
+                }
+
+                Ok(())
+            },
+        );
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "music_player:next",
+            "info": "Cross-fade to the next music track in the queue, or fade out if the queue is empty.",
+            "member": [
+                { "name": "fade", "info": "OPTIONAL: The cross-fade time, in second. Default: the fade time of the next music track.", "kind": "number?" }
+            ]
+        }
+        */
+        method.add_method("next", |_, this, fade: Option<f32>| {
+            let mut state = this.get_state();
+
+            match state.queue.pop_front() {
+                Some(mut track) => {
+                    if let Some(fade) = fade {
+                        track.fade = fade.max(0.0);
+                    }
+
+                    state.set_track(track);
+                }
+                None => {
+                    let fade = fade.unwrap_or(state.fade);
+                    state.set_fade_out(fade);
+                }
+            }
+
+            Ok(())
+        });
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "music_player:stop",
+            "info": "Fade out the current music track. The queue is kept.",
+            "member": [
+                { "name": "fade", "info": "OPTIONAL: The fade-out time, in second. Default: the default cross-fade time.", "kind": "number?" }
+            ]
+        }
+        */
+        method.add_method("stop", |_, this, fade: Option<f32>| {
+            let mut state = this.get_state();
+            let fade = fade.unwrap_or(state.fade);
+
+            state.set_fade_out(fade);
+
+            Ok(())
+        });
+
+        /* entry
+        { "version": "1.0.0", "name": "music_player:clear_queue", "info": "Clear the queue." }
+        */
+        method.add_method("clear_queue", |_, this, ()| {
+            this.get_state().queue.clear();
+            Ok(())
+        });
+
+        /* entry
+        { "version": "1.0.0", "name": "music_player:pause", "info": "Pause the music player." }
+        */
+        method.add_method("pause", |_, this, ()| {
+            this.get_state().set_pause(true);
+            Ok(())
+        });
+
+        /* entry
+        { "version": "1.0.0", "name": "music_player:resume", "info": "Resume the music player." }
+        */
+        method.add_method("resume", |_, this, ()| {
+            this.get_state().set_pause(false);
+            Ok(())
+        });
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "music_player:get_pause",
+            "info": "Check if the music player is paused.",
+            "result": [
+                { "name": "state", "info": "State of the music player.", "kind": "boolean" }
+            ]
+        }
+        */
+        method.add_method("get_pause", |_, this, ()| Ok(this.get_state().pause));
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "music_player:get_current",
+            "info": "Get the current music track.",
+            "result": [
+                { "name": "music", "info": "The current music, if any.", "kind": "music?" }
+            ]
+        }
+        */
+        method.add_method("get_current", |_, this, ()| {
+            Ok(this.get_state().current.as_ref().map(|x| x.music.clone()))
+        });
     }
 }
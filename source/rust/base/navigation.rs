@@ -0,0 +1,1392 @@
+/*
+* Copyright (c) 2025 sockentrocken
+*
+* Redistribution and use in source and binary forms, with or without
+* modification, are permitted provided that the following conditions are met:
+*
+* 1. Redistributions of source code must retain the above copyright notice,
+* this list of conditions and the following disclaimer.
+*
+* 2. Redistributions in binary form must reproduce the above copyright notice,
+* this list of conditions and the following disclaimer in the documentation
+* and/or other materials provided with the distribution.
+*
+* Subject to the terms and conditions of this license, each copyright holder
+* and contributor hereby grants to those receiving rights under this license
+* a perpetual, worldwide, non-exclusive, no-charge, royalty-free, irrevocable
+* (except for failure to satisfy the conditions of this license) patent license
+* to make, have made, use, offer to sell, sell, import, and otherwise transfer
+* this software, where such license applies only to those patent claims, already
+* acquired or hereafter acquired, licensable by such copyright holder or
+* contributor that are necessarily infringed by:
+*
+* (a) their Contribution(s) (the licensed copyrights of copyright holders and
+* non-copyrightable additions of contributors, in source or binary form) alone;
+* or
+*
+* (b) combination of their Contribution(s) with the work of authorship to which
+* such Contribution(s) was added by such copyright holder or contributor, if,
+* at the time the Contribution is added, such addition causes such combination
+* to be necessarily infringed. The patent license shall not apply to any other
+* combinations which include the Contribution.
+*
+* Except as expressly stated above, no rights or licenses from any copyright
+* holder or contributor is granted under this license, whether expressly, by
+* implication, estoppel or otherwise.
+*
+* DISCLAIMER
+*
+* THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+* AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+* IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+* DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDERS OR CONTRIBUTORS BE LIABLE
+* FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+* DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+* SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+* CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+* OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+* OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+*/
+
+use crate::base::model::Model;
+use crate::script::*;
+use crate::status::*;
+
+//================================================================
+
+use mlua::prelude::*;
+use raylib::prelude::*;
+use std::sync::Arc;
+
+//================================================================
+
+/* class
+{ "version": "1.0.0", "name": "quiver.navigation", "info": "The navigation API." }
+*/
+#[rustfmt::skip]
+pub fn set_global(lua: &Lua, table: &mlua::Table, _: &StatusInfo, _: Option<&ScriptInfo>) -> mlua::Result<()> {
+    let navigation = lua.create_table()?;
+
+    navigation.set("new_grid",       lua.create_function(self::NavigationGrid::new)?)?;
+    navigation.set("new_mesh",       lua.create_function(self::NavigationMesh::new)?)?;
+    navigation.set("new_mesh_model", lua.create_function(self::NavigationMesh::new_model)?)?;
+    navigation.set("new_crowd",      lua.create_function(self::NavigationCrowd::new)?)?;
+
+    table.set("navigation", navigation)?;
+
+    Ok(())
+}
+
+//================================================================
+
+/* class
+{
+    "version": "1.0.0",
+    "name": "navigation_grid",
+    "info": "A 2D grid for path-finding. Every cell has a cost to walk into it, with a cost of 0.0 (or less) being a wall. Cell coordinate are in the [0, shape - 1] range.",
+    "member": [
+        { "name": "shape_x",  "info": "The shape of the grid. (X).",                                   "kind": "number"        },
+        { "name": "shape_y",  "info": "The shape of the grid. (Y).",                                   "kind": "number"        },
+        { "name": "diagonal", "info": "The rule for diagonal movement. Default: DIAGONAL_RULE.NO_OBSTACLE.", "kind": "diagonal_rule" }
+    ],
+    "test": "navigation/grid.lua"
+}
+*/
+struct NavigationGrid(GridData);
+
+impl NavigationGrid {
+    /* entry
+    {
+        "version": "1.0.0",
+        "name": "quiver.navigation.new_grid",
+        "info": "Create a new navigation grid.",
+        "member": [
+            { "name": "shape_x", "info": "The shape of the grid. (X).",                           "kind": "number"  },
+            { "name": "shape_y", "info": "The shape of the grid. (Y).",                           "kind": "number"  },
+            { "name": "cost",    "info": "OPTIONAL: The cost of every cell. Default: 1.0.", "kind": "number?" }
+        ],
+        "result": [
+            { "name": "navigation_grid", "info": "The navigation grid.", "kind": "navigation_grid" }
+        ]
+    }
+    */
+    fn new(_: &Lua, (shape_x, shape_y, cost): (i32, i32, Option<f32>)) -> mlua::Result<Self> {
+        if shape_x <= 0 || shape_y <= 0 {
+            return Err(mlua::Error::runtime(
+                "quiver.navigation.new_grid(): Shape must be greater than zero.",
+            ));
+        }
+
+        let Some(count) = (shape_x as usize).checked_mul(shape_y as usize) else {
+            return Err(mlua::Error::runtime(
+                "quiver.navigation.new_grid(): Shape is too large.",
+            ));
+        };
+
+        Ok(Self(GridData {
+            shape_x,
+            shape_y,
+            cost: vec![cost.unwrap_or(1.0); count],
+            diagonal: GridData::DIAGONAL_NO_OBSTACLE,
+        }))
+    }
+
+    fn get_cell(&self, name: &str, x: i32, y: i32) -> mlua::Result<usize> {
+        self.0.get_index(x, y).ok_or(mlua::Error::runtime(format!(
+            "navigation_grid:{name}(): Cell {{ {x}, {y} }} is out of bounds."
+        )))
+    }
+}
+
+impl mlua::UserData for NavigationGrid {
+    fn add_fields<F: mlua::UserDataFields<Self>>(field: &mut F) {
+        field.add_field_method_get("shape_x", |_, this| Ok(this.0.shape_x));
+        field.add_field_method_get("shape_y", |_, this| Ok(this.0.shape_y));
+        field.add_field_method_get("diagonal", |_, this| Ok(this.0.diagonal));
+        field.add_field_method_set("diagonal", |_, this, value: i32| {
+            if !(GridData::DIAGONAL_NEVER..=GridData::DIAGONAL_ALWAYS).contains(&value) {
+                return Err(mlua::Error::runtime(
+                    "navigation_grid.diagonal: Invalid diagonal rule.",
+                ));
+            }
+
+            this.0.diagonal = value;
+            Ok(())
+        });
+    }
+
+    fn add_methods<M: mlua::UserDataMethods<Self>>(method: &mut M) {
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "navigation_grid:get_cost",
+            "info": "Get the cost of a cell.",
+            "member": [
+                { "name": "x", "info": "The cell coordinate. (X).", "kind": "number" },
+                { "name": "y", "info": "The cell coordinate. (Y).", "kind": "number" }
+            ],
+            "result": [
+                { "name": "cost", "info": "The cost.", "kind": "number" }
+            ]
+        }
+        */
+        method.add_method("get_cost", |_: &Lua, this, (x, y): (i32, i32)| {
+            Ok(this.0.cost[this.get_cell("get_cost", x, y)?])
+        });
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "navigation_grid:set_cost",
+            "info": "Set the cost of a cell. Use 0.0 for a wall.",
+            "member": [
+                { "name": "x",    "info": "The cell coordinate. (X).", "kind": "number" },
+                { "name": "y",    "info": "The cell coordinate. (Y).", "kind": "number" },
+                { "name": "cost", "info": "The cost.",                 "kind": "number" }
+            ]
+        }
+        */
+        method.add_method_mut(
+            "set_cost",
+            |_: &Lua, this, (x, y, cost): (i32, i32, f32)| {
+                let index = this.get_cell("set_cost", x, y)?;
+                this.0.cost[index] = cost;
+                Ok(())
+            },
+        );
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "navigation_grid:set_cost_data",
+            "info": "Set the cost of every cell from a data buffer (i.e. from noise:get_grid), in row-major order.",
+            "member": [
+                { "name": "data", "info": "The data buffer, with a number for each cell.", "kind": "data" }
+            ]
+        }
+        */
+        method.add_method_mut(
+            "set_cost_data",
+            |_: &Lua, this, data: mlua::UserDataRef<crate::base::data::Data<f32>>| {
+                if data.0.len() != this.0.cost.len() {
+                    return Err(mlua::Error::runtime(
+                        "navigation_grid:set_cost_data(): Data length must match the cell count of the grid.",
+                    ));
+                }
+
+                this.0.cost.copy_from_slice(&data.0);
+                Ok(())
+            },
+        );
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "navigation_grid:find_path",
+            "info": "Find the cheapest path between two cell, using A*. Jump point search is much faster on a large, open grid, but will only work with DIAGONAL_RULE.NO_OBSTACLE, and will ignore the cost of every cell (other than a wall).",
+            "member": [
+                { "name": "from", "info": "The cell to start from.",                                 "kind": "vector_2" },
+                { "name": "to",   "info": "The cell to go to.",                                      "kind": "vector_2" },
+                { "name": "jump", "info": "OPTIONAL: Use jump point search. Default: false.", "kind": "boolean?" }
+            ],
+            "result": [
+                { "name": "path", "info": "The path, as a table of vector_2 cell, including the start and the goal. Nil if there is no path.", "kind": "table?" }
+            ]
+        }
+        */
+        method.add_method(
+            "find_path",
+            |lua: &Lua, this, (from, to, jump): (LuaValue, LuaValue, Option<bool>)| {
+                let from: Vector2 = lua.from_value(from)?;
+                let to: Vector2 = lua.from_value(to)?;
+                let from = (from.x.floor() as i32, from.y.floor() as i32);
+                let to = (to.x.floor() as i32, to.y.floor() as i32);
+
+                let path = if jump.unwrap_or_default() {
+                    if this.0.diagonal != GridData::DIAGONAL_NO_OBSTACLE {
+                        return Err(mlua::Error::runtime(
+                            "navigation_grid:find_path(): Jump point search requires DIAGONAL_RULE.NO_OBSTACLE.",
+                        ));
+                    }
+
+                    this.0.find_path_jump(from, to)
+                } else {
+                    this.0.find_path(from, to)
+                };
+
+                match path {
+                    Some(path) => {
+                        let path: Vec<Vector2> = path
+                            .iter()
+                            .map(|(x, y)| Vector2::new(*x as f32, *y as f32))
+                            .collect();
+
+                        lua.to_value(&path)
+                    }
+                    None => Ok(LuaValue::Nil),
+                }
+            },
+        );
+    }
+}
+
+//================================================================
+
+/* class
+{
+    "version": "1.0.0",
+    "name": "navigation_mesh",
+    "info": "A navigation mesh, baked from the walkable (front-facing, not too steep) triangle of a triangle mesh. Vertex closer than 0.001 unit are welded together, and two triangle are connected only if they share an edge.",
+    "member": [
+        { "name": "point_count",    "info": "The vertex count.",   "kind": "number" },
+        { "name": "triangle_count", "info": "The triangle count.", "kind": "number" }
+    ],
+    "test": "navigation/mesh.lua"
+}
+*/
+#[derive(Clone)]
+struct NavigationMesh(Arc<MeshData>);
+
+impl NavigationMesh {
+    // the default maximum slope of a walkable triangle, in degree.
+    const SLOPE: f32 = 45.0;
+
+    /* entry
+    {
+        "version": "1.0.0",
+        "name": "quiver.navigation.new_mesh",
+        "info": "Bake a navigation mesh from a triangle mesh (i.e. from rapier:get_collider_shape_tri_mesh, or model:mesh_vertex and model:mesh_index).",
+        "member": [
+            { "name": "point_table", "info": "The point array table.",                                                    "kind": "table"   },
+            { "name": "index_table", "info": "The index array table, with three index for each triangle, starting at 0.", "kind": "table"   },
+            { "name": "slope",       "info": "OPTIONAL: The maximum slope of a walkable triangle, in degree. Default: 45.0.", "kind": "number?" }
+        ],
+        "result": [
+            { "name": "navigation_mesh", "info": "The navigation mesh.", "kind": "navigation_mesh" }
+        ]
+    }
+    */
+    fn new(
+        lua: &Lua,
+        (point, index, slope): (LuaValue, LuaValue, Option<f32>),
+    ) -> mlua::Result<Self> {
+        let point: Vec<Vector3> = lua.from_value(point)?;
+        let index: Vec<usize> = lua.from_value(index)?;
+
+        if index.len() % 3 != 0 {
+            return Err(mlua::Error::runtime(
+                "quiver.navigation.new_mesh(): Index count is not a multiple of 3.",
+            ));
+        }
+
+        if index.iter().any(|x| *x >= point.len()) {
+            return Err(mlua::Error::runtime(
+                "quiver.navigation.new_mesh(): Index is out of bounds.",
+            ));
+        }
+
+        Ok(Self(Arc::new(MeshData::new(
+            &point,
+            &index,
+            slope.unwrap_or(Self::SLOPE),
+        ))))
+    }
+
+    /* entry
+    {
+        "version": "1.0.0",
+        "name": "quiver.navigation.new_mesh_model",
+        "info": "Bake a navigation mesh from every mesh in a model, in model space.",
+        "member": [
+            { "name": "model", "info": "The model.",                                                                  "kind": "model"   },
+            { "name": "slope", "info": "OPTIONAL: The maximum slope of a walkable triangle, in degree. Default: 45.0.", "kind": "number?" }
+        ],
+        "result": [
+            { "name": "navigation_mesh", "info": "The navigation mesh.", "kind": "navigation_mesh" }
+        ]
+    }
+    */
+    fn new_model(
+        _: &Lua,
+        (model, slope): (mlua::UserDataRef<Model>, Option<f32>),
+    ) -> mlua::Result<Self> {
+        let mut point: Vec<Vector3> = Vec::new();
+        let mut index: Vec<usize> = Vec::new();
+
+        for mesh in model.0.meshes() {
+            let base = point.len();
+            let vertex = mesh.vertices();
+
+            point.extend_from_slice(vertex);
+
+            // a mesh without any index data is a plain triangle list.
+            if mesh.as_ref().indices.is_null() {
+                index.extend(base..base + vertex.len());
+            } else {
+                unsafe {
+                    let work = std::slice::from_raw_parts(
+                        mesh.as_ref().indices as *const u16,
+                        (mesh.as_ref().triangleCount * 3) as usize,
+                    );
+
+                    index.extend(work.iter().map(|x| base + *x as usize));
+                }
+            }
+        }
+
+        Ok(Self(Arc::new(MeshData::new(
+            &point,
+            &index,
+            slope.unwrap_or(Self::SLOPE),
+        ))))
+    }
+}
+
+impl mlua::UserData for NavigationMesh {
+    fn add_fields<F: mlua::UserDataFields<Self>>(field: &mut F) {
+        field.add_field_method_get("point_count", |_, this| Ok(this.0.point.len()));
+        field.add_field_method_get("triangle_count", |_, this| Ok(this.0.triangle.len()));
+    }
+
+    fn add_methods<M: mlua::UserDataMethods<Self>>(method: &mut M) {
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "navigation_mesh:find_path",
+            "info": "Find the shortest path between two point. The path is string-pulled, so it will only have a corner where it needs to turn around an edge of the navigation mesh. Both point will be moved to the closest point on the navigation mesh.",
+            "member": [
+                { "name": "from", "info": "The point to start from.", "kind": "vector_3" },
+                { "name": "to",   "info": "The point to go to.",      "kind": "vector_3" }
+            ],
+            "result": [
+                { "name": "path", "info": "The path, as a table of vector_3 point, including the start and the goal. Nil if there is no path.", "kind": "table?" }
+            ]
+        }
+        */
+        method.add_method(
+            "find_path",
+            |lua: &Lua, this, (from, to): (LuaValue, LuaValue)| {
+                let from: Vector3 = lua.from_value(from)?;
+                let to: Vector3 = lua.from_value(to)?;
+
+                match this.0.find_path(from, to) {
+                    Some(path) => lua.to_value(&path),
+                    None => Ok(LuaValue::Nil),
+                }
+            },
+        );
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "navigation_mesh:get_closest",
+            "info": "Get the closest point on the navigation mesh to a point.",
+            "member": [
+                { "name": "point", "info": "The point.", "kind": "vector_3" }
+            ],
+            "result": [
+                { "name": "point", "info": "The closest point. Nil if the navigation mesh is empty.", "kind": "vector_3?" }
+            ]
+        }
+        */
+        method.add_method("get_closest", |lua: &Lua, this, point: LuaValue| {
+            let point: Vector3 = lua.from_value(point)?;
+
+            match this.0.get_closest(point) {
+                Some((_, point)) => lua.to_value(&point),
+                None => Ok(LuaValue::Nil),
+            }
+        });
+    }
+}
+
+//================================================================
+
+/* class
+{
+    "version": "1.0.0",
+    "name": "navigation_crowd",
+    "info": "A crowd of agent, moving on a navigation mesh. Every agent will follow its own path with a simple steering behavior (seek, arrive, and separation from any other agent), and will be kept on the navigation mesh.",
+    "member": [
+        { "name": "count", "info": "The agent count.", "kind": "number" }
+    ],
+    "test": "navigation/mesh.lua"
+}
+*/
+struct NavigationCrowd {
+    mesh: Arc<MeshData>,
+    agent: Vec<Option<Agent>>,
+}
+
+struct Agent {
+    point: Vector3,
+    velocity: Vector3,
+    radius: f32,
+    speed: f32,
+    path: Vec<Vector3>,
+    index: usize,
+}
+
+impl NavigationCrowd {
+    // how quickly an agent will turn its velocity toward the desired velocity.
+    const STEER: f32 = 8.0;
+
+    /* entry
+    {
+        "version": "1.0.0",
+        "name": "quiver.navigation.new_crowd",
+        "info": "Create a new crowd.",
+        "member": [
+            { "name": "navigation_mesh", "info": "The navigation mesh to move on.", "kind": "navigation_mesh" }
+        ],
+        "result": [
+            { "name": "navigation_crowd", "info": "The crowd.", "kind": "navigation_crowd" }
+        ]
+    }
+    */
+    fn new(_: &Lua, mesh: mlua::UserDataRef<NavigationMesh>) -> mlua::Result<Self> {
+        Ok(Self {
+            mesh: mesh.0.clone(),
+            agent: Vec::new(),
+        })
+    }
+
+    fn get_agent(&mut self, name: &str, index: usize) -> mlua::Result<&mut Agent> {
+        index
+            .checked_sub(1)
+            .and_then(|index| self.agent.get_mut(index))
+            .and_then(|agent| agent.as_mut())
+            .ok_or(mlua::Error::runtime(format!(
+                "navigation_crowd:{name}(): Invalid agent index."
+            )))
+    }
+
+    // get the desired velocity of every agent.
+    fn get_desire(&self, agent: &Agent) -> Vector3 {
+        let mut desire = Vector3::zero();
+
+        if let Some(target) = agent.path.get(agent.index) {
+            let mut delta = *target - agent.point;
+            delta.y = 0.0;
+            let length = delta.length();
+
+            if length > f32::EPSILON {
+                let speed = if agent.index == agent.path.len() - 1 {
+                    // slow down, when close to the goal.
+                    agent.speed * (length / (agent.radius * 2.0)).min(1.0)
+                } else {
+                    agent.speed
+                };
+
+                desire = delta * (speed / length);
+            }
+        }
+
+        // push away from any other agent that is too close.
+        for other in self.agent.iter().flatten() {
+            let mut delta = agent.point - other.point;
+            delta.y = 0.0;
+            let length = delta.length();
+            let limit = agent.radius + other.radius;
+
+            if length > f32::EPSILON && length < limit {
+                desire += delta * (agent.speed * (limit - length) / (limit * length));
+            }
+        }
+
+        desire
+    }
+}
+
+impl mlua::UserData for NavigationCrowd {
+    fn add_fields<F: mlua::UserDataFields<Self>>(field: &mut F) {
+        field.add_field_method_get("count", |_, this| Ok(this.agent.iter().flatten().count()));
+    }
+
+    fn add_methods<M: mlua::UserDataMethods<Self>>(method: &mut M) {
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "navigation_crowd:add_agent",
+            "info": "Add an agent to the crowd.",
+            "member": [
+                { "name": "point",  "info": "The point of the agent. Will be moved to the closest point on the navigation mesh.", "kind": "vector_3" },
+                { "name": "radius", "info": "OPTIONAL: The radius of the agent. Default: 0.5.",                                 "kind": "number?"  },
+                { "name": "speed",  "info": "OPTIONAL: The maximum speed of the agent, in unit per second. Default: 4.0.",       "kind": "number?"  }
+            ],
+            "result": [
+                { "name": "index", "info": "The agent index.", "kind": "number" }
+            ]
+        }
+        */
+        method.add_method_mut(
+            "add_agent",
+            |lua: &Lua, this, (point, radius, speed): (LuaValue, Option<f32>, Option<f32>)| {
+                let point: Vector3 = lua.from_value(point)?;
+                let point = this.mesh.get_closest(point).map_or(point, |x| x.1);
+                let agent = Agent {
+                    point,
+                    velocity: Vector3::zero(),
+                    radius: radius.unwrap_or(0.5),
+                    speed: speed.unwrap_or(4.0),
+                    path: Vec::new(),
+                    index: 0,
+                };
+
+                // re-use the slot of a removed agent, if any.
+                if let Some(index) = this.agent.iter().position(|x| x.is_none()) {
+                    this.agent[index] = Some(agent);
+                    Ok(index + 1)
+                } else {
+                    this.agent.push(Some(agent));
+                    Ok(this.agent.len())
+                }
+            },
+        );
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "navigation_crowd:remove_agent",
+            "info": "Remove an agent from the crowd.",
+            "member": [
+                { "name": "index", "info": "The agent index.", "kind": "number" }
+            ]
+        }
+        */
+        method.add_method_mut("remove_agent", |_: &Lua, this, index: usize| {
+            this.get_agent("remove_agent", index)?;
+            this.agent[index - 1] = None;
+            Ok(())
+        });
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "navigation_crowd:set_target",
+            "info": "Set the target of an agent. The agent will find a path to it, and follow it on every navigation_crowd:update call.",
+            "member": [
+                { "name": "index",  "info": "The agent index.",                            "kind": "number"    },
+                { "name": "target", "info": "The target. Use nil to stop the agent.", "kind": "vector_3?" }
+            ],
+            "result": [
+                { "name": "path", "info": "True if a path to the target was found.", "kind": "boolean" }
+            ]
+        }
+        */
+        method.add_method_mut(
+            "set_target",
+            |lua: &Lua, this, (index, target): (usize, LuaValue)| {
+                let target: Option<Vector3> = lua.from_value(target)?;
+                let mesh = this.mesh.clone();
+                let agent = this.get_agent("set_target", index)?;
+
+                agent.path = target
+                    .and_then(|target| mesh.find_path(agent.point, target))
+                    .unwrap_or_default();
+                agent.index = 0;
+
+                Ok(!agent.path.is_empty())
+            },
+        );
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "navigation_crowd:get_agent",
+            "info": "Get the state of an agent.",
+            "member": [
+                { "name": "index", "info": "The agent index.", "kind": "number" }
+            ],
+            "result": [
+                { "name": "point",    "info": "The point of the agent.",                  "kind": "vector_3" },
+                { "name": "velocity", "info": "The velocity of the agent.",               "kind": "vector_3" },
+                { "name": "active",   "info": "True if the agent is still following a path.", "kind": "boolean"  }
+            ]
+        }
+        */
+        method.add_method_mut("get_agent", |lua: &Lua, this, index: usize| {
+            let agent = this.get_agent("get_agent", index)?;
+
+            Ok((
+                lua.to_value(&agent.point)?,
+                lua.to_value(&agent.velocity)?,
+                agent.index < agent.path.len(),
+            ))
+        });
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "navigation_crowd:update",
+            "info": "Move every agent in the crowd.",
+            "member": [
+                { "name": "step", "info": "The time step, in second (i.e. from quiver.general.get_frame_time).", "kind": "number" }
+            ]
+        }
+        */
+        method.add_method_mut("update", |_: &Lua, this, step: f32| {
+            // get every desired velocity first, so that the order of each agent will not matter.
+            let desire: Vec<Option<Vector3>> = this
+                .agent
+                .iter()
+                .map(|agent| agent.as_ref().map(|agent| this.get_desire(agent)))
+                .collect();
+
+            let steer = (step * Self::STEER).min(1.0);
+
+            for (agent, desire) in this.agent.iter_mut().zip(desire) {
+                let (Some(agent), Some(desire)) = (agent, desire) else {
+                    continue;
+                };
+
+                agent.velocity += (desire - agent.velocity) * steer;
+
+                let point = agent.point + agent.velocity * step;
+                agent.point = this.mesh.get_closest(point).map_or(point, |x| x.1);
+
+                // move on to the next corner of the path, once close enough.
+                while let Some(target) = agent.path.get(agent.index) {
+                    let mut delta = *target - agent.point;
+                    delta.y = 0.0;
+
+                    let limit = if agent.index == agent.path.len() - 1 {
+                        0.05
+                    } else {
+                        agent.radius * 0.5
+                    };
+
+                    if delta.length() > limit {
+                        break;
+                    }
+
+                    agent.index += 1;
+                }
+
+                if agent.index >= agent.path.len() {
+                    agent.path.clear();
+                    agent.index = 0;
+                }
+            }
+
+            Ok(())
+        });
+    }
+}
+//================================================================
+
+// an open-list entry for A*, ordered by the lowest estimated cost first.
+#[derive(PartialEq)]
+struct Node {
+    cost: f32,
+    index: usize,
+}
+
+impl Eq for Node {}
+
+impl Ord for Node {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        other.cost.total_cmp(&self.cost)
+    }
+}
+
+impl PartialOrd for Node {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+//================================================================
+
+#[derive(Clone)]
+struct GridData {
+    shape_x: i32,
+    shape_y: i32,
+    cost: Vec<f32>,
+    diagonal: i32,
+}
+
+impl GridData {
+    const DIAGONAL_NEVER: i32 = 0;
+    const DIAGONAL_NO_OBSTACLE: i32 = 1;
+    const DIAGONAL_ONE_OBSTACLE: i32 = 2;
+    const DIAGONAL_ALWAYS: i32 = 3;
+
+    fn get_index(&self, x: i32, y: i32) -> Option<usize> {
+        // compute in usize, as the cell count of a large grid will not fit in an i32.
+        if x >= 0 && y >= 0 && x < self.shape_x && y < self.shape_y {
+            Some(y as usize * self.shape_x as usize + x as usize)
+        } else {
+            None
+        }
+    }
+
+    fn get_point(&self, index: usize) -> (i32, i32) {
+        let shape_x = self.shape_x as usize;
+
+        ((index % shape_x) as i32, (index / shape_x) as i32)
+    }
+
+    fn get_walk(&self, x: i32, y: i32) -> bool {
+        self.get_index(x, y)
+            .is_some_and(|index| self.cost[index] > 0.0)
+    }
+
+    // check if a move from a cell to a neighbor cell is allowed by the diagonal rule.
+    fn get_move(&self, x: i32, y: i32, dx: i32, dy: i32) -> bool {
+        if !self.get_walk(x + dx, y + dy) {
+            return false;
+        }
+
+        if dx == 0 || dy == 0 {
+            return true;
+        }
+
+        let side_a = self.get_walk(x + dx, y);
+        let side_b = self.get_walk(x, y + dy);
+
+        match self.diagonal {
+            Self::DIAGONAL_NO_OBSTACLE => side_a && side_b,
+            Self::DIAGONAL_ONE_OBSTACLE => side_a || side_b,
+            Self::DIAGONAL_ALWAYS => true,
+            _ => false,
+        }
+    }
+
+    fn get_heuristic(&self, from: (i32, i32), to: (i32, i32)) -> f32 {
+        let dx = (from.0 - to.0).abs() as f32;
+        let dy = (from.1 - to.1).abs() as f32;
+
+        if self.diagonal == Self::DIAGONAL_NEVER {
+            dx + dy
+        } else {
+            // octile distance.
+            dx.max(dy) + (std::f32::consts::SQRT_2 - 1.0) * dx.min(dy)
+        }
+    }
+
+    // walk back from the goal to the start, through every parent.
+    fn get_trail(&self, parent: &[usize], goal: usize) -> Vec<(i32, i32)> {
+        let mut path = vec![self.get_point(goal)];
+        let mut index = goal;
+
+        while parent[index] != usize::MAX {
+            index = parent[index];
+            path.push(self.get_point(index));
+        }
+
+        path.reverse();
+        path
+    }
+
+    fn find_path(&self, from: (i32, i32), to: (i32, i32)) -> Option<Vec<(i32, i32)>> {
+        let start = self.get_index(from.0, from.1)?;
+        let goal = self.get_index(to.0, to.1)?;
+
+        if !self.get_walk(from.0, from.1) || !self.get_walk(to.0, to.1) {
+            return None;
+        }
+
+        // scale the heuristic by the cheapest cell, so it will never over-estimate.
+        let scale = self
+            .cost
+            .iter()
+            .filter(|x| **x > 0.0)
+            .fold(f32::MAX, |a, b| a.min(*b));
+
+        let mut cost = vec![f32::MAX; self.cost.len()];
+        let mut parent = vec![usize::MAX; self.cost.len()];
+        let mut close = vec![false; self.cost.len()];
+        let mut open = std::collections::BinaryHeap::new();
+
+        cost[start] = 0.0;
+        open.push(Node {
+            cost: self.get_heuristic(from, to) * scale,
+            index: start,
+        });
+
+        while let Some(Node { index, .. }) = open.pop() {
+            if index == goal {
+                return Some(self.get_trail(&parent, goal));
+            }
+
+            if close[index] {
+                continue;
+            }
+
+            close[index] = true;
+
+            let (x, y) = self.get_point(index);
+
+            for (dx, dy) in NEIGHBOR {
+                if !self.get_move(x, y, dx, dy) {
+                    continue;
+                }
+
+                let Some(next) = self.get_index(x + dx, y + dy) else {
+                    continue;
+                };
+                let step = if dx != 0 && dy != 0 {
+                    std::f32::consts::SQRT_2
+                } else {
+                    1.0
+                };
+                let value = cost[index] + self.cost[next] * step;
+
+                if value < cost[next] {
+                    cost[next] = value;
+                    parent[next] = index;
+                    open.push(Node {
+                        cost: value + self.get_heuristic((x + dx, y + dy), to) * scale,
+                        index: next,
+                    });
+                }
+            }
+        }
+
+        None
+    }
+
+    //================================================================
+
+    // jump point search, for an uniform-cost grid where diagonal move must not cut a corner.
+    fn find_path_jump(&self, from: (i32, i32), to: (i32, i32)) -> Option<Vec<(i32, i32)>> {
+        let start = self.get_index(from.0, from.1)?;
+        let goal = self.get_index(to.0, to.1)?;
+
+        if !self.get_walk(from.0, from.1) || !self.get_walk(to.0, to.1) {
+            return None;
+        }
+
+        let mut cost = vec![f32::MAX; self.cost.len()];
+        let mut parent = vec![usize::MAX; self.cost.len()];
+        let mut close = vec![false; self.cost.len()];
+        let mut open = std::collections::BinaryHeap::new();
+
+        cost[start] = 0.0;
+        open.push(Node {
+            cost: self.get_heuristic(from, to),
+            index: start,
+        });
+
+        while let Some(Node { index, .. }) = open.pop() {
+            if index == goal {
+                // fill in every cell between each jump point.
+                let jump = self.get_trail(&parent, goal);
+                let mut path = vec![jump[0]];
+
+                for pair in jump.windows(2) {
+                    let dx = (pair[1].0 - pair[0].0).signum();
+                    let dy = (pair[1].1 - pair[0].1).signum();
+                    let mut point = pair[0];
+
+                    while point != pair[1] {
+                        point = (point.0 + dx, point.1 + dy);
+                        path.push(point);
+                    }
+                }
+
+                return Some(path);
+            }
+
+            if close[index] {
+                continue;
+            }
+
+            close[index] = true;
+
+            let (x, y) = self.get_point(index);
+
+            for (dx, dy) in self.get_jump_neighbor(x, y, parent[index]) {
+                let Some((jx, jy)) = self.get_jump(x + dx, y + dy, dx, dy, to) else {
+                    continue;
+                };
+
+                let Some(next) = self.get_index(jx, jy) else {
+                    continue;
+                };
+                let value = cost[index] + self.get_heuristic((x, y), (jx, jy));
+
+                if value < cost[next] {
+                    cost[next] = value;
+                    parent[next] = index;
+                    open.push(Node {
+                        cost: value + self.get_heuristic((jx, jy), to),
+                        index: next,
+                    });
+                }
+            }
+        }
+
+        None
+    }
+
+    // get every direction to search from a jump point, pruned by the direction of travel.
+    fn get_jump_neighbor(&self, x: i32, y: i32, parent: usize) -> Vec<(i32, i32)> {
+        let mut list = Vec::with_capacity(8);
+
+        if parent == usize::MAX {
+            for (dx, dy) in NEIGHBOR {
+                if self.get_move(x, y, dx, dy) {
+                    list.push((dx, dy));
+                }
+            }
+
+            return list;
+        }
+
+        let (px, py) = self.get_point(parent);
+        let dx = (x - px).signum();
+        let dy = (y - py).signum();
+
+        if dx != 0 && dy != 0 {
+            if self.get_walk(x, y + dy) {
+                list.push((0, dy));
+            }
+            if self.get_walk(x + dx, y) {
+                list.push((dx, 0));
+            }
+            if self.get_walk(x, y + dy) && self.get_walk(x + dx, y) && self.get_walk(x + dx, y + dy)
+            {
+                list.push((dx, dy));
+            }
+        } else if dx != 0 {
+            let next = self.get_walk(x + dx, y);
+            let side_a = self.get_walk(x, y + 1);
+            let side_b = self.get_walk(x, y - 1);
+
+            if next {
+                list.push((dx, 0));
+
+                if side_a && self.get_walk(x + dx, y + 1) {
+                    list.push((dx, 1));
+                }
+                if side_b && self.get_walk(x + dx, y - 1) {
+                    list.push((dx, -1));
+                }
+            }
+            if side_a {
+                list.push((0, 1));
+            }
+            if side_b {
+                list.push((0, -1));
+            }
+        } else {
+            let next = self.get_walk(x, y + dy);
+            let side_a = self.get_walk(x + 1, y);
+            let side_b = self.get_walk(x - 1, y);
+
+            if next {
+                list.push((0, dy));
+
+                if side_a && self.get_walk(x + 1, y + dy) {
+                    list.push((1, dy));
+                }
+                if side_b && self.get_walk(x - 1, y + dy) {
+                    list.push((-1, dy));
+                }
+            }
+            if side_a {
+                list.push((1, 0));
+            }
+            if side_b {
+                list.push((-1, 0));
+            }
+        }
+
+        list
+    }
+
+    // move in a direction until a jump point (the goal, or a cell with a forced neighbor) is found.
+    fn get_jump(&self, x: i32, y: i32, dx: i32, dy: i32, goal: (i32, i32)) -> Option<(i32, i32)> {
+        let (mut x, mut y) = (x, y);
+
+        loop {
+            if !self.get_walk(x, y) {
+                return None;
+            }
+
+            if (x, y) == goal {
+                return Some((x, y));
+            }
+
+            if dx != 0 && dy != 0 {
+                if self.get_jump(x + dx, y, dx, 0, goal).is_some()
+                    || self.get_jump(x, y + dy, 0, dy, goal).is_some()
+                {
+                    return Some((x, y));
+                }
+            } else if dx != 0 {
+                if (self.get_walk(x, y - 1) && !self.get_walk(x - dx, y - 1))
+                    || (self.get_walk(x, y + 1) && !self.get_walk(x - dx, y + 1))
+                {
+                    return Some((x, y));
+                }
+            } else if (self.get_walk(x - 1, y) && !self.get_walk(x - 1, y - dy))
+                || (self.get_walk(x + 1, y) && !self.get_walk(x + 1, y - dy))
+            {
+                return Some((x, y));
+            }
+
+            // a diagonal move must not cut a corner.
+            if !self.get_walk(x + dx, y) || !self.get_walk(x, y + dy) {
+                return None;
+            }
+
+            x += dx;
+            y += dy;
+        }
+    }
+}
+
+const NEIGHBOR: [(i32, i32); 8] = [
+    (1, 0),
+    (-1, 0),
+    (0, 1),
+    (0, -1),
+    (1, 1),
+    (1, -1),
+    (-1, 1),
+    (-1, -1),
+];
+//================================================================
+
+#[derive(Default)]
+struct MeshData {
+    point: Vec<Vector3>,
+    triangle: Vec<[usize; 3]>,
+    // the neighbor triangle across each edge (A-B, B-C, C-A) of a triangle.
+    neighbor: Vec<[usize; 3]>,
+}
+
+impl MeshData {
+    // weld every vertex closer than this distance into one.
+    const WELD: f32 = 0.001;
+
+    fn new(point: &[Vector3], index: &[usize], slope: f32) -> Self {
+        let mut mesh = Self::default();
+        let mut weld = std::collections::HashMap::new();
+        let limit = slope.to_radians().cos();
+
+        for face in index.chunks_exact(3) {
+            let [a, b, c] = [point[face[0]], point[face[1]], point[face[2]]];
+            let normal = (b - a).cross(c - a);
+            let length = normal.length();
+
+            // discard any degenerate triangle, or a triangle too steep to walk on.
+            if length <= f32::EPSILON || normal.y / length < limit {
+                continue;
+            }
+
+            let mut triangle = [0; 3];
+
+            for (i, vertex) in [a, b, c].iter().enumerate() {
+                let key = (
+                    (vertex.x / Self::WELD).round() as i64,
+                    (vertex.y / Self::WELD).round() as i64,
+                    (vertex.z / Self::WELD).round() as i64,
+                );
+
+                triangle[i] = *weld.entry(key).or_insert_with(|| {
+                    mesh.point.push(*vertex);
+                    mesh.point.len() - 1
+                });
+            }
+
+            if triangle[0] != triangle[1]
+                && triangle[1] != triangle[2]
+                && triangle[2] != triangle[0]
+            {
+                mesh.triangle.push(triangle);
+            }
+        }
+
+        // link every triangle with each triangle it shares an edge with.
+        let mut edge: std::collections::HashMap<(usize, usize), Vec<(usize, usize)>> =
+            std::collections::HashMap::new();
+
+        for (i, triangle) in mesh.triangle.iter().enumerate() {
+            for k in 0..3 {
+                let a = triangle[k];
+                let b = triangle[(k + 1) % 3];
+
+                edge.entry((a.min(b), a.max(b))).or_default().push((i, k));
+            }
+        }
+
+        mesh.neighbor = vec![[usize::MAX; 3]; mesh.triangle.len()];
+
+        for list in edge.values() {
+            if let [(a, edge_a), (b, edge_b)] = list[..] {
+                mesh.neighbor[a][edge_a] = b;
+                mesh.neighbor[b][edge_b] = a;
+            }
+        }
+
+        mesh
+    }
+
+    fn get_center(&self, triangle: usize) -> Vector3 {
+        let [a, b, c] = self.triangle[triangle];
+
+        (self.point[a] + self.point[b] + self.point[c]) / 3.0
+    }
+
+    // get the closest triangle to a point, and the closest point in it.
+    fn get_closest(&self, point: Vector3) -> Option<(usize, Vector3)> {
+        let mut result = None;
+        let mut distance = f32::MAX;
+
+        for (i, [a, b, c]) in self.triangle.iter().enumerate() {
+            let close = get_closest_triangle(point, self.point[*a], self.point[*b], self.point[*c]);
+            let length = (close - point).length();
+
+            if length < distance {
+                distance = length;
+                result = Some((i, close));
+            }
+        }
+
+        result
+    }
+
+    fn find_path(&self, from: Vector3, to: Vector3) -> Option<Vec<Vector3>> {
+        let (start, from) = self.get_closest(from)?;
+        let (goal, to) = self.get_closest(to)?;
+        let goal_center = self.get_center(goal);
+
+        let mut cost = vec![f32::MAX; self.triangle.len()];
+        let mut parent = vec![usize::MAX; self.triangle.len()];
+        let mut close = vec![false; self.triangle.len()];
+        let mut open = std::collections::BinaryHeap::new();
+
+        cost[start] = 0.0;
+        open.push(Node {
+            cost: (self.get_center(start) - goal_center).length(),
+            index: start,
+        });
+
+        while let Some(Node { index, .. }) = open.pop() {
+            if index == goal {
+                break;
+            }
+
+            if close[index] {
+                continue;
+            }
+
+            close[index] = true;
+
+            let center = self.get_center(index);
+
+            for next in self.neighbor[index] {
+                if next == usize::MAX {
+                    continue;
+                }
+
+                let next_center = self.get_center(next);
+                let value = cost[index] + (next_center - center).length();
+
+                if value < cost[next] {
+                    cost[next] = value;
+                    parent[next] = index;
+                    open.push(Node {
+                        cost: value + (next_center - goal_center).length(),
+                        index: next,
+                    });
+                }
+            }
+        }
+
+        if goal != start && parent[goal] == usize::MAX {
+            return None;
+        }
+
+        let mut corridor = vec![goal];
+
+        while parent[*corridor.last()?] != usize::MAX {
+            corridor.push(parent[*corridor.last()?]);
+        }
+
+        corridor.reverse();
+
+        // get the portal (the shared edge) between each triangle in the corridor.
+        let mut portal = vec![(from, from)];
+
+        for pair in corridor.windows(2) {
+            let k = self.neighbor[pair[0]].iter().position(|x| *x == pair[1])?;
+            let a = self.point[self.triangle[pair[0]][k]];
+            let b = self.point[self.triangle[pair[0]][(k + 1) % 3]];
+            let center_a = self.get_center(pair[0]);
+            let center_b = self.get_center(pair[1]);
+
+            if get_side(center_a, center_b, a) > get_side(center_a, center_b, b) {
+                portal.push((a, b));
+            } else {
+                portal.push((b, a));
+            }
+        }
+
+        portal.push((to, to));
+
+        Some(get_funnel(&portal))
+    }
+}
+
+// get the side of point C in relation to the line A-B, on the X/Z plane. positive is left.
+fn get_side(a: Vector3, b: Vector3, c: Vector3) -> f32 {
+    (b.x - a.x) * (c.z - a.z) - (b.z - a.z) * (c.x - a.x)
+}
+
+fn get_equal(a: Vector3, b: Vector3) -> bool {
+    (a - b).length() < MeshData::WELD
+}
+
+// simple stupid funnel algorithm (string-pulling), from a list of (left, right) portal.
+fn get_funnel(portal: &[(Vector3, Vector3)]) -> Vec<Vector3> {
+    let mut path = vec![portal[0].0];
+    let mut apex = portal[0].0;
+    let (mut left, mut right) = portal[0];
+    let (mut left_index, mut right_index) = (0, 0);
+    let mut i = 1;
+
+    while i < portal.len() {
+        let (next_left, next_right) = portal[i];
+
+        // narrow the funnel from the right side.
+        if get_side(apex, right, next_right) >= 0.0 {
+            if get_equal(apex, right) || get_side(apex, left, next_right) < 0.0 {
+                right = next_right;
+                right_index = i;
+            } else {
+                // the right side crossed over the left side, which is now a corner.
+                get_corner(&mut path, left);
+                apex = left;
+                right = apex;
+                right_index = left_index;
+                i = left_index + 1;
+                continue;
+            }
+        }
+
+        // narrow the funnel from the left side.
+        if get_side(apex, left, next_left) <= 0.0 {
+            if get_equal(apex, left) || get_side(apex, right, next_left) > 0.0 {
+                left = next_left;
+                left_index = i;
+            } else {
+                // the left side crossed over the right side, which is now a corner.
+                get_corner(&mut path, right);
+                apex = right;
+                left = apex;
+                left_index = right_index;
+                i = right_index + 1;
+                continue;
+            }
+        }
+
+        i += 1;
+    }
+
+    get_corner(&mut path, portal[portal.len() - 1].0);
+
+    path
+}
+
+// push a corner to a path, unless it is the same as the last corner.
+fn get_corner(path: &mut Vec<Vector3>, corner: Vector3) {
+    if !path.last().is_some_and(|x| get_equal(*x, corner)) {
+        path.push(corner);
+    }
+}
+
+// get the closest point on a triangle to a point.
+fn get_closest_triangle(p: Vector3, a: Vector3, b: Vector3, c: Vector3) -> Vector3 {
+    let ab = b - a;
+    let ac = c - a;
+    let ap = p - a;
+    let d1 = ab.dot(ap);
+    let d2 = ac.dot(ap);
+
+    if d1 <= 0.0 && d2 <= 0.0 {
+        return a;
+    }
+
+    let bp = p - b;
+    let d3 = ab.dot(bp);
+    let d4 = ac.dot(bp);
+
+    if d3 >= 0.0 && d4 <= d3 {
+        return b;
+    }
+
+    let vc = d1 * d4 - d3 * d2;
+
+    if vc <= 0.0 && d1 >= 0.0 && d3 <= 0.0 {
+        return a + ab * (d1 / (d1 - d3));
+    }
+
+    let cp = p - c;
+    let d5 = ab.dot(cp);
+    let d6 = ac.dot(cp);
+
+    if d6 >= 0.0 && d5 <= d6 {
+        return c;
+    }
+
+    let vb = d5 * d2 - d1 * d6;
+
+    if vb <= 0.0 && d2 >= 0.0 && d6 <= 0.0 {
+        return a + ac * (d2 / (d2 - d6));
+    }
+
+    let va = d3 * d6 - d5 * d4;
+
+    if va <= 0.0 && (d4 - d3) >= 0.0 && (d5 - d6) >= 0.0 {
+        return b + (c - b) * ((d4 - d3) / ((d4 - d3) + (d5 - d6)));
+    }
+
+    let denom = 1.0 / (va + vb + vc);
+
+    a + ab * (vb * denom) + ac * (vc * denom)
+}
@@ -0,0 +1,650 @@
+/*
+* Copyright (c) 2025 sockentrocken
+*
+* Redistribution and use in source and binary forms, with or without
+* modification, are permitted provided that the following conditions are met:
+*
+* 1. Redistributions of source code must retain the above copyright notice,
+* this list of conditions and the following disclaimer.
+*
+* 2. Redistributions in binary form must reproduce the above copyright notice,
+* this list of conditions and the following disclaimer in the documentation
+* and/or other materials provided with the distribution.
+*
+* Subject to the terms and conditions of this license, each copyright holder
+* and contributor hereby grants to those receiving rights under this license
+* a perpetual, worldwide, non-exclusive, no-charge, royalty-free, irrevocable
+* (except for failure to satisfy the conditions of this license) patent license
+* to make, have made, use, offer to sell, sell, import, and otherwise transfer
+* this software, where such license applies only to those patent claims, already
+* acquired or hereafter acquired, licensable by such copyright holder or
+* contributor that are necessarily infringed by:
+*
+* (a) their Contribution(s) (the licensed copyrights of copyright holders and
+* non-copyrightable additions of contributors, in source or binary form) alone;
+* or
+*
+* (b) combination of their Contribution(s) with the work of authorship to which
+* such Contribution(s) was added by such copyright holder or contributor, if,
+* at the time the Contribution is added, such addition causes such combination
+* to be necessarily infringed. The patent license shall not apply to any other
+* combinations which include the Contribution.
+*
+* Except as expressly stated above, no rights or licenses from any copyright
+* holder or contributor is granted under this license, whether expressly, by
+* implication, estoppel or otherwise.
+*
+* DISCLAIMER
+*
+* THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+* AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+* IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+* DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDERS OR CONTRIBUTORS BE LIABLE
+* FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+* DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+* SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+* CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+* OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+* OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+*/
+
+use crate::base::data::Data;
+use crate::script::*;
+use crate::status::*;
+
+//================================================================
+
+use mlua::prelude::*;
+
+//================================================================
+
+/* class
+{ "version": "1.0.0", "name": "quiver.noise", "info": "The noise API." }
+*/
+#[rustfmt::skip]
+pub fn set_global(lua: &Lua, table: &mlua::Table, _: &StatusInfo, _: Option<&ScriptInfo>) -> mlua::Result<()> {
+    let noise = lua.create_table()?;
+
+    noise.set("new", lua.create_function(self::Noise::new)?)?;
+
+    table.set("noise", noise)?;
+
+    Ok(())
+}
+
+//================================================================
+
+/* class
+{
+    "version": "1.0.0",
+    "name": "noise",
+    "info": "A procedural noise generator, for 1D, 2D, 3D or 4D point. Every value will be in the [-1.0, 1.0] range. Two generators with the same seed and setting will always give back the same value.",
+    "member": [
+        { "name": "kind",       "info": "The kind of noise.",                                                                            "kind": "noise_kind"   },
+        { "name": "seed",       "info": "The seed.",                                                                                     "kind": "number"       },
+        { "name": "frequency",  "info": "The frequency. A point will be multiplied by this value before sampling. Default: 1.0.",        "kind": "number"       },
+        { "name": "fractal",    "info": "The kind of fractal. Default: FRACTAL_KIND.NONE.",                                              "kind": "fractal_kind" },
+        { "name": "octave",     "info": "The number of octave (layer) for a fractal, in the [1, 16] range. Default: 4.",                 "kind": "number"       },
+        { "name": "lacunarity", "info": "The frequency multiplier for each octave of a fractal. Default: 2.0.",                          "kind": "number"       },
+        { "name": "gain",       "info": "The amplitude multiplier for each octave of a fractal. Default: 0.5.",                          "kind": "number"       },
+        { "name": "warp",       "info": "The domain warp amplitude, in noise space (after frequency). Use 0.0 to disable. Default: 0.0.", "kind": "number"       }
+    ],
+    "test": "noise/noise.lua"
+}
+*/
+#[derive(Clone)]
+struct Noise {
+    kind: i32,
+    seed: u64,
+    frequency: f64,
+    fractal: i32,
+    octave: i32,
+    lacunarity: f64,
+    gain: f64,
+    warp: f64,
+}
+
+impl Noise {
+    const KIND_PERLIN: i32 = 0;
+    const KIND_SIMPLEX: i32 = 1;
+    const KIND_WORLEY: i32 = 2;
+    const KIND_VALUE: i32 = 3;
+    const FRACTAL_NONE: i32 = 0;
+    const FRACTAL_FBM: i32 = 1;
+    const FRACTAL_RIDGED: i32 = 2;
+    const FRACTAL_BILLOW: i32 = 3;
+    const OCTAVE_MAX: i32 = 16;
+    // the largest cell count of a grid (256 MB of 32-bit float).
+    const GRID_MAX: usize = 1 << 26;
+
+    /* entry
+    {
+        "version": "1.0.0",
+        "name": "quiver.noise.new",
+        "info": "Create a new noise generator.",
+        "member": [
+            { "name": "kind", "info": "OPTIONAL: The kind of noise. Default: NOISE_KIND.PERLIN.", "kind": "noise_kind?" },
+            { "name": "seed", "info": "OPTIONAL: The seed. Default: 0.",                          "kind": "number?"     }
+        ],
+        "result": [
+            { "name": "noise", "info": "The noise generator.", "kind": "noise" }
+        ]
+    }
+    */
+    fn new(_: &Lua, (kind, seed): (Option<i32>, Option<u64>)) -> mlua::Result<Self> {
+        Ok(Self {
+            kind: Self::get_kind(kind.unwrap_or(Self::KIND_PERLIN))?,
+            seed: seed.unwrap_or_default(),
+            frequency: 1.0,
+            fractal: Self::FRACTAL_NONE,
+            octave: 4,
+            lacunarity: 2.0,
+            gain: 0.5,
+            warp: 0.0,
+        })
+    }
+
+    fn get_kind(kind: i32) -> mlua::Result<i32> {
+        if (Self::KIND_PERLIN..=Self::KIND_VALUE).contains(&kind) {
+            Ok(kind)
+        } else {
+            Err(mlua::Error::runtime("noise.kind: Invalid noise kind."))
+        }
+    }
+
+    fn get_fractal_kind(fractal: i32) -> mlua::Result<i32> {
+        if (Self::FRACTAL_NONE..=Self::FRACTAL_BILLOW).contains(&fractal) {
+            Ok(fractal)
+        } else {
+            Err(mlua::Error::runtime("noise.fractal: Invalid fractal kind."))
+        }
+    }
+
+    fn get_base(&self, seed: u64, point: &[f64]) -> f64 {
+        match self.kind {
+            Self::KIND_SIMPLEX => sample::simplex(seed, point),
+            Self::KIND_WORLEY => sample::worley(seed, point),
+            Self::KIND_VALUE => sample::value(seed, point),
+            _ => sample::perlin(seed, point),
+        }
+    }
+
+    fn get_layer(&self, point: &[f64]) -> f64 {
+        if self.fractal == Self::FRACTAL_NONE {
+            return self.get_base(self.seed, point);
+        }
+
+        let mut layer = [0.0; sample::DIMENSION];
+        let mut amplitude = 1.0;
+        let mut total = 0.0;
+        let mut result = 0.0;
+
+        layer[..point.len()].copy_from_slice(point);
+
+        for octave in 0..self.octave {
+            // every octave gets its own seed, to avoid artifact at the origin.
+            let value = self.get_base(self.seed.wrapping_add(octave as u64), &layer[..point.len()]);
+
+            let value = match self.fractal {
+                Self::FRACTAL_RIDGED => {
+                    let value = 1.0 - value.abs();
+                    value * value * 2.0 - 1.0
+                }
+                Self::FRACTAL_BILLOW => value.abs() * 2.0 - 1.0,
+                _ => value,
+            };
+
+            result += value * amplitude;
+            total += amplitude;
+            amplitude *= self.gain;
+
+            for value in layer[..point.len()].iter_mut() {
+                *value *= self.lacunarity;
+            }
+        }
+
+        if total > 0.0 {
+            (result / total).clamp(-1.0, 1.0)
+        } else {
+            0.0
+        }
+    }
+
+    fn get(&self, point: &[f64]) -> f64 {
+        let mut scale = [0.0; sample::DIMENSION];
+
+        for (i, value) in point.iter().enumerate() {
+            scale[i] = value * self.frequency;
+        }
+
+        if self.warp != 0.0 {
+            let mut warp = scale;
+
+            // offset each axis by a different noise field, then sample the fractal from there.
+            for (i, value) in warp[..point.len()].iter_mut().enumerate() {
+                let seed = self.seed ^ sample::get_mix(i as u64 + 1);
+
+                *value += self.warp * self.get_base(seed, &scale[..point.len()]);
+            }
+
+            scale = warp;
+        }
+
+        self.get_layer(&scale[..point.len()])
+    }
+}
+
+impl mlua::UserData for Noise {
+    fn add_fields<F: mlua::UserDataFields<Self>>(field: &mut F) {
+        field.add_field_method_get("kind", |_, this| Ok(this.kind));
+        field.add_field_method_get("seed", |_, this| Ok(this.seed));
+        field.add_field_method_get("frequency", |_, this| Ok(this.frequency));
+        field.add_field_method_get("fractal", |_, this| Ok(this.fractal));
+        field.add_field_method_get("octave", |_, this| Ok(this.octave));
+        field.add_field_method_get("lacunarity", |_, this| Ok(this.lacunarity));
+        field.add_field_method_get("gain", |_, this| Ok(this.gain));
+        field.add_field_method_get("warp", |_, this| Ok(this.warp));
+
+        field.add_field_method_set("kind", |_, this, value: i32| {
+            this.kind = Self::get_kind(value)?;
+            Ok(())
+        });
+        field.add_field_method_set("seed", |_, this, value: u64| {
+            this.seed = value;
+            Ok(())
+        });
+        field.add_field_method_set("frequency", |_, this, value: f64| {
+            this.frequency = value;
+            Ok(())
+        });
+        field.add_field_method_set("fractal", |_, this, value: i32| {
+            this.fractal = Self::get_fractal_kind(value)?;
+            Ok(())
+        });
+        field.add_field_method_set("octave", |_, this, value: i32| {
+            if !(1..=Self::OCTAVE_MAX).contains(&value) {
+                return Err(mlua::Error::runtime(
+                    "noise.octave: Octave count must be in the [1, 16] range.",
+                ));
+            }
+
+            this.octave = value;
+            Ok(())
+        });
+        field.add_field_method_set("lacunarity", |_, this, value: f64| {
+            this.lacunarity = value;
+            Ok(())
+        });
+        field.add_field_method_set("gain", |_, this, value: f64| {
+            this.gain = value;
+            Ok(())
+        });
+        field.add_field_method_set("warp", |_, this, value: f64| {
+            this.warp = value;
+            Ok(())
+        });
+    }
+
+    fn add_methods<M: mlua::UserDataMethods<Self>>(method: &mut M) {
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "noise:get",
+            "info": "Get the value of the noise at a point. The number of coordinate given will set the dimension of the noise.",
+            "member": [
+                { "name": "x", "info": "The X coordinate.",           "kind": "number"  },
+                { "name": "y", "info": "OPTIONAL: The Y coordinate.", "kind": "number?" },
+                { "name": "z", "info": "OPTIONAL: The Z coordinate.", "kind": "number?" },
+                { "name": "w", "info": "OPTIONAL: The W coordinate.", "kind": "number?" }
+            ],
+            "result": [
+                { "name": "value", "info": "The value, in the [-1.0, 1.0] range.", "kind": "number" }
+            ]
+        }
+        */
+        method.add_method(
+            "get",
+            |_: &Lua, this, (x, y, z, w): (f64, Option<f64>, Option<f64>, Option<f64>)| {
+                let point = match (y, z, w) {
+                    (None, None, None) => vec![x],
+                    (Some(y), None, None) => vec![x, y],
+                    (Some(y), Some(z), None) => vec![x, y, z],
+                    (Some(y), Some(z), Some(w)) => vec![x, y, z, w],
+                    _ => {
+                        return Err(mlua::Error::runtime(
+                            "noise:get(): Coordinate must not have a gap (e.g. X and Z, without Y).",
+                        ));
+                    }
+                };
+
+                if !point.iter().all(|x| x.is_finite()) {
+                    return Err(mlua::Error::runtime(
+                        "noise:get(): Coordinate must be finite.",
+                    ));
+                }
+
+                Ok(this.get(&point))
+            },
+        );
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "noise:get_grid",
+            "info": "Fill a grid with the value of the noise. The number of value in the shape will set the dimension of the noise. The grid is laid out in row-major order, with X being the fastest axis.",
+            "member": [
+                { "name": "shape", "info": "The shape of the grid, with 1 to 4 value (i.e. { 256, 256 }).",                            "kind": "table"   },
+                { "name": "point", "info": "OPTIONAL: The point of the first cell of the grid. Default: the origin.",                  "kind": "table?"  },
+                { "name": "step",  "info": "OPTIONAL: The distance between each cell of the grid, before frequency. Default: 1.0.", "kind": "number?" }
+            ],
+            "result": [
+                { "name": "data", "info": "The data buffer, with a value in the [-1.0, 1.0] range for each cell.", "kind": "data" }
+            ]
+        }
+        */
+        method.add_method(
+            "get_grid",
+            |lua: &Lua, this, (shape, point, step): (Vec<usize>, Option<Vec<f64>>, Option<f64>)| {
+                if shape.is_empty() || shape.len() > sample::DIMENSION {
+                    return Err(mlua::Error::runtime(
+                        "noise:get_grid(): Shape must have 1 to 4 value.",
+                    ));
+                }
+
+                if shape.contains(&0) {
+                    return Err(mlua::Error::runtime(
+                        "noise:get_grid(): Shape must be greater than zero.",
+                    ));
+                }
+
+                let point = point.unwrap_or_else(|| vec![0.0; shape.len()]);
+                let step = step.unwrap_or(1.0);
+
+                if point.len() != shape.len() {
+                    return Err(mlua::Error::runtime(
+                        "noise:get_grid(): Point must have as many value as shape.",
+                    ));
+                }
+
+                if !point.iter().all(|x| x.is_finite()) || !step.is_finite() {
+                    return Err(mlua::Error::runtime(
+                        "noise:get_grid(): Point and step must be finite.",
+                    ));
+                }
+
+                let count = shape
+                    .iter()
+                    .try_fold(1usize, |count, value| count.checked_mul(*value))
+                    .filter(|count| *count <= Self::GRID_MAX)
+                    .ok_or(mlua::Error::runtime(
+                        "noise:get_grid(): Shape is too large.",
+                    ))?;
+
+                let mut data = Vec::with_capacity(count);
+                let mut index = vec![0usize; shape.len()];
+                let mut cell = vec![0.0; shape.len()];
+
+                for _ in 0..count {
+                    for (i, value) in cell.iter_mut().enumerate() {
+                        *value = point[i] + index[i] as f64 * step;
+                    }
+
+                    data.push(this.get(&cell) as f32);
+
+                    // advance the index, X first.
+                    for (i, value) in index.iter_mut().enumerate() {
+                        *value += 1;
+
+                        if *value < shape[i] {
+                            break;
+                        }
+
+                        *value = 0;
+                    }
+                }
+
+                Data::new(lua, data)
+            },
+        );
+    }
+}
+
+//================================================================
+
+// every noise function takes a point of 1 to 4 dimension, and gives back a value in the [-1.0, 1.0] range.
+// the lattice arithmetic wraps around, as a very large coordinate will saturate the cell to i64::MIN/MAX.
+mod sample {
+    pub const DIMENSION: usize = 4;
+
+    // scale factor for each dimension, to bring the peak value close to 1.0.
+    const SCALE_PERLIN: [f64; DIMENSION] = [2.0, 1.414, 1.559, 1.65];
+    const SCALE_SIMPLEX: [f64; DIMENSION] = [70.0, 99.0, 110.0, 110.0];
+
+    fn get_hash(seed: u64, cell: &[i64]) -> u64 {
+        let mut hash = seed.wrapping_mul(0x9E3779B97F4A7C15);
+
+        for value in cell {
+            hash = get_mix(hash ^ (*value as u64));
+        }
+
+        hash
+    }
+
+    // SplitMix64 finalizer.
+    pub fn get_mix(value: u64) -> u64 {
+        let mut value = value.wrapping_add(0x9E3779B97F4A7C15);
+        value = (value ^ (value >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+        value = (value ^ (value >> 27)).wrapping_mul(0x94D049BB133111EB);
+        value ^ (value >> 31)
+    }
+
+    // get a number in the [0.0, 1.0) range from a hash.
+    fn get_unit(hash: u64) -> f64 {
+        (hash >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    // get a random unit-length gradient for a lattice point.
+    fn get_gradient(seed: u64, cell: &[i64], gradient: &mut [f64]) {
+        let mut hash = get_hash(seed, cell);
+
+        loop {
+            let mut length = 0.0;
+
+            for value in gradient.iter_mut() {
+                hash = get_mix(hash);
+                *value = get_unit(hash) * 2.0 - 1.0;
+                length += *value * *value;
+            }
+
+            // reject any point outside of the unit sphere, for an uniform direction.
+            if length > 0.0001 && length <= 1.0 {
+                let length: f64 = length.sqrt();
+
+                for value in gradient.iter_mut() {
+                    *value /= length;
+                }
+
+                return;
+            }
+        }
+    }
+
+    // quintic fade curve.
+    fn get_fade(value: f64) -> f64 {
+        value * value * value * (value * (value * 6.0 - 15.0) + 10.0)
+    }
+
+    fn get_corner(cell: &[i64], corner: usize, result: &mut [i64]) {
+        for (i, value) in result.iter_mut().enumerate() {
+            *value = cell[i].wrapping_add(((corner >> i) & 1) as i64);
+        }
+    }
+
+    pub fn perlin(seed: u64, point: &[f64]) -> f64 {
+        let n = point.len();
+        let mut cell = [0i64; DIMENSION];
+        let mut frac = [0.0; DIMENSION];
+        let mut fade = [0.0; DIMENSION];
+
+        for i in 0..n {
+            cell[i] = point[i].floor() as i64;
+            frac[i] = point[i] - point[i].floor();
+            fade[i] = get_fade(frac[i]);
+        }
+
+        let mut result = 0.0;
+        let mut gradient = [0.0; DIMENSION];
+        let mut corner_cell = [0i64; DIMENSION];
+
+        for corner in 0..(1 << n) {
+            let mut weight = 1.0;
+            let mut dot = 0.0;
+
+            get_corner(&cell[..n], corner, &mut corner_cell[..n]);
+            get_gradient(seed, &corner_cell[..n], &mut gradient[..n]);
+
+            for i in 0..n {
+                if (corner >> i) & 1 == 1 {
+                    dot += gradient[i] * (frac[i] - 1.0);
+                    weight *= fade[i];
+                } else {
+                    dot += gradient[i] * frac[i];
+                    weight *= 1.0 - fade[i];
+                }
+            }
+
+            result += weight * dot;
+        }
+
+        (result * SCALE_PERLIN[n - 1]).clamp(-1.0, 1.0)
+    }
+
+    pub fn simplex(seed: u64, point: &[f64]) -> f64 {
+        let n = point.len();
+        let n_f = n as f64;
+        let skew = ((n_f + 1.0).sqrt() - 1.0) / n_f;
+        let unskew = (1.0 - 1.0 / (n_f + 1.0).sqrt()) / n_f;
+
+        let sum = point.iter().sum::<f64>() * skew;
+        let mut cell = [0i64; DIMENSION];
+        let mut distance = [0.0; DIMENSION];
+
+        for i in 0..n {
+            cell[i] = (point[i] + sum).floor() as i64;
+        }
+
+        let sum = cell[..n]
+            .iter()
+            .fold(0i64, |sum, value| sum.wrapping_add(*value)) as f64
+            * unskew;
+
+        for i in 0..n {
+            distance[i] = point[i] - (cell[i] as f64 - sum);
+        }
+
+        // traverse the corner of the simplex, from the largest coordinate to the smallest.
+        let mut order = [0usize, 1, 2, 3];
+        order[..n].sort_by(|a, b| distance[*b].total_cmp(&distance[*a]));
+
+        let mut result = 0.0;
+        let mut offset = [0i64; DIMENSION];
+        let mut corner_cell = [0i64; DIMENSION];
+        let mut gradient = [0.0; DIMENSION];
+
+        for corner in 0..=n {
+            if corner > 0 {
+                offset[order[corner - 1]] = 1;
+            }
+
+            let mut length = 0.0;
+            let mut delta = [0.0; DIMENSION];
+
+            for i in 0..n {
+                delta[i] = distance[i] - offset[i] as f64 + corner as f64 * unskew;
+                length += delta[i] * delta[i];
+                corner_cell[i] = cell[i].wrapping_add(offset[i]);
+            }
+
+            let t = 0.5 - length;
+
+            if t > 0.0 {
+                get_gradient(seed, &corner_cell[..n], &mut gradient[..n]);
+
+                let dot: f64 = (0..n).map(|i| gradient[i] * delta[i]).sum();
+                let t = t * t;
+
+                result += t * t * dot;
+            }
+        }
+
+        (result * SCALE_SIMPLEX[n - 1]).clamp(-1.0, 1.0)
+    }
+
+    pub fn worley(seed: u64, point: &[f64]) -> f64 {
+        let n = point.len();
+        let mut cell = [0i64; DIMENSION];
+
+        for i in 0..n {
+            cell[i] = point[i].floor() as i64;
+        }
+
+        let mut result = f64::MAX;
+        let mut neighbor = [0i64; DIMENSION];
+
+        // check every neighbor cell, each one with a single feature point in it.
+        for index in 0..3usize.pow(n as u32) {
+            let mut index = index;
+            let mut length = 0.0;
+
+            for i in 0..n {
+                neighbor[i] = cell[i].wrapping_add((index % 3) as i64 - 1);
+                index /= 3;
+            }
+
+            let mut hash = get_hash(seed, &neighbor[..n]);
+
+            for i in 0..n {
+                hash = get_mix(hash);
+                let delta = neighbor[i] as f64 + get_unit(hash) - point[i];
+                length += delta * delta;
+            }
+
+            result = result.min(length);
+        }
+
+        // distance to the closest feature point, from [0.0, 1.0] to [-1.0, 1.0].
+        (result.sqrt() * 2.0 - 1.0).clamp(-1.0, 1.0)
+    }
+
+    pub fn value(seed: u64, point: &[f64]) -> f64 {
+        let n = point.len();
+        let mut cell = [0i64; DIMENSION];
+        let mut fade = [0.0; DIMENSION];
+
+        for i in 0..n {
+            cell[i] = point[i].floor() as i64;
+            fade[i] = get_fade(point[i] - point[i].floor());
+        }
+
+        let mut result = 0.0;
+        let mut corner_cell = [0i64; DIMENSION];
+
+        for corner in 0..(1 << n) {
+            let mut weight = 1.0;
+
+            get_corner(&cell[..n], corner, &mut corner_cell[..n]);
+
+            for (i, fade) in fade[..n].iter().enumerate() {
+                weight *= if (corner >> i) & 1 == 1 {
+                    *fade
+                } else {
+                    1.0 - fade
+                };
+            }
+
+            result += weight * (get_unit(get_hash(seed, &corner_cell[..n])) * 2.0 - 1.0);
+        }
+
+        result
+    }
+}
@@ -0,0 +1,485 @@
+/*
+* Copyright (c) 2025 sockentrocken
+*
+* Redistribution and use in source and binary forms, with or without
+* modification, are permitted provided that the following conditions are met:
+*
+* 1. Redistributions of source code must retain the above copyright notice,
+* this list of conditions and the following disclaimer.
+*
+* 2. Redistributions in binary form must reproduce the above copyright notice,
+* this list of conditions and the following disclaimer in the documentation
+* and/or other materials provided with the distribution.
+*
+* Subject to the terms and conditions of this license, each copyright holder
+* and contributor hereby grants to those receiving rights under this license
+* a perpetual, worldwide, non-exclusive, no-charge, royalty-free, irrevocable
+* (except for failure to satisfy the conditions of this license) patent license
+* to make, have made, use, offer to sell, sell, import, and otherwise transfer
+* this software, where such license applies only to those patent claims, already
+* acquired or hereafter acquired, licensable by such copyright holder or
+* contributor that are necessarily infringed by:
+*
+* (a) their Contribution(s) (the licensed copyrights of copyright holders and
+* non-copyrightable additions of contributors, in source or binary form) alone;
+* or
+*
+* (b) combination of their Contribution(s) with the work of authorship to which
+* such Contribution(s) was added by such copyright holder or contributor, if,
+* at the time the Contribution is added, such addition causes such combination
+* to be necessarily infringed. The patent license shall not apply to any other
+* combinations which include the Contribution.
+*
+* Except as expressly stated above, no rights or licenses from any copyright
+* holder or contributor is granted under this license, whether expressly, by
+* implication, estoppel or otherwise.
+*
+* DISCLAIMER
+*
+* THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+* AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+* IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+* DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDERS OR CONTRIBUTORS BE LIABLE
+* FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+* DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+* SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+* CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+* OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+* OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+*/
+
+use crate::script::*;
+use crate::status::*;
+
+//================================================================
+
+use mlua::prelude::*;
+
+//================================================================
+
+/* class
+{ "version": "1.0.0", "name": "quiver.random", "info": "The random API." }
+*/
+#[rustfmt::skip]
+pub fn set_global(lua: &Lua, table: &mlua::Table, _: &StatusInfo, _: Option<&ScriptInfo>) -> mlua::Result<()> {
+    let random = lua.create_table()?;
+
+    random.set("new", lua.create_function(self::Random::new)?)?;
+
+    table.set("random", random)?;
+
+    Ok(())
+}
+
+//================================================================
+
+/* class
+{
+    "version": "1.0.0",
+    "name": "random",
+    "info": "A deterministic random number generator (PCG32). Two generators with the same seed and stream will always give back the same sequence, independent of any other generator, or of Lua's math.random.",
+    "test": "random/random.lua"
+}
+*/
+#[derive(Clone)]
+struct Random {
+    state: u64,
+    increment: u64,
+}
+
+impl Random {
+    const MULTIPLIER: u64 = 6364136223846793005;
+
+    /* entry
+    {
+        "version": "1.0.0",
+        "name": "quiver.random.new",
+        "info": "Create a new random number generator.",
+        "member": [
+            { "name": "seed",   "info": "OPTIONAL: The seed. If nil, will use the current time.",                                               "kind": "number?" },
+            { "name": "stream", "info": "OPTIONAL: The stream. Generators with the same seed but a different stream will give back a different sequence.", "kind": "number?" }
+        ],
+        "result": [
+            { "name": "random", "info": "The random number generator.", "kind": "random" }
+        ]
+    }
+    */
+    fn new(_: &Lua, (seed, stream): (Option<u64>, Option<u64>)) -> mlua::Result<Self> {
+        let seed = seed.unwrap_or_else(|| {
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map(|x| x.as_nanos() as u64)
+                .unwrap_or_default()
+        });
+
+        Ok(Self::new_seed(seed, stream.unwrap_or_default()))
+    }
+
+    fn new_seed(seed: u64, stream: u64) -> Self {
+        let mut random = Self {
+            state: 0,
+            increment: (stream << 1) | 1,
+        };
+
+        random.next_u32();
+        random.state = random.state.wrapping_add(seed);
+        random.next_u32();
+
+        random
+    }
+
+    fn next_u32(&mut self) -> u32 {
+        let state = self.state;
+
+        self.state = state
+            .wrapping_mul(Self::MULTIPLIER)
+            .wrapping_add(self.increment);
+
+        let value = (((state >> 18) ^ state) >> 27) as u32;
+
+        value.rotate_right((state >> 59) as u32)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        ((self.next_u32() as u64) << 32) | self.next_u32() as u64
+    }
+
+    // get a number in the [0.0, 1.0) range, with 53 bits of precision.
+    fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    // get an integer in the [0, bound) range, without bias.
+    fn next_bound(&mut self, bound: u64) -> u64 {
+        let limit = bound.wrapping_neg() % bound;
+
+        loop {
+            let value = self.next_u64();
+
+            if value >= limit {
+                return value % bound;
+            }
+        }
+    }
+
+    // get a number with a standard normal distribution (Box-Muller).
+    fn next_gaussian(&mut self) -> f64 {
+        let a = 1.0 - self.next_f64();
+        let b = self.next_f64();
+
+        (-2.0 * a.ln()).sqrt() * (std::f64::consts::TAU * b).cos()
+    }
+
+    fn get_list(list: &mlua::Table) -> mlua::Result<Vec<LuaValue>> {
+        list.sequence_values().collect()
+    }
+}
+
+impl mlua::UserData for Random {
+    fn add_fields<F: mlua::UserDataFields<Self>>(_: &mut F) {}
+
+    fn add_methods<M: mlua::UserDataMethods<Self>>(method: &mut M) {
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "random:set_seed",
+            "info": "Set the seed of the random number generator, resetting its sequence.",
+            "member": [
+                { "name": "seed",   "info": "The seed.",                          "kind": "number"  },
+                { "name": "stream", "info": "OPTIONAL: The stream. Default: 0.", "kind": "number?" }
+            ]
+        }
+        */
+        method.add_method_mut(
+            "set_seed",
+            |_: &Lua, this, (seed, stream): (u64, Option<u64>)| {
+                *this = Self::new_seed(seed, stream.unwrap_or_default());
+                Ok(())
+            },
+        );
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "random:get_state",
+            "info": "Get the current state of the random number generator, to restore it later with random:set_state.",
+            "result": [
+                { "name": "state", "info": "The state.", "kind": "string" }
+            ]
+        }
+        */
+        method.add_method("get_state", |_: &Lua, this, _: ()| {
+            Ok(format!("{:016x}{:016x}", this.state, this.increment))
+        });
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "random:set_state",
+            "info": "Restore the state of the random number generator.",
+            "member": [
+                { "name": "state", "info": "The state, from random:get_state.", "kind": "string" }
+            ]
+        }
+        */
+        method.add_method_mut("set_state", |_: &Lua, this, state: String| {
+            let error = || mlua::Error::runtime("random:set_state(): Invalid state.");
+
+            if state.len() != 32 || !state.is_ascii() {
+                return Err(error());
+            }
+
+            this.state = u64::from_str_radix(&state[..16], 16).map_err(|_| error())?;
+            this.increment = u64::from_str_radix(&state[16..], 16).map_err(|_| error())? | 1;
+
+            Ok(())
+        });
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "random:fork",
+            "info": "Create a new, independent random number generator, seeded from this one. Useful for giving every system its own generator from a single seed.",
+            "member": [
+                { "name": "stream", "info": "OPTIONAL: The stream of the new generator. Default: 0.", "kind": "number?" }
+            ],
+            "result": [
+                { "name": "random", "info": "The random number generator.", "kind": "random" }
+            ]
+        }
+        */
+        method.add_method_mut("fork", |_: &Lua, this, stream: Option<u64>| {
+            Ok(Self::new_seed(this.next_u64(), stream.unwrap_or_default()))
+        });
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "random:get_integer",
+            "info": "Get a random integer, in the [min, max] range.",
+            "member": [
+                { "name": "min", "info": "The minimum value.", "kind": "number" },
+                { "name": "max", "info": "The maximum value.", "kind": "number" }
+            ],
+            "result": [
+                { "name": "value", "info": "The value.", "kind": "number" }
+            ]
+        }
+        */
+        method.add_method_mut("get_integer", |_: &Lua, this, (min, max): (i64, i64)| {
+            if min > max {
+                return Err(mlua::Error::runtime(
+                    "random:get_integer(): Minimum is greater than maximum.",
+                ));
+            }
+
+            let span = max.wrapping_sub(min) as u64;
+
+            if span == u64::MAX {
+                Ok(this.next_u64() as i64)
+            } else {
+                Ok(min.wrapping_add(this.next_bound(span + 1) as i64))
+            }
+        });
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "random:get_number",
+            "info": "Get a random number, in the [min, max) range.",
+            "member": [
+                { "name": "min", "info": "OPTIONAL: The minimum value. Default: 0.0.", "kind": "number?" },
+                { "name": "max", "info": "OPTIONAL: The maximum value. Default: 1.0.", "kind": "number?" }
+            ],
+            "result": [
+                { "name": "value", "info": "The value.", "kind": "number" }
+            ]
+        }
+        */
+        method.add_method_mut(
+            "get_number",
+            |_: &Lua, this, (min, max): (Option<f64>, Option<f64>)| {
+                let min = min.unwrap_or(0.0);
+                let max = max.unwrap_or(1.0);
+
+                Ok(min + (max - min) * this.next_f64())
+            },
+        );
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "random:get_boolean",
+            "info": "Get a random boolean.",
+            "member": [
+                { "name": "chance", "info": "OPTIONAL: The chance of the value being true, in the [0.0, 1.0] range. Default: 0.5.", "kind": "number?" }
+            ],
+            "result": [
+                { "name": "value", "info": "The value.", "kind": "boolean" }
+            ]
+        }
+        */
+        method.add_method_mut("get_boolean", |_: &Lua, this, chance: Option<f64>| {
+            Ok(this.next_f64() < chance.unwrap_or(0.5))
+        });
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "random:get_gaussian",
+            "info": "Get a random number, with a normal (gaussian) distribution.",
+            "member": [
+                { "name": "mean",      "info": "OPTIONAL: The mean. Default: 0.0.",               "kind": "number?" },
+                { "name": "deviation", "info": "OPTIONAL: The standard deviation. Default: 1.0.", "kind": "number?" }
+            ],
+            "result": [
+                { "name": "value", "info": "The value.", "kind": "number" }
+            ]
+        }
+        */
+        method.add_method_mut(
+            "get_gaussian",
+            |_: &Lua, this, (mean, deviation): (Option<f64>, Option<f64>)| {
+                Ok(mean.unwrap_or(0.0) + deviation.unwrap_or(1.0) * this.next_gaussian())
+            },
+        );
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "random:get_choice",
+            "info": "Get a random value from a list.",
+            "member": [
+                { "name": "list",   "info": "The list of value.",                                                                  "kind": "table"  },
+                { "name": "weight", "info": "OPTIONAL: The list of weight, one for each value. If nil, every value will have the same weight.", "kind": "table?" }
+            ],
+            "result": [
+                { "name": "value", "info": "The value.",                     "kind": "any"    },
+                { "name": "index", "info": "The index of the value in the list.", "kind": "number" }
+            ]
+        }
+        */
+        method.add_method_mut(
+            "get_choice",
+            |_: &Lua, this, (list, weight): (mlua::Table, Option<Vec<f64>>)| {
+                let list = Self::get_list(&list)?;
+
+                if list.is_empty() {
+                    return Err(mlua::Error::runtime("random:get_choice(): List is empty."));
+                }
+
+                let index = match weight {
+                    Some(weight) => {
+                        if weight.len() != list.len() || weight.iter().any(|x| *x < 0.0) {
+                            return Err(mlua::Error::runtime(
+                                "random:get_choice(): Weight list must have one non-negative weight for each value.",
+                            ));
+                        }
+
+                        let total: f64 = weight.iter().sum();
+
+                        if total <= 0.0 {
+                            return Err(mlua::Error::runtime(
+                                "random:get_choice(): Weight list must have a total greater than zero.",
+                            ));
+                        }
+
+                        let mut point = this.next_f64() * total;
+                        let mut index = list.len() - 1;
+
+                        for (i, weight) in weight.iter().enumerate() {
+                            if point < *weight {
+                                index = i;
+                                break;
+                            }
+
+                            point -= weight;
+                        }
+
+                        index
+                    }
+                    None => this.next_bound(list.len() as u64) as usize,
+                };
+
+                Ok((list[index].clone(), index + 1))
+            },
+        );
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "random:shuffle",
+            "info": "Shuffle a list, in-place.",
+            "member": [
+                { "name": "list", "info": "The list to shuffle.", "kind": "table" }
+            ],
+            "result": [
+                { "name": "list", "info": "The same list, shuffled.", "kind": "table" }
+            ]
+        }
+        */
+        method.add_method_mut("shuffle", |_: &Lua, this, list: mlua::Table| {
+            let mut value = Self::get_list(&list)?;
+
+            for x in (1..value.len()).rev() {
+                let y = this.next_bound(x as u64 + 1) as usize;
+                value.swap(x, y);
+            }
+
+            for (i, value) in value.into_iter().enumerate() {
+                list.raw_set(i + 1, value)?;
+            }
+
+            Ok(list)
+        });
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "random:get_circle",
+            "info": "Get a random point inside of a circle, with an uniform distribution.",
+            "member": [
+                { "name": "radius", "info": "OPTIONAL: The radius of the circle. Default: 1.0.", "kind": "number?" }
+            ],
+            "result": [
+                { "name": "point_x", "info": "The point (X).", "kind": "number" },
+                { "name": "point_y", "info": "The point (Y).", "kind": "number" }
+            ]
+        }
+        */
+        method.add_method_mut("get_circle", |_: &Lua, this, radius: Option<f64>| {
+            let angle = this.next_f64() * std::f64::consts::TAU;
+            let length = radius.unwrap_or(1.0) * this.next_f64().sqrt();
+
+            Ok((angle.cos() * length, angle.sin() * length))
+        });
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "random:get_sphere",
+            "info": "Get a random point on the surface of a sphere, with an uniform distribution.",
+            "member": [
+                { "name": "radius", "info": "OPTIONAL: The radius of the sphere. Default: 1.0.", "kind": "number?" }
+            ],
+            "result": [
+                { "name": "point_x", "info": "The point (X).", "kind": "number" },
+                { "name": "point_y", "info": "The point (Y).", "kind": "number" },
+                { "name": "point_z", "info": "The point (Z).", "kind": "number" }
+            ]
+        }
+        */
+        method.add_method_mut("get_sphere", |_: &Lua, this, radius: Option<f64>| {
+            let radius = radius.unwrap_or(1.0);
+            let angle = this.next_f64() * std::f64::consts::TAU;
+            let z = this.next_f64() * 2.0 - 1.0;
+            let length = (1.0 - z * z).sqrt();
+
+            Ok((
+                angle.cos() * length * radius,
+                angle.sin() * length * radius,
+                z * radius,
+            ))
+        });
+    }
+}
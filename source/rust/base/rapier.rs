@@ -448,6 +448,51 @@ impl mlua::UserData for Rapier {
             },
         );
 
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "rapier:get_collider_shape_tri_mesh",
+            "info": "Get the shape of a collider (tri-mesh), in world space. Useful for baking a navigation mesh with quiver.navigation.new_mesh.",
+            "member": [
+                { "name": "collider", "info": "Collider handle.", "kind": "table" }
+            ],
+            "result": [
+                { "name": "point_table", "info": "The point array table.", "kind": "table" },
+                { "name": "index_table", "info": "The index array table.", "kind": "table" }
+            ]
+        }
+        */
+        method.add_method(
+            "get_collider_shape_tri_mesh",
+            |lua, this, collider: LuaValue| {
+                let collider: ColliderHandle = lua.from_value(collider)?;
+
+                if let Some(collider) = this.collider_set.get(collider) {
+                    if let Some(shape) = collider.shape().as_trimesh() {
+                        let point: Vec<Vector3> = shape
+                            .vertices()
+                            .iter()
+                            .map(|x| {
+                                let x = collider.position() * x;
+                                Vector3::new(x.x, x.y, x.z)
+                            })
+                            .collect();
+                        let index: Vec<u32> = shape.indices().iter().flatten().copied().collect();
+
+                        return Ok((lua.to_value(&point)?, lua.to_value(&index)?));
+                    } else {
+                        return Err(mlua::Error::runtime(
+                            "rapier:get_collider_shape_tri_mesh(): Collider is not a tri-mesh.",
+                        ));
+                    }
+                }
+
+                Err(mlua::Error::runtime(
+                    "rapier:get_collider_shape_tri_mesh(): Invalid collider handle.",
+                ))
+            },
+        );
+
         //================================================================
 
         /* entry
@@ -0,0 +1,358 @@
+/*
+* Copyright (c) 2025 sockentrocken
+*
+* Redistribution and use in source and binary forms, with or without
+* modification, are permitted provided that the following conditions are met:
+*
+* 1. Redistributions of source code must retain the above copyright notice,
+* this list of conditions and the following disclaimer.
+*
+* 2. Redistributions in binary form must reproduce the above copyright notice,
+* this list of conditions and the following disclaimer in the documentation
+* and/or other materials provided with the distribution.
+*
+* Subject to the terms and conditions of this license, each copyright holder
+* and contributor hereby grants to those receiving rights under this license
+* a perpetual, worldwide, non-exclusive, no-charge, royalty-free, irrevocable
+* (except for failure to satisfy the conditions of this license) patent license
+* to make, have made, use, offer to sell, sell, import, and otherwise transfer
+* this software, where such license applies only to those patent claims, already
+* acquired or hereafter acquired, licensable by such copyright holder or
+* contributor that are necessarily infringed by:
+*
+* (a) their Contribution(s) (the licensed copyrights of copyright holders and
+* non-copyrightable additions of contributors, in source or binary form) alone;
+* or
+*
+* (b) combination of their Contribution(s) with the work of authorship to which
+* such Contribution(s) was added by such copyright holder or contributor, if,
+* at the time the Contribution is added, such addition causes such combination
+* to be necessarily infringed. The patent license shall not apply to any other
+* combinations which include the Contribution.
+*
+* Except as expressly stated above, no rights or licenses from any copyright
+* holder or contributor is granted under this license, whether expressly, by
+* implication, estoppel or otherwise.
+*
+* DISCLAIMER
+*
+* THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+* AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+* IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+* DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDERS OR CONTRIBUTORS BE LIABLE
+* FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+* DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+* SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+* CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+* OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+* OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+*/
+
+use crate::base::data::*;
+use crate::sandbox::*;
+use crate::script::*;
+use crate::status::*;
+
+//================================================================
+
+use mlua::prelude::*;
+use std::collections::HashMap;
+use std::io::Write;
+
+//================================================================
+
+/* class
+{ "version": "1.0.0", "name": "quiver.save", "info": "The save API." }
+*/
+#[rustfmt::skip]
+pub fn set_global(lua: &Lua, table: &mlua::Table, _: &StatusInfo, _: Option<&ScriptInfo>) -> mlua::Result<()> {
+    let save = lua.create_table()?;
+
+    save.set("new", lua.create_function(self::Save::new)?)?;
+
+    table.set("save", save)?;
+
+    Ok(())
+}
+
+/* class
+{
+    "version": "1.0.0",
+    "name": "save",
+    "info": "An unique handle to a save slot. A save is always written atomically, with a version and a checksum. Previous saves are kept as back-up, and will be used if the latest save is corrupt.",
+    "test": "save/save_load.lua"
+}
+*/
+pub struct Save {
+    path: String,
+    version: u32,
+    backup: u32,
+    migration: HashMap<u32, mlua::Function>,
+}
+
+impl mlua::UserData for Save {
+    fn add_fields<F: mlua::UserDataFields<Self>>(field: &mut F) {
+        field.add_field_method_get("path", |_, this| Ok(this.path.clone()));
+        field.add_field_method_get("version", |_, this| Ok(this.version));
+    }
+
+    fn add_methods<M: mlua::UserDataMethods<Self>>(method: &mut M) {
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "save:set_migration",
+            "info": "Set the migration call-back for a given version. On load, every migration from the save's version up to the current version will be run, in order.",
+            "member": [
+                { "name": "version", "info": "The version to migrate from. The call-back must return the data in the next version's form.", "kind": "number"   },
+                { "name": "call",    "info": "The call-back. Must accept the save data, and return the migrated save data.",             "kind": "function" }
+            ]
+        }
+        */
+        method.add_method_mut(
+            "set_migration",
+            |_: &Lua, this, (version, call): (u32, mlua::Function)| {
+                this.migration.insert(version, call);
+                Ok(())
+            },
+        );
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "save:save",
+            "info": "Write a value to the save slot. The current save will be rotated into the back-up list.",
+            "member": [
+                { "name": "value", "info": "The value to save. Must be serializable.", "kind": "any" }
+            ]
+        }
+        */
+        method.add_method("save", |lua: &Lua, this, value: LuaValue| {
+            let value: serde_json::Value = lua.from_value(value)?;
+
+            this.save(&Self::encode(this.version, &value)?)
+        });
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "save:load",
+            "info": "Read the value from the save slot. If the latest save is corrupt, every back-up will be tried, from the most recent one. If the save is from an older version, every migration will be run.",
+            "result": [
+                { "name": "value", "info": "The value. Nil if no save exists.", "kind": "any" }
+            ]
+        }
+        */
+        method.add_method("load", |lua: &Lua, this, _: ()| this.load(lua));
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "save:get_exist",
+            "info": "Check if the save slot, or any back-up, does exist.",
+            "result": [
+                { "name": "exist", "info": "True if the save does exist, false otherwise.", "kind": "boolean" }
+            ]
+        }
+        */
+        method.add_method("get_exist", |_: &Lua, this, _: ()| {
+            Ok(this
+                .get_list()
+                .iter()
+                .any(|path| std::path::Path::new(path).is_file()))
+        });
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "save:remove",
+            "info": "Remove the save slot, and every back-up."
+        }
+        */
+        method.add_method("remove", |_: &Lua, this, _: ()| {
+            for path in this.get_list() {
+                if let Err(error) = std::fs::remove_file(&path) {
+                    if error.kind() != std::io::ErrorKind::NotFound {
+                        return Err(mlua::Error::runtime(error.to_string()));
+                    }
+                }
+            }
+
+            Ok(())
+        });
+    }
+}
+
+impl Save {
+    const MAGIC: &'static [u8; 4] = b"QSAV";
+    // magic, version, checksum, length.
+    const HEADER: usize = 16;
+
+    /* entry
+    {
+        "version": "1.0.0",
+        "name": "quiver.save.new",
+        "info": "Create a new save slot handle. A relative path will be relative to the save path.",
+        "member": [
+            { "name": "path",    "info": "Path to the save slot.",                                          "kind": "string"  },
+            { "name": "version", "info": "The current version of the save data.",                           "kind": "number"  },
+            { "name": "backup",  "info": "OPTIONAL: The amount of back-up saves to keep. Default: 2.",       "kind": "number?" }
+        ],
+        "result": [
+            { "name": "save", "info": "Save slot handle.", "kind": "save" }
+        ]
+    }
+    */
+    fn new(lua: &Lua, (path, version, backup): (String, u32, Option<u32>)) -> mlua::Result<Self> {
+        let path = {
+            let script_data = lua.app_data_ref::<ScriptData>().unwrap();
+            let root = &script_data.sandbox.get_root(Sandbox::ROOT_SAVE).path;
+
+            root.join(path).display().to_string()
+        };
+
+        Ok(Self {
+            path: ScriptData::get_path(lua, &path, SandboxAccess::Write)?,
+            version,
+            backup: backup.unwrap_or(2),
+            migration: HashMap::new(),
+        })
+    }
+
+    // get the path of the save, and then the path of every back-up, from the most recent one.
+    fn get_list(&self) -> Vec<String> {
+        let mut list = vec![self.path.clone()];
+
+        for x in 1..=self.backup {
+            list.push(format!("{}.{x}", self.path));
+        }
+
+        list
+    }
+
+    fn save(&self, data: &[u8]) -> mlua::Result<()> {
+        let path = std::path::Path::new(&self.path);
+        let temp = format!("{}.tmp", self.path);
+
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+
+        // write the save to a temporary file first, and make sure it has reached the disk.
+        let mut file = std::fs::File::create(&temp)?;
+        file.write_all(data)?;
+        file.sync_all()?;
+
+        // rotate every back-up, discarding the oldest one.
+        if path.is_file() && self.backup > 0 {
+            let list = self.get_list();
+
+            for x in (0..list.len() - 1).rev() {
+                if std::path::Path::new(&list[x]).is_file() {
+                    std::fs::rename(&list[x], &list[x + 1])?;
+                }
+            }
+        }
+
+        // a rename is atomic: the save is either the previous one, or the new one, but never a partial one.
+        std::fs::rename(&temp, path)?;
+
+        // make sure the rename itself has reached the disk.
+        #[cfg(unix)]
+        if let Some(parent) = path.parent() {
+            if let Ok(parent) = std::fs::File::open(parent) {
+                parent.sync_all()?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn load(&self, lua: &Lua) -> mlua::Result<LuaValue> {
+        let mut error = Vec::new();
+
+        for path in self.get_list() {
+            match std::fs::read(&path) {
+                Ok(data) => match Self::decode(&data) {
+                    Ok((version, value)) => return self.migrate(lua, version, value),
+                    Err(value) => error.push(format!("\"{path}\": {value}")),
+                },
+                Err(value) => {
+                    if value.kind() != std::io::ErrorKind::NotFound {
+                        error.push(format!("\"{path}\": {value}"));
+                    }
+                }
+            }
+        }
+
+        if error.is_empty() {
+            Ok(mlua::Value::Nil)
+        } else {
+            Err(mlua::Error::runtime(format!(
+                "save:load(): Could not load any save. {}",
+                error.join(", ")
+            )))
+        }
+    }
+
+    fn migrate(&self, lua: &Lua, version: u32, value: serde_json::Value) -> mlua::Result<LuaValue> {
+        if version > self.version {
+            return Err(mlua::Error::runtime(format!(
+                "save:load(): Save version ({version}) is newer than the current version ({}).",
+                self.version
+            )));
+        }
+
+        let mut value = lua.to_value(&value)?;
+
+        for x in version..self.version {
+            if let Some(call) = self.migration.get(&x) {
+                value = call.call(value)?;
+            } else {
+                return Err(mlua::Error::runtime(format!(
+                    "save:load(): No migration from version {x}."
+                )));
+            }
+        }
+
+        Ok(value)
+    }
+
+    fn encode(version: u32, value: &serde_json::Value) -> mlua::Result<Vec<u8>> {
+        let data = serde_json::to_vec(value).map_err(|e| mlua::Error::runtime(e.to_string()))?;
+        let data = compress_buffer(&data);
+
+        let mut result = Vec::with_capacity(Self::HEADER + data.len());
+        result.extend_from_slice(Self::MAGIC);
+        result.extend_from_slice(&version.to_le_bytes());
+        result.extend_from_slice(&hash_buffer(&data).to_le_bytes());
+        result.extend_from_slice(&(data.len() as u32).to_le_bytes());
+        result.extend_from_slice(&data);
+
+        Ok(result)
+    }
+
+    fn decode(data: &[u8]) -> Result<(u32, serde_json::Value), String> {
+        if data.len() < Self::HEADER || &data[0..4] != Self::MAGIC {
+            return Err("Not a save file.".to_string());
+        }
+
+        let get = |index: usize| u32::from_le_bytes(data[index..index + 4].try_into().unwrap());
+        let version = get(4);
+        let hash = get(8);
+        let size = get(12) as usize;
+        let data = &data[Self::HEADER..];
+
+        if data.len() != size {
+            return Err("Save file is truncated.".to_string());
+        }
+
+        if hash_buffer(data) != hash {
+            return Err("Save file checksum mismatch.".to_string());
+        }
+
+        let data = decompress_buffer(data).map_err(|e| e.to_string())?;
+        let data = serde_json::from_slice(&data).map_err(|e| e.to_string())?;
+
+        Ok((version, data))
+    }
+}
@@ -48,6 +48,8 @@
 * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 */
 
+use crate::base::texture::{RenderTexture, Texture};
+use crate::sandbox::*;
 use crate::script::*;
 use crate::status::*;
 
@@ -55,6 +57,9 @@ use crate::status::*;
 
 use mlua::prelude::*;
 use raylib::prelude::*;
+use std::cell::Cell;
+use std::collections::HashMap;
+use std::ffi::c_void;
 
 //================================================================
 
@@ -75,10 +80,20 @@ pub fn set_global(lua: &Lua, table: &mlua::Table, _: &StatusInfo, _: Option<&Scr
 
 pub type RLShader = raylib::shaders::Shader;
 
+thread_local! {
+    // the shader of the inner-most shader:begin call, so that any other shader draw can restore it.
+    static SHADER_ACTIVE: Cell<Option<ffi::Shader>> = const { Cell::new(None) };
+}
+
 /* class
-{ "version": "1.0.0", "name": "shader", "info": "An unique handle for a shader in memory." }
+{
+    "version": "1.0.0",
+    "name": "shader",
+    "info": "An unique handle for a shader in memory.",
+    "test": "shader/uniform.lua"
+}
 */
-pub struct Shader(pub RLShader);
+pub struct Shader(pub RLShader, HashMap<String, i32>);
 
 unsafe impl Send for Shader {}
 
@@ -101,7 +116,11 @@ impl Shader {
         unsafe {
             let v_path = match v_path {
                 Some(name) => {
-                    let pointer = Script::rust_to_c_string(&ScriptData::get_path(lua, &name)?)?;
+                    let pointer = Script::rust_to_c_string(&ScriptData::get_path(
+                        lua,
+                        &name,
+                        SandboxAccess::Read,
+                    )?)?;
 
                     pointer.into_raw()
                 }
@@ -110,7 +129,11 @@ impl Shader {
 
             let f_path = match f_path {
                 Some(name) => {
-                    let pointer = Script::rust_to_c_string(&ScriptData::get_path(lua, &name)?)?;
+                    let pointer = Script::rust_to_c_string(&ScriptData::get_path(
+                        lua,
+                        &name,
+                        SandboxAccess::Read,
+                    )?)?;
 
                     pointer.into_raw()
                 }
@@ -120,7 +143,7 @@ impl Shader {
             let data = ffi::LoadShader(v_path, f_path);
 
             if ffi::IsShaderValid(data) {
-                Ok(Self(RLShader::from_raw(data)))
+                Ok(Self(RLShader::from_raw(data), HashMap::new()))
             } else {
                 Err(mlua::Error::RuntimeError(
                     "Shader::new(): Could not load file.".to_string(),
@@ -133,43 +156,218 @@ impl Shader {
     {
         "version": "1.0.0",
         "name": "quiver.shader.new_from_memory",
-        "info": "TO-DO"
+        "info": "Create a new shader resource, from GLSL source code rather than from a file. Use nil for either stage to use the default one.",
+        "test": "shader/uniform.lua",
+        "member": [
+            { "name": "v_code", "info": "OPTIONAL: The vertex shader source code.",   "kind": "string?" },
+            { "name": "f_code", "info": "OPTIONAL: The fragment shader source code.", "kind": "string?" }
+        ],
+        "result": [
+            { "name": "shader", "info": "Shader resource.", "kind": "shader" }
+        ]
     }
     */
     fn new_from_memory(
-        lua: &Lua,
-        (v_path, f_path): (Option<String>, Option<String>),
+        _: &Lua,
+        (v_code, f_code): (Option<String>, Option<String>),
     ) -> mlua::Result<Self> {
+        // the source code is not a path, so it does not go through the sand-box.
+        let v_code = v_code.map(|x| Script::rust_to_c_string(&x)).transpose()?;
+        let f_code = f_code.map(|x| Script::rust_to_c_string(&x)).transpose()?;
+
         unsafe {
-            let v_path = match v_path {
-                Some(name) => {
-                    let pointer = Script::rust_to_c_string(&ScriptData::get_path(lua, &name)?)?;
+            let data = ffi::LoadShaderFromMemory(
+                v_code.as_ref().map_or(std::ptr::null(), |x| x.as_ptr()),
+                f_code.as_ref().map_or(std::ptr::null(), |x| x.as_ptr()),
+            );
 
-                    pointer.into_raw()
+            if ffi::IsShaderValid(data) {
+                Ok(Self(RLShader::from_raw(data), HashMap::new()))
+            } else {
+                Err(mlua::Error::RuntimeError(
+                    "Shader::new_from_memory(): Could not compile shader.".to_string(),
+                ))
+            }
+        }
+    }
+
+    // the uniform kind for every component count, as float or as integer.
+    #[rustfmt::skip]
+    const UNIFORM_KIND: [[i32; 4]; 2] = [
+        [
+            ffi::ShaderUniformDataType::SHADER_UNIFORM_FLOAT as i32,
+            ffi::ShaderUniformDataType::SHADER_UNIFORM_VEC2  as i32,
+            ffi::ShaderUniformDataType::SHADER_UNIFORM_VEC3  as i32,
+            ffi::ShaderUniformDataType::SHADER_UNIFORM_VEC4  as i32,
+        ],
+        [
+            ffi::ShaderUniformDataType::SHADER_UNIFORM_INT   as i32,
+            ffi::ShaderUniformDataType::SHADER_UNIFORM_IVEC2 as i32,
+            ffi::ShaderUniformDataType::SHADER_UNIFORM_IVEC3 as i32,
+            ffi::ShaderUniformDataType::SHADER_UNIFORM_IVEC4 as i32,
+        ],
+    ];
+
+    // get the location of a uniform, from the cache if possible.
+    fn get_uniform_location(&mut self, name: &str) -> i32 {
+        if let Some(location) = self.1.get(name) {
+            return *location;
+        }
+
+        let location = self.0.get_shader_location(name);
+
+        self.1.insert(name.to_string(), location);
+
+        location
+    }
+
+    // infer a uniform from a Lua value.
+    fn get_uniform(lua: &Lua, value: LuaValue, integer: bool) -> mlua::Result<Uniform> {
+        match value {
+            LuaValue::UserData(data) => {
+                if let Ok(data) = data.borrow::<Texture>() {
+                    Ok(Uniform::Texture(data.0))
+                } else if let Ok(data) = data.borrow::<RenderTexture>() {
+                    Ok(Uniform::Texture(data.0.texture))
+                } else {
+                    Err(mlua::Error::runtime(
+                        "shader:set_uniform(): Value is not a texture or render texture.",
+                    ))
+                }
+            }
+            LuaValue::Table(table) => {
+                if table.contains_key("m0")? {
+                    return Ok(Uniform::Matrix(lua.from_value(LuaValue::Table(table))?));
                 }
-                None => std::ptr::null(),
-            };
 
-            let f_path = match f_path {
-                Some(name) => {
-                    let pointer = Script::rust_to_c_string(&ScriptData::get_path(lua, &name)?)?;
+                if table.raw_len() == 0 {
+                    let (data, component, integer) =
+                        Self::get_element(&LuaValue::Table(table), integer)?;
 
-                    pointer.into_raw()
+                    return Ok(Uniform::Value(data, component, integer));
                 }
-                None => std::ptr::null(),
-            };
 
-            let data = ffi::LoadShaderFromMemory(v_path, f_path);
+                // an array, with every element being of the same kind.
+                let mut data = Vec::new();
+                let mut shape = None;
 
-            if ffi::IsShaderValid(data) {
-                Ok(Self(RLShader::from_raw(data)))
-            } else {
-                Err(mlua::Error::RuntimeError(
-                    "Shader::new_from_memory(): Could not load file.".to_string(),
-                ))
+                for value in table.sequence_values::<LuaValue>() {
+                    let (value, component, integer) = Self::get_element(&value?, integer)?;
+
+                    if *shape.get_or_insert((component, integer)) != (component, integer) {
+                        return Err(mlua::Error::runtime(
+                            "shader:set_uniform(): Every array element must be of the same kind.",
+                        ));
+                    }
+
+                    data.extend(value);
+                }
+
+                let (component, integer) = shape.unwrap();
+
+                Ok(Uniform::Value(data, component, integer))
+            }
+            value => {
+                let (data, component, integer) = Self::get_element(&value, integer)?;
+
+                Ok(Uniform::Value(data, component, integer))
+            }
+        }
+    }
+
+    // infer a single uniform element (number, boolean, vector or color), and get the data, component count, and whether it is an integer.
+    fn get_element(value: &LuaValue, integer: bool) -> mlua::Result<(Vec<f64>, usize, bool)> {
+        match value {
+            LuaValue::Boolean(value) => Ok((vec![*value as i32 as f64], 1, true)),
+            LuaValue::Integer(value) => Ok((vec![*value as f64], 1, integer)),
+            LuaValue::Number(value) => Ok((vec![*value], 1, integer)),
+            LuaValue::Table(table) => {
+                if table.contains_key("x")? {
+                    let mut data = Vec::with_capacity(4);
+
+                    for key in ["x", "y", "z", "w"] {
+                        match table.get::<Option<f64>>(key)? {
+                            Some(value) => data.push(value),
+                            None => break,
+                        }
+                    }
+
+                    if data.len() < 2 {
+                        return Err(mlua::Error::runtime(
+                            "shader:set_uniform(): Vector must have at least an X and Y component.",
+                        ));
+                    }
+
+                    let component = data.len();
+
+                    Ok((data, component, integer))
+                } else if table.contains_key("r")? {
+                    // a color will be normalized to the [0.0, 1.0] range.
+                    let data = ["r", "g", "b", "a"]
+                        .iter()
+                        .map(|key| Ok(table.get::<Option<f64>>(*key)?.unwrap_or(255.0) / 255.0))
+                        .collect::<mlua::Result<Vec<_>>>()?;
+
+                    Ok((data, 4, false))
+                } else {
+                    Err(mlua::Error::runtime(
+                        "shader:set_uniform(): Table is not a vector, color, matrix or array.",
+                    ))
+                }
+            }
+            _ => Err(mlua::Error::runtime(
+                "shader:set_uniform(): Value can not be a uniform.",
+            )),
+        }
+    }
+
+    fn set_uniform(&self, location: i32, uniform: Uniform) {
+        unsafe {
+            match uniform {
+                Uniform::Value(data, component, integer) => {
+                    let kind = Self::UNIFORM_KIND[integer as usize][component - 1];
+                    let count = (data.len() / component) as i32;
+
+                    if integer {
+                        let data: Vec<i32> = data.iter().map(|x| *x as i32).collect();
+                        ffi::SetShaderValueV(
+                            *self.0,
+                            location,
+                            data.as_ptr() as *const c_void,
+                            kind,
+                            count,
+                        );
+                    } else {
+                        let data: Vec<f32> = data.iter().map(|x| *x as f32).collect();
+                        ffi::SetShaderValueV(
+                            *self.0,
+                            location,
+                            data.as_ptr() as *const c_void,
+                            kind,
+                            count,
+                        );
+                    }
+                }
+                Uniform::Matrix(value) => {
+                    ffi::SetShaderValueMatrix(*self.0, location, value.into())
+                }
+                Uniform::Texture(value) => ffi::SetShaderValueTexture(*self.0, location, value),
             }
         }
     }
+
+    // get the shader of the inner-most shader:begin call, if any.
+    pub fn get_active() -> Option<ffi::Shader> {
+        SHADER_ACTIVE.get()
+    }
+}
+
+// a uniform value, as inferred from a Lua value.
+enum Uniform {
+    // the data, the component count (1 to 4), and whether the data is integer.
+    Value(Vec<f64>, usize, bool),
+    Matrix(Matrix),
+    Texture(ffi::Texture2D),
 }
 
 impl mlua::UserData for Shader {
@@ -187,15 +385,26 @@ impl mlua::UserData for Shader {
         }
         */
         method.add_method("begin", |_: &Lua, this, call: mlua::Function| {
+            let previous = SHADER_ACTIVE.replace(Some(*this.0));
+
             unsafe {
                 ffi::BeginShaderMode(*this.0);
+            }
 
-                call.call::<()>(())?;
+            let result = call.call::<()>(());
 
+            // restore the shader of an outer shader:begin call, even on error.
+            unsafe {
                 ffi::EndShaderMode();
+
+                if let Some(previous) = previous {
+                    ffi::BeginShaderMode(previous);
+                }
             }
 
-            Ok(())
+            SHADER_ACTIVE.set(previous);
+
+            result
         });
 
         /* entry
@@ -328,5 +537,48 @@ impl mlua::UserData for Shader {
                 Ok(())
             },
         );
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "shader:set_uniform",
+            "info": "Set a uniform by name. The uniform kind is inferred from the value: a number is a float, a boolean is an int, a vector is a vec2, vec3 or vec4, a color is a normalized vec4, a matrix is a mat4, and a texture or render texture is a sampler. A sequence table of any of these (but a matrix or texture) is an array. Uniform location are cached, and a uniform that does not exist will be ignored.",
+            "member": [
+                { "name": "name",    "info": "The uniform name.",                                                                              "kind": "string"   },
+                { "name": "value",   "info": "The uniform value.",                                                                             "kind": "any"      },
+                { "name": "integer", "info": "OPTIONAL: Set a number or vector as an int or ivec, rather than as a float or vec. Default: false.", "kind": "boolean?" }
+            ]
+        }
+        */
+        method.add_method_mut(
+            "set_uniform",
+            |lua, this, (name, value, integer): (String, LuaValue, Option<bool>)| {
+                let uniform = Self::get_uniform(lua, value, integer.unwrap_or_default())?;
+                let location = this.get_uniform_location(&name);
+
+                if location >= 0 {
+                    this.set_uniform(location, uniform);
+                }
+
+                Ok(())
+            },
+        );
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "shader:get_uniform_location",
+            "info": "Get the location of a uniform by name. Uniform location are cached.",
+            "member": [
+                { "name": "name", "info": "The uniform name.", "kind": "string" }
+            ],
+            "result": [
+                { "name": "location", "info": "The uniform location, or -1 if the uniform does not exist.", "kind": "number" }
+            ]
+        }
+        */
+        method.add_method_mut("get_uniform_location", |_, this, name: String| {
+            Ok(this.get_uniform_location(&name))
+        });
     }
 }
@@ -60,7 +60,7 @@ use tokio::net::{TcpListener, TcpStream, UdpSocket};
 //================================================================
 
 /* class
-{ "version": "1.0.0", "name": "quiver.socket", "info": "The socket API.", "head": true }
+{ "version": "1.0.0", "name": "quiver.socket", "info": "The socket API." }
 */
 #[rustfmt::skip]
 pub fn set_global(lua: &Lua, table: &mlua::Table, _: &StatusInfo, _: Option<&ScriptInfo>) -> mlua::Result<()> {
@@ -48,6 +48,8 @@
 * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 */
 
+use crate::base::audio::{AudioBus, AudioLink, AudioSpatial};
+use crate::sandbox::*;
 use crate::script::*;
 use crate::status::*;
 
@@ -78,7 +80,7 @@ type RLSound = ffi::Sound;
 /* class
 { "version": "1.0.0", "name": "sound", "info": "An unique handle for sound in memory." }
 */
-struct Sound(RLSound, Vec<RLSound>);
+struct Sound(RLSound, Vec<RLSound>, Option<AudioLink>, Vec<AudioSpatial>);
 
 unsafe impl Send for Sound {}
 
@@ -100,7 +102,8 @@ impl Sound {
     */
     async fn new(lua: Lua, (path, alias): (String, Option<usize>)) -> mlua::Result<Self> {
         tokio::task::spawn_blocking(move || unsafe {
-            let name = Script::rust_to_c_string(&ScriptData::get_path(&lua, &path)?)?;
+            let name =
+                Script::rust_to_c_string(&ScriptData::get_path(&lua, &path, SandboxAccess::Read)?)?;
             let data = ffi::LoadSound(name.as_ptr());
             let alias = alias.unwrap_or_default();
             let mut array = Vec::with_capacity(alias);
@@ -112,7 +115,7 @@ impl Sound {
                     array.push(data);
                 }
 
-                Ok(Self(data, array))
+                Ok(Self(data, array, None, Vec::new()))
             } else {
                 Err(mlua::Error::RuntimeError(format!(
                     "Sound::new(): Could not load file \"{path}\"."
@@ -168,7 +171,7 @@ impl Sound {
                         array.push(data);
                     }
 
-                    Ok(Self(sound, array))
+                    Ok(Self(sound, array, None, Vec::new()))
                 } else {
                     Err(mlua::Error::RuntimeError(
                         "Sound::new_from_memory(): Could not load file.".to_string(),
@@ -185,8 +188,32 @@ impl Sound {
     }
 }
 
+impl Sound {
+    // get the sound itself, or a sound alias.
+    fn get_sound(&self, call: &str, alias: Option<usize>) -> mlua::Result<RLSound> {
+        match alias {
+            Some(alias) => self
+                .1
+                .get(alias)
+                .copied()
+                .ok_or(mlua::Error::runtime(format!(
+                    "sound::{call}(): Invalid alias index."
+                ))),
+            None => Ok(self.0),
+        }
+    }
+
+    fn get_spatial(&self, sound: &RLSound) -> Option<&AudioSpatial> {
+        self.3.iter().find(|x| x.is_stream(&sound.stream))
+    }
+}
+
 impl Drop for Sound {
     fn drop(&mut self) {
+        // detach from the bus before the sound is gone.
+        self.3.clear();
+        self.2 = None;
+
         unsafe {
             for alias in &self.1 {
                 ffi::UnloadSoundAlias(*alias);
@@ -210,6 +237,10 @@ impl mlua::UserData for Sound {
             let data = ffi::LoadSoundAlias(this.0);
             this.1.push(data);
 
+            if let Some(link) = &mut this.2 {
+                link.attach(data.stream)?;
+            }
+
             Ok(())
         });
 
@@ -219,6 +250,12 @@ impl mlua::UserData for Sound {
         method.add_method_mut("remove_alias", |_, this, _: ()| unsafe {
             if !this.1.is_empty() {
                 if let Some(alias) = this.1.first() {
+                    if let Some(link) = &mut this.2 {
+                        link.detach(alias.stream);
+                    }
+
+                    this.3.retain(|x| !x.is_stream(&alias.stream));
+
                     ffi::UnloadSoundAlias(*alias);
                     this.1.remove(0);
                 }
@@ -232,6 +269,12 @@ impl mlua::UserData for Sound {
         */
         method.add_method_mut("clear_alias", |_, this, _: ()| unsafe {
             for alias in &this.1 {
+                if let Some(link) = &mut this.2 {
+                    link.detach(alias.stream);
+                }
+
+                this.3.retain(|x| !x.is_stream(&alias.stream));
+
                 ffi::UnloadSoundAlias(*alias);
             }
 
@@ -240,6 +283,35 @@ impl mlua::UserData for Sound {
             Ok(())
         });
 
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "sound:set_bus",
+            "info": "Set the audio bus of the sound, and of every sound alias.",
+            "member": [
+                { "name": "bus", "info": "The audio bus. Use nil to remove the sound from its bus.", "kind": "audio_bus?" }
+            ]
+        }
+        */
+        method.add_method_mut(
+            "set_bus",
+            |_, this, bus: Option<LuaUserDataRef<AudioBus>>| {
+                this.2 = None;
+
+                if let Some(bus) = bus {
+                    let mut link = AudioLink::new(&bus);
+
+                    for sound in std::iter::once(&this.0).chain(&this.1) {
+                        link.attach(sound.stream)?;
+                    }
+
+                    this.2 = Some(link);
+                }
+
+                Ok(())
+            },
+        );
+
         /* entry
         { "version": "1.0.0", "name": "sound:play", "info": "Play the sound." }
         */
@@ -376,16 +448,12 @@ impl mlua::UserData for Sound {
         method.add_method(
             "set_pitch",
             |_, this, (value, alias): (f32, Option<usize>)| unsafe {
-                if let Some(alias) = alias {
-                    if let Some(alias) = this.1.get(alias) {
-                        ffi::SetSoundPitch(*alias, value);
-                    } else {
-                        return Err(mlua::Error::runtime(
-                            "sound::set_pitch(): Invalid alias index.",
-                        ));
-                    }
+                let sound = this.get_sound("set_pitch", alias)?;
+
+                if let Some(spatial) = this.get_spatial(&sound) {
+                    spatial.set_pitch(value);
                 } else {
-                    ffi::SetSoundPitch(this.0, value);
+                    ffi::SetSoundPitch(sound, value);
                 }
 
                 Ok(())
@@ -420,5 +488,56 @@ impl mlua::UserData for Sound {
                 Ok(())
             },
         );
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "sound:set_spatial",
+            "info": "Set the sound (or sound alias) as a spatial source, with distance attenuation, pan and doppler pitch shift from the listener.",
+            "member": [
+                { "name": "option", "info": "The spatial option: { point, velocity?, kind? (ATTENUATION_KIND), distance_min?, distance_max?, rolloff? }. Use nil to remove the spatial source.", "kind": "table?"  },
+                { "name": "alias",  "info": "OPTIONAL: The sound alias index. Default: the sound itself.",                                                                                                       "kind": "number?" }
+            ]
+        }
+        */
+        method.add_method_mut(
+            "set_spatial",
+            |lua, this, (option, alias): (LuaValue, Option<usize>)| {
+                let sound = this.get_sound("set_spatial", alias)?;
+
+                this.3.retain(|x| !x.is_stream(&sound.stream));
+
+                if !option.is_nil() {
+                    this.3
+                        .push(AudioSpatial::new(lua, "set_spatial", sound.stream, option)?);
+                }
+
+                Ok(())
+            },
+        );
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "sound:set_spatial_point",
+            "info": "Set the point of the spatial source of the sound (or sound alias).",
+            "member": [
+                { "name": "point",    "info": "The point of the source.",                                                       "kind": "vector_3"  },
+                { "name": "velocity", "info": "OPTIONAL: The velocity of the source. Default: derived from the last source point.", "kind": "vector_3?" },
+                { "name": "alias",    "info": "OPTIONAL: The sound alias index. Default: the sound itself.",                    "kind": "number?"   }
+            ]
+        }
+        */
+        method.add_method(
+            "set_spatial_point",
+            |lua, this, (point, velocity, alias): (LuaValue, Option<LuaValue>, Option<usize>)| {
+                let sound = this.get_sound("set_spatial_point", alias)?;
+                let spatial = this.get_spatial(&sound).ok_or(mlua::Error::runtime(
+                    "sound::set_spatial_point(): Sound is not a spatial source.",
+                ))?;
+
+                spatial.set_point(lua, point, velocity)
+            },
+        );
     }
 }
@@ -49,6 +49,7 @@
 */
 
 use crate::base::*;
+use crate::sandbox::*;
 use crate::script::*;
 use crate::status::*;
 
@@ -393,7 +394,8 @@ impl Texture {
     }
     */
     fn new(lua: &Lua, path: String) -> mlua::Result<Self> {
-        let name = Script::rust_to_c_string(&ScriptData::get_path(lua, &path)?)?;
+        let name =
+            Script::rust_to_c_string(&ScriptData::get_path(lua, &path, SandboxAccess::Read)?)?;
 
         unsafe {
             let data = ffi::LoadTexture(name.as_ptr());
@@ -0,0 +1,617 @@
+/*
+* Copyright (c) 2025 sockentrocken
+*
+* Redistribution and use in source and binary forms, with or without
+* modification, are permitted provided that the following conditions are met:
+*
+* 1. Redistributions of source code must retain the above copyright notice,
+* this list of conditions and the following disclaimer.
+*
+* 2. Redistributions in binary form must reproduce the above copyright notice,
+* this list of conditions and the following disclaimer in the documentation
+* and/or other materials provided with the distribution.
+*
+* Subject to the terms and conditions of this license, each copyright holder
+* and contributor hereby grants to those receiving rights under this license
+* a perpetual, worldwide, non-exclusive, no-charge, royalty-free, irrevocable
+* (except for failure to satisfy the conditions of this license) patent license
+* to make, have made, use, offer to sell, sell, import, and otherwise transfer
+* this software, where such license applies only to those patent claims, already
+* acquired or hereafter acquired, licensable by such copyright holder or
+* contributor that are necessarily infringed by:
+*
+* (a) their Contribution(s) (the licensed copyrights of copyright holders and
+* non-copyrightable additions of contributors, in source or binary form) alone;
+* or
+*
+* (b) combination of their Contribution(s) with the work of authorship to which
+* such Contribution(s) was added by such copyright holder or contributor, if,
+* at the time the Contribution is added, such addition causes such combination
+* to be necessarily infringed. The patent license shall not apply to any other
+* combinations which include the Contribution.
+*
+* Except as expressly stated above, no rights or licenses from any copyright
+* holder or contributor is granted under this license, whether expressly, by
+* implication, estoppel or otherwise.
+*
+* DISCLAIMER
+*
+* THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+* AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+* IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+* DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDERS OR CONTRIBUTORS BE LIABLE
+* FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+* DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+* SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+* CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+* OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+* OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+*/
+
+use crate::base::data::Data;
+use crate::sandbox::*;
+use crate::script::*;
+use crate::status::*;
+
+//================================================================
+
+use mlua::prelude::*;
+use raylib::prelude::*;
+use std::f32::consts::TAU;
+use std::ffi::c_void;
+
+//================================================================
+
+/* class
+{ "version": "1.0.0", "name": "quiver.wave", "info": "The wave API." }
+*/
+#[rustfmt::skip]
+pub fn set_global(lua: &Lua, table: &mlua::Table, _: &StatusInfo, _: Option<&ScriptInfo>) -> mlua::Result<()> {
+    let wave = lua.create_table()?;
+
+    wave.set("new",             lua.create_async_function(self::Wave::new)?)?;
+    wave.set("new_from_memory", lua.create_async_function(self::Wave::new_from_memory)?)?;
+    wave.set("new_from_data",   lua.create_function(self::Wave::new_from_data)?)?;
+
+    table.set("wave", wave)?;
+
+    Ok(())
+}
+
+type RLWave = ffi::Wave;
+
+// the default, and the largest spectrum size.
+const SPECTRUM_SIZE: usize = 1024;
+const SPECTRUM_SIZE_MAX: usize = 16384;
+
+/* class
+{
+    "version": "1.0.0",
+    "name": "wave",
+    "info": "A sample buffer, decoded from a sound file. Sample are 32-bit float in the [-1.0, 1.0] range, interleaved by channel.",
+    "member": [
+        { "name": "sample_rate", "info": "The sample rate.",             "kind": "number" },
+        { "name": "channel",     "info": "The channel count.",           "kind": "number" },
+        { "name": "frame_count", "info": "The frame count.",             "kind": "number" },
+        { "name": "length",      "info": "The time length, in second.", "kind": "number" }
+    ],
+    "test": "wave/wave.lua"
+}
+*/
+pub struct Wave {
+    data: Vec<f32>,
+    sample_rate: u32,
+    channel: u32,
+}
+
+impl Wave {
+    /* entry
+    {
+        "version": "1.0.0",
+        "name": "quiver.wave.new",
+        "info": "Create a new wave resource.",
+        "member": [
+            { "name": "path", "info": "Path to sound file.", "kind": "string" }
+        ],
+        "result": [
+            { "name": "wave", "info": "Wave resource.", "kind": "wave" }
+        ],
+        "routine": true
+    }
+    */
+    async fn new(lua: Lua, path: String) -> mlua::Result<Self> {
+        tokio::task::spawn_blocking(move || unsafe {
+            let name =
+                Script::rust_to_c_string(&ScriptData::get_path(&lua, &path, SandboxAccess::Read)?)?;
+            let data = ffi::LoadWave(name.as_ptr());
+
+            if ffi::IsWaveValid(data) {
+                Ok(Self::from_wave(data))
+            } else {
+                Err(mlua::Error::RuntimeError(format!(
+                    "Wave::new(): Could not load file \"{path}\"."
+                )))
+            }
+        })
+        .await
+        .unwrap()
+    }
+
+    /* entry
+    {
+        "version": "1.0.0",
+        "name": "quiver.wave.new_from_memory",
+        "info": "Create a new wave resource, from memory.",
+        "member": [
+            { "name": "data", "info": "The data buffer.",                     "kind": "data"   },
+            { "name": "kind", "info": "The kind of sound file (.wav, etc.).", "kind": "string" }
+        ],
+        "result": [
+            { "name": "wave", "info": "Wave resource.", "kind": "wave" }
+        ],
+        "routine": true
+    }
+    */
+    async fn new_from_memory(_: Lua, (data, kind): (LuaValue, String)) -> mlua::Result<Self> {
+        let data = Data::<u8>::get_buffer(data)?;
+
+        tokio::task::spawn_blocking(move || unsafe {
+            let data = &data.0;
+
+            let data = ffi::LoadWaveFromMemory(
+                Script::rust_to_c_string(&kind)?.as_ptr(),
+                data.as_ptr(),
+                data.len() as i32,
+            );
+
+            if ffi::IsWaveValid(data) {
+                Ok(Self::from_wave(data))
+            } else {
+                Err(mlua::Error::RuntimeError(
+                    "Wave::new_from_memory(): Could not load file.".to_string(),
+                ))
+            }
+        })
+        .await
+        .unwrap()
+    }
+
+    /* entry
+    {
+        "version": "1.0.0",
+        "name": "quiver.wave.new_from_data",
+        "info": "Create a new wave resource, from a sample buffer.",
+        "member": [
+            { "name": "sample",      "info": "The sample, as either a data buffer of 32-bit float, or a table of number.", "kind": "data | table" },
+            { "name": "sample_rate", "info": "The sample rate, in Hz.",                                                    "kind": "number"       },
+            { "name": "channel",     "info": "The channel count.",                                                         "kind": "number"       }
+        ],
+        "result": [
+            { "name": "wave", "info": "Wave resource.", "kind": "wave" }
+        ]
+    }
+    */
+    fn new_from_data(
+        _: &Lua,
+        (sample, sample_rate, channel): (LuaValue, u32, u32),
+    ) -> mlua::Result<Self> {
+        if sample_rate == 0 || channel == 0 {
+            return Err(mlua::Error::runtime(
+                "quiver.wave.new_from_data(): Sample rate and channel count must be greater than zero.",
+            ));
+        }
+
+        let data = match sample {
+            LuaValue::Table(table) => table
+                .sequence_values::<f32>()
+                .collect::<mlua::Result<_>>()?,
+            value => Data::<f32>::get_buffer(value)?.0.clone(),
+        };
+
+        if data.len() % channel as usize != 0 {
+            return Err(mlua::Error::runtime(
+                "quiver.wave.new_from_data(): Sample count must be a multiple of the channel count.",
+            ));
+        }
+
+        Ok(Self {
+            data,
+            sample_rate,
+            channel,
+        })
+    }
+
+    // take every sample out of a raylib wave, and unload it.
+    unsafe fn from_wave(wave: RLWave) -> Self {
+        unsafe {
+            let sample = ffi::LoadWaveSamples(wave);
+            let length = wave.frameCount as usize * wave.channels as usize;
+            let data = std::slice::from_raw_parts(sample, length).to_vec();
+
+            ffi::UnloadWaveSamples(sample);
+            ffi::UnloadWave(wave);
+
+            Self {
+                data,
+                sample_rate: wave.sampleRate,
+                channel: wave.channels,
+            }
+        }
+    }
+
+    fn get_frame_count(&self) -> usize {
+        self.data.len() / self.channel as usize
+    }
+
+    // get the sample in a frame range, with the whole wave by default.
+    fn get_range(
+        &self,
+        call: &str,
+        frame_a: Option<usize>,
+        frame_b: Option<usize>,
+    ) -> mlua::Result<&[f32]> {
+        let channel = self.channel as usize;
+        let frame_a = frame_a.unwrap_or_default();
+        let frame_b = frame_b.unwrap_or(self.get_frame_count());
+
+        frame_a
+            .checked_mul(channel)
+            .zip(frame_b.checked_mul(channel))
+            .and_then(|(index_a, index_b)| self.data.get(index_a..index_b))
+            .ok_or(mlua::Error::runtime(format!(
+                "wave:{call}(): Invalid frame range."
+            )))
+    }
+
+    fn crop(&mut self, frame_a: usize, frame_b: usize) -> mlua::Result<()> {
+        self.data = self
+            .get_range("crop", Some(frame_a), Some(frame_b))?
+            .to_vec();
+
+        Ok(())
+    }
+
+    // resample with a linear interpolation between every frame.
+    fn resample(&mut self, sample_rate: u32) -> mlua::Result<()> {
+        if sample_rate == 0 {
+            return Err(mlua::Error::runtime(
+                "wave:resample(): Sample rate must be greater than zero.",
+            ));
+        }
+
+        let channel = self.channel as usize;
+        let frame = self.get_frame_count();
+        let count = (frame as u64 * sample_rate as u64 / self.sample_rate as u64) as usize;
+        let step = self.sample_rate as f64 / sample_rate as f64;
+        let mut data = Vec::with_capacity(count * channel);
+
+        for i in 0..count {
+            let time = i as f64 * step;
+            let a = (time as usize).min(frame - 1);
+            let b = (a + 1).min(frame - 1);
+            let delta = (time - a as f64) as f32;
+
+            for c in 0..channel {
+                let value_a = self.data[a * channel + c];
+                let value_b = self.data[b * channel + c];
+
+                data.push(value_a + (value_b - value_a) * delta);
+            }
+        }
+
+        self.data = data;
+        self.sample_rate = sample_rate;
+
+        Ok(())
+    }
+
+    // convert to another channel count: down-mix to mono, duplicate from mono, or else map each channel to the nearest one.
+    fn set_channel(&mut self, channel: u32) -> mlua::Result<()> {
+        if channel == 0 {
+            return Err(mlua::Error::runtime(
+                "wave:set_channel(): Channel count must be greater than zero.",
+            ));
+        }
+
+        let source = self.channel as usize;
+        let target = channel as usize;
+        let mut data = Vec::with_capacity(self.get_frame_count() * target);
+
+        for frame in self.data.chunks_exact(source) {
+            if target == 1 {
+                data.push(frame.iter().sum::<f32>() / source as f32);
+            } else {
+                for c in 0..target {
+                    data.push(frame[c.min(source - 1)]);
+                }
+            }
+        }
+
+        self.data = data;
+        self.channel = channel;
+
+        Ok(())
+    }
+
+    // get a down-mix of every channel, from a frame onward.
+    fn get_mono(&self, frame: usize, size: usize) -> Vec<f32> {
+        let channel = self.channel as usize;
+
+        self.data
+            .chunks_exact(channel)
+            .skip(frame)
+            .take(size)
+            .map(|x| x.iter().sum::<f32>() / channel as f32)
+            .collect()
+    }
+}
+
+impl mlua::UserData for Wave {
+    fn add_fields<F: mlua::UserDataFields<Self>>(field: &mut F) {
+        field.add_field_method_get("sample_rate", |_, this| Ok(this.sample_rate));
+        field.add_field_method_get("channel", |_, this| Ok(this.channel));
+        field.add_field_method_get("frame_count", |_, this| Ok(this.get_frame_count()));
+        field.add_field_method_get("length", |_, this| {
+            Ok(this.get_frame_count() as f32 / this.sample_rate as f32)
+        });
+    }
+
+    fn add_methods<M: mlua::UserDataMethods<Self>>(method: &mut M) {
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "wave:get_data",
+            "info": "Get the sample buffer of the wave.",
+            "result": [
+                { "name": "data", "info": "The sample, as a data buffer of 32-bit float.", "kind": "data" }
+            ]
+        }
+        */
+        method.add_method("get_data", |lua, this, _: ()| {
+            Data::new(lua, this.data.clone())
+        });
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "wave:copy",
+            "info": "Get a copy of the wave.",
+            "result": [
+                { "name": "wave", "info": "Wave resource.", "kind": "wave" }
+            ]
+        }
+        */
+        method.add_method("copy", |_, this, _: ()| {
+            Ok(Self {
+                data: this.data.clone(),
+                sample_rate: this.sample_rate,
+                channel: this.channel,
+            })
+        });
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "wave:crop",
+            "info": "Crop the wave to a frame range.",
+            "member": [
+                { "name": "frame_a", "info": "The first frame, inclusive.", "kind": "number" },
+                { "name": "frame_b", "info": "The last frame, exclusive.",  "kind": "number" }
+            ]
+        }
+        */
+        method.add_method_mut("crop", |_, this, (frame_a, frame_b): (usize, usize)| {
+            this.crop(frame_a, frame_b)
+        });
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "wave:resample",
+            "info": "Resample the wave to another sample rate.",
+            "member": [
+                { "name": "sample_rate", "info": "The sample rate, in Hz.", "kind": "number" }
+            ]
+        }
+        */
+        method.add_method_mut("resample", |_, this, sample_rate: u32| {
+            this.resample(sample_rate)
+        });
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "wave:set_channel",
+            "info": "Convert the wave to another channel count. Converting to mono will down-mix every channel.",
+            "member": [
+                { "name": "channel", "info": "The channel count.", "kind": "number" }
+            ]
+        }
+        */
+        method.add_method_mut("set_channel", |_, this, channel: u32| {
+            this.set_channel(channel)
+        });
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "wave:export",
+            "info": "Export the wave to a file. The format is picked from the extension (.wav or .raw). The wave is written with 32-bit float samples, so .qoa (16-bit only) is not supported.",
+            "member": [
+                { "name": "path", "info": "Path to the file.", "kind": "string" }
+            ]
+        }
+        */
+        method.add_method("export", |lua, this, path: String| unsafe {
+            let name =
+                Script::rust_to_c_string(&ScriptData::get_path(lua, &path, SandboxAccess::Write)?)?;
+            let wave = RLWave {
+                frameCount: this.get_frame_count() as u32,
+                sampleRate: this.sample_rate,
+                sampleSize: 32,
+                channels: this.channel,
+                data: this.data.as_ptr() as *mut c_void,
+            };
+
+            if ffi::ExportWave(wave, name.as_ptr()) {
+                Ok(())
+            } else {
+                Err(mlua::Error::RuntimeError(format!(
+                    "wave:export(): Could not export file \"{path}\"."
+                )))
+            }
+        });
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "wave:get_rms",
+            "info": "Get the RMS (root mean square) level of the wave, across every channel.",
+            "member": [
+                { "name": "frame_a", "info": "OPTIONAL: The first frame, inclusive. Default: the first frame.", "kind": "number?" },
+                { "name": "frame_b", "info": "OPTIONAL: The last frame, exclusive. Default: the last frame.",   "kind": "number?" }
+            ],
+            "result": [
+                { "name": "rms", "info": "The RMS level.", "kind": "number" }
+            ]
+        }
+        */
+        method.add_method(
+            "get_rms",
+            |_, this, (frame_a, frame_b): (Option<usize>, Option<usize>)| {
+                Ok(get_rms(this.get_range("get_rms", frame_a, frame_b)?))
+            },
+        );
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "wave:get_peak",
+            "info": "Get the peak level of the wave, across every channel.",
+            "member": [
+                { "name": "frame_a", "info": "OPTIONAL: The first frame, inclusive. Default: the first frame.", "kind": "number?" },
+                { "name": "frame_b", "info": "OPTIONAL: The last frame, exclusive. Default: the last frame.",   "kind": "number?" }
+            ],
+            "result": [
+                { "name": "peak", "info": "The peak level.", "kind": "number" }
+            ]
+        }
+        */
+        method.add_method(
+            "get_peak",
+            |_, this, (frame_a, frame_b): (Option<usize>, Option<usize>)| {
+                Ok(get_peak(this.get_range("get_peak", frame_a, frame_b)?))
+            },
+        );
+
+        /* entry
+        {
+            "version": "1.0.0",
+            "name": "wave:get_spectrum",
+            "info": "Get the magnitude spectrum of the wave, from a frame onward, with every channel down-mixed. Any frame past the end of the wave will be silence.",
+            "member": [
+                { "name": "frame", "info": "The first frame.",                                                         "kind": "number"  },
+                { "name": "size",  "info": "OPTIONAL: The FFT size, as a power of two (up to 16384). Default: 1024.", "kind": "number?" }
+            ],
+            "result": [
+                { "name": "spectrum", "info": "The magnitude of every frequency bin (size / 2), each bin being sample_rate / size Hz wide.", "kind": "table" }
+            ]
+        }
+        */
+        method.add_method(
+            "get_spectrum",
+            |_, this, (frame, size): (usize, Option<usize>)| {
+                let size = get_size("wave:get_spectrum", size)?;
+
+                Ok(get_spectrum(&this.get_mono(frame, size), size))
+            },
+        );
+    }
+}
+
+//================================================================
+
+// validate an FFT size, with a default size.
+pub fn get_size(call: &str, size: Option<usize>) -> mlua::Result<usize> {
+    let size = size.unwrap_or(SPECTRUM_SIZE);
+
+    if size < 2 || size > SPECTRUM_SIZE_MAX || !size.is_power_of_two() {
+        return Err(mlua::Error::runtime(format!(
+            "{call}(): Size must be a power of two, up to {SPECTRUM_SIZE_MAX}."
+        )));
+    }
+
+    Ok(size)
+}
+
+pub fn get_rms(data: &[f32]) -> f32 {
+    if data.is_empty() {
+        return 0.0;
+    }
+
+    (data.iter().map(|x| x * x).sum::<f32>() / data.len() as f32).sqrt()
+}
+
+pub fn get_peak(data: &[f32]) -> f32 {
+    data.iter().fold(0.0_f32, |peak, x| peak.max(x.abs()))
+}
+
+// get the magnitude spectrum of a mono sample buffer, with a Hann window, through a radix-2 FFT. a short buffer will be padded with silence.
+pub fn get_spectrum(data: &[f32], size: usize) -> Vec<f32> {
+    let mut real = vec![0.0_f32; size];
+    let mut imag = vec![0.0_f32; size];
+    let mut scale = 0.0;
+
+    for (i, value) in real.iter_mut().enumerate() {
+        let window = 0.5 - 0.5 * (TAU * i as f32 / (size - 1) as f32).cos();
+
+        *value = data.get(i).copied().unwrap_or_default() * window;
+        scale += window;
+    }
+
+    // bit-reversal permutation.
+    let mut j = 0;
+
+    for i in 1..size {
+        let mut bit = size >> 1;
+
+        while j & bit != 0 {
+            j ^= bit;
+            bit >>= 1;
+        }
+
+        j |= bit;
+
+        if i < j {
+            real.swap(i, j);
+        }
+    }
+
+    let mut length = 2;
+
+    while length <= size {
+        let angle = -TAU / length as f32;
+
+        for start in (0..size).step_by(length) {
+            for k in 0..length / 2 {
+                let (sin, cos) = (angle * k as f32).sin_cos();
+                let a = start + k;
+                let b = a + length / 2;
+                let value_real = real[b] * cos - imag[b] * sin;
+                let value_imag = real[b] * sin + imag[b] * cos;
+
+                real[b] = real[a] - value_real;
+                imag[b] = imag[a] - value_imag;
+                real[a] += value_real;
+                imag[a] += value_imag;
+            }
+        }
+
+        length <<= 1;
+    }
+
+    // scale by the window, so that a full-scale sine will have a magnitude of 1.0.
+    (0..size / 2)
+        .map(|k| real[k].hypot(imag[k]) * 2.0 / scale)
+        .collect()
+}
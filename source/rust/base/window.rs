@@ -48,6 +48,7 @@
 * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 */
 
+use crate::sandbox::*;
 use crate::script::*;
 use crate::status::*;
 
@@ -55,10 +56,7 @@ use crate::status::*;
 
 use mlua::prelude::*;
 use raylib::prelude::*;
-use std::{
-    collections::HashMap,
-    ffi::CStr,
-};
+use std::{collections::HashMap, ffi::CStr};
 
 //================================================================
 
@@ -856,7 +854,7 @@ fn get_screen_name(_: &Lua, index: i32) -> mlua::Result<String> {
 */
 fn get_screen_shot(lua: &Lua, path: String) -> mlua::Result<()> {
     unsafe {
-        let path = ScriptData::get_path(lua, &path)?;
+        let path = ScriptData::get_path(lua, &path, SandboxAccess::Write)?;
         let path = Script::rust_to_c_string(&path)?;
         ffi::TakeScreenshot(path.as_ptr());
         Ok(())
@@ -48,6 +48,7 @@
 * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 */
 
+use crate::sandbox::*;
 use crate::script::*;
 use crate::status::*;
 
@@ -201,7 +202,7 @@ impl Zip {
     }
     */
     fn new(lua: &Lua, path: String) -> mlua::Result<Self> {
-        let file = std::fs::File::open(ScriptData::get_path(lua, &path)?)?;
+        let file = std::fs::File::open(ScriptData::get_path(lua, &path, SandboxAccess::Read)?)?;
         let file = zip::ZipArchive::new(file).map_err(|e| mlua::Error::runtime(e.to_string()))?;
 
         Ok(Self(file))
@@ -49,6 +49,7 @@
 */
 
 mod base;
+mod sandbox;
 mod script;
 mod status;
 mod test;
@@ -57,6 +58,7 @@ mod window;
 //================================================================
 
 use crate::status::*;
+use crate::test::*;
 
 //================================================================
 
@@ -64,6 +66,11 @@ use crate::status::*;
 #[rustfmt::skip]
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    // test mode: run every Lua test file in a folder, then exit.
+    if let Some(test) = TestInfo::new() {
+        std::process::exit(if test.run().await { 0 } else { 1 });
+    }
+
     // create the Quiver state.
     let mut status = Status::new().await;
 
@@ -99,7 +106,9 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                         status = state;
                     }
                 } else {
-                    panic!("{error:?}")
+                    // no RL context (head-less mode), write the error out and exit with a failure code.
+                    eprintln!("{error}");
+                    std::process::exit(1);
                 }
             }
             // closure status: break the infinite loop and close.
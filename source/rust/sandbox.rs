@@ -0,0 +1,347 @@
+/*
+* Copyright (c) 2025 sockentrocken
+*
+* Redistribution and use in source and binary forms, with or without
+* modification, are permitted provided that the following conditions are met:
+*
+* 1. Redistributions of source code must retain the above copyright notice,
+* this list of conditions and the following disclaimer.
+*
+* 2. Redistributions in binary form must reproduce the above copyright notice,
+* this list of conditions and the following disclaimer in the documentation
+* and/or other materials provided with the distribution.
+*
+* Subject to the terms and conditions of this license, each copyright holder
+* and contributor hereby grants to those receiving rights under this license
+* a perpetual, worldwide, non-exclusive, no-charge, royalty-free, irrevocable
+* (except for failure to satisfy the conditions of this license) patent license
+* to make, have made, use, offer to sell, sell, import, and otherwise transfer
+* this software, where such license applies only to those patent claims, already
+* acquired or hereafter acquired, licensable by such copyright holder or
+* contributor that are necessarily infringed by:
+*
+* (a) their Contribution(s) (the licensed copyrights of copyright holders and
+* non-copyrightable additions of contributors, in source or binary form) alone;
+* or
+*
+* (b) combination of their Contribution(s) with the work of authorship to which
+* such Contribution(s) was added by such copyright holder or contributor, if,
+* at the time the Contribution is added, such addition causes such combination
+* to be necessarily infringed. The patent license shall not apply to any other
+* combinations which include the Contribution.
+*
+* Except as expressly stated above, no rights or licenses from any copyright
+* holder or contributor is granted under this license, whether expressly, by
+* implication, estoppel or otherwise.
+*
+* DISCLAIMER
+*
+* THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+* AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+* IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+* DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDERS OR CONTRIBUTORS BE LIABLE
+* FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+* DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+* SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+* CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+* OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+* OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+*/
+
+use crate::script::*;
+use crate::status::*;
+
+//================================================================
+
+use serde::{Deserialize, Serialize};
+use std::path::{Component, Path, PathBuf};
+
+//================================================================
+
+#[derive(Copy, Clone)]
+pub enum SandboxAccess {
+    Read,
+    Write,
+}
+
+impl std::fmt::Display for SandboxAccess {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Read => write!(f, "read"),
+            Self::Write => write!(f, "write"),
+        }
+    }
+}
+
+// the read/write permission of a sand-box root.
+#[derive(Serialize, Deserialize, Clone)]
+pub struct SandboxRule {
+    #[serde(default = "SandboxRule::read")]
+    pub read: bool,
+    #[serde(default = "SandboxRule::write")]
+    pub write: bool,
+}
+
+#[rustfmt::skip]
+impl SandboxRule {
+    fn read()  -> bool { true }
+    fn write() -> bool { true }
+
+    fn get(&self, access: SandboxAccess) -> bool {
+        match access {
+            SandboxAccess::Read  => self.read,
+            SandboxAccess::Write => self.write,
+        }
+    }
+}
+
+impl Default for SandboxRule {
+    fn default() -> Self {
+        Self {
+            read: Self::read(),
+            write: Self::write(),
+        }
+    }
+}
+
+// the "sandbox" entry in the info manifest, with the permission for each sand-box root.
+#[derive(Serialize, Deserialize, Default, Clone)]
+pub struct SandboxInfo {
+    #[serde(default)]
+    pub project: SandboxRule,
+    #[serde(default)]
+    pub save: SandboxRule,
+    #[serde(default)]
+    pub config: SandboxRule,
+    #[serde(default)]
+    pub cache: SandboxRule,
+    #[serde(default)]
+    pub temp: SandboxRule,
+}
+
+// a per-user directory kind.
+#[derive(Copy, Clone)]
+pub enum SandboxUser {
+    Data,
+    Config,
+    Cache,
+}
+
+//================================================================
+
+#[derive(Serialize, Clone)]
+pub struct SandboxRoot {
+    pub name: &'static str,
+    pub path: PathBuf,
+    #[serde(skip)]
+    real: PathBuf,
+    pub rule: SandboxRule,
+}
+
+impl SandboxRoot {
+    fn new(name: &'static str, path: PathBuf, rule: SandboxRule) -> Self {
+        let path = Sandbox::normalize(&path);
+        let real = Sandbox::canonicalize(&path);
+
+        Self {
+            name,
+            path,
+            real,
+            rule,
+        }
+    }
+}
+
+#[derive(Serialize, Clone)]
+pub struct Sandbox {
+    pub safe: bool,
+    pub root: Vec<SandboxRoot>,
+}
+
+impl Sandbox {
+    pub const ROOT_PROJECT: &'static str = "project";
+    pub const ROOT_SAVE: &'static str = "save";
+    pub const ROOT_CONFIG: &'static str = "config";
+    pub const ROOT_CACHE: &'static str = "cache";
+    pub const ROOT_TEMP: &'static str = "temp";
+
+    pub fn new(status_info: &StatusInfo, script_info: &ScriptInfo) -> Self {
+        let info = &status_info.sandbox;
+        let name = Self::get_name(&script_info.name);
+        let project = std::path::absolute(&status_info.path)
+            .unwrap_or_else(|_| PathBuf::from(&status_info.path));
+
+        Self {
+            safe: status_info.safe,
+            root: vec![
+                SandboxRoot::new(Self::ROOT_PROJECT, project, info.project.clone()),
+                SandboxRoot::new(
+                    Self::ROOT_SAVE,
+                    Self::get_user_path(SandboxUser::Data, &name),
+                    info.save.clone(),
+                ),
+                SandboxRoot::new(
+                    Self::ROOT_CONFIG,
+                    Self::get_user_path(SandboxUser::Config, &name),
+                    info.config.clone(),
+                ),
+                SandboxRoot::new(
+                    Self::ROOT_CACHE,
+                    Self::get_user_path(SandboxUser::Cache, &name),
+                    info.cache.clone(),
+                ),
+                SandboxRoot::new(
+                    Self::ROOT_TEMP,
+                    std::env::temp_dir().join("quiver").join(&name),
+                    info.temp.clone(),
+                ),
+            ],
+        }
+    }
+
+    // resolve a path from Lua. a relative path is always relative to the project root. in safe mode, the path must be within a sand-box root that does allow the given access.
+    pub fn get_path(&self, path: &str, access: SandboxAccess) -> mlua::Result<String> {
+        let full = Path::new(path);
+        let full = if full.is_absolute() {
+            full.to_path_buf()
+        } else {
+            self.get_root(Self::ROOT_PROJECT).path.join(full)
+        };
+        let full = Self::normalize(&full);
+
+        if !self.safe {
+            return Ok(full.display().to_string());
+        }
+
+        // check against the real path, so that a symbolic link can not be used to leave a root.
+        let real = Self::canonicalize(&full);
+
+        // pick the most specific root, in case of a root being inside another one.
+        let root = self
+            .root
+            .iter()
+            .filter(|root| real.starts_with(&root.real))
+            .max_by_key(|root| root.real.components().count());
+
+        match root {
+            Some(root) => {
+                if root.rule.get(access) {
+                    Ok(full.display().to_string())
+                } else {
+                    Err(mlua::Error::runtime(format!(
+                        "Sandbox: No {access} access to path \"{path}\" in root \"{}\".",
+                        root.name
+                    )))
+                }
+            }
+            None => Err(mlua::Error::runtime(format!(
+                "Sandbox: Path \"{path}\" is not within any root."
+            ))),
+        }
+    }
+
+    pub fn get_root(&self, name: &str) -> &SandboxRoot {
+        self.root
+            .iter()
+            .find(|root| root.name == name)
+            .unwrap_or_else(|| panic!("Sandbox::get_root(): Unknown root \"{name}\"."))
+    }
+
+    // get a file-system safe version of the game's name.
+    pub fn get_name(name: &str) -> String {
+        name.chars()
+            .map(|c| {
+                if c.is_alphanumeric() || c == '-' || c == '_' || c == ' ' {
+                    c
+                } else {
+                    '_'
+                }
+            })
+            .collect()
+    }
+
+    // get a per-user path for the game, following the XDG base directory specification on Linux, and the platform's convention otherwise.
+    pub fn get_user_path(kind: SandboxUser, name: &str) -> PathBuf {
+        let home = std::env::var_os("HOME").map(PathBuf::from);
+        let folder = match kind {
+            SandboxUser::Data => "data",
+            SandboxUser::Config => "config",
+            SandboxUser::Cache => "cache",
+        };
+
+        // Windows and macOS have a single base path for both data and configuration, so each kind gets its own sub-folder.
+        let path = if cfg!(target_os = "windows") {
+            match kind {
+                SandboxUser::Data | SandboxUser::Config => std::env::var_os("APPDATA"),
+                SandboxUser::Cache => std::env::var_os("LOCALAPPDATA"),
+            }
+            .map(|path| PathBuf::from(path).join(name).join(folder))
+        } else if cfg!(target_os = "macos") {
+            match kind {
+                SandboxUser::Data | SandboxUser::Config => {
+                    home.map(|home| home.join("Library/Application Support"))
+                }
+                SandboxUser::Cache => home.map(|home| home.join("Library/Caches")),
+            }
+            .map(|path| path.join(name).join(folder))
+        } else {
+            let (variable, fallback) = match kind {
+                SandboxUser::Data => ("XDG_DATA_HOME", ".local/share"),
+                SandboxUser::Config => ("XDG_CONFIG_HOME", ".config"),
+                SandboxUser::Cache => ("XDG_CACHE_HOME", ".cache"),
+            };
+
+            // a relative path in an XDG variable is invalid, and must be ignored.
+            std::env::var_os(variable)
+                .map(PathBuf::from)
+                .filter(|path| path.is_absolute())
+                .or_else(|| home.map(|home| home.join(fallback)))
+                .map(|path| path.join(name))
+        };
+
+        // with no per-user path at all, fall back to a folder apart from the temporary path root.
+        path.unwrap_or_else(|| {
+            std::env::temp_dir()
+                .join("quiver_user")
+                .join(name)
+                .join(folder)
+        })
+    }
+
+    // resolve every "." and ".." in a path, without touching the file-system.
+    fn normalize(path: &Path) -> PathBuf {
+        let mut result = PathBuf::new();
+
+        for component in path.components() {
+            match component {
+                Component::CurDir => {}
+                Component::ParentDir => {
+                    result.pop();
+                }
+                _ => result.push(component),
+            }
+        }
+
+        result
+    }
+
+    // resolve every symbolic link in the longest part of the path that does exist.
+    fn canonicalize(path: &Path) -> PathBuf {
+        let mut head = path.to_path_buf();
+        let mut tail = Vec::new();
+
+        loop {
+            if let Ok(real) = std::fs::canonicalize(&head) {
+                return tail.iter().rev().fold(real, |real, name| real.join(name));
+            }
+
+            match (head.file_name(), head.parent()) {
+                (Some(name), Some(parent)) => {
+                    tail.push(name.to_os_string());
+                    head = parent.to_path_buf();
+                }
+                _ => return path.to_path_buf(),
+            }
+        }
+    }
+}
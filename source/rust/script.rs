@@ -49,7 +49,9 @@
 */
 
 use crate::base::*;
+use crate::sandbox::*;
 use crate::status::*;
+use crate::test::*;
 
 //================================================================
 
@@ -63,6 +65,7 @@ use std::io::Read;
 use mlua::prelude::*;
 use serde::Serialize;
 use std::ffi::{CStr, CString};
+use std::path::{Component, Path, PathBuf};
 
 //================================================================
 
@@ -111,42 +114,79 @@ impl Script {
         include_str!(concat!(env!("OUT_DIR"), "/main.lua")),
     );
     const FILE_META: &'static str = include_str!("../lua/meta.lua");
+    const FILE_TEST: &'static str = include_str!("../lua/test.lua");
     const NAME_MAIN: &'static str = "main.lua";
     const NAME_META: &'static str = "meta.lua";
+    const NAME_TEST: &'static str = "test.lua";
     const CALL_MAIN: &'static str = "main";
     const CALL_INFO: &'static str = "info";
     const CALL_FAIL: &'static str = "fail";
+    pub const NAME_MOUNT: &'static str = "quiver.mount";
+    // every table from the head API, which require a window and an audio device.
+    const LIST_HEAD: [&'static str; 16] = [
+        "window",
+        "draw",
+        "draw_3d",
+        "draw_2d",
+        "input",
+        "model",
+        "model_animation",
+        "texture",
+        "render_texture",
+        "image",
+        "sound",
+        "music",
+        "font",
+        "shader",
+        "automation",
+        "audio",
+    ];
 
     //================================================================
 
-    #[allow(dead_code)]
-    pub async fn new_test(path: &str) -> mlua::Result<()> {
+    // run a test file in its own Lua VM, and get back a Vec<TestCase> for the file: one for each test case in it,
+    // or a single one named after the file if it has none (or if the file itself failed outside of a test case).
+    pub async fn new_test(path: &str, status_info: &StatusInfo) -> mlua::Result<Vec<TestCase>> {
         // initialize lua VM, depending on what safe flag is set.
         let lua = Lua::new_with(LuaStdLib::ALL_SAFE, LuaOptions::new())?;
 
-        let status_info = StatusInfo {
-            safe: true,
-            path: "test/asset".to_string(),
-        };
-
-        let quiver = Self::set_environment(&lua, &status_info)?;
+        let quiver = Self::set_environment(&lua, status_info)?;
 
-        let script_info = ScriptInfo::default();
+        let script_info = ScriptInfo {
+            head: !status_info.head_less,
+            ..Default::default()
+        };
 
         // set script data.
         lua.set_app_data(ScriptData::new(status_info.clone(), script_info.clone()));
 
         // set the standard Quiver library.
-        Self::system(&lua, &quiver, &status_info, Some(&script_info))?;
+        Self::system(&lua, &quiver, status_info, Some(&script_info))?;
 
-        let main_data = std::fs::read(path)
-            .unwrap_or_else(|_| panic!("Script::new_test(): Could not read file \"{path}\""));
+        let main_data = std::fs::read(path).map_err(|e| {
+            mlua::Error::runtime(format!(
+                "Script::new_test(): Could not read file \"{path}\": {e}"
+            ))
+        })?;
 
         lua.load("quiver.general.load_base()").exec()?;
 
-        lua.load(main_data).exec_async().await?;
+        // set the test API.
+        let test: mlua::Table = lua
+            .load(Self::FILE_TEST)
+            .set_name(format!("@{}", Self::NAME_TEST))
+            .eval()?;
 
-        Ok(())
+        let result = lua
+            .load(main_data)
+            .set_name(format!("@{path}"))
+            .exec_async()
+            .await;
+
+        let finish: mlua::Function = test.get("finish")?;
+        let result: LuaValue = finish.call((path, result.err().map(|e| e.to_string())))?;
+
+        lua.from_value(result)
     }
 
     // get a new script instance.
@@ -162,19 +202,33 @@ impl Script {
             }
         };
 
+        // resolve the project root once, before changing directory, as a relative path would otherwise be resolved against itself.
+        let status_info = &StatusInfo {
+            path: Self::get_root(&status_info.path).display().to_string(),
+            ..status_info.clone()
+        };
+
+        let quiver = Self::set_environment(&lua, status_info)?;
+
         unsafe {
-            // this is causing lua to be unable to load main.lua initially...?
+            // the file API is still relative to the work path, but "require" will always use the project root.
             raylib::ffi::ChangeDirectory(Script::rust_to_c_string(&status_info.path)?.as_ptr());
         }
 
-        let quiver = Self::set_environment(&lua, status_info)?;
+        // set the default script data, for any file access before the info manifest is known.
+        lua.set_app_data(ScriptData::new(status_info.clone(), ScriptInfo::default()));
 
         // set the standard Quiver library.
         Self::system(&lua, &quiver, status_info, None)?;
 
         lua.load(Self::get_main_data(status_info)?).exec()?;
 
-        let script_info = Self::get_script_info(&lua, &quiver).await?;
+        let mut script_info = Self::get_script_info(&lua, &quiver).await?;
+
+        // head-less mode from the info file or the command line will always take priority.
+        if status_info.head_less {
+            script_info.head = false;
+        }
 
         // set script data.
         lua.set_app_data(ScriptData::new(status_info.clone(), script_info.clone()));
@@ -224,6 +278,7 @@ impl Script {
         StatusInfo {
             safe: true,
             path: path.to_string(),
+            ..Default::default()
         }
         .dump();
     }
@@ -234,10 +289,16 @@ impl Script {
         StatusInfo {
             safe: true,
             path: path.to_string(),
+            ..Default::default()
         }
         .dump();
     }
 
+    // check if the head API should be loaded. the head-less pass will load it, unless head-less mode has been set from the info file or the command line.
+    pub fn get_head(status_info: &StatusInfo, script_info: Option<&ScriptInfo>) -> bool {
+        !status_info.head_less && script_info.is_none_or(|info| info.head)
+    }
+
     pub fn rust_to_c_string(text: &str) -> mlua::Result<CString> {
         CString::new(text).map_err(|e| mlua::Error::runtime(e.to_string()))
     }
@@ -262,21 +323,37 @@ impl Script {
         script_info: Option<&ScriptInfo>,
     ) -> mlua::Result<()> {
         general::set_global   (lua, quiver, status_info, script_info)?;
-        window::set_global    (lua, quiver, status_info, script_info)?;
-        draw::set_global      (lua, quiver, status_info, script_info)?;
-        input::set_global     (lua, quiver, status_info, script_info)?;
-        model::set_global     (lua, quiver, status_info, script_info)?;
-        texture::set_global   (lua, quiver, status_info, script_info)?;
-        image::set_global     (lua, quiver, status_info, script_info)?;
-        sound::set_global     (lua, quiver, status_info, script_info)?;
-        music::set_global     (lua, quiver, status_info, script_info)?;
-        font::set_global      (lua, quiver, status_info, script_info)?;
-        shader::set_global    (lua, quiver, status_info, script_info)?;
+
+        if Self::get_head(status_info, script_info) {
+            window::set_global    (lua, quiver, status_info, script_info)?;
+            draw::set_global      (lua, quiver, status_info, script_info)?;
+            input::set_global     (lua, quiver, status_info, script_info)?;
+            model::set_global     (lua, quiver, status_info, script_info)?;
+            texture::set_global   (lua, quiver, status_info, script_info)?;
+            image::set_global     (lua, quiver, status_info, script_info)?;
+            sound::set_global     (lua, quiver, status_info, script_info)?;
+            music::set_global     (lua, quiver, status_info, script_info)?;
+            font::set_global      (lua, quiver, status_info, script_info)?;
+            shader::set_global    (lua, quiver, status_info, script_info)?;
+            automation::set_global(lua, quiver, status_info, script_info)?;
+            audio::set_global     (lua, quiver, status_info, script_info)?;
+        } else {
+            // remove any head API from the previous pass, if the info manifest has turned head-less mode on.
+            for name in Self::LIST_HEAD {
+                quiver.raw_remove(name)?;
+            }
+        }
+
         file::set_global      (lua, quiver, status_info, script_info)?;
         data::set_global      (lua, quiver, status_info, script_info)?;
+        save::set_global      (lua, quiver, status_info, script_info)?;
+        random::set_global    (lua, quiver, status_info, script_info)?;
+        noise::set_global     (lua, quiver, status_info, script_info)?;
+        navigation::set_global(lua, quiver, status_info, script_info)?;
         socket::set_global    (lua, quiver, status_info, script_info)?;
-        automation::set_global(lua, quiver, status_info, script_info)?;
         collision::set_global (lua, quiver, status_info, script_info)?;
+        locale::set_global    (lua, quiver, status_info, script_info)?;
+        wave::set_global      (lua, quiver, status_info, script_info)?;
 
         #[cfg(feature = "rapier3d")] rapier::set_global (lua, quiver, status_info, script_info)?;
         #[cfg(feature = "zip")]      zip::set_global    (lua, quiver, status_info, script_info)?;
@@ -316,7 +393,7 @@ impl Script {
             .unwrap();
     }
 
-    fn set_environment(lua: &Lua, _status_info: &StatusInfo) -> mlua::Result<mlua::Table> {
+    fn set_environment(lua: &Lua, status_info: &StatusInfo) -> mlua::Result<mlua::Table> {
         // get the global lua table.
         let global = lua.globals();
         // over-load print to use rust's println instead. otherwise, RL will consume the Lua print.
@@ -332,37 +409,8 @@ impl Script {
             })?,
         )?;
 
-        // set the lua package loader to also consider the current game path.
-        //let package = global.get::<mlua::Table>("package")?;
-        //package.set(
-        //    "path",
-        //    format!(
-        //        "{:?};{}/?.lua",
-        //        package.get::<mlua::String>("path")?,
-        //        status_info.path
-        //    ),
-        //)?;
-
-        #[cfg(feature = "embed")]
-        {
-            let package = global.get::<mlua::Table>("package")?;
-            let loader: mlua::Table = package.get("loaders")?;
-            loader.push(lua.create_function(|lua, path: String| {
-                let path = format!("{path}.lua");
-
-                if let Some(asset) = Asset::get(&path) {
-                    if let Ok(asset) = String::from_utf8(asset.data.to_vec()) {
-                        Ok(mlua::Value::Function(lua.load(asset).into_function()?))
-                    } else {
-                        Err(mlua::Error::runtime(format!(
-                            "File '\"{path}\"' did not contain valid UTF-8 data."
-                        )))
-                    }
-                } else {
-                    lua.to_value(&format!("\n\tno file '\"{path}\"' in embed data"))
-                }
-            })?)?;
-        }
+        // set the lua package loader to consider the project root, every search path, every mount and the embed data.
+        Self::set_loader(lua, status_info)?;
 
         // get the global table.
         let global = lua.globals();
@@ -374,6 +422,109 @@ impl Script {
         global.get("quiver")
     }
 
+    fn set_loader(lua: &Lua, status_info: &StatusInfo) -> mlua::Result<()> {
+        let package = lua.globals().get::<mlua::Table>("package")?;
+        let loader: mlua::Table = package.get("loaders")?;
+
+        // the mount table, for every Lua file-system that wants to take part in "require".
+        lua.set_named_registry_value(Self::NAME_MOUNT, lua.create_table()?)?;
+
+        // get the absolute path to the project root, so that a change of the work path will not break "require".
+        let root = Self::get_root(&status_info.path);
+
+        // insert right after the pre-load loader, to take priority over Lua's own file loader.
+        loader.raw_insert(
+            2,
+            lua.create_function(move |lua, name: String| Self::get_module(lua, &root, &name))?,
+        )?;
+
+        Ok(())
+    }
+
+    // find a module by name, returning either the module's chunk or the list of every location tried.
+    fn get_module(lua: &Lua, root: &Path, name: &str) -> mlua::Result<LuaValue> {
+        let name = name.replace('.', "/");
+        let list = [format!("{name}.lua"), format!("{name}/init.lua")];
+        let mut error = String::new();
+
+        // search the project root, and then every search path in the info manifest.
+        for path in Self::get_module_path(lua, root) {
+            for file in &list {
+                let file = path.join(file);
+
+                if file.is_file() {
+                    let data = std::fs::read(&file)?;
+
+                    return Self::get_module_chunk(lua, data, &file.display().to_string());
+                }
+
+                error.push_str(&format!("\n\tno file '{}'", file.display()));
+            }
+        }
+
+        // search every mount.
+        let mount: mlua::Table = lua.named_registry_value(Self::NAME_MOUNT)?;
+
+        for pair in mount.pairs::<LuaValue, mlua::Function>() {
+            let (_, call) = pair?;
+
+            for file in &list {
+                if let Some(data) = call.call::<Option<mlua::String>>(file.as_str())? {
+                    return Self::get_module_chunk(lua, data.as_bytes().to_vec(), file);
+                }
+
+                error.push_str(&format!("\n\tno file '{file}' in mount"));
+            }
+        }
+
+        // search the embed data.
+        #[cfg(feature = "embed")]
+        for file in &list {
+            if let Some(asset) = Asset::get(file) {
+                return Self::get_module_chunk(lua, asset.data.to_vec(), file);
+            }
+
+            error.push_str(&format!("\n\tno file '{file}' in embed data"));
+        }
+
+        lua.to_value(&error)
+    }
+
+    // get the absolute path to the project root.
+    fn get_root(path: &str) -> PathBuf {
+        std::fs::canonicalize(path).unwrap_or_else(|_| PathBuf::from(path))
+    }
+
+    fn get_module_chunk(lua: &Lua, data: Vec<u8>, name: &str) -> mlua::Result<LuaValue> {
+        Ok(LuaValue::Function(
+            lua.load(data)
+                .set_name(format!("@{name}"))
+                .into_function()?,
+        ))
+    }
+
+    // get every path to search a module in: the project root first, then every search path from the info manifest.
+    fn get_module_path(lua: &Lua, root: &Path) -> Vec<PathBuf> {
+        let mut list = vec![root.to_path_buf()];
+
+        if let Some(script_data) = lua.app_data_ref::<ScriptData>() {
+            for path in &script_data.script_info.search {
+                let path = Path::new(path);
+
+                // in safe mode, a search path may not leave the project root.
+                if script_data.status_info.safe
+                    && (path.is_absolute() || path.components().any(|x| x == Component::ParentDir))
+                {
+                    continue;
+                }
+
+                list.push(root.join(path));
+            }
+        }
+
+        list
+    }
+
     #[allow(unused)]
     fn get_main_data(status_info: &StatusInfo) -> mlua::Result<String> {
         #[allow(unused_mut)]
@@ -474,45 +625,27 @@ pub struct ScriptData {
     pub script_info: ScriptInfo,
     pub version: String,
     pub feature: Feature,
-    pub path_escape: bool,
+    pub sandbox: Sandbox,
 }
 
 impl ScriptData {
     pub fn new(status_info: StatusInfo, script_info: ScriptInfo) -> Self {
+        let sandbox = Sandbox::new(&status_info, &script_info);
+
         Self {
             status_info,
             script_info,
             version: Status::VERSION.to_string(),
             feature: Feature::new(),
-            path_escape: false,
+            sandbox,
         }
     }
 
-    #[rustfmt::skip]
-    pub fn get_path(lua: &Lua, path: &str) -> mlua::Result<String> {
+    // resolve a path from Lua through the sand-box.
+    pub fn get_path(lua: &Lua, path: &str, access: SandboxAccess) -> mlua::Result<String> {
         let script_data = lua.app_data_ref::<ScriptData>().unwrap();
 
-        if script_data.status_info.safe {
-            //let path = format!("{}/{path}", script_data.status_info.path);
-
-            // always disallow going up the directory in safe mode.
-            let path = path.replace("../", "");
-            let path = path.replace("..",  "");
-
-            Ok(path)
-        } else if script_data.path_escape {
-            Ok(path.to_string())
-        } else {
-            Ok(format!("{}/{path}", script_data.status_info.path))
-        }
-    }
-
-    pub fn set_path_escape(lua: &Lua, state: bool) -> mlua::Result<()> {
-        let mut script_data = lua.app_data_mut::<ScriptData>().unwrap();
-
-        script_data.path_escape = state;
-
-        Ok(())
+        script_data.sandbox.get_path(path, access)
     }
 }
 
@@ -562,6 +695,8 @@ pub struct ScriptInfo {
     pub mouse_pass: bool,
     #[serde(default = "ScriptInfo::interlace")]
     pub interlace: bool,
+    #[serde(default = "ScriptInfo::search")]
+    pub search: Vec<String>,
 }
 
 #[rustfmt::skip]
@@ -587,6 +722,7 @@ impl ScriptInfo {
     fn msaa()       -> bool           { false }
     fn mouse_pass() -> bool           { false }
     fn interlace()  -> bool           { false }
+    fn search()     -> Vec<String>    { Vec::new() }
 }
 
 #[rustfmt::skip]
@@ -614,6 +750,7 @@ impl Default for ScriptInfo {
             msaa:       Self::msaa(),
             mouse_pass: Self::mouse_pass(),
             interlace:  Self::interlace(),
+            search:     Self::search(),
         }
     }
 }
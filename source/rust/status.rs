@@ -48,6 +48,7 @@
 * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 */
 
+use crate::sandbox::*;
 use crate::script::*;
 use crate::window::*;
 
@@ -97,6 +98,7 @@ impl Status {
                     println!("// -> StatusInfo manifest:");
                     println!("//   * Safe: {}", info.safe);
                     println!("//   * Path: {}", info.path);
+                    println!("//   * Head: {}", script.info.head);
                     println!("//");
                     println!("// -> Feature list:");
 
@@ -151,11 +153,20 @@ impl Status {
 
     // create a RL context.
     pub async fn window(&self) -> Option<(RaylibHandle, RaylibThread, RaylibAudio)> {
-        let info = match self {
-            Self::Success(script) => &script.info,
-            _ => &ScriptInfo::default(),
+        let (info, head) = match self {
+            Self::Success(script) => (&script.info, script.info.head),
+            // no script is available, but head-less mode might still be set from the info file or the command line.
+            _ => (
+                &ScriptInfo::default(),
+                !StatusInfo::new().is_ok_and(|info| info.head_less),
+            ),
         };
 
+        // head-less mode, do not create a window or an audio device.
+        if !head {
+            return None;
+        }
+
         let mut flag: u32 = 0;
 
         if info.sync {
@@ -346,6 +357,10 @@ pub enum InfoResult {
 pub struct StatusInfo {
     pub safe: bool,
     pub path: String,
+    #[serde(default)]
+    pub head_less: bool,
+    #[serde(default)]
+    pub sandbox: SandboxInfo,
 }
 
 impl StatusInfo {
@@ -365,6 +380,7 @@ impl StatusInfo {
             result = Some(Self {
                 safe: true,
                 path: ".".to_string(),
+                ..Default::default()
             });
         }
 
@@ -376,6 +392,7 @@ impl StatusInfo {
             result = Some(Self {
                 safe: true,
                 path: Self::MAIN_PATH.to_string(),
+                ..Default::default()
             });
         }
 
@@ -389,6 +406,7 @@ impl StatusInfo {
                 result = Some(Self {
                     safe: true,
                     path: ".".to_string(),
+                    ..Default::default()
                 });
             }
         }
@@ -416,10 +434,12 @@ impl StatusInfo {
 
         //================================================================
 
+        let mut argument_head = false;
         let mut argument_pick = false;
         let mut argument_ = StatusInfo {
             safe: true,
             path: ".".to_string(),
+            ..Default::default()
         };
         let mut argument_list = std::env::args();
 
@@ -429,6 +449,9 @@ impl StatusInfo {
                     argument_.safe = false;
                     argument_pick = true;
                 }
+                "--head-less" => {
+                    argument_head = true;
+                }
                 "--path" => {
                     if let Some(next) = argument_list.next() {
                         argument_.path = next;
@@ -446,6 +469,12 @@ impl StatusInfo {
             result = Some(argument_);
         }
 
+        // head-less mode can be set on top of any other info source.
+        if argument_head {
+            let result = result.get_or_insert(argument_);
+            result.head_less = true;
+        }
+
         //================================================================
 
         // file does not exist, return missing.
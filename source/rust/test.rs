@@ -48,20 +48,276 @@
 * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 */
 
+use crate::script::*;
+use crate::status::*;
+
+//================================================================
+
+use raylib::prelude::*;
+use serde::Deserialize;
+use std::path::{Path, PathBuf};
+
+//================================================================
+
+#[derive(Deserialize, Clone)]
+pub struct TestCase {
+    pub name: String,
+    pub fail: Option<String>,
+    pub time: f64,
+}
+
+pub struct TestFile {
+    pub path: String,
+    pub case: Vec<TestCase>,
+    pub time: f64,
+}
+
+// the test runner, for running every Lua test file in a folder from the command line.
+pub struct TestInfo {
+    pub path: String,
+    pub junit: Option<String>,
+    pub head_less: bool,
+}
+
+impl TestInfo {
+    // get the test runner info from the command line, if "--test" was given.
+    pub fn new() -> Option<Self> {
+        let mut result: Option<Self> = None;
+        let mut junit = None;
+        let mut head_less = false;
+        let mut argument_list = std::env::args();
+
+        while let Some(argument) = argument_list.next() {
+            match &*argument {
+                "--test" => {
+                    if let Some(next) = argument_list.next() {
+                        result = Some(Self {
+                            path: next,
+                            junit: None,
+                            head_less: false,
+                        });
+                    } else {
+                        eprintln!("ERROR: Was expecting argument for --test.")
+                    }
+                }
+                "--junit" => {
+                    if let Some(next) = argument_list.next() {
+                        junit = Some(next);
+                    } else {
+                        eprintln!("ERROR: Was expecting argument for --junit.")
+                    }
+                }
+                "--head-less" => head_less = true,
+                _ => {}
+            }
+        }
+
+        result.map(|result| Self {
+            junit,
+            head_less,
+            ..result
+        })
+    }
+
+    // run every test file, print out a summary, and write out a JUnit XML report if asked to. returns true if every test case passed.
+    pub async fn run(&self) -> bool {
+        // create a RL context, unless running in head-less mode.
+        let _context = if self.head_less {
+            None
+        } else {
+            let (mut handle, thread) = raylib::init()
+                .title("Quiver - Test")
+                .size(1024, 768)
+                .build();
+
+            handle.set_target_fps(60);
+
+            let audio = RaylibAudio::init_audio_device()
+                .map_err(|e| Status::panic(&e.to_string()))
+                .unwrap();
+
+            Some((handle, thread, audio))
+        };
+
+        let status_info = StatusInfo {
+            safe: true,
+            path: self.path.clone(),
+            head_less: self.head_less,
+            ..Default::default()
+        };
+
+        let mut path_list = Vec::new();
+        Self::get_file(Path::new(&self.path), &mut path_list);
+        path_list.sort();
+
+        let mut file_list = Vec::new();
+
+        for path in path_list {
+            let path = path.display().to_string();
+            let time = std::time::Instant::now();
+
+            let case = match Script::new_test(&path, &status_info).await {
+                Ok(case) => case,
+                // the test file could not even be set up.
+                Err(error) => vec![TestCase {
+                    name: path.clone(),
+                    fail: Some(error.to_string()),
+                    time: 0.0,
+                }],
+            };
+
+            for case in &case {
+                if let Some(fail) = &case.fail {
+                    println!("FAIL: {path}: {}\n\t{fail}", case.name);
+                } else {
+                    println!("PASS: {path}: {}", case.name);
+                }
+            }
+
+            file_list.push(TestFile {
+                path,
+                case,
+                time: time.elapsed().as_secs_f64(),
+            });
+        }
+
+        let total: usize = file_list.iter().map(|x| x.case.len()).sum();
+        let fail: usize = file_list.iter().map(|x| x.get_fail()).sum();
+        let time: f64 = file_list.iter().map(|x| x.time).sum();
+
+        println!("//================================================================");
+        println!(
+            "// {} passed, {fail} failed, in {} file(s) ({time:.3}s).",
+            total - fail,
+            file_list.len()
+        );
+        println!("//================================================================");
+
+        if let Some(junit) = &self.junit {
+            if let Err(error) = std::fs::write(junit, Self::get_junit(&file_list)) {
+                eprintln!("ERROR: Could not write JUnit report \"{junit}\": {error}");
+                return false;
+            }
+        }
+
+        fail == 0
+    }
+
+    // recursively get every Lua file in a folder.
+    fn get_file(path: &Path, list: &mut Vec<PathBuf>) {
+        if path.is_file() {
+            list.push(path.to_path_buf());
+            return;
+        }
+
+        if let Ok(entry_list) = std::fs::read_dir(path) {
+            for entry in entry_list.flatten() {
+                let entry = entry.path();
+
+                if entry.is_dir() {
+                    Self::get_file(&entry, list);
+                } else if entry.extension().is_some_and(|x| x == "lua") {
+                    list.push(entry);
+                }
+            }
+        }
+    }
+
+    fn get_junit(file_list: &[TestFile]) -> String {
+        let total: usize = file_list.iter().map(|x| x.case.len()).sum();
+        let fail: usize = file_list.iter().map(|x| x.get_fail()).sum();
+        let time: f64 = file_list.iter().map(|x| x.time).sum();
+
+        let mut buffer = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+
+        buffer.push_str(&format!(
+            "<testsuites name=\"Quiver\" tests=\"{total}\" failures=\"{fail}\" time=\"{time:.3}\">\n"
+        ));
+
+        for file in file_list {
+            let path = Self::get_escape(&file.path);
+
+            buffer.push_str(&format!(
+                "  <testsuite name=\"{path}\" tests=\"{}\" failures=\"{}\" time=\"{:.3}\">\n",
+                file.case.len(),
+                file.get_fail(),
+                file.time
+            ));
+
+            for case in &file.case {
+                buffer.push_str(&format!(
+                    "    <testcase name=\"{}\" classname=\"{path}\" time=\"{:.3}\"",
+                    Self::get_escape(&case.name),
+                    case.time
+                ));
+
+                if let Some(fail) = &case.fail {
+                    let fail = Self::get_escape(fail);
+
+                    buffer.push_str(&format!(
+                        ">\n      <failure message=\"{fail}\">{fail}</failure>\n    </testcase>\n"
+                    ));
+                } else {
+                    buffer.push_str("/>\n");
+                }
+            }
+
+            buffer.push_str("  </testsuite>\n");
+        }
+
+        buffer.push_str("</testsuites>\n");
+
+        buffer
+    }
+
+    fn get_escape(text: &str) -> String {
+        text.replace('&', "&amp;")
+            .replace('<', "&lt;")
+            .replace('>', "&gt;")
+            .replace('"', "&quot;")
+            .replace('\'', "&apos;")
+    }
+}
+
+impl TestFile {
+    fn get_fail(&self) -> usize {
+        self.case.iter().filter(|x| x.fail.is_some()).count()
+    }
+}
+
+//================================================================
+
 #[cfg(test)]
 mod test_main {
     use crate::script::*;
+    use crate::status::*;
     use raylib::prelude::*;
 
     async fn test_folder(path: &str) {
-        let path_list = std::fs::read_dir(format!("test/system/{path}")).unwrap();
+        let path_list = std::fs::read_dir(format!("test/base/{path}")).unwrap();
+
+        let status_info = StatusInfo {
+            safe: true,
+            path: "test".to_string(),
+            ..Default::default()
+        };
 
         for entry in path_list {
             let entry = entry.unwrap().path().display().to_string();
 
-            if let Err(error) = Script::new_test(&entry).await {
-                println!("Assertion fail or panic in entry: \"{entry}\"");
-                panic!("{error:?}");
+            match Script::new_test(&entry, &status_info).await {
+                Ok(case) => {
+                    for case in case {
+                        if let Some(fail) = case.fail {
+                            println!("Assertion fail or panic in entry: \"{entry}\"");
+                            panic!("{}: {fail}", case.name);
+                        }
+                    }
+                }
+                Err(error) => {
+                    println!("Assertion fail or panic in entry: \"{entry}\"");
+                    panic!("{error:?}");
+                }
             }
         }
     }
@@ -80,10 +336,22 @@ mod test_main {
         // create RL audio context.
         let _audio = RaylibAudio::init_audio_device().unwrap();
 
+        test_folder("audio").await;
+        test_folder("automation").await;
         test_folder("data").await;
         test_folder("file").await;
+        test_folder("font").await;
+        test_folder("general").await;
         test_folder("input").await;
+        test_folder("locale").await;
         test_folder("lua").await;
+        test_folder("music").await;
+        test_folder("navigation").await;
+        test_folder("noise").await;
+        test_folder("random").await;
+        test_folder("save").await;
+        test_folder("shader").await;
+        test_folder("wave").await;
 
         /*
         #[cfg(feature = "rapier3d")]
@@ -96,6 +364,15 @@ mod test_main {
         #[cfg(feature = "request")]
         test_folder("request").await;
 
+        #[cfg(feature = "serialization")]
+        test_folder("serialization").await;
+
+        #[cfg(feature = "codec")]
+        test_folder("codec").await;
+
+        #[cfg(feature = "crypto")]
+        test_folder("crypto").await;
+
         /*
         // NOTE: you MUST have Steam running for this test.
         #[cfg(feature = "steam")]
@@ -0,0 +1,37 @@
+-- The master, music, sfx and voice bus will always exist.
+local master = quiver.audio.get_bus("master")
+local music  = quiver.audio.get_bus("music")
+local voice  = quiver.audio.get_bus("voice")
+
+assert(master.name == "master")
+assert(not pcall(quiver.audio.get_bus, "unknown"))
+
+-- Create a new bus, as a child of the sfx bus.
+local ambient = quiver.audio.new_bus("ambient", quiver.audio.get_bus("sfx"))
+
+ambient.volume = 0.5
+ambient.mute   = true
+
+assert(ambient.volume == 0.5)
+assert(ambient.mute)
+
+-- Making the same bus again (i.e. after a script reload) will give back the same bus, but not with another parent.
+assert(quiver.audio.new_bus("ambient", quiver.audio.get_bus("sfx")).volume == 0.5)
+assert(not pcall(quiver.audio.new_bus, "ambient"))
+
+-- Lower the music whenever the voice bus is playing.
+music:set_duck(voice, 0.75)
+
+-- Muffle every sound, like being underwater.
+local filter = master:add_effect(AUDIO_EFFECT.LOW_PASS, { cutoff = 800.0 })
+
+master:set_effect(filter, { cutoff = 400.0 })
+master:add_effect(AUDIO_EFFECT.REVERB, { room = 0.8, mix = 0.25 })
+master:remove_effect(filter)
+master:clear_effect()
+
+-- Put an audio stream in the ambient bus.
+local stream = quiver.audio.new_stream(44100, 2)
+
+stream:set_bus(ambient)
+stream:set_bus(nil)
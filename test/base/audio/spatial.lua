@@ -0,0 +1,24 @@
+-- Set the listener, looking down the negative Z axis.
+quiver.audio.set_listener(vector_3:old(0.0, 0.0, 0.0), vector_3:old(0.0, 0.0, -1.0), vector_3:old(0.0, 1.0, 0.0))
+
+-- Exaggerate the doppler pitch shift.
+quiver.audio.set_doppler(2.0)
+
+-- Set an audio stream as a spatial source, to the right of the listener.
+local stream = quiver.audio.new_stream(44100, 1)
+
+assert(not pcall(stream.set_spatial_point, stream, vector_3:old(1.0, 0.0, 0.0)))
+
+stream:set_spatial({
+    point        = vector_3:old(4.0, 0.0, 0.0),
+    kind         = ATTENUATION_KIND.LINEAR,
+    distance_max = 32.0,
+})
+
+-- Move the source, with a given velocity.
+stream:set_spatial_point(vector_3:old(8.0, 0.0, 0.0), vector_3:old(4.0, 0.0, 0.0))
+stream:set_pitch(1.5)
+
+assert(not pcall(stream.set_spatial, stream, { kind = 8 }))
+
+stream:set_spatial(nil)
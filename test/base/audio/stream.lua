@@ -0,0 +1,33 @@
+-- Create a mono audio stream, at 44100 Hz.
+local stream = quiver.audio.new_stream(44100, 1)
+
+assert(stream.sample_rate == 44100)
+assert(stream.channel == 1)
+assert(stream.capacity == 44100)
+
+-- Synthesize a 440 Hz sine wave, a tenth of a second long.
+local sample = {}
+
+for i = 1, 4410 do
+    sample[i] = math.sin((i - 1) / 44100 * 440.0 * math.pi * 2.0) * 0.25
+end
+
+assert(stream:push(sample) == 4410)
+assert(stream.queue == 4410)
+
+-- Any sample past the ring buffer capacity will be dropped.
+for _ = 1, 10 do
+    stream:push(sample)
+end
+
+assert(stream.queue == stream.capacity)
+
+stream:clear()
+
+assert(stream.queue == 0)
+
+stream:play()
+
+assert(stream:get_playing())
+
+stream:stop()
@@ -0,0 +1,35 @@
+-- Create a replay, with a fixed random seed and frame time.
+local replay = quiver.automation.new_replay(1234, 1.0 / 60.0)
+
+-- Record the input for a few frames. The frame time will be fixed while recording.
+replay:record()
+
+local value_a = math.random()
+
+for x = 1, 4 do
+    assert(quiver.general.get_frame_time() == replay.frame_time)
+
+    quiver.draw.begin(function() end)
+end
+
+replay:stop()
+
+-- Save the replay, and load it back.
+local path = quiver.file.get_temp_path() .. "/replay.json"
+
+replay:save(path)
+
+local replay = quiver.automation.load_replay(path)
+
+assert(replay.seed == 1234)
+
+-- Play the replay back. The random seed will be the same as when recording, and input will be played back automatically at the end of every frame.
+replay:play()
+
+assert(math.random() == value_a)
+
+while replay:get_play() do
+    quiver.draw.begin(function() end)
+end
+
+replay:stop()
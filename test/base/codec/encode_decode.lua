@@ -0,0 +1,13 @@
+local data = quiver.data.new({ 0, 127, 255 })
+
+-- Encode the data as a hexadecimal string, and decode it back.
+local text = quiver.data.encode(data, ENCODE_KIND.HEX)
+
+assert(text == "007fff")
+assert(quiver.data.decode(text, ENCODE_KIND.HEX):get_buffer()[3] == 255)
+
+-- Encode the data as URL-safe Base64 (no padding), and decode it back.
+local text = quiver.data.encode(data, ENCODE_KIND.BASE64_URL)
+
+assert(text == "AH__")
+assert(quiver.data.decode(text, ENCODE_KIND.BASE64_URL):get_buffer()[2] == 127)
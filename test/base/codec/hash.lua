@@ -0,0 +1,22 @@
+local data = quiver.data.new({ 97, 98, 99 })
+
+-- Hash "abc" with CRC32, MD5 and SHA1. Every digest is a data buffer, and may be turned into a hexadecimal string.
+assert(quiver.data.encode(quiver.data.hash(data, HASH_KIND.CRC32), ENCODE_KIND.HEX) == "352441c2")
+assert(quiver.data.encode(quiver.data.hash(data, HASH_KIND.MD5), ENCODE_KIND.HEX) == "900150983cd24fb0d6963f7d28e17f72")
+assert(quiver.data.encode(quiver.data.hash(data, HASH_KIND.SHA1), ENCODE_KIND.HEX) == "a9993e364706816aba3e25717850c26c9cd0d89d")
+
+-- Hash "abc" with SHA256. The digest is given back as a data buffer.
+local hash = quiver.data.hash(data, HASH_KIND.SHA256)
+
+assert(quiver.data.encode(hash, ENCODE_KIND.HEX) == "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad")
+
+-- BLAKE3 and xxHash work the same way.
+assert(quiver.data.hash(data, HASH_KIND.BLAKE3):get_buffer()[1] ~= nil)
+assert(#quiver.data.hash(data, HASH_KIND.XXHASH):get_buffer() == 8)
+
+-- Sign "abc" with a secret key (HMAC-SHA256). The same key will always give the same code.
+local code_a = quiver.data.encode(quiver.data.hash_hmac(data, "key"), ENCODE_KIND.HEX)
+local code_b = quiver.data.encode(quiver.data.hash_hmac("abc", "key"), ENCODE_KIND.HEX)
+
+assert(code_a == code_b)
+assert(code_a ~= quiver.data.encode(quiver.data.hash_hmac(data, "other"), ENCODE_KIND.HEX))
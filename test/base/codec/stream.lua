@@ -0,0 +1,26 @@
+-- Create a streaming compressor (Zstandard).
+local stream = quiver.data.new_stream(COMPRESS_KIND.ZSTD, true, 9)
+local cursor = quiver.data.new_cursor()
+
+-- Push a few chunks in, keeping every piece of output.
+for x = 1, 16 do
+    cursor:write_data(stream:push(string.rep("quiver", 64)))
+end
+
+cursor:write_data(stream:finish())
+
+-- The stream can not be used after finishing it.
+assert(not pcall(stream.push, stream, "foo"))
+
+-- Decompress all of the output at once, and check the size.
+local data = quiver.data.decompress(cursor:get_data(), COMPRESS_KIND.ZSTD)
+
+assert(#data:get_buffer() == 16 * 64 * 6)
+
+-- LZ4 works the same way.
+local data = quiver.data.compress(data, COMPRESS_KIND.LZ4, 9)
+local stream = quiver.data.new_stream(COMPRESS_KIND.LZ4, false)
+
+stream:push(data)
+
+assert(#stream:finish():get_buffer() == 16 * 64 * 6)
@@ -0,0 +1,21 @@
+-- Derive a key from a passphrase. A random salt will be made for us.
+local key, salt = quiver.data.derive_key("correct horse battery staple")
+
+-- Deriving it again with the same salt will give back the same key.
+local key_b = quiver.data.derive_key("correct horse battery staple", salt)
+
+assert(quiver.data.encode(key, ENCODE_KIND.HEX) == quiver.data.encode(key_b, ENCODE_KIND.HEX))
+
+-- Encrypt some data, with a header that is authenticated but left in plain text.
+local data = quiver.data.encrypt("save data", key, "header")
+
+-- Decrypt it back.
+local text = quiver.data.decrypt(data, key, "header")
+
+assert(quiver.data.from_data(text, 2) == "save data")
+
+-- A wrong key, or a wrong header, will fail to decrypt.
+local key_c = quiver.data.derive_key("wrong passphrase", salt)
+
+assert(not pcall(quiver.data.decrypt, data, key_c, "header"))
+assert(not pcall(quiver.data.decrypt, data, key, "other"))
@@ -0,0 +1,34 @@
+-- Create a cursor over a new, empty data buffer.
+local cursor = quiver.data.new_cursor()
+
+-- Write a few values. The data buffer will grow as needed.
+cursor:write_u16(0xBEEF)
+cursor:write_i32(-64)
+cursor:write_f32(0.5)
+cursor:write_variable(300)
+cursor:write_variable(-2, true)
+cursor:write_string("foo")
+
+-- Go back to the beginning, and read them back.
+cursor:seek(0)
+
+assert(cursor:read_u16() == 0xBEEF)
+assert(cursor:read_i32() == -64)
+assert(cursor:read_f32() == 0.5)
+assert(cursor:read_variable() == 300)
+assert(cursor:read_variable(true) == -2)
+assert(cursor:read_string() == "foo")
+assert(cursor:tell() == cursor:get_length())
+
+-- Mutate the data buffer in-place, in big-endian order.
+cursor:seek(0)
+cursor:set_endian(true)
+cursor:write_u16(0x0102)
+
+assert(cursor:get_data():get_buffer()[1] == 0x01)
+assert(cursor:get_data():get_buffer()[2] == 0x02)
+
+-- Reading past the end of the data buffer is an error.
+cursor:seek()
+
+assert(not pcall(cursor.read_u8, cursor))
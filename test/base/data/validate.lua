@@ -0,0 +1,64 @@
+-- A schema for a list of enemy.
+local schema = {
+    type = "object",
+    required = { "enemy" },
+    properties = {
+        enemy = {
+            type = "array",
+            items = {
+                type = "object",
+                required = { "name", "health" },
+                additionalProperties = false,
+                properties = {
+                    name   = { type = "string", minLength = 1 },
+                    health = { type = "integer", minimum = 1 },
+                    kind   = { enum = { "melee", "range" } },
+                },
+            },
+        },
+    },
+}
+
+-- A valid value.
+local value = quiver.data.deserialize([[
+{
+    "enemy": [
+        { "name": "grunt", "health": 10, "kind": "melee" },
+        { "name": "sniper", "health": 5 }
+    ]
+}
+]])
+
+local valid, error = quiver.data.validate(value, schema)
+
+assert(valid)
+assert(#error == 0)
+
+-- An invalid value. Every error will be qualified with its path.
+local value = quiver.data.deserialize([[
+{
+    "enemy": [
+        { "name": "grunt", "health": 10 },
+        { "name": "", "health": "high", "kind": "magic", "speed": 1 }
+    ]
+}
+]])
+
+local valid, error = quiver.data.validate(value, schema)
+local text = table.concat(error, "\n")
+
+assert(not valid)
+assert(#error == 4)
+assert(text:find("$.enemy[2].name", 1, true))
+assert(text:find("$.enemy[2].health: Expected integer, got string.", 1, true))
+assert(text:find("$.enemy[2].kind", 1, true))
+assert(text:find("$.enemy[2].speed: Unknown value.", 1, true))
+
+-- A null value is nil, and can be matched by either "nil" or "null".
+local value = quiver.data.deserialize([[ { "name": null } ]])
+local valid, error = quiver.data.validate(value, { properties = { name = { type = "string" } } })
+
+assert(not valid)
+assert(error[1] == "$.name: Expected string, got nil.")
+assert(quiver.data.validate(value, { properties = { name = { type = "null" } } }))
+assert(quiver.data.validate(value, { properties = { name = { type = "nil" } } }))
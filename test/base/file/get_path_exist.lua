@@ -1,4 +1,10 @@
--- Write "123" to the file "foo.txt". Since we don't want to write binary, pass false as the third argument.
-quiver.file.set("work/foo.txt", "123", false)
+-- Create the path "foo", in the temporary path.
+local path = quiver.file.get_temp_path() .. "/foo"
 
-assert(quiver.file.get_file_exist("work/foo.txt"))
+quiver.file.create_path(path)
+
+assert(quiver.file.get_path_exist(path))
+
+quiver.file.remove_path(path)
+
+assert(not quiver.file.get_path_exist(path))
@@ -0,0 +1,9 @@
+-- Get the per-user save path. Quiver will create the path if missing.
+local path = quiver.file.get_save_path()
+
+assert(quiver.file.get_path_exist(path))
+
+-- Write a file to the save path. The save path is part of the sand-box, even in safe mode.
+quiver.file.set_file(path .. "/foo.txt", "123")
+
+assert(quiver.file.get_file(path .. "/foo.txt", false) == "123")
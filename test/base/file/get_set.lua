@@ -1,7 +1,11 @@
--- Write "123" to the file "foo.txt". Since we don't want to write binary, pass false as the third argument.
-quiver.file.set("work/foo.txt", "123", false)
+-- Write "123" to the file "foo.txt", in the temporary path.
+local path = quiver.file.get_temp_path() .. "/foo.txt"
 
--- Read the data back. Again, since we know the file isn't binary, we pass false.
-local data = quiver.file.get("work/foo.txt", false)
+quiver.file.set_file(path, "123")
+
+-- Read the data back. Since we know the file isn't binary, we pass false.
+local data = quiver.file.get_file(path, false)
 
 assert(data == "123")
+
+quiver.file.remove_file(path)
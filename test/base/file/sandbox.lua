@@ -0,0 +1,9 @@
+-- A relative path is always relative to the project root, and a path may not leave the project root by going up the directory.
+local success = pcall(quiver.file.get_file, "../foo.txt", false)
+
+assert(not success)
+
+-- An absolute path outside of every sand-box root (project, save, temp) will also be refused.
+local success = pcall(quiver.file.get_file, "/foo.txt", false)
+
+assert(not success)
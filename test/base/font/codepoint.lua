@@ -0,0 +1,42 @@
+-- Load the default font, with only the printable ASCII range.
+local font = quiver.font.new_default(32)
+
+assert(font:has_codepoint("Hello"))
+assert(not font:has_codepoint("é"))
+
+-- Rasterize a glyph into the glyph atlas ahead of time...
+font:load_codepoint("é")
+
+assert(font:has_codepoint("é"))
+
+-- ...or let it be rasterized on demand, the first time it is measured or drawn.
+font:measure_text("ñ", 32.0, 1.0)
+
+assert(font:has_codepoint(0xF1))
+
+-- Load a font with a whole string table, and a codepoint range.
+local font = quiver.font.new_default(32, {
+    greeting = "¡Hola!",
+    farewell = "Adiós",
+    { first = 0xC0, last = 0xFF },
+})
+
+assert(font:has_codepoint({ "¡Hola!", "Adiós", 0xC0, 0xFF }))
+
+-- A glyph the font file does not have will be taken from a fallback font, if any.
+local fallback = quiver.font.new_default(16)
+
+font:set_fallback({ fallback })
+
+local size_x, size_y = font:measure_text("Hola\nAdiós", 32.0, 1.0)
+
+assert(size_x > 0.0 and size_y > 32.0)
+
+-- A codepoint range is clamped to valid codepoints, and a very large one is an error.
+assert(not pcall(quiver.font.new_default, 32, { first = 0, last = 0x7FFFFFFF }))
+
+-- The limit is for the whole set, not for each range in it...
+assert(not pcall(quiver.font.new_default, 32, { { first = 0x0000, last = 0xFFFF }, { first = 0x10000, last = 0x1FFFF } }))
+
+-- ...and for the whole glyph atlas, so a font can not grow past it either.
+assert(not pcall(font.load_codepoint, font, { first = 0x80, last = 0x1007F }))
@@ -0,0 +1,35 @@
+-- Lay out a text with in-line markup, wrapped to a width.
+local font = quiver.font.new_default(10)
+local coin = quiver.image.new_color({ x = 16.0, y = 16.0 }, { r = 255, g = 192, b = 0, a = 255 }):to_texture()
+
+local layout = quiver.font.new_layout(font,
+    "Hello, [color=#FF0000]red[/color] [b]world[/b]! [image=coin] [wave]wave[/wave] [shake]shake[/shake]\nA new line, with a [[literal] bracket.", {
+        width = 96.0,
+        align = TEXT_ALIGN.JUSTIFY,
+        bold  = font,
+        image = { coin = coin },
+    })
+
+assert(layout.line_count > 2)
+assert(layout.size_x <= 96.0)
+
+-- Every glyph has a rectangle, for hit-testing.
+local glyph = layout:get_glyph(1)
+
+assert(glyph.text == "H")
+assert(glyph.line == 1)
+assert(layout:get_glyph_at({ x = glyph.x + 1.0, y = glyph.y + 1.0 }) == 1)
+assert(layout:get_glyph_at({ x = -1.0, y = -1.0 }) == nil)
+
+-- Draw only the first half of the layout, for a typewriter effect.
+layout:draw({ x = 8.0, y = 8.0 }, { r = 255, g = 255, b = 255, a = 255 }, 1.0, math.floor(layout.count / 2))
+
+-- An unknown image is an error.
+assert(not pcall(quiver.font.new_layout, font, "[image=none]"))
+
+-- A line-break inside a bracket that is not a tag is still a line-break.
+assert(quiver.font.new_layout(font, "[not\na tag]").line_count == 2)
+
+-- A close tag with no matching open tag is kept as-is.
+assert(quiver.font.new_layout(font, "a[/b]").count == 5)
+assert(quiver.font.new_layout(font, "[b]a[/foo][/b]").count == 7)
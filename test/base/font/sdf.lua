@@ -0,0 +1,18 @@
+-- Load the default font as a signed distance field, to draw it at any scale.
+local font = quiver.font.new_default(32, nil, FONT_MODE.SDF)
+
+assert(font.mode == FONT_MODE.SDF)
+
+-- Draw the font at a large scale, with an outline, a glow and a drop-shadow.
+font:draw("Hello, world!", { x = 8.0, y = 8.0 }, { x = 0.0, y = 0.0 }, 0.0, 96.0, 1.0, { r = 255, g = 255, b = 255, a = 255 }, {
+    outline       = 1.0,
+    outline_color = { r = 0, g = 0, b = 0, a = 255 },
+    glow          = 1.0,
+    glow_color    = { r = 255, g = 192, b = 0, a = 128 },
+    shadow        = { x = 4.0, y = 4.0 },
+})
+
+-- On-demand glyph caching will also work on a SDF font.
+font:measure_text("ñ", 96.0, 1.0)
+
+assert(font:has_codepoint("ñ"))
@@ -0,0 +1,18 @@
+-- Only available in head-less mode ("--head-less", or "head_less" in the info file).
+if quiver.general.tick then
+    local time = quiver.general.get_time()
+
+    -- Run four ticks of the main loop. Each one will wait until the next tick, at the frame-rate from the info manifest.
+    for x = 1, 4 do
+        local frame_time = quiver.general.tick()
+
+        assert(frame_time > 0.0)
+        assert(quiver.general.get_frame_time() == frame_time)
+    end
+
+    assert(quiver.general.get_time() > time)
+
+    -- The head API will not be available at all.
+    assert(quiver.window == nil)
+    assert(quiver.draw == nil)
+end
@@ -0,0 +1,73 @@
+-- Create a locale, with English as the fallback language.
+local locale = quiver.locale.new("es-MX", "en")
+
+-- Load a string table, such as one from quiver.data.deserialize.
+locale:load("en", quiver.data.deserialize([[
+{
+    "menu": { "play": "Play", "quit": "Quit" },
+    "greet": "Hello, {name}!",
+    "apple": { "one": "{count} apple", "other": "{count} apples" }
+}
+]]))
+
+-- Load a gettext .po file.
+locale:load_po("es", [[
+msgid ""
+msgstr ""
+"Language: es\n"
+
+msgctxt "menu"
+msgid "play"
+msgstr "Jugar"
+
+msgid "greet"
+msgstr "¡Hola, {name}!"
+
+msgid "apple"
+msgid_plural "apples"
+msgstr[0] "{count} manzana"
+msgstr[1] "{count} manzanas"
+]])
+
+-- es-MX will fall back to its base language, es.
+assert(locale:get("menu.play") == "Jugar")
+assert(locale:get("greet", { name = "Ana" }) == "¡Hola, Ana!")
+assert(locale:get("apple", { count = 1 }) == "1 manzana")
+assert(locale:get("apple", { count = 3 }) == "3 manzanas")
+
+-- A missing key will fall back to English, and then to the key itself.
+assert(locale:get("menu.quit") == "Quit")
+assert(locale:get("unknown") == "unknown")
+assert(not locale:has("menu.quit"))
+
+local missing = locale:get_missing()
+
+assert(#missing == 2)
+assert(missing[1] == "menu.quit")
+assert(missing[2] == "unknown")
+
+-- Plural rule will follow the language.
+locale.language = "en"
+
+assert(locale:get("apple", { count = 1 }) == "1 apple")
+assert(locale:get("apple", { count = 0 }) == "0 apples")
+
+-- Romanian will use "few" for 0, and for 2 to 19 (and 101 to 119, etc.).
+locale:load("ro", { apple = { one = "{count} măr", few = "{count} mere", other = "{count} de mere" } })
+locale.language = "ro"
+
+assert(locale:get("apple", { count = 1 }) == "1 măr")
+assert(locale:get("apple", { count = 101 }) == "101 mere")
+assert(locale:get("apple", { count = 20 }) == "20 de mere")
+
+-- A non-ASCII part will never be taken as a script.
+assert(quiver.locale.new("en-éé").language == "en-ÉÉ")
+
+assert(type(quiver.locale.get_system()) == "string")
+
+-- A plural index past the last plural category is an error.
+assert(not pcall(locale.load_po, locale, "es", [[
+msgid "apple"
+msgid_plural "apples"
+msgstr[99999999999] "{count} manzanas"
+]]))
@@ -1,21 +1,26 @@
 -- Create a scheduler.
 local i = scheduler:new()
+local step = 0
 
 -- Insert a new function by a given name. Name is optional.
 i:insert(function()
-    local time = quiver.general.get_time()
+    step = 1
 
-    print("instance " .. time)
+    -- Yield back to the scheduler, i.e. to wait on a request or a timer.
+    coroutine.yield()
 
-    local data = quiver.request.get("http://httpbin.org/ip")
-
-    print("instance " .. time .. " done.")
+    step = 2
 end, "routine_1")
 
 -- Resume scheduler. This would typically be done in your main game loop.
 i:resume()
 
 -- Check if the co-routine is still alive.
-if i.routine["routine_1"] then
-    quiver.draw_2d.draw_text("Running co-routine...", vector_2:old(8.0, 128.0), 32.0, color:white())
-end
+assert(step == 1)
+assert(i.routine["routine_1"])
+
+-- Resume it once more, to let it finish. A dead co-routine will be removed.
+i:resume()
+
+assert(step == 2)
+assert(i.routine["routine_1"] == nil)
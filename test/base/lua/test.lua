@@ -0,0 +1,27 @@
+-- Only available when running Quiver with "--test <folder>". Every Lua file in the folder will run in its own Lua VM.
+-- Use "--head-less" to run without a window or audio device, and "--junit <file>" to write out a JUnit XML report.
+
+describe("vector_2", function()
+    it("can add", function()
+        local value = vector_2:old(1.0, 2.0) + vector_2:old(3.0, 4.0)
+
+        expect(value.x):equal(4.0)
+        expect(value.y):equal(6.0)
+    end)
+
+    it("can get the length", function()
+        expect(vector_2:old(1.0, 1.0):magnitude()):near(1.4142, 0.001)
+    end)
+end)
+
+describe("data", function()
+    it("can not deserialize bad JSON", function()
+        expect(function()
+            quiver.data.deserialize("{")
+        end):error()
+    end)
+
+    it("can compare tables by content", function()
+        expect(quiver.data.deserialize("[1, 2, 3]")):equal({ 1, 2, 3 })
+    end)
+end)
@@ -0,0 +1,53 @@
+-- Synthesize a short music track, by exporting a wave.
+local sample = {}
+
+for i = 1, 44100 do
+    sample[i] = math.sin((i - 1) / 44100 * 220.0 * math.pi * 2.0) * 0.25
+end
+
+local path = quiver.file.get_temp_path() .. "/music.wav"
+
+quiver.wave.new_from_data(sample, 44100, 1):export(path)
+
+local intro = quiver.music.new(path)
+local theme = quiver.music.new(path)
+
+-- Create a music player, with a half-second cross-fade by default.
+local player = quiver.music.new_player(0.5)
+
+assert(player.fade == 0.5)
+assert(player:get_current() == nil)
+
+-- Play the intro, then queue the theme, looping from 0.25 seconds in.
+player:queue(intro)
+player:queue(theme, { loop_start = 0.25, fade = 0.1 })
+
+assert(player:get_current() == intro)
+assert(player.count == 1)
+assert(not pcall(player.queue, player, theme, { loop_start = 0.5, loop_end = 0.25 }))
+assert(not pcall(player.play, player, player))
+
+-- Move on to the theme right away.
+player:next()
+
+assert(player:get_current() == theme)
+assert(player.count == 0)
+
+player.volume = 0.5
+player:pause()
+
+assert(player:get_pause())
+
+player:resume()
+player:stop(0.0)
+
+assert(player:get_current() == nil)
+
+-- Unload every track before removing the file, as the music stream will keep it open.
+player = nil
+intro = nil
+theme = nil
+
+collectgarbage()
+
+quiver.file.remove_file(path)
@@ -0,0 +1,35 @@
+-- Create a 16x16 grid, with a wall in the middle.
+local grid = quiver.navigation.new_grid(16, 16)
+
+for y = 0, 12 do
+    grid:set_cost(8, y, 0.0)
+end
+
+-- Find a path around the wall.
+local path = grid:find_path({ x = 0, y = 0 }, { x = 15, y = 0 })
+
+assert(path)
+assert(path[1].x == 0 and path[1].y == 0)
+assert(path[#path].x == 15 and path[#path].y == 0)
+
+-- Jump point search will find a path of the same length, on an uniform-cost grid.
+local jump = grid:find_path({ x = 0, y = 0 }, { x = 15, y = 0 }, true)
+
+assert(#jump == #path)
+
+-- Make a cell more expensive to walk into. Without diagonal movement, the path will go around it.
+grid.diagonal = DIAGONAL_RULE.NEVER
+grid:set_cost(8, 13, 100.0)
+
+local path = grid:find_path({ x = 0, y = 15 }, { x = 15, y = 15 })
+
+for _, cell in ipairs(path) do
+    assert(not (cell.x == 8 and cell.y == 13))
+end
+
+-- Close the wall off. There is no path.
+grid:set_cost(8, 13, 0.0)
+grid:set_cost(8, 14, 0.0)
+grid:set_cost(8, 15, 0.0)
+
+assert(grid:find_path({ x = 0, y = 0 }, { x = 15, y = 0 }) == nil)
@@ -0,0 +1,47 @@
+-- Make an L-shaped floor out of three quad, with two triangle each.
+local point = {
+    { x = 0.0, y = 0.0, z = 0.0 }, { x = 4.0, y = 0.0, z = 0.0 }, { x = 8.0, y = 0.0, z = 0.0 },
+    { x = 0.0, y = 0.0, z = 4.0 }, { x = 4.0, y = 0.0, z = 4.0 }, { x = 8.0, y = 0.0, z = 4.0 },
+    { x = 0.0, y = 0.0, z = 8.0 }, { x = 4.0, y = 0.0, z = 8.0 },
+}
+
+-- Every triangle is counter-clockwise, when seen from above.
+local index = {
+    0, 3, 4, 0, 4, 1,
+    1, 4, 5, 1, 5, 2,
+    3, 6, 7, 3, 7, 4,
+}
+
+local mesh = quiver.navigation.new_mesh(point, index)
+
+assert(mesh.triangle_count == 6)
+
+-- An index count that is not a multiple of 3 is an error.
+assert(not pcall(quiver.navigation.new_mesh, point, { 0, 3 }))
+
+-- Find a path around the inner corner of the L.
+local path = mesh:find_path({ x = 7.0, y = 0.0, z = 3.0 }, { x = 3.0, y = 0.0, z = 7.0 })
+
+assert(path)
+assert(#path == 3)
+assert(path[2].x == 4.0 and path[2].z == 4.0)
+
+-- Any point off the navigation mesh will be moved to the closest point on it.
+local point = mesh:get_closest({ x = 6.0, y = 2.0, z = 6.0 })
+
+assert(point.y == 0.0)
+
+-- Move an agent to the other side of the L.
+local crowd = quiver.navigation.new_crowd(mesh)
+local agent = crowd:add_agent({ x = 7.0, y = 0.0, z = 1.0 }, 0.5, 4.0)
+
+assert(crowd:set_target(agent, { x = 1.0, y = 0.0, z = 7.0 }))
+
+for x = 1, 600 do
+    crowd:update(1.0 / 60.0)
+end
+
+local point, _, active = crowd:get_agent(agent)
+
+assert(not active)
+assert(math.abs(point.x - 1.0) < 0.1 and math.abs(point.z - 7.0) < 0.1)
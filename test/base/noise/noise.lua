@@ -0,0 +1,45 @@
+-- Create two generators with the same seed. They will give back the same value.
+local noise_a = quiver.noise.new(NOISE_KIND.SIMPLEX, 1234)
+local noise_b = quiver.noise.new(NOISE_KIND.SIMPLEX, 1234)
+
+assert(noise_a:get(0.5, 1.5) == noise_b:get(0.5, 1.5))
+
+-- Sample the noise in 1D, 2D, 3D or 4D.
+for _, value in ipairs({
+    noise_a:get(0.25),
+    noise_a:get(0.25, 0.5),
+    noise_a:get(0.25, 0.5, 0.75),
+    noise_a:get(0.25, 0.5, 0.75, 1.0),
+}) do
+    assert(value >= -1.0 and value <= 1.0)
+end
+
+-- Set up a ridged fractal, with domain warping.
+noise_a.frequency  = 0.05
+noise_a.fractal    = FRACTAL_KIND.RIDGED
+noise_a.octave     = 5
+noise_a.lacunarity = 2.0
+noise_a.gain       = 0.5
+noise_a.warp       = 0.5
+
+-- Fill a 64x32 grid with the noise, for a height-map.
+local grid = noise_a:get_grid({ 64, 32 }, { 100.0, 100.0 })
+
+assert(grid:get_length() == 64 * 32)
+
+-- Every cell of the grid matches a single sample.
+assert(math.abs(grid:get_buffer()[2] - noise_a:get(101.0, 100.0)) < 0.0001)
+
+-- An empty or very large shape is an error.
+assert(not pcall(noise_a.get_grid, noise_a, { 64, 0 }))
+assert(not pcall(noise_a.get_grid, noise_a, { 65536, 65536 }))
+
+-- Worley (cellular) noise, in 3D.
+local noise_c = quiver.noise.new(NOISE_KIND.WORLEY)
+
+assert(noise_c:get(1.0, 2.0, 3.0) >= -1.0)
+
+-- A non-finite coordinate is an error, but a very large one is still sampled.
+assert(not pcall(noise_a.get, noise_a, math.huge))
+assert(not pcall(noise_a.get_grid, noise_a, { 4 }, { 0.0 / 0.0 }))
+assert(noise_c:get(1e300, -1e300, 1e300) >= -1.0)
@@ -0,0 +1,39 @@
+-- Create two generators with the same seed. They will give back the same sequence.
+local random_a = quiver.random.new(1234)
+local random_b = quiver.random.new(1234)
+
+for x = 1, 8 do
+    assert(random_a:get_integer(1, 100) == random_b:get_integer(1, 100))
+end
+
+-- Save the state, and restore it later to repeat the sequence.
+local state = random_a:get_state()
+local value = random_a:get_number()
+
+random_a:set_state(state)
+
+assert(random_a:get_number() == value)
+
+-- Give every system its own generator, forked from a single one.
+local random_c = random_a:fork(1)
+
+assert(random_c:get_number(-1.0, 1.0) >= -1.0)
+
+-- Pick a value from a list, with a weight for each one.
+local value, index = random_a:get_choice({ "common", "rare" }, { 0.9, 0.1 })
+
+assert(value == "common" or value == "rare")
+
+-- Shuffle a list, in-place.
+local list = random_a:shuffle({ 1, 2, 3, 4, 5 })
+
+assert(#list == 5)
+
+-- Get a point inside of a circle, and on the surface of a sphere.
+local x, y = random_a:get_circle(2.0)
+
+assert(x * x + y * y <= 4.0)
+
+local x, y, z = random_a:get_sphere()
+
+assert(math.abs(x * x + y * y + z * z - 1.0) < 0.0001)
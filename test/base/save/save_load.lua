@@ -0,0 +1,66 @@
+-- Create a save slot handle, at version 2. It will be in the save path, and will keep 2 back-up saves.
+local save = quiver.save.new("slot_1", 2, 2)
+
+-- Set the migration from version 1 to version 2, in case we load an older save.
+save:set_migration(1, function(data)
+    data.health = data.health or 100
+    return data
+end)
+
+-- Write the save.
+save:save({ level = 4, health = 50 })
+
+-- Read the save back.
+local data = save:load()
+
+assert(data.level == 4)
+assert(data.health == 50)
+
+-- Remove the save slot, and every back-up.
+save:remove()
+
+assert(not save:get_exist())
+
+-- Write a save at version 1, with no health field.
+local save_old = quiver.save.new("slot_2", 1)
+
+save_old:save({ level = 1 })
+
+-- Load it back with a version 2 slot: the migration will be run.
+local save_new = quiver.save.new("slot_2", 2)
+
+save_new:set_migration(1, function(data)
+    data.health = data.health or 100
+    return data
+end)
+
+data = save_new:load()
+
+assert(data.level == 1)
+assert(data.health == 100)
+
+-- Without the migration from version 1, the load will fail.
+local save_bad = quiver.save.new("slot_2", 2)
+
+local success, result = pcall(save_bad.load, save_bad)
+
+assert(not success)
+assert(tostring(result):find("No migration from version 1"))
+
+save_new:remove()
+
+-- Write two saves: the first one will be rotated into the back-up list.
+save:save({ level = 5 })
+save:save({ level = 6 })
+
+-- Corrupt the latest save.
+quiver.file.set_file(quiver.file.get_save_path() .. "/slot_1", "corrupt")
+
+-- Load will fall back to the most recent back-up.
+data = save:load()
+
+assert(data.level == 5)
+
+save:remove()
+
+assert(not save:get_exist())
@@ -0,0 +1,28 @@
+-- Serialize a table with a data buffer in it to MessagePack. The result is a data buffer.
+local data = quiver.data.serialize({
+    name = "foo",
+    size = 4,
+    blob = quiver.data.new({ 255, 0, 255, 0 }),
+}, FORMAT_KIND.MSGPACK)
+
+-- Deserialize it back. The data buffer will be given back as a data buffer.
+local data = quiver.data.deserialize(data, FORMAT_KIND.MSGPACK)
+
+assert(data.name == "foo")
+assert(data.size == 4)
+assert(data.blob:get_buffer()[1] == 255)
+
+-- CBOR works the same way.
+local data = quiver.data.deserialize(quiver.data.serialize({ 1, 2, 3 }, FORMAT_KIND.CBOR), FORMAT_KIND.CBOR)
+
+assert(data[3] == 3)
+
+-- A recursive table is an error, but a table used twice is not.
+local loop = {}
+local leaf = { 1 }
+
+loop.loop = loop
+
+assert(not pcall(quiver.data.serialize, loop, FORMAT_KIND.MSGPACK))
+assert(not pcall(quiver.data.serialize, loop, FORMAT_KIND.CBOR))
+assert(quiver.data.serialize({ a = leaf, b = leaf }, FORMAT_KIND.CBOR))
@@ -0,0 +1,53 @@
+-- A fragment shader, with a uniform of every kind.
+local shader = quiver.shader.new_from_memory(nil, [[
+#version 330
+
+in vec2 fragTexCoord;
+
+uniform float time;
+uniform vec2 offset;
+uniform vec4 tint;
+uniform ivec2 cell;
+uniform bool invert;
+uniform float weight[3];
+uniform mat4 transform;
+uniform sampler2D mask;
+
+out vec4 finalColor;
+
+void main()
+{
+    vec4 value = texture(mask, fragTexCoord + offset) * tint * transform;
+    value.rgb *= weight[0] + weight[1] + weight[2] + float(cell.x + cell.y) + time;
+
+    finalColor = invert ? vec4(1.0) - value : value;
+}
+]])
+
+-- Set each uniform by name, with the kind inferred from the value.
+shader:set_uniform("time", 1.0)
+shader:set_uniform("offset", vector_2:old(0.5, 0.5))
+shader:set_uniform("tint", { r = 255, g = 128, b = 0, a = 255 })
+shader:set_uniform("cell", vector_2:old(4, 8), true)
+shader:set_uniform("invert", false)
+shader:set_uniform("weight", { 0.25, 0.5, 0.25 })
+shader:set_uniform("transform", matrix:old(
+    1.0, 0.0, 0.0, 0.0,
+    0.0, 1.0, 0.0, 0.0,
+    0.0, 0.0, 1.0, 0.0,
+    0.0, 0.0, 0.0, 1.0
+))
+shader:set_uniform("mask", quiver.image.new_color({ x = 4.0, y = 4.0 }, { r = 255, g = 255, b = 255, a = 255 }):to_texture())
+
+assert(shader:get_uniform_location("time") >= 0)
+
+-- A uniform that does not exist will be ignored.
+assert(shader:get_uniform_location("unknown") == -1)
+shader:set_uniform("unknown", 1.0)
+
+-- Every array element must be of the same kind.
+assert(not pcall(shader.set_uniform, shader, "weight", { 1.0, vector_2:old(1.0, 1.0) }))
+assert(not pcall(shader.set_uniform, shader, "time", "string"))
+
+-- Source code that does not compile is an error.
+assert(not pcall(quiver.shader.new_from_memory, nil, "not a shader"))
@@ -0,0 +1,45 @@
+-- Synthesize a 1000 Hz sine wave at half-scale, one second long.
+local sample = {}
+
+for i = 1, 48000 do
+    sample[i] = math.sin((i - 1) / 48000 * 1000.0 * math.pi * 2.0) * 0.5
+end
+
+local wave = quiver.wave.new_from_data(sample, 48000, 1)
+
+assert(wave.frame_count == 48000)
+assert(wave.length == 1.0)
+
+-- The peak of a sine wave is its amplitude, and the RMS is the amplitude over the square root of two.
+assert(math.abs(wave:get_peak() - 0.5) < 0.001)
+assert(math.abs(wave:get_rms() - 0.5 / math.sqrt(2.0)) < 0.001)
+
+-- With a 4096 FFT size, each bin is 48000 / 4096 Hz wide: 1000 Hz will be close to bin 85.
+local spectrum = wave:get_spectrum(0, 4096)
+
+assert(#spectrum == 2048)
+assert(spectrum[86] > spectrum[40])
+assert(not pcall(wave.get_spectrum, wave, 0, 1000))
+
+-- Convert to stereo, at half the sample rate, then keep the first half.
+wave:set_channel(2)
+wave:resample(24000)
+wave:crop(0, 12000)
+
+assert(wave.channel == 2)
+assert(wave.sample_rate == 24000)
+assert(wave.frame_count == 12000)
+assert(wave:get_data():get_length() == 24000)
+assert(not pcall(wave.crop, wave, 0, 48000))
+
+-- Export to the temporary path, then load it back.
+local path = quiver.file.get_temp_path() .. "/wave.wav"
+
+wave:export(path)
+
+local load = quiver.wave.new(path)
+
+assert(load.frame_count == 12000)
+assert(load.channel == 2)
+
+quiver.file.remove_file(path)